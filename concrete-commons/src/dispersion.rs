@@ -69,6 +69,7 @@ pub trait DispersionParameter: Copy {
 /// let modular_params = LogStandardDev::from_modular_log_standard_dev::<u32>(22.);
 /// assert_eq!(modular_params.get_standard_dev(), 2_f64.powf(-10.));
 /// ```
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct LogStandardDev(pub f64);
 
@@ -202,6 +203,7 @@ impl DispersionParameter for StandardDev {
 ///     2_f64.powf(32. - 25.).powi(2)
 /// );
 /// ```
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Variance(pub f64);
 
@@ -182,3 +182,45 @@ pub struct LutCountLog(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct ModulusSwitchOffset(pub usize);
+
+/// The modulus of the integers in which the coefficients of a ciphertext live.
+///
+/// With the `Native` modulus, the coefficients live modulo $2^n$, where $n$ is the number of bits
+/// of the integer type used to store them, and are operated on with wrapping arithmetic. With a
+/// `Custom` modulus $q$, the coefficients are kept in $[0, q)$, which allows to use prime or
+/// NTT-friendly moduli.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub enum CiphertextModulus {
+    #[default]
+    Native,
+    Custom(u128),
+}
+
+impl CiphertextModulus {
+    /// Returns whether the modulus is the native modulus of the integer type.
+    pub fn is_native(&self) -> bool {
+        matches!(self, CiphertextModulus::Native)
+    }
+
+    /// Returns the custom modulus $q$, if any.
+    pub fn get_custom_modulus(&self) -> Option<u128> {
+        match self {
+            CiphertextModulus::Native => None,
+            CiphertextModulus::Custom(modulus) => Some(*modulus),
+        }
+    }
+
+    /// Returns whether the modulus can be used with an integer type of `integer_bits` bits.
+    ///
+    /// A custom modulus must be at least two, and strictly smaller than $2^n$ where $n$ is the
+    /// number of bits of the integer type.
+    pub fn is_compatible_with_bits(&self, integer_bits: usize) -> bool {
+        match self {
+            CiphertextModulus::Native => true,
+            CiphertextModulus::Custom(modulus) => {
+                *modulus >= 2 && (integer_bits >= 128 || *modulus < (1u128 << integer_bits))
+            }
+        }
+    }
+}
@@ -19,6 +19,10 @@
 //! the benchmarked device. Depending on the available memory, this could be problematic. To
 //! accomodate this situation, the input parameter `batch_size` can be used to provide a different
 //! batch size.
+//!
+//! The duration of an execution measured in every timing loop is also stored in a [`Recorder`],
+//! which allows to write the results of the benchmarks to a file.
+use crate::record::{Record, Recorder};
 use concrete_core::prelude::AbstractEngine;
 use concrete_core_fixture::fixture::Fixture;
 use concrete_core_fixture::generation::{IntegerPrecision, Maker};
@@ -40,6 +44,7 @@ pub trait BenchmarkFixture<Precision: IntegerPrecision, Engine: AbstractEngine,
         maker: &mut Maker,
         engine: &mut Engine,
         criterion: &mut Criterion,
+        recorder: &mut Recorder,
         batch_size: Option<u64>,
     ) {
         let mut group = criterion.benchmark_group(format!(
@@ -50,7 +55,7 @@ pub trait BenchmarkFixture<Precision: IntegerPrecision, Engine: AbstractEngine,
             type_name::<RelatedEntities>()
         ));
         for params in Self::generate_parameters_iterator() {
-            Self::bench(maker, engine, params, &mut group, batch_size);
+            Self::bench(maker, engine, params, &mut group, recorder, batch_size);
         }
         group.finish();
     }
@@ -61,9 +66,13 @@ pub trait BenchmarkFixture<Precision: IntegerPrecision, Engine: AbstractEngine,
         engine: &mut Engine,
         parameters: Self::Parameters,
         fixture_group: &mut BenchmarkGroup<WallTime>,
+        recorder: &mut Recorder,
         batch_size: Option<u64>,
     ) {
         let batch_size = batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+        let formatted_parameters = format!("{:?}", parameters);
+        // The duration of an execution, in nanoseconds, measured by every timing loop.
+        let mut samples = Vec::new();
 
         // We generate the prototypes once (used for all repetitions of the benchmark).
         let repetition_proto = Self::generate_random_repetition_prototypes(&parameters, maker);
@@ -74,7 +83,7 @@ pub trait BenchmarkFixture<Precision: IntegerPrecision, Engine: AbstractEngine,
         // pre-context is passed by value, and the post-context must be gathered to be
         // properly disposed afterward.
         fixture_group.bench_with_input(
-            BenchmarkId::from_parameter(format!("{:?}", parameters)),
+            BenchmarkId::from_parameter(formatted_parameters.as_str()),
             &(repetition_proto, sample_proto),
             |b, (repetition_proto, sample_proto)| {
                 b.iter_custom(|iterations_to_go| {
@@ -118,10 +127,27 @@ pub trait BenchmarkFixture<Precision: IntegerPrecision, Engine: AbstractEngine,
                         })
                     }
 
+                    if iterations_to_go > 0 {
+                        samples.push(duration.as_nanos() as f64 / iterations_to_go as f64);
+                    }
                     duration
                 })
             },
         );
+
+        // No sample is taken when the benchmark is filtered out.
+        if let Some(record) = Record::from_samples(
+            type_name::<Self>().to_string(),
+            type_name::<Precision>()
+                .trim_start_matches("Precision")
+                .to_string(),
+            type_name::<Engine>().to_string(),
+            short_type_name::<RelatedEntities>(),
+            formatted_parameters,
+            samples,
+        ) {
+            recorder.push(record);
+        }
     }
 }
 
@@ -134,6 +160,23 @@ where
 {
 }
 
+/// A function returning the name of a type, in which the paths of all the types are removed.
+fn short_type_name<T: ?Sized>() -> String {
+    let mut output = String::new();
+    let mut path = String::new();
+    for character in std::any::type_name::<T>().chars() {
+        if character.is_alphanumeric() || character == '_' || character == ':' {
+            path.push(character);
+        } else {
+            output.push_str(path.rsplit("::").next().unwrap());
+            output.push(character);
+            path.clear();
+        }
+    }
+    output.push_str(path.rsplit("::").next().unwrap());
+    output
+}
+
 /// A function returning the name of a type (just the name, not the path).
 fn type_name<T: ?Sized>() -> &'static str {
     std::any::type_name::<T>()
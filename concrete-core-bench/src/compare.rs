@@ -0,0 +1,193 @@
+//! A module containing the command comparing two files of benchmark records.
+//!
+//! The command is executed with:
+//! ```shell
+//! cargo run --release -- compare baseline.json current.json --threshold 5
+//! ```
+//! For every benchmark recorded in both files, the median durations are compared, and the
+//! benchmarks whose median duration increased by more than the threshold (in percent) are flagged
+//! as regressions. The command exits with a non-zero status when a regression is found.
+use crate::record::{read_records, Record};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// The name of the command.
+pub const COMMAND: &str = "compare";
+
+/// The threshold used when none is given, in percent.
+pub const DEFAULT_THRESHOLD: f64 = 5.;
+
+/// The comparison of a benchmark recorded in both files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// The record of the current file.
+    pub current: Record,
+    /// The median duration recorded in the baseline file, in nanoseconds.
+    pub baseline_median_ns: f64,
+    /// The relative change of the median duration, in percent.
+    pub relative_change: f64,
+    /// Whether the relative change exceeds the threshold.
+    pub regression: bool,
+}
+
+/// The result of the comparison of two files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// The benchmarks recorded in both files.
+    pub changes: Vec<Change>,
+    /// The benchmarks only recorded in the baseline file.
+    pub missing: Vec<Record>,
+    /// The benchmarks only recorded in the current file.
+    pub added: Vec<Record>,
+}
+
+impl Comparison {
+    /// Compares the records of two files, with a threshold in percent.
+    pub fn new(baseline: &[Record], current: &[Record], threshold: f64) -> Comparison {
+        let baseline_by_key: HashMap<_, _> = baseline
+            .iter()
+            .map(|record| (record.key(), record))
+            .collect();
+        let current_by_key: HashMap<_, _> = current
+            .iter()
+            .map(|record| (record.key(), record))
+            .collect();
+        let mut changes = Vec::new();
+        let mut added = Vec::new();
+        for record in current {
+            match baseline_by_key.get(&record.key()) {
+                Some(baseline) => {
+                    let relative_change = (record.median_ns / baseline.median_ns - 1.) * 100.;
+                    changes.push(Change {
+                        current: record.clone(),
+                        baseline_median_ns: baseline.median_ns,
+                        relative_change,
+                        regression: relative_change > threshold,
+                    });
+                }
+                None => added.push(record.clone()),
+            }
+        }
+        let missing = baseline
+            .iter()
+            .filter(|record| !current_by_key.contains_key(&record.key()))
+            .cloned()
+            .collect();
+        Comparison {
+            changes,
+            missing,
+            added,
+        }
+    }
+
+    /// Returns the changes flagged as regressions.
+    pub fn regressions(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.regression)
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in self.changes.iter() {
+            writeln!(
+                f,
+                "{:<10} {:>+8.2}% {:>14.0} ns -> {:>14.0} ns  {} ({} bits, {}) {}",
+                if change.regression {
+                    "REGRESSION"
+                } else {
+                    "ok"
+                },
+                change.relative_change,
+                change.baseline_median_ns,
+                change.current.median_ns,
+                change.current.operator,
+                change.current.precision,
+                change.current.engine,
+                change.current.parameters
+            )?;
+        }
+        for record in self.missing.iter() {
+            writeln!(
+                f,
+                "{:<10} {} ({} bits, {}) {}",
+                "missing", record.operator, record.precision, record.engine, record.parameters
+            )?;
+        }
+        for record in self.added.iter() {
+            writeln!(
+                f,
+                "{:<10} {} ({} bits, {}) {}",
+                "added", record.operator, record.precision, record.engine, record.parameters
+            )?;
+        }
+        write!(
+            f,
+            "{} benchmarks compared, {} regressions.",
+            self.changes.len(),
+            self.regressions().count()
+        )
+    }
+}
+
+/// Executes the command with its arguments, and returns whether a regression was found.
+pub fn run(arguments: &[String]) -> Result<bool, Box<dyn Error>> {
+    let usage = "Usage: compare <baseline> <current> [--threshold <percent>]";
+    let (paths, options) = arguments.split_at(arguments.len().min(2));
+    if paths.len() != 2 {
+        return Err(usage.into());
+    }
+    let threshold = match options {
+        [] => DEFAULT_THRESHOLD,
+        [flag, value] if flag == "--threshold" => value.parse()?,
+        _ => return Err(usage.into()),
+    };
+    let baseline = read_records(Path::new(&paths[0]))?;
+    let current = read_records(Path::new(&paths[1]))?;
+    let comparison = Comparison::new(baseline.as_slice(), current.as_slice(), threshold);
+    println!("{}", comparison);
+    let regression_found = comparison.regressions().next().is_some();
+    Ok(regression_found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Comparison;
+    use crate::record::Record;
+
+    fn record(operator: &str, median_ns: f64) -> Record {
+        Record::from_samples(
+            operator.to_string(),
+            "64".to_string(),
+            "CoreEngine".to_string(),
+            "(LweCiphertext64)".to_string(),
+            "Parameters".to_string(),
+            vec![median_ns],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_comparison() {
+        let baseline = vec![
+            record("Bootstrap", 100.),
+            record("Keyswitch", 100.),
+            record("Removed", 1.),
+        ];
+        let current = vec![
+            record("Bootstrap", 120.),
+            record("Keyswitch", 103.),
+            record("Added", 1.),
+        ];
+        let comparison = Comparison::new(baseline.as_slice(), current.as_slice(), 5.);
+        let regressions: Vec<_> = comparison
+            .regressions()
+            .map(|change| change.current.operator.as_str())
+            .collect();
+        assert_eq!(regressions, vec!["Bootstrap"]);
+        assert_eq!(comparison.changes.len(), 2);
+        assert_eq!(comparison.missing, vec![record("Removed", 1.)]);
+        assert_eq!(comparison.added, vec![record("Added", 1.)]);
+    }
+}
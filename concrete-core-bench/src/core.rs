@@ -1,4 +1,5 @@
 use crate::benchmark::BenchmarkFixture;
+use crate::record::Recorder;
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
 use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
@@ -6,25 +7,26 @@ use criterion::Criterion;
 use paste::paste;
 
 macro_rules! bench {
-    ($fixture: ident, $precision: ident, ($($types:ident),+), $maker: ident, $engine: ident, $criterion: ident) => {
+    ($fixture: ident, $precision: ident, ($($types:ident),+), $maker: ident, $engine: ident, $criterion: ident, $recorder: ident) => {
         paste!{
             <$fixture as BenchmarkFixture<$precision, CoreEngine, ($($types,)+),>>::bench_all_parameters(
                 &mut $maker,
                 &mut $engine,
                 &mut $criterion,
+                $recorder,
                 None
             );
         }
     };
     ($(($fixture: ident, ($($types:ident),+))),+) => {
-        pub fn bench() {
+        pub fn bench(recorder: &mut Recorder) {
             let mut criterion = Criterion::default().configure_from_args();
             let mut maker = Maker::default();
             let mut engine = CoreEngine::new().unwrap();
             $(
                 paste!{
-                    bench!{$fixture, Precision32, ($([< $types 32 >]),+), maker, engine, criterion}
-                    bench!{$fixture, Precision64, ($([< $types 64 >]),+), maker, engine, criterion}
+                    bench!{$fixture, Precision32, ($([< $types 32 >]),+), maker, engine, criterion, recorder}
+                    bench!{$fixture, Precision64, ($([< $types 64 >]),+), maker, engine, criterion, recorder}
                 }
             )+
         }
@@ -100,5 +102,7 @@ bench! {
     (PlaintextVectorCreationFixture, (PlaintextVector)),
     (PlaintextVectorRetrievalFixture, (PlaintextVector)),
     (GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
-    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext))
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext,
+        ExternalProductWorkspace))
 }
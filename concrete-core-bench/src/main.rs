@@ -4,20 +4,51 @@
 //! Each `backend_*` submodule here is expected to be activated by a given feature flag
 //! (matching the module name), and to contain the instantiation of a generic benchmarking
 //! for every implemented operator.
+//!
+//! The results of the benchmarks can be written to a file (see the [`record`] module), and two
+//! such files can be compared with the [`compare`] command.
+use record::{write_records, Recorder, OUTPUT_VARIABLE};
+use std::path::Path;
 
 pub mod benchmark;
+pub mod compare;
+pub mod record;
 
 #[cfg(feature = "backend_core")]
 mod core;
 
 // The main entry point. Uses criterion as benchmark harness.
 fn main() {
+    // The comparison command is handled before criterion parses the arguments.
+    let arguments: Vec<String> = std::env::args().collect();
+    if arguments.get(1).map(String::as_str) == Some(compare::COMMAND) {
+        match compare::run(&arguments[2..]) {
+            Ok(false) => return,
+            Ok(true) => std::process::exit(1),
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    #[allow(unused_mut)]
+    let mut recorder = Recorder::default();
+
     // We instantiate the benchmarks for different backends depending on the feature flag activated.
     #[cfg(feature = "backend_core")]
-    core::bench();
+    core::bench(&mut recorder);
 
     // We launch the benchmarks.
     criterion::Criterion::default()
         .configure_from_args()
         .final_summary();
+
+    // We write the records, if asked to.
+    if let Ok(path) = std::env::var(OUTPUT_VARIABLE) {
+        if let Err(error) = write_records(Path::new(&path), recorder.records()) {
+            eprintln!("The records could not be written to {}: {}", path, error);
+            std::process::exit(2);
+        }
+    }
 }
@@ -0,0 +1,195 @@
+//! A module containing the machine-readable output of the benchmarks.
+//!
+//! When the [`OUTPUT_VARIABLE`] environment variable is set, the timings measured for every
+//! benchmarked operator, precision and parameter set are recorded, and written to the file it
+//! points to once the benchmarks are over. Depending on the extension of the file, the records are
+//! written either as JSON:
+//! ```json
+//! {
+//!   "version": 1,
+//!   "records": [
+//!     {
+//!       "operator": "LweCiphertextDiscardingKeyswitchFixture",
+//!       "precision": "64",
+//!       "engine": "CoreEngine",
+//!       "entities": "(LweKeyswitchKey64, LweCiphertext64, LweCiphertext64)",
+//!       "parameters": "LweCiphertextDiscardingKeyswitchParameters { .. }",
+//!       "samples": 112,
+//!       "mean_ns": 2503110.2,
+//!       "median_ns": 2498020.5,
+//!       "min_ns": 2412004.0
+//!     }
+//!   ]
+//! }
+//! ```
+//! or as CSV, with one line per record and the same fields as columns. The fields of a record are
+//! stable, which allows to compare files produced by different versions of the library with the
+//! [`compare`](crate::compare) command.
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs::File;
+use std::path::Path;
+
+/// The name of the environment variable containing the path of the file to write the records to.
+pub const OUTPUT_VARIABLE: &str = "CONCRETE_CORE_BENCH_OUTPUT";
+
+/// The version of the format of the JSON files.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// The timings measured for an operator, with a given precision and parameter set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// The name of the fixture used to benchmark the operator.
+    pub operator: String,
+    /// The number of bits of the integers.
+    pub precision: String,
+    /// The name of the benchmarked engine.
+    pub engine: String,
+    /// The names of the entities the operator was benchmarked with.
+    pub entities: String,
+    /// The parameters used by the fixture.
+    pub parameters: String,
+    /// The number of timing samples taken.
+    pub samples: usize,
+    /// The mean duration of an execution, in nanoseconds.
+    pub mean_ns: f64,
+    /// The median duration of an execution, in nanoseconds.
+    pub median_ns: f64,
+    /// The shortest duration of an execution, in nanoseconds.
+    pub min_ns: f64,
+}
+
+impl Record {
+    /// Creates a record from the durations of an execution measured in every sample, in
+    /// nanoseconds. Returns `None` if no sample was taken.
+    pub fn from_samples(
+        operator: String,
+        precision: String,
+        engine: String,
+        entities: String,
+        parameters: String,
+        mut samples: Vec<f64>,
+    ) -> Option<Record> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let length = samples.len();
+        let median_ns = if length % 2 == 1 {
+            samples[length / 2]
+        } else {
+            (samples[length / 2 - 1] + samples[length / 2]) / 2.
+        };
+        Some(Record {
+            operator,
+            precision,
+            engine,
+            entities,
+            parameters,
+            samples: length,
+            mean_ns: samples.iter().sum::<f64>() / length as f64,
+            median_ns,
+            min_ns: samples[0],
+        })
+    }
+
+    /// Returns the fields identifying the benchmark the record was measured with.
+    pub fn key(&self) -> (&str, &str, &str, &str, &str) {
+        (
+            &self.operator,
+            &self.precision,
+            &self.engine,
+            &self.entities,
+            &self.parameters,
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonRecords {
+    version: u32,
+    records: Vec<Record>,
+}
+
+/// The formats the records can be written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Returns the format matching the extension of a path.
+    pub fn from_path(path: &Path) -> Result<Format, Box<dyn Error>> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Ok(Format::Json),
+            Some("csv") => Ok(Format::Csv),
+            _ => Err(format!(
+                "The extension of {} must be either `json` or `csv`.",
+                path.display()
+            )
+            .into()),
+        }
+    }
+}
+
+/// Writes records to a file, whose format depends on its extension.
+pub fn write_records(path: &Path, records: &[Record]) -> Result<(), Box<dyn Error>> {
+    match Format::from_path(path)? {
+        Format::Json => {
+            let records = JsonRecords {
+                version: FORMAT_VERSION,
+                records: records.to_vec(),
+            };
+            serde_json::to_writer_pretty(File::create(path)?, &records)?;
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_path(path)?;
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads records from a file, whose format depends on its extension.
+pub fn read_records(path: &Path) -> Result<Vec<Record>, Box<dyn Error>> {
+    match Format::from_path(path)? {
+        Format::Json => {
+            let records: JsonRecords = serde_json::from_reader(File::open(path)?)?;
+            if records.version != FORMAT_VERSION {
+                return Err(format!(
+                    "{} was written with version {} of the format, expected {}.",
+                    path.display(),
+                    records.version,
+                    FORMAT_VERSION
+                )
+                .into());
+            }
+            Ok(records.records)
+        }
+        Format::Csv => Ok(csv::Reader::from_path(path)?
+            .deserialize()
+            .collect::<Result<_, _>>()?),
+    }
+}
+
+/// A type collecting the records of the benchmarks as they are executed.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    records: Vec<Record>,
+}
+
+impl Recorder {
+    /// Adds a record to the recorder.
+    pub fn push(&mut self, record: Record) {
+        self.records.push(record);
+    }
+
+    /// Returns the records collected so far.
+    pub fn records(&self) -> &[Record] {
+        self.records.as_slice()
+    }
+}
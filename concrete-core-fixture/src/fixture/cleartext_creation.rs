@@ -7,11 +7,12 @@ use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{CleartextCreationEngine, CleartextEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextCreationEngine` trait.
 pub struct CleartextCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CleartextCreationParameters;
 
 impl<Precision, Engine, Cleartext> Fixture<Precision, Engine, (Cleartext,)>
@@ -7,11 +7,12 @@ use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{CleartextDiscardingRetrievalEngine, CleartextEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextDiscardingRetrievalEngine` trait.
 pub struct CleartextDiscardingRetrievalFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CleartextDiscardingRetrievalParameters;
 
 impl<Precision, Engine, Cleartext> Fixture<Precision, Engine, (Cleartext,)>
@@ -7,11 +7,12 @@ use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{CleartextEntity, CleartextRetrievalEngine};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextRetrievalEngine` trait.
 pub struct CleartextRetrievalFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CleartextRetrievalParameters;
 
 impl<Precision, Engine, Cleartext> Fixture<Precision, Engine, (Cleartext,)>
@@ -8,11 +8,12 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::CleartextCount;
 
 use concrete_core::prelude::{CleartextVectorCreationEngine, CleartextVectorEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextVectorCreationEngine` trait.
 pub struct CleartextVectorCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CleartextVectorCreationParameters {
     count: CleartextCount,
 }
@@ -8,11 +8,12 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::CleartextCount;
 
 use concrete_core::prelude::{CleartextVectorDiscardingRetrievalEngine, CleartextVectorEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextVectorDiscardingRetrievalEngine` trait.
 pub struct CleartextVectorDiscardingRetrievalFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CleartextVectorDiscardingRetrievalParameters {
     count: CleartextCount,
 }
@@ -8,11 +8,12 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::CleartextCount;
 
 use concrete_core::prelude::{CleartextVectorEntity, CleartextVectorRetrievalEngine};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `CleartextVectorRetrievalEngine` trait.
 pub struct CleartextVectorRetrievalFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CleartextVectorRetrievalParameters {
     count: CleartextCount,
 }
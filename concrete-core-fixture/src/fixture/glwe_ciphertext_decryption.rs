@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     GlweCiphertextDecryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextDecryptionEngine` trait.
 pub struct GlweCiphertextDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextDecryptionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     GlweCiphertextDiscardingDecryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextDiscardingDecryptionEngine` trait.
 pub struct GlweCiphertextDiscardingDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextDiscardingDecryptionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
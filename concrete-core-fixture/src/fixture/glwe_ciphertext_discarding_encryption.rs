@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     GlweCiphertextDiscardingEncryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextDiscardingEncryptionEngine` trait.
 pub struct GlweCiphertextDiscardingEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextDiscardingEncryptionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     GlweCiphertextEncryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextEncryptionEngine` trait.
 pub struct GlweCiphertextEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextEncryptionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
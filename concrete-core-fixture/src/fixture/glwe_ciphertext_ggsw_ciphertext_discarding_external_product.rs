@@ -20,13 +20,14 @@ use concrete_core::prelude::{
     GgswCiphertextEntity, GlweCiphertextEntity,
     GlweCiphertextGgswCiphertextDiscardingExternalProductEngine,
 };
+use serde::{Deserialize, Serialize};
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `GlweCiphertextGgswCiphertextDiscardingExternalProduct`
 /// trait.
 pub struct GlweCiphertextGgswCiphertextDiscardingExternalProductFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextGgswCiphertextDiscardingExternalProductParameters {
     pub ggsw_noise: Variance,
     pub glwe_noise: Variance,
@@ -19,13 +19,14 @@ use concrete_core::prelude::markers::{
 use concrete_core::prelude::{
     GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextGgswCiphertextExternalProductEngine,
 };
+use serde::{Deserialize, Serialize};
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `GlweCiphertextGgswCiphertextExternalProduct`
 /// trait.
 pub struct GlweCiphertextGgswCiphertextExternalProductFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextGgswCiphertextExternalProductParameters {
     pub ggsw_noise: Variance,
     pub glwe_noise: Variance,
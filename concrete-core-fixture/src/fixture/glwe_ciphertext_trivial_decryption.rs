@@ -9,11 +9,12 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweCiphertextEntity, GlweCiphertextTrivialDecryptionEngine, PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextTrivialDecryptionEngine` trait.
 pub struct GlweCiphertextTrivialDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextTrivialDecryptionParameters {
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
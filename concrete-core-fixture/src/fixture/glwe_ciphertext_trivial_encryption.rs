@@ -9,11 +9,12 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweCiphertextEntity, GlweCiphertextTrivialEncryptionEngine, PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextTrivialEncryptionEngine` trait.
 pub struct GlweCiphertextTrivialEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextTrivialEncryptionParameters {
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
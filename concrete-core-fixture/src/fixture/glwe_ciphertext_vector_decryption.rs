@@ -14,11 +14,12 @@ use crate::generation::synthesizing::{
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorDecryptionEngine` trait.
 pub struct GlweCiphertextVectorDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextVectorDecryptionParameters {
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     GlweCiphertextVectorEncryptionEngine, GlweCiphertextVectorEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorEncryptionEngine` trait.
 pub struct GlweCiphertextVectorEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextVectorEncryptionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
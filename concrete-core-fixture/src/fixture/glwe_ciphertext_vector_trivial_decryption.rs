@@ -11,11 +11,12 @@ use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, Polynomia
 use concrete_core::prelude::{
     GlweCiphertextVectorEntity, GlweCiphertextVectorTrivialDecryptionEngine, PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorTrivialDecryptionEngine` trait.
 pub struct GlweCiphertextVectorTrivialDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextVectorTrivialDecryptionParameters {
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
@@ -11,11 +11,12 @@ use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, Polynomia
 use concrete_core::prelude::{
     GlweCiphertextVectorEntity, GlweCiphertextVectorTrivialEncryptionEngine, PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorTrivialEncryptionEngine` trait.
 pub struct GlweCiphertextVectorTrivialEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextVectorTrivialEncryptionParameters {
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
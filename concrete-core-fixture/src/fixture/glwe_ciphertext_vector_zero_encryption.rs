@@ -13,11 +13,12 @@ use crate::generation::synthesizing::{SynthesizesGlweCiphertextVector, Synthesiz
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextVectorZeroEncryptionEngine` trait.
 pub struct GlweCiphertextVectorZeroEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextVectorZeroEncryptionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
@@ -11,11 +11,12 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweCiphertextEntity, GlweCiphertextZeroEncryptionEngine, GlweSecretKeyEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweCiphertextZeroEncryptionEngine` trait.
 pub struct GlweCiphertextZeroEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextZeroEncryptionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
@@ -3,11 +3,12 @@ use crate::generation::synthesizing::SynthesizesGlweSecretKey;
 use crate::generation::{IntegerPrecision, Maker};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{GlweSecretKeyCreationEngine, GlweSecretKeyEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `GlweSecretKeyCreationEngine` trait.
 pub struct GlweSecretKeyCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GlweSecretKeyCreationParameters {
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
@@ -6,11 +6,12 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweSecretKeyEntity, GlweToLweSecretKeyTransmutationEngine, LweSecretKeyEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweKeyswitchKeyCreationEngine` trait.
 pub struct LweKeyswitchKeyCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweKeyswitchKeyCreationParameters {
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
@@ -9,11 +9,12 @@ use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
 use concrete_core::prelude::{LweBootstrapKeyConversionEngine, LweBootstrapKeyEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweBootstrapKeyConversionEngine` trait.
 pub struct LweSecretKeyConversionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweSecretKeyConversionParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
@@ -11,11 +11,12 @@ use concrete_commons::parameters::{
 use concrete_core::prelude::{
     GlweSecretKeyEntity, LweBootstrapKeyCreationEngine, LweBootstrapKeyEntity, LweSecretKeyEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweBootstrapKeyCreationEngine` trait.
 pub struct LweSecretKeyCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweSecretKeyCreationParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
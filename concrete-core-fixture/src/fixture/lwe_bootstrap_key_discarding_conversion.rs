@@ -9,11 +9,12 @@ use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
 use concrete_core::prelude::{LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweBootstrapKeyDiscardingConversionEngine` trait.
 pub struct LweSecretKeyDiscardingConversionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweSecretKeyDiscardingConversionParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
@@ -12,12 +12,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     CleartextEntity, LweCiphertextCleartextDiscardingMultiplicationEngine, LweCiphertextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextCleartextDiscardingMultiplicationEngine`
 /// trait.
 pub struct LweCiphertextCleartextDiscardingMultiplicationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextCleartextDiscardingMultiplicationParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
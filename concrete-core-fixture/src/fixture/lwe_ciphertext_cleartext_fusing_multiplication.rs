@@ -13,12 +13,13 @@ use crate::generation::synthesizing::{SynthesizesCleartext, SynthesizesLweCipher
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextCleartextFusingMultiplicationEngine`
 /// trait.
 pub struct LweCiphertextCleartextFusingMultiplicationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextCleartextFusingMultiplicationParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -14,11 +14,12 @@ use crate::generation::synthesizing::{
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDecryptionEngine` trait.
 pub struct LweCiphertextDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDecryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
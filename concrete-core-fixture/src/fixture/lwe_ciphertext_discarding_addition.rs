@@ -10,12 +10,13 @@ use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweCiphertextDiscardingAdditionEngine, LweCiphertextEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingAdditionEngine`
 /// trait.
 pub struct LweCiphertextDiscardingAdditionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingAdditionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
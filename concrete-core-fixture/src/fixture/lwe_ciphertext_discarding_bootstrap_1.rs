@@ -22,12 +22,13 @@ use concrete_core::prelude::{
     GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextDiscardingBootstrapEngine,
     LweCiphertextEntity,
 };
+use serde::{Deserialize, Serialize};
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingBootstrapEngine` trait.
 pub struct LweCiphertextDiscardingBootstrapFixture1;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingBootstrapParameters1 {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -277,7 +278,7 @@ where
 // FIXME:
 // The current NPE does not use the key distribution markers of concrete-core. This function makes
 // the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
-fn fix_estimate_pbs_noise<T, D, K>(
+pub(crate) fn fix_estimate_pbs_noise<T, D, K>(
     lwe_mask_size: LweDimension,
     poly_size: PolynomialSize,
     rlwe_mask_size: GlweDimension,
@@ -17,11 +17,12 @@ use concrete_core::prelude::{
     GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextDiscardingBootstrapEngine,
     LweCiphertextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingBootstrapEngine` trait.
 pub struct LweCiphertextDiscardingBootstrapFixture2;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingBootstrapParameters2 {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     LweCiphertextDiscardingDecryptionEngine, LweCiphertextEntity, LweSecretKeyEntity,
     PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingDecryptionEngine` trait.
 pub struct LweCiphertextDiscardingDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingDecryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
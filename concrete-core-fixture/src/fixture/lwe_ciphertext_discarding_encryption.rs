@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     LweCiphertextDiscardingEncryptionEngine, LweCiphertextEntity, LweSecretKeyEntity,
     PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingEncryptionEngine` trait.
 pub struct LweCiphertextDiscardingEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingEncryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -12,11 +12,12 @@ use concrete_commons::parameters::{GlweDimension, LweDimension, MonomialIndex, P
 use concrete_core::prelude::{
     GlweCiphertextEntity, LweCiphertextDiscardingExtractionEngine, LweCiphertextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingExtractionEngine` trait.
 pub struct LweCiphertextDiscardingExtractionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingExtractionParameters {
     pub noise: Variance,
     pub glwe_dimension: GlweDimension,
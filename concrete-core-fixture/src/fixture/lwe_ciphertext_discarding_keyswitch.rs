@@ -16,12 +16,13 @@ use concrete_core::prelude::markers::{
 use concrete_core::prelude::{
     LweCiphertextDiscardingKeyswitchEngine, LweCiphertextEntity, LweKeyswitchKeyEntity,
 };
+use serde::{Deserialize, Serialize};
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingKeyswitchEngine` trait.
 pub struct LweCiphertextDiscardingKeyswitchFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingKeyswitchParameters {
     pub n_bit_msg: usize,
     pub input_noise: Variance,
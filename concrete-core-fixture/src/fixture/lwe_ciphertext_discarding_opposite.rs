@@ -10,12 +10,13 @@ use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweCiphertextDiscardingOppositeEngine, LweCiphertextEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingOppositeEngine`
 /// trait.
 pub struct LweCiphertextDiscardingOppositeFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingOppositeParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
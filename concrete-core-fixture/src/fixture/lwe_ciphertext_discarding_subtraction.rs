@@ -11,12 +11,13 @@ use crate::generation::synthesizing::SynthesizesLweCiphertext;
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingSubtractionEngine`
 /// trait.
 pub struct LweCiphertextDiscardingSubtractionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextDiscardingSubtractionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -13,11 +13,12 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEncryptionEngine, LweCiphertextEntity, LweSecretKeyEntity, PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextEncryptionEngine` trait.
 pub struct LweCiphertextEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextEncryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
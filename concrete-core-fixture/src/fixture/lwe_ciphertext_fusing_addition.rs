@@ -10,12 +10,13 @@ use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweCiphertextEntity, LweCiphertextFusingAdditionEngine};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextFusingAdditionEngine`
 /// trait.
 pub struct LweCiphertextFusingAdditionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextFusingAdditionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
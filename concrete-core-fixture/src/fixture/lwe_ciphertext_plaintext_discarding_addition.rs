@@ -12,12 +12,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextDiscardingAdditionEngine, PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextDiscardingAdditionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextDiscardingAdditionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextPlaintextDiscardingAdditionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -12,12 +12,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextDiscardingSubtractionEngine, PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextDiscardingSubtractionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextDiscardingSubtractionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextPlaintextDiscardingSubtractionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -12,12 +12,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextFusingAdditionEngine, PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextFusingAdditionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextFusingAdditionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextPlaintextFusingAdditionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -12,12 +12,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextFusingSubtractionEngine, PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextFusingSubtractionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextFusingSubtractionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextPlaintextFusingSubtractionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
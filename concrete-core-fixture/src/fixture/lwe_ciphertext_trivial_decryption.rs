@@ -9,11 +9,12 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextTrivialDecryptionEngine, PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextTrivialDecryptionEngine` trait.
 pub struct LweCiphertextTrivialDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextTrivialDecryptionParameters {
     pub lwe_dimension: LweDimension,
 }
@@ -9,11 +9,12 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextTrivialEncryptionEngine, PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextTrivialEncryptionEngine` trait.
 pub struct LweCiphertextTrivialEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextTrivialEncryptionParameters {
     pub lwe_dimension: LweDimension,
 }
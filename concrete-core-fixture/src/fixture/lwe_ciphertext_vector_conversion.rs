@@ -10,11 +10,12 @@ use crate::generation::synthesizing::SynthesizesLweCiphertextVector;
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorConversionEngine` trait.
 pub struct LweCiphertextVectorConversionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorConversionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
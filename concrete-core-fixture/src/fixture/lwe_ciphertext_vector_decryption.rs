@@ -14,11 +14,12 @@ use concrete_core::prelude::{
     LweCiphertextVectorDecryptionEngine, LweCiphertextVectorEntity, LweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorDecryptionEngine` trait.
 pub struct LweCiphertextVectorDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorDecryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
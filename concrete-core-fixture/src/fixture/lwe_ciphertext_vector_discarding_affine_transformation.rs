@@ -18,12 +18,13 @@ use concrete_core::prelude::{
     LweCiphertextVectorDiscardingAffineTransformationEngine, LweCiphertextVectorEntity,
     PlaintextEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the
 /// `LweCiphertextVectorDiscardingAffineTransformationEngine` trait.
 pub struct LweCiphertextVectorDiscardingAffineTransformationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorDiscardingAffineTransformationParameters {
     pub nb_ct: LweCiphertextCount,
     pub noise: Variance,
@@ -15,11 +15,12 @@ use crate::generation::synthesizing::{
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorDiscardingEncryptionEngine` trait.
 pub struct LweCiphertextVectorDiscardingEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorDiscardingEncryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
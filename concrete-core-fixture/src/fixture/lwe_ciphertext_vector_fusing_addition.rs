@@ -13,12 +13,13 @@ use crate::generation::synthesizing::SynthesizesLweCiphertextVector;
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorFusingAdditionEngine`
 /// trait.
 pub struct LweCiphertextVectorFusingAdditionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorFusingAdditionParameters {
     pub lwe_ciphertext_count: LweCiphertextCount,
     pub noise: Variance,
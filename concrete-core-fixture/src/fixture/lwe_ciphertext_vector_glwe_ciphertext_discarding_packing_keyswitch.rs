@@ -18,12 +18,13 @@ use concrete_core::prelude::{
     LweCiphertextVectorEntity, LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
     PackingKeyswitchKeyEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the
 /// `LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine` trait.
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchParameters {
     pub input_lwe_noise: Variance,
     pub pksk_noise: Variance,
@@ -10,11 +10,12 @@ use crate::generation::synthesizing::{SynthesizesLweCiphertextVector, Synthesize
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorTrivialDecryptionEngine` trait.
 pub struct LweCiphertextVectorTrivialDecryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorTrivialDecryptionParameters {
     pub lwe_dimension: LweDimension,
     pub count: LweCiphertextCount,
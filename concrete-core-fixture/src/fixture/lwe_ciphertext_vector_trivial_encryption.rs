@@ -9,11 +9,12 @@ use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
 use concrete_core::prelude::{
     LweCiphertextVectorEntity, LweCiphertextVectorTrivialEncryptionEngine, PlaintextVectorEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorTrivialEncryptionEngine` trait.
 pub struct LweCiphertextVectorTrivialEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorTrivialEncryptionParameters {
     pub lwe_dimension: LweDimension,
     pub count: LweCiphertextCount,
@@ -13,11 +13,12 @@ use crate::generation::synthesizing::{SynthesizesLweCiphertextVector, Synthesize
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextVectorZeroEncryptionEngine` trait.
 pub struct LweCiphertextVectorZeroEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextVectorZeroEncryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
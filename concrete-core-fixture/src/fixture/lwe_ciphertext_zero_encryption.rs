@@ -12,11 +12,12 @@ use crate::generation::prototyping::{
 use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesLweSecretKey};
 use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::statistical_test::assert_noise_distribution;
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweCiphertextZeroEncryptionEngine` trait.
 pub struct LweCiphertextZeroEncryptionFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweCiphertextZeroEncryptionParameters {
     pub noise: Variance,
     pub lwe_dimension: LweDimension,
@@ -7,11 +7,12 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 use concrete_core::prelude::{
     LweKeyswitchKeyCreationEngine, LweKeyswitchKeyEntity, LweSecretKeyEntity,
 };
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweKeyswitchKeyCreationEngine` trait.
 pub struct LweKeyswitchKeyCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweKeyswitchKeyCreationParameters {
     pub noise: Variance,
     pub lwe_dimension_in: LweDimension,
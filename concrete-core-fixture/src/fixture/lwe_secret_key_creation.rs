@@ -3,11 +3,12 @@ use crate::generation::synthesizing::SynthesizesLweSecretKey;
 use crate::generation::{IntegerPrecision, Maker};
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweSecretKeyCreationEngine, LweSecretKeyEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `LweSecretKeyCreationEngine` trait.
 pub struct LweSecretKeyCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LweSecretKeyCreationParameters {
     pub lwe_dimension: LweDimension,
 }
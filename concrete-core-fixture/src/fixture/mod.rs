@@ -31,11 +31,24 @@
 //!
 //! In particular, once the [`Fixture`] mandatory methods and types are defined, the user can
 //! benefit from the default methods [`Fixture::sample`], [`Fixture::test`] or [`Fixture::stress`].
+//!
+//! The parameters of a fixture can also be read from a file, instead of the hardcoded ones, with
+//! [`Fixture::load_parameters_iterator`]. See the [`parameters`](crate::parameters) module for the
+//! format of this file.
+//!
+//! Fixtures which also define the [`Fixture::compute_failure_threshold`] and
+//! [`Fixture::compute_errors`] methods can be used to estimate the probability that the output of
+//! the engine is decoded to a wrong message, with [`Fixture::estimate_failure_probability`].
 use crate::generation::{IntegerPrecision, Maker};
+use crate::parameters::{ParametersFile, ParametersLoadingError};
+use crate::raw::statistical_test::FailureProbabilityEstimate;
 use crate::{Repetitions, SampleSize};
 use concrete_core::prelude::AbstractEngine;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::fmt::Debug;
 use std::ops::BitAnd;
+use std::path::Path;
 
 /// A trait for types implementing a fixture for a particular engine trait.
 ///
@@ -43,7 +56,7 @@ use std::ops::BitAnd;
 /// `stress` and `stress_all` use the associated types and methods.
 pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEntities> {
     /// A type containing the parameters needed to generate the execution context.
-    type Parameters: Debug;
+    type Parameters: Debug + Serialize + DeserializeOwned;
 
     /// A type containing the input prototypes generated at the level of the repetition (reused).
     type RepetitionPrototypes;
@@ -66,6 +79,18 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
     /// A method which outputs an iterator over parameters.
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>>;
 
+    /// A method which outputs an iterator over the parameters read from a TOML or JSON file, in
+    /// place of the ones of `generate_parameters_iterator`.
+    fn load_parameters_iterator(
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = Self::Parameters>>, ParametersLoadingError>
+    where
+        Self::Parameters: 'static,
+    {
+        let file = ParametersFile::<Self::Parameters>::read(path)?;
+        Ok(Box::new(file.parameters.into_iter()))
+    }
+
     /// Generate a random set of repetition-level prototypes.
     fn generate_random_repetition_prototypes(
         parameters: &Self::Parameters,
@@ -117,6 +142,57 @@ pub trait Fixture<Precision: IntegerPrecision, Engine: AbstractEngine, RelatedEn
     /// A method which verify that the outcomes verify some criteria.
     fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool;
 
+    /// A method which returns the largest error, as a fraction of the torus, that an output can
+    /// contain while still being decoded to the right message.
+    ///
+    /// The default implementation returns `None`, meaning that the fixture does not support
+    /// failure probability estimation.
+    fn compute_failure_threshold(_parameters: &Self::Parameters) -> Option<f64> {
+        None
+    }
+
+    /// A method which returns the errors contained in an outcome, as signed fractions of the
+    /// torus.
+    ///
+    /// The default implementation returns no errors.
+    fn compute_errors(_outcome: &Self::Outcome) -> Vec<f64> {
+        Vec::new()
+    }
+
+    /// A method which estimates the probability that an output of the engine is decoded to a wrong
+    /// message, for a fixed set of parameters.
+    ///
+    /// The errors of `sample_size` executions, sharing the same repetition-level prototypes, are
+    /// measured and the failure probability is extrapolated from their variance. The outcomes are
+    /// not kept in memory, which allows to use very large samples. Returns `None` if the fixture
+    /// does not support failure probability estimation.
+    fn estimate_failure_probability(
+        maker: &mut Maker,
+        engine: &mut Engine,
+        parameters: &Self::Parameters,
+        sample_size: SampleSize,
+    ) -> Option<FailureProbabilityEstimate> {
+        let threshold = Self::compute_failure_threshold(parameters)?;
+        let repetition_proto = Self::generate_random_repetition_prototypes(parameters, maker);
+        let errors = (0..sample_size.0).flat_map(|_| {
+            let sample_proto =
+                Self::generate_random_sample_prototypes(parameters, maker, &repetition_proto);
+            let pre_execution_context =
+                Self::prepare_context(parameters, maker, &repetition_proto, &sample_proto);
+            let post_execution_context =
+                Self::execute_engine(parameters, engine, pre_execution_context);
+            let output = Self::process_context(
+                parameters,
+                maker,
+                &repetition_proto,
+                &sample_proto,
+                post_execution_context,
+            );
+            Self::compute_errors(&output)
+        });
+        Some(FailureProbabilityEstimate::from_errors(errors, threshold))
+    }
+
     /// A method which verifies the statistical properties of a sample of engine executions, over
     /// multiple randomly generated raw inputs, over multiple sets of parameters.
     fn stress_all_parameters(
@@ -366,6 +442,9 @@ pub use lwe_ciphertext_discarding_bootstrap_1::*;
 mod lwe_ciphertext_discarding_bootstrap_2;
 pub use lwe_ciphertext_discarding_bootstrap_2::*;
 
+mod lwe_ciphertext_discarding_bootstrap_3;
+pub use lwe_ciphertext_discarding_bootstrap_3::*;
+
 mod lwe_ciphertext_discarding_extraction;
 pub use lwe_ciphertext_discarding_extraction::*;
 
@@ -375,6 +454,9 @@ pub use plaintext_creation::*;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product_with_workspace;
+pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product_with_workspace::*;
+
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
 
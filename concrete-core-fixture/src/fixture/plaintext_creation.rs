@@ -7,11 +7,12 @@ use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{PlaintextCreationEngine, PlaintextEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `PlaintextCreationEngine` trait.
 pub struct PlaintextCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlaintextCreationParameters;
 
 impl<Precision, Engine, Plaintext> Fixture<Precision, Engine, (Plaintext,)>
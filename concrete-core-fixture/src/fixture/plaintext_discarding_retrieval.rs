@@ -7,11 +7,12 @@ use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{PlaintextDiscardingRetrievalEngine, PlaintextEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `PlaintextDiscardingRetrievalEngine` trait.
 pub struct PlaintextDiscardingRetrievalFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlaintextDiscardingRetrievalParameters;
 
 impl<Precision, Engine, Plaintext> Fixture<Precision, Engine, (Plaintext,)>
@@ -8,11 +8,12 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::PlaintextCount;
 
 use concrete_core::prelude::{PlaintextVectorCreationEngine, PlaintextVectorEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `PlaintextVectorCreationEngine` trait.
 pub struct PlaintextVectorCreationFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlaintextVectorCreationParameters {
     count: PlaintextCount,
 }
@@ -8,11 +8,12 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::PlaintextCount;
 
 use concrete_core::prelude::{PlaintextVectorDiscardingRetrievalEngine, PlaintextVectorEntity};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `PlaintextVectorDiscardingRetrievalEngine` trait.
 pub struct PlaintextVectorDiscardingRetrievalFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlaintextVectorDiscardingRetrievalParameters {
     count: PlaintextCount,
 }
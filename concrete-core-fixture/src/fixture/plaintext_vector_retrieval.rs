@@ -8,11 +8,12 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::PlaintextCount;
 
 use concrete_core::prelude::{PlaintextVectorEntity, PlaintextVectorRetrievalEngine};
+use serde::{Deserialize, Serialize};
 
 /// A fixture for the types implementing the `PlaintextVectorRetrievalEngine` trait.
 pub struct PlaintextVectorRetrievalFixture;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlaintextVectorRetrievalParameters {
     count: PlaintextCount,
 }
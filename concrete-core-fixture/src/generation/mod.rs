@@ -19,7 +19,7 @@
 //! prototypical ones, and extracting _raw_ outputs. Also, the fixture developer should ensure that
 //! the entities are destroyed after the execution of the engine. Again, this can be done by the
 //! [`Maker`] instance and the `Synthesizes*` traits, which contains functions to destroy data.
-use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::generation::{seed_raw_generator, RawUnsignedIntegers};
 use concrete_core::prelude::AbstractEngine;
 
 pub mod prototypes;
@@ -55,6 +55,21 @@ pub struct Maker {
     core_engine: concrete_core::backends::core::engines::CoreEngine,
 }
 
+impl Maker {
+    /// Creates a maker whose prototypes are fully determined by the given seed.
+    ///
+    /// This seeds both the engine used to generate the prototypical entities, and the generator
+    /// used to sample raw integers on the current thread (see
+    /// [`seed_raw_generator`](crate::raw::generation::seed_raw_generator)). Generating the same
+    /// sequence of prototypes with two makers created from the same seed gives the same results.
+    pub fn new_seeded(seed: u128) -> Maker {
+        seed_raw_generator(seed);
+        Maker {
+            core_engine: concrete_core::backends::core::engines::CoreEngine::new_seeded(!seed),
+        }
+    }
+}
+
 impl Default for Maker {
     fn default() -> Self {
         Maker {
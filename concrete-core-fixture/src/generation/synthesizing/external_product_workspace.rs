@@ -0,0 +1,61 @@
+use crate::generation::IntegerPrecision;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use concrete_core::prelude::ExternalProductWorkspaceEntity;
+
+/// A trait allowing to synthesize an actual external product workspace entity.
+///
+/// Workspaces do not carry any meaningful data, and as such, do not have a prototype.
+pub trait SynthesizesExternalProductWorkspace<Precision: IntegerPrecision, Workspace>
+where
+    Workspace: ExternalProductWorkspaceEntity,
+{
+    fn synthesize_external_product_workspace(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Workspace;
+    fn destroy_external_product_workspace(&mut self, entity: Workspace);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::synthesizing::SynthesizesExternalProductWorkspace;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    use concrete_core::prelude::{
+        DestructionEngine, ExternalProductWorkspace32, ExternalProductWorkspace64,
+        ExternalProductWorkspaceCreationEngine,
+    };
+
+    impl SynthesizesExternalProductWorkspace<Precision32, ExternalProductWorkspace32> for Maker {
+        fn synthesize_external_product_workspace(
+            &mut self,
+            glwe_dimension: GlweDimension,
+            polynomial_size: PolynomialSize,
+        ) -> ExternalProductWorkspace32 {
+            self.core_engine
+                .create_external_product_workspace(glwe_dimension, polynomial_size)
+                .unwrap()
+        }
+
+        fn destroy_external_product_workspace(&mut self, entity: ExternalProductWorkspace32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesExternalProductWorkspace<Precision64, ExternalProductWorkspace64> for Maker {
+        fn synthesize_external_product_workspace(
+            &mut self,
+            glwe_dimension: GlweDimension,
+            polynomial_size: PolynomialSize,
+        ) -> ExternalProductWorkspace64 {
+            self.core_engine
+                .create_external_product_workspace(glwe_dimension, polynomial_size)
+                .unwrap()
+        }
+
+        fn destroy_external_product_workspace(&mut self, entity: ExternalProductWorkspace64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -5,6 +5,7 @@
 
 mod cleartext;
 mod cleartext_vector;
+mod external_product_workspace;
 mod ggsw_ciphertext;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
@@ -20,6 +21,7 @@ mod plaintext_vector;
 
 pub use cleartext::*;
 pub use cleartext_vector::*;
+pub use external_product_workspace::*;
 pub use ggsw_ciphertext::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
@@ -7,6 +7,7 @@
 
 pub mod fixture;
 pub mod generation;
+pub mod parameters;
 pub mod raw;
 
 /// A type representing the number of times we repeat a test for a given set of parameters.
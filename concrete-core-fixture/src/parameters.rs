@@ -0,0 +1,110 @@
+//! A module containing the tools to load fixture parameters from a file.
+//!
+//! Every fixture hardcodes a list of parameters in [`Fixture::generate_parameters_iterator`]. The
+//! [`Fixture::load_parameters_iterator`] method allows to use another list, read from a TOML or a
+//! JSON file, without modifying the fixture. The file contains a `parameters` array, whose
+//! elements follow the fields of the `Parameters` type of the fixture. For instance, the
+//! parameters of the [`LweCiphertextEncryptionFixture`] can be given in TOML with:
+//! ```toml
+//! [[parameters]]
+//! noise = 1e-8
+//! lwe_dimension = 630
+//!
+//! [[parameters]]
+//! noise = 1e-10
+//! lwe_dimension = 1024
+//! ```
+//! or in JSON with:
+//! ```json
+//! {
+//!     "parameters": [
+//!         { "noise": 1e-8, "lwe_dimension": 630 },
+//!         { "noise": 1e-10, "lwe_dimension": 1024 }
+//!     ]
+//! }
+//! ```
+//!
+//! [`Fixture::generate_parameters_iterator`]: crate::fixture::Fixture::generate_parameters_iterator
+//! [`Fixture::load_parameters_iterator`]: crate::fixture::Fixture::load_parameters_iterator
+//! [`LweCiphertextEncryptionFixture`]: crate::fixture::LweCiphertextEncryptionFixture
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// The formats a parameters file can be written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParametersFormat {
+    Toml,
+    Json,
+}
+
+impl ParametersFormat {
+    /// Returns the format matching the extension of a file, if any.
+    pub fn from_path(path: &Path) -> Option<ParametersFormat> {
+        match path.extension()?.to_str()? {
+            "toml" => Some(ParametersFormat::Toml),
+            "json" => Some(ParametersFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// The content of a parameters file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParametersFile<Parameters> {
+    pub parameters: Vec<Parameters>,
+}
+
+impl<Parameters: DeserializeOwned> ParametersFile<Parameters> {
+    /// Parses the content of a parameters file written in the given format.
+    pub fn parse(input: &str, format: ParametersFormat) -> Result<Self, ParametersLoadingError> {
+        match format {
+            ParametersFormat::Toml => {
+                toml::from_str(input).map_err(|error| ParametersLoadingError::Parse(error.into()))
+            }
+            ParametersFormat::Json => serde_json::from_str(input)
+                .map_err(|error| ParametersLoadingError::Parse(error.into())),
+        }
+    }
+
+    /// Reads a parameters file, whose format is deduced from its extension.
+    pub fn read(path: &Path) -> Result<Self, ParametersLoadingError> {
+        let format = ParametersFormat::from_path(path)
+            .ok_or_else(|| ParametersLoadingError::UnknownFormat(path.display().to_string()))?;
+        let input = std::fs::read_to_string(path).map_err(ParametersLoadingError::Io)?;
+        Self::parse(&input, format)
+    }
+}
+
+/// An error occurring while loading a parameters file.
+#[derive(Debug)]
+pub enum ParametersLoadingError {
+    /// The extension of the file is neither `toml` nor `json`.
+    UnknownFormat(String),
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file content does not match the parameters of the fixture.
+    Parse(Box<dyn Error>),
+}
+
+impl Display for ParametersLoadingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParametersLoadingError::UnknownFormat(path) => write!(
+                f,
+                "The format of {} is unknown. The extension must be `toml` or `json`.",
+                path
+            ),
+            ParametersLoadingError::Io(error) => {
+                write!(f, "The parameters file could not be read: {}", error)
+            }
+            ParametersLoadingError::Parse(error) => {
+                write!(f, "The parameters file could not be parsed: {}", error)
+            }
+        }
+    }
+}
+
+impl Error for ParametersLoadingError {}
@@ -1,9 +1,26 @@
 //! A module containing sampling entry points for raw integers
 use concrete_commons::numeric::{CastInto, UnsignedInteger};
 use concrete_core::backends::core::private::math::random::RandomGenerator;
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Range;
 
+thread_local! {
+    static GENERATOR: RefCell<RandomGenerator> = RefCell::new(RandomGenerator::new(None));
+}
+
+/// Seeds the generator used to sample raw integers on the current thread.
+///
+/// The raw integers sampled afterwards on this thread are fully determined by the seed, which
+/// allows the prototypes of a fixture to be generated again when a failure must be reproduced.
+pub fn seed_raw_generator(seed: u128) {
+    GENERATOR.with(|generator| *generator.borrow_mut() = RandomGenerator::new(Some(seed)));
+}
+
+fn with_generator<Output>(f: impl FnOnce(&mut RandomGenerator) -> Output) -> Output {
+    GENERATOR.with(|generator| f(&mut generator.borrow_mut()))
+}
+
 /// A trait to generate raw unsigned integer values.
 pub trait RawUnsignedIntegers: UnsignedInteger + CastInto<f64> + CastInto<i64> + Debug {
     fn one() -> Self;
@@ -47,55 +64,57 @@ impl RawUnsignedIntegers for u32 {
     }
 
     fn pick(array: &[Self]) -> Self {
-        let mut generator = RandomGenerator::new(None);
-        let index: u16 = generator.random_uniform();
-        let index = index % array.len() as u16;
-        array[index as usize]
+        with_generator(|generator| {
+            let index: u16 = generator.random_uniform();
+            let index = index % array.len() as u16;
+            array[index as usize]
+        })
     }
 
     fn pick_vec(array: &[Self], size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        (0..size)
-            .map(|_| {
-                let index: u16 = generator.random_uniform();
-                let index = index % array.len() as u16;
-                array[index as usize]
-            })
-            .collect()
+        with_generator(|generator| {
+            (0..size)
+                .map(|_| {
+                    let index: u16 = generator.random_uniform();
+                    let index = index % array.len() as u16;
+                    array[index as usize]
+                })
+                .collect()
+        })
     }
 
     fn uniform() -> Self {
-        let mut generator = RandomGenerator::new(None);
-        generator.random_uniform()
+        with_generator(|generator| generator.random_uniform())
     }
     fn uniform_vec(size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        generator.random_uniform_tensor(size).into_container()
+        with_generator(|generator| generator.random_uniform_tensor(size).into_container())
     }
     fn uniform_n_msb(n: usize) -> Self {
-        let mut generator = RandomGenerator::new(None);
-        generator.random_uniform_n_msb(n)
+        with_generator(|generator| generator.random_uniform_n_msb(n))
     }
     fn uniform_n_msb_vec(n: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        generator
-            .random_uniform_n_msb_tensor(size, n)
-            .into_container()
+        with_generator(|generator| {
+            generator
+                .random_uniform_n_msb_tensor(size, n)
+                .into_container()
+        })
     }
 
     fn uniform_between(range: Range<usize>) -> Self {
-        let mut generator = RandomGenerator::new(None);
-        let val: u32 = generator.random_uniform();
-        val % ((range.end as u32) - (range.start as u32)) + (range.start as u32)
+        with_generator(|generator| {
+            let val: u32 = generator.random_uniform();
+            val % ((range.end as u32) - (range.start as u32)) + (range.start as u32)
+        })
     }
 
     fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        let mut output = generator.random_uniform_tensor(size).into_container();
-        output.iter_mut().for_each(|val| {
-            *val %= ((range.end as u32) - (range.start as u32)) + (range.start as u32)
-        });
-        output
+        with_generator(|generator| {
+            let mut output = generator.random_uniform_tensor(size).into_container();
+            output.iter_mut().for_each(|val| {
+                *val %= ((range.end as u32) - (range.start as u32)) + (range.start as u32)
+            });
+            output
+        })
     }
 
     fn uniform_zero_centered(width: usize) -> Self {
@@ -106,15 +125,16 @@ impl RawUnsignedIntegers for u32 {
         val as u32
     }
     fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        let mut output = generator.random_uniform_tensor(size).into_container();
-        output.iter_mut().for_each(|val| {
-            let v = *val % (width as u32);
-            let v: i32 = v as i32;
-            let v = v - ((width / 2) as i32);
-            *val = v as u32;
-        });
-        output
+        with_generator(|generator| {
+            let mut output = generator.random_uniform_tensor(size).into_container();
+            output.iter_mut().for_each(|val| {
+                let v = *val % (width as u32);
+                let v: i32 = v as i32;
+                let v = v - ((width / 2) as i32);
+                *val = v as u32;
+            });
+            output
+        })
     }
 }
 
@@ -140,54 +160,56 @@ impl RawUnsignedIntegers for u64 {
     }
 
     fn pick(array: &[Self]) -> Self {
-        let mut generator = RandomGenerator::new(None);
-        let index: u16 = generator.random_uniform();
-        let index = index % array.len() as u16;
-        array[index as usize]
+        with_generator(|generator| {
+            let index: u16 = generator.random_uniform();
+            let index = index % array.len() as u16;
+            array[index as usize]
+        })
     }
 
     fn pick_vec(array: &[Self], size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        (0..size)
-            .map(|_| {
-                let index: u16 = generator.random_uniform();
-                let index = index % array.len() as u16;
-                array[index as usize]
-            })
-            .collect()
+        with_generator(|generator| {
+            (0..size)
+                .map(|_| {
+                    let index: u16 = generator.random_uniform();
+                    let index = index % array.len() as u16;
+                    array[index as usize]
+                })
+                .collect()
+        })
     }
     fn uniform() -> Self {
-        let mut generator = RandomGenerator::new(None);
-        generator.random_uniform()
+        with_generator(|generator| generator.random_uniform())
     }
     fn uniform_vec(size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        generator.random_uniform_tensor(size).into_container()
+        with_generator(|generator| generator.random_uniform_tensor(size).into_container())
     }
     fn uniform_n_msb(n: usize) -> Self {
-        let mut generator = RandomGenerator::new(None);
-        generator.random_uniform_n_msb(n)
+        with_generator(|generator| generator.random_uniform_n_msb(n))
     }
     fn uniform_n_msb_vec(n: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        generator
-            .random_uniform_n_msb_tensor(size, n)
-            .into_container()
+        with_generator(|generator| {
+            generator
+                .random_uniform_n_msb_tensor(size, n)
+                .into_container()
+        })
     }
 
     fn uniform_between(range: Range<usize>) -> Self {
-        let mut generator = RandomGenerator::new(None);
-        let val: u64 = generator.random_uniform();
-        val % ((range.end as u64) - (range.start as u64)) + (range.start as u64)
+        with_generator(|generator| {
+            let val: u64 = generator.random_uniform();
+            val % ((range.end as u64) - (range.start as u64)) + (range.start as u64)
+        })
     }
 
     fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        let mut output = generator.random_uniform_tensor(size).into_container();
-        output.iter_mut().for_each(|val| {
-            *val %= ((range.end as u64) - (range.start as u64)) + (range.start as u64)
-        });
-        output
+        with_generator(|generator| {
+            let mut output = generator.random_uniform_tensor(size).into_container();
+            output.iter_mut().for_each(|val| {
+                *val %= ((range.end as u64) - (range.start as u64)) + (range.start as u64)
+            });
+            output
+        })
     }
 
     fn uniform_zero_centered(width: usize) -> Self {
@@ -198,14 +220,15 @@ impl RawUnsignedIntegers for u64 {
         val as u64
     }
     fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
-        let mut output = generator.random_uniform_tensor(size).into_container();
-        output.iter_mut().for_each(|val| {
-            let v = *val % (width as u64);
-            let v: i64 = v as i64;
-            let v = v - ((width / 2) as i64);
-            *val = v as u64;
-        });
-        output
+        with_generator(|generator| {
+            let mut output = generator.random_uniform_tensor(size).into_container();
+            output.iter_mut().for_each(|val| {
+                let v = *val % (width as u64);
+                let v: i64 = v as i64;
+                let v = v - ((width / 2) as i64);
+                *val = v as u64;
+            });
+            output
+        })
     }
 }
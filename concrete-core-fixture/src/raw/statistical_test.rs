@@ -3,6 +3,7 @@ use crate::raw::generation::RawUnsignedIntegers;
 use concrete_commons::dispersion::{DispersionParameter, Variance};
 use concrete_core::backends::core::private::math::random::RandomGenerator;
 use kolmogorov_smirnov;
+use std::fmt::{Display, Formatter};
 
 /// A function performing a Kolmogorov Smirnov statistical test.
 ///
@@ -90,6 +91,195 @@ where
     true
 }
 
+/// A function returning the errors of an array of samples, as signed fractions of the torus.
+///
+/// The `expected_means` argument encodes the mean expected for each element of the `tested` array.
+pub fn torus_errors<Raw>(tested: &[Raw], expected_means: &[Raw]) -> Vec<f64>
+where
+    Raw: RawUnsignedIntegers,
+{
+    tested
+        .iter()
+        .zip(expected_means.iter())
+        .map(|(tested, mean)| torus_modular_distance(*tested, *mean))
+        .collect()
+}
+
+/// A function returning the base 2 logarithm of the empirical standard deviation of some errors.
+pub fn log2_standard_dev(errors: &[f64]) -> f64 {
+    let mean = errors.iter().sum::<f64>() / errors.len() as f64;
+    let variance = errors.iter().map(|x| f64::powi(x - mean, 2)).sum::<f64>()
+        / (errors.len().max(2) - 1) as f64;
+    f64::log2(f64::sqrt(variance))
+}
+
+/// A function performing a two-sample Kolmogorov Smirnov statistical test.
+///
+/// The `first_errors` and `second_errors` arguments point to arrays of errors which are tested to
+/// follow the same distribution. As in [`assert_noise_distribution`], a rejected test is tolerated
+/// when the standard deviations of the two samples differ by less than half a bit. Samples too
+/// small to perform the test are only compared through their standard deviations.
+pub fn assert_same_noise_distribution(first_errors: &[f64], second_errors: &[f64]) -> bool {
+    let first_noiseless = first_errors.iter().all(|error| *error == 0.);
+    let second_noiseless = second_errors.iter().all(|error| *error == 0.);
+    if first_noiseless || second_noiseless {
+        return first_noiseless == second_noiseless;
+    }
+    let confidence = 0.95;
+    let rejected = first_errors.len() <= 7
+        || second_errors.len() <= 7
+        || kolmogorov_smirnov::test_f64(first_errors, second_errors, confidence).is_rejected;
+    !rejected | {
+        // Here again, the half bit of slack is arbitrary.
+        let delta = log2_standard_dev(first_errors) - log2_standard_dev(second_errors);
+        delta.abs() <= 0.5
+    }
+}
+
+/// An estimation of the probability that the output of an operation is decoded to a wrong message.
+///
+/// Measuring failure probabilities such as `2^-40` directly would require an intractable number of
+/// executions. Instead, the variance of the errors is measured over a sample of executions, and
+/// the failure probability is extrapolated from it, assuming that the errors follow a centered
+/// normal distribution. The number of failures actually observed in the sample is reported as
+/// well, which allows to detect errors which are not normally distributed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FailureProbabilityEstimate {
+    /// The number of errors measured.
+    pub sample_size: usize,
+    /// The number of errors whose magnitude exceeded the failure threshold.
+    pub failures: usize,
+    /// The largest error magnitude, as a fraction of the torus, still decoded correctly.
+    pub threshold: f64,
+    /// The measured variance of the errors.
+    pub measured_variance: Variance,
+    /// The bounds of the 95% confidence interval of the variance of the errors.
+    pub variance_confidence_interval: (Variance, Variance),
+    /// The base 2 logarithm of the failure probability implied by the measured variance.
+    pub log2_failure_probability: f64,
+    /// The base 2 logarithm of the failure probability implied by the upper bound of the
+    /// confidence interval of the variance.
+    pub log2_failure_probability_upper_bound: f64,
+}
+
+impl FailureProbabilityEstimate {
+    /// Estimates the failure probability from a sample of errors, given as signed fractions of
+    /// the torus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `errors` is empty.
+    pub fn from_errors(errors: impl Iterator<Item = f64>, threshold: f64) -> Self {
+        let (mut sample_size, mut failures, mut sum_of_squares) = (0, 0, 0.);
+        for error in errors {
+            sample_size += 1;
+            sum_of_squares += error * error;
+            if error.abs() > threshold {
+                failures += 1;
+            }
+        }
+        assert!(sample_size > 0, "No errors were measured.");
+
+        // The errors being centered, the variance estimator follows a chi-square distribution
+        // with one degree of freedom per error.
+        let measured_variance = sum_of_squares / sample_size as f64;
+        let lower_bound = sum_of_squares / chi_square_quantile(sample_size, 0.975);
+        let upper_bound = sum_of_squares / chi_square_quantile(sample_size, 0.025);
+        let log2_failure_probability = |variance: f64| {
+            if variance == 0. {
+                f64::NEG_INFINITY
+            } else {
+                log2_erfc(threshold / f64::sqrt(2. * variance))
+            }
+        };
+        FailureProbabilityEstimate {
+            sample_size,
+            failures,
+            threshold,
+            measured_variance: Variance(measured_variance),
+            variance_confidence_interval: (Variance(lower_bound), Variance(upper_bound)),
+            log2_failure_probability: log2_failure_probability(measured_variance),
+            log2_failure_probability_upper_bound: log2_failure_probability(upper_bound),
+        }
+    }
+
+    /// Returns the fraction of the sample whose errors exceeded the failure threshold.
+    pub fn empirical_failure_rate(&self) -> f64 {
+        self.failures as f64 / self.sample_size as f64
+    }
+
+    /// Returns whether the failure probability is below `2^log2_target`, with a 97.5% confidence.
+    ///
+    /// A sample in which a failure was observed never meets a target smaller than the empirical
+    /// failure rate.
+    pub fn meets_target(&self, log2_target: f64) -> bool {
+        self.log2_failure_probability_upper_bound <= log2_target
+            && (self.failures == 0 || self.empirical_failure_rate().log2() <= log2_target)
+    }
+}
+
+impl Display for FailureProbabilityEstimate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "sample size: {}, failures: {}",
+            self.sample_size, self.failures
+        )?;
+        writeln!(
+            f,
+            "measured std dev: 2^{:.3} (95% interval: [2^{:.3}, 2^{:.3}]), threshold: 2^{:.3}",
+            self.measured_variance.get_log_standard_dev(),
+            self.variance_confidence_interval.0.get_log_standard_dev(),
+            self.variance_confidence_interval.1.get_log_standard_dev(),
+            self.threshold.log2()
+        )?;
+        write!(
+            f,
+            "failure probability: 2^{:.3} (upper bound: 2^{:.3})",
+            self.log2_failure_probability, self.log2_failure_probability_upper_bound
+        )
+    }
+}
+
+// Approximates the quantile of order `probability` of the chi-square distribution with `degrees`
+// degrees of freedom, using the Wilson-Hilferty transformation.
+fn chi_square_quantile(degrees: usize, probability: f64) -> f64 {
+    let k = degrees as f64;
+    let z = normal_quantile(probability);
+    let h = 2. / (9. * k);
+    k * f64::powi(1. - h + z * h.sqrt(), 3).max(f64::MIN_POSITIVE)
+}
+
+// Approximates the quantile of order `probability` of the standard normal distribution, with an
+// absolute error smaller than 4.5e-4 (Abramowitz and Stegun, 26.2.23).
+fn normal_quantile(probability: f64) -> f64 {
+    let (p, sign) = if probability < 0.5 {
+        (probability, -1.)
+    } else {
+        (1. - probability, 1.)
+    };
+    let t = f64::sqrt(-2. * p.ln());
+    let numerator = 2.515517 + t * (0.802853 + t * 0.010328);
+    let denominator = 1. + t * (1.432788 + t * (0.189269 + t * 0.001308));
+    sign * (t - numerator / denominator)
+}
+
+// Computes the base 2 logarithm of the complementary error function of a non-negative value, with
+// a relative error smaller than 1.2e-7 (Numerical Recipes, 6.2). Working with the logarithm
+// avoids the underflow of the function for large values.
+fn log2_erfc(x: f64) -> f64 {
+    let t = 1. / (1. + 0.5 * x);
+    let polynomial = -1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    t.log2() + (polynomial - x * x) / std::f64::consts::LN_2
+}
+
 fn torus_modular_distance<T: RawUnsignedIntegers>(first: T, other: T) -> f64 {
     let d0 = first.wrapping_sub(other);
     let d1 = other.wrapping_sub(first);
@@ -0,0 +1,322 @@
+//! A binary running a fixture of the core engine over the parameters read from a file.
+//!
+//! For every set of parameters of the file, the fixture is either tested once or stressed, and a
+//! summary table of the results is printed at the end. The process exits with a non-zero code if
+//! one of the sets of parameters failed. See the `parameters` module of the library for the format
+//! of the file.
+use clap::{App, AppSettings, Arg};
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_core_fixture::{Repetitions, SampleSize};
+use paste::paste;
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// The ways a fixture can be executed for a set of parameters.
+#[derive(Clone, Copy, Debug)]
+enum Mode {
+    /// Generates a single set of repetition prototypes and tests a sample of executions.
+    Test,
+    /// Repeats the test with new repetition prototypes a number of times.
+    Stress,
+}
+
+struct Options {
+    parameters: PathBuf,
+    mode: Mode,
+    repetitions: Repetitions,
+    sample_size: SampleSize,
+}
+
+/// The result of the execution of a fixture for a set of parameters.
+struct Row {
+    parameters: String,
+    passed: bool,
+    duration: Duration,
+}
+
+fn sweep<Precision, RelatedEntities, F>(options: &Options) -> Result<Vec<Row>, Box<dyn Error>>
+where
+    Precision: IntegerPrecision,
+    F: Fixture<Precision, CoreEngine, RelatedEntities>,
+    F::Parameters: 'static,
+{
+    let mut maker = Maker::default();
+    let mut engine = CoreEngine::new().unwrap();
+    let rows = F::load_parameters_iterator(&options.parameters)?
+        .map(|parameters| {
+            let start = Instant::now();
+            let passed = match options.mode {
+                Mode::Test => {
+                    let repetition_proto =
+                        F::generate_random_repetition_prototypes(&parameters, &mut maker);
+                    F::test(
+                        &mut maker,
+                        &mut engine,
+                        &parameters,
+                        &repetition_proto,
+                        options.sample_size,
+                    )
+                }
+                Mode::Stress => F::stress(
+                    &mut maker,
+                    &mut engine,
+                    &parameters,
+                    options.repetitions,
+                    options.sample_size,
+                ),
+            };
+            Row {
+                parameters: format!("{:?}", parameters),
+                passed,
+                duration: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(rows)
+}
+
+macro_rules! fixtures {
+    ($(($fixture: ident, ($($types:ident),+))),+ $(,)?) => {
+        /// The names of the fixtures which can be executed.
+        const FIXTURES: &[&str] = &[$(stringify!($fixture)),+];
+
+        fn dispatch(
+            fixture: &str,
+            precision: &str,
+            options: &Options,
+        ) -> Result<Vec<Row>, Box<dyn Error>> {
+            $(
+                if fixture == stringify!($fixture) {
+                    return paste! {
+                        match precision {
+                            "32" => sweep::<Precision32, ($([< $types 32 >],)+), $fixture>(options),
+                            _ => sweep::<Precision64, ($([< $types 64 >],)+), $fixture>(options),
+                        }
+                    };
+                }
+            )+
+            Err(format!("Unknown fixture {}. Use `--list` to show the fixtures.", fixture).into())
+        }
+    };
+}
+
+fixtures! {
+    (CleartextVectorCreationFixture, (CleartextVector)),
+    (CleartextVectorDiscardingRetrievalFixture, (CleartextVector)),
+    (CleartextVectorRetrievalFixture, (CleartextVector)),
+    (GlweCiphertextDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    (GlweCiphertextDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    (GlweCiphertextDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    (GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    (GlweCiphertextTrivialDecryptionFixture, (PlaintextVector, GlweCiphertext)),
+    (GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
+    (GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
+    (GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
+    (GlweCiphertextVectorDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
+    (GlweCiphertextVectorTrivialDecryptionFixture, (PlaintextVector, GlweCiphertextVector)),
+    (GlweCiphertextVectorTrivialEncryptionFixture, (PlaintextVector, GlweCiphertextVector)),
+    (GlweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey,
+        GlweCiphertextVector)),
+    (GlweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey,
+        GlweCiphertextVector)),
+    (GlweCiphertextVectorZeroEncryptionFixture, (GlweSecretKey, GlweCiphertextVector)),
+    (LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    (LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
+    (LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
+    (LweCiphertextTrivialDecryptionFixture, (Plaintext, LweCiphertext)),
+    (LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
+    (LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    (LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    (LweCiphertextVectorDecryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    (LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, LweSecretKey,
+        LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, LweSecretKey,
+        LweCiphertextVector)),
+    (LweCiphertextCleartextDiscardingMultiplicationFixture, (LweCiphertext, Cleartext,
+        LweCiphertext)),
+    (LweCiphertextCleartextFusingMultiplicationFixture, (LweCiphertext, Cleartext)),
+    (LweCiphertextFusingOppositeFixture, (LweCiphertext)),
+    (LweCiphertextFusingSubtractionFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextVectorFusingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextVectorFusingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector,
+        CleartextVector, Plaintext, LweCiphertext)),
+    (LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingOppositeFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextFusingAdditionFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextVectorTrivialDecryptionFixture, (PlaintextVector, LweCiphertextVector)),
+    (LweCiphertextVectorTrivialEncryptionFixture, (PlaintextVector, LweCiphertextVector)),
+    (LweCiphertextDiscardingSubtractionFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingDecryptionFixture, (LweCiphertext, LweSecretKey, Plaintext)),
+    (LweCiphertextPlaintextDiscardingAdditionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
+    (LweCiphertextPlaintextFusingAdditionFixture, (Plaintext, LweCiphertext)),
+    (LweCiphertextPlaintextDiscardingSubtractionFixture, (LweCiphertext, Plaintext,
+        LweCiphertext)),
+    (LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
+    (LweCiphertextDiscardingBootstrapFixture1, (FourierLweBootstrapKey, GlweCiphertext,
+        LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingBootstrapFixture2, (FourierLweBootstrapKey, GlweCiphertext,
+        LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingBootstrapFixture3, (FourierLweBootstrapKey, GlweCiphertext,
+        LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+    (LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector,
+        PackingKeyswitchKey, GlweCiphertext)),
+    (PlaintextVectorDiscardingRetrievalFixture, (PlaintextVector)),
+    (PlaintextVectorCreationFixture, (PlaintextVector)),
+    (PlaintextVectorRetrievalFixture, (PlaintextVector)),
+    (GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext,
+        GlweCiphertext)),
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext,
+        FourierGgswCiphertext, GlweCiphertext)),
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceFixture, (GlweCiphertext,
+        FourierGgswCiphertext, GlweCiphertext, ExternalProductWorkspace)),
+}
+
+fn print_summary(rows: &[Row]) {
+    let headers = ["#", "Parameters", "Result", "Time (s)"];
+    let cells: Vec<[String; 4]> = rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            [
+                index.to_string(),
+                row.parameters.clone(),
+                if row.passed { "passed" } else { "FAILED" }.to_string(),
+                format!("{:.3}", row.duration.as_secs_f64()),
+            ]
+        })
+        .collect();
+    let mut widths = headers.map(str::len);
+    for row in cells.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let print_row = |row: [&str; 4]| {
+        println!(
+            "| {:>w0$} | {:<w1$} | {:<w2$} | {:>w3$} |",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    };
+    print_row(headers);
+    println!(
+        "|{}|",
+        widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("|")
+    );
+    for row in cells.iter() {
+        print_row([&row[0], &row[1], &row[2], &row[3]]);
+    }
+    let failures = rows.iter().filter(|row| !row.passed).count();
+    println!("\n{} passed, {} failed.", rows.len() - failures, failures);
+}
+
+fn main() {
+    let matches = App::new("sweep")
+        .about("Runs a fixture of the core engine over parameters read from a TOML or JSON file")
+        .arg(
+            Arg::with_name("list")
+                .long("list")
+                .help("Prints the names of the available fixtures"),
+        )
+        .arg(
+            Arg::with_name("fixture")
+                .help("The name of the fixture to execute")
+                .required_unless("list")
+                .index(1),
+        )
+        .arg(
+            Arg::with_name("parameters")
+                .help("The file containing the parameters (with a `toml` or `json` extension)")
+                .required_unless("list")
+                .index(2),
+        )
+        .arg(
+            Arg::with_name("mode")
+                .long("mode")
+                .takes_value(true)
+                .possible_values(&["test", "stress"])
+                .default_value("stress")
+                .help("Tests a single repetition, or stresses the fixture over many repetitions"),
+        )
+        .arg(
+            Arg::with_name("precision")
+                .long("precision")
+                .takes_value(true)
+                .possible_values(&["32", "64"])
+                .default_value("64")
+                .help("The integer precision of the entities"),
+        )
+        .arg(
+            Arg::with_name("repetitions")
+                .long("repetitions")
+                .takes_value(true)
+                .default_value("10")
+                .help("The number of repetitions in stress mode"),
+        )
+        .arg(
+            Arg::with_name("sample-size")
+                .long("sample-size")
+                .takes_value(true)
+                .default_value("100")
+                .help("The number of executions sampled for every repetition"),
+        )
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .get_matches();
+
+    if matches.is_present("list") {
+        for fixture in FIXTURES {
+            println!("{}", fixture);
+        }
+        return;
+    }
+
+    let parse_count = |name: &str| -> usize {
+        let value = matches.value_of(name).unwrap();
+        value.parse().unwrap_or_else(|_| {
+            eprintln!("The value of --{} must be an integer, got {}.", name, value);
+            std::process::exit(2);
+        })
+    };
+    let options = Options {
+        parameters: PathBuf::from(matches.value_of("parameters").unwrap()),
+        mode: match matches.value_of("mode").unwrap() {
+            "test" => Mode::Test,
+            _ => Mode::Stress,
+        },
+        repetitions: Repetitions(parse_count("repetitions")),
+        sample_size: SampleSize(parse_count("sample-size")),
+    };
+    let fixture = matches.value_of("fixture").unwrap();
+    let precision = matches.value_of("precision").unwrap();
+    match dispatch(fixture, precision, &options) {
+        Ok(rows) => {
+            print_summary(&rows);
+            if rows.iter().any(|row| !row.passed) {
+                std::process::exit(1);
+            }
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+    }
+}
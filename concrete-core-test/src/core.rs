@@ -1,4 +1,5 @@
-use crate::{REPETITIONS, SAMPLE_SIZE};
+use crate::differential::DifferentialFixture;
+use crate::{seed, FAILURE_PROBABILITY_TARGET, FAILURE_SAMPLE_SIZE, REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
 use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
@@ -95,6 +96,7 @@ test! {
     (LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
     (LweCiphertextDiscardingBootstrapFixture1, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     (LweCiphertextDiscardingBootstrapFixture2, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingBootstrapFixture3, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     (LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
     (LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector,
         PackingKeyswitchKey, GlweCiphertext)),
@@ -105,5 +107,99 @@ test! {
     (PlaintextVectorCreationFixture, (PlaintextVector)),
     (PlaintextVectorRetrievalFixture, (PlaintextVector)),
     (GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
-    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext))
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext,
+        ExternalProductWorkspace))
+}
+
+macro_rules! failure_probability_test {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< failure_probability_test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = CoreEngine::new().unwrap();
+                for parameters in <$fixture as Fixture<
+                        $precision,
+                        CoreEngine,
+                        ($($types,)+),
+                    >>::generate_parameters_iterator() {
+                    let estimate =
+                        <$fixture as Fixture<
+                            $precision,
+                            CoreEngine,
+                            ($($types,)+),
+                        >>::estimate_failure_probability(
+                            &mut maker,
+                            &mut engine,
+                            &parameters,
+                            FAILURE_SAMPLE_SIZE,
+                        ).unwrap();
+                    println!("{:?}\n{}", parameters, estimate);
+                    assert!(estimate.meets_target(FAILURE_PROBABILITY_TARGET));
+                }
+            }
+        }
+    };
+    ($(($fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                failure_probability_test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                failure_probability_test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+failure_probability_test! {
+    (LweCiphertextDiscardingBootstrapFixture3, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext))
+}
+
+// The core engine is compared with a second instance of itself, which exercises the differential
+// harness. Other backends can be compared with the core one the same way.
+macro_rules! differential_test {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< differential_test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let seed = seed();
+                let mut first_engine = CoreEngine::new().unwrap();
+                let mut second_engine = CoreEngine::new().unwrap();
+                let divergences =
+                    <$fixture as DifferentialFixture<
+                        $precision,
+                        CoreEngine,
+                        ($($types,)+),
+                        CoreEngine,
+                        ($($types,)+),
+                    >>::compare_all_parameters(
+                        &mut first_engine,
+                        &mut second_engine,
+                        seed,
+                        REPETITIONS,
+                        SAMPLE_SIZE,
+                    );
+                for divergence in divergences.iter() {
+                    println!("{}", divergence);
+                }
+                assert!(divergences.is_empty(), "Divergences found with seed {}.", seed);
+            }
+        }
+    };
+    ($(($fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                differential_test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                differential_test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+differential_test! {
+    (PlaintextCreationFixture, (Plaintext)),
+    (LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
+    (LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    (LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext))
 }
@@ -0,0 +1,403 @@
+//! A module containing a differential testing harness, comparing the engines of two backends.
+//!
+//! The correctness tests of the backend modules verify the statistical properties of a single
+//! engine. When a backend implements an operator which is already implemented by another backend,
+//! we also want to verify that both engines behave the same when given the same inputs. The
+//! [`DifferentialFixture`] trait, implemented for every [`Fixture`] which can be instantiated for
+//! two engines, does just that:
+//! ```text
+//! | Iterate over a set of parameters:
+//! | | Repeat multiple times:
+//! | | | Derive a seed for the repetition, and seed a maker with it.
+//! | | | Generate repetition-level input prototypes.
+//! | | | Sample multiple executions:
+//! | | | | Generate sample-level input prototypes.
+//! | | | | Execute the first engine on entities synthesized from the prototypes.
+//! | | | | Execute the second engine on entities synthesized from the same prototypes.
+//! | | | Verify that the samples of both engines match the criteria of the fixture.
+//! | | | Compare the decrypted outputs and the noise distributions of both samples.
+//! ```
+//!
+//! Every [`Divergence`] is reported with the parameters and the seed of the repetition in which
+//! it occurred. Giving them to [`DifferentialFixture::compare`] generates the same prototypes
+//! again, which allows to reproduce the divergence.
+//!
+//! # Note:
+//!
+//! The seed only determines the prototypes generated by the maker. The randomness used by the
+//! engines themselves (to encrypt, or to generate keys) is not controlled by the harness.
+use concrete_core::backends::core::private::math::random::RandomGenerator;
+use concrete_core::prelude::AbstractEngine;
+use concrete_core_fixture::fixture::Fixture;
+use concrete_core_fixture::generation::{IntegerPrecision, Maker};
+use concrete_core_fixture::raw::generation::RawUnsignedIntegers;
+use concrete_core_fixture::raw::statistical_test::{
+    assert_same_noise_distribution, log2_standard_dev, torus_errors,
+};
+use concrete_core_fixture::{Repetitions, SampleSize};
+use std::fmt::{Debug, Display, Formatter};
+
+/// A trait for the outcomes of fixtures, which can be compared between two engines.
+pub trait DifferentialOutcome<Raw: RawUnsignedIntegers> {
+    /// Returns the expected and actual raw values contained in the outcome.
+    fn expected_and_actual(&self) -> (Vec<Raw>, Vec<Raw>);
+}
+
+impl<Raw: RawUnsignedIntegers> DifferentialOutcome<Raw> for () {
+    fn expected_and_actual(&self) -> (Vec<Raw>, Vec<Raw>) {
+        (Vec::new(), Vec::new())
+    }
+}
+
+impl<Raw: RawUnsignedIntegers> DifferentialOutcome<Raw> for (Raw, Raw) {
+    fn expected_and_actual(&self) -> (Vec<Raw>, Vec<Raw>) {
+        (vec![self.0], vec![self.1])
+    }
+}
+
+impl<Raw: RawUnsignedIntegers> DifferentialOutcome<Raw> for (Vec<Raw>, Vec<Raw>) {
+    fn expected_and_actual(&self) -> (Vec<Raw>, Vec<Raw>) {
+        (self.0.clone(), self.1.clone())
+    }
+}
+
+/// A type identifying one of the two engines compared by a [`DifferentialFixture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    First,
+    Second,
+}
+
+/// The different ways two engines can diverge.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DivergenceKind {
+    /// The sample of one of the engines does not match the criteria of the fixture.
+    FailedVerification(Side),
+    /// The first engine output values without noise, but the second engine output other values.
+    OutputMismatch { sample: usize },
+    /// The noise of the outputs of the two engines do not follow the same distribution.
+    NoiseDistributionMismatch {
+        first_log2_std_dev: f64,
+        second_log2_std_dev: f64,
+    },
+}
+
+impl Display for DivergenceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DivergenceKind::FailedVerification(side) => {
+                write!(
+                    f,
+                    "The {:?} engine failed the verification of the fixture.",
+                    side
+                )
+            }
+            DivergenceKind::OutputMismatch { sample } => {
+                write!(
+                    f,
+                    "The engines output different values for sample {}.",
+                    sample
+                )
+            }
+            DivergenceKind::NoiseDistributionMismatch {
+                first_log2_std_dev,
+                second_log2_std_dev,
+            } => write!(
+                f,
+                "The noise distributions of the engines differ (log2 std dev {} vs {}).",
+                first_log2_std_dev, second_log2_std_dev
+            ),
+        }
+    }
+}
+
+/// A divergence between two engines, along with the parameters and seed needed to reproduce it.
+#[derive(Debug)]
+pub struct Divergence<Parameters> {
+    pub parameters: Parameters,
+    pub seed: u128,
+    pub kind: DivergenceKind,
+}
+
+impl<Parameters: Debug> Display for Divergence<Parameters> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} Parameters: {:?}, seed: {}",
+            self.kind, self.parameters, self.seed
+        )
+    }
+}
+
+/// A trait for fixtures which can execute two engines on the same prototypes.
+///
+/// This trait is implemented for every type implementing [`Fixture`] for two engines, as long as
+/// both implementations use the same parameters, prototypes and outcomes.
+pub trait DifferentialFixture<Precision, FirstEngine, FirstEntities, SecondEngine, SecondEntities> {
+    /// The type of the parameters of the fixture.
+    type Parameters: Debug;
+
+    /// A method which compares the two engines over multiple randomly generated raw inputs, over
+    /// multiple sets of parameters.
+    ///
+    /// The seeds of the repetitions are derived from `seed`, and every repetition in which the
+    /// engines diverge is reported.
+    fn compare_all_parameters(
+        first_engine: &mut FirstEngine,
+        second_engine: &mut SecondEngine,
+        seed: u128,
+        repetitions: Repetitions,
+        sample_size: SampleSize,
+    ) -> Vec<Divergence<Self::Parameters>>;
+
+    /// A method which compares the two engines over a sample of executions, for a fixed set of
+    /// parameters, and the prototypes generated from `seed`.
+    fn compare(
+        first_engine: &mut FirstEngine,
+        second_engine: &mut SecondEngine,
+        parameters: &Self::Parameters,
+        seed: u128,
+        sample_size: SampleSize,
+    ) -> Result<(), DivergenceKind>;
+}
+
+impl<Fix, Precision, FirstEngine, FirstEntities, SecondEngine, SecondEntities>
+    DifferentialFixture<Precision, FirstEngine, FirstEntities, SecondEngine, SecondEntities> for Fix
+where
+    Precision: IntegerPrecision,
+    FirstEngine: AbstractEngine,
+    SecondEngine: AbstractEngine,
+    Fix: Fixture<Precision, FirstEngine, FirstEntities>,
+    Fix: Fixture<
+        Precision,
+        SecondEngine,
+        SecondEntities,
+        Parameters = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::Parameters,
+        RepetitionPrototypes = <Fix as Fixture<
+            Precision,
+            FirstEngine,
+            FirstEntities,
+        >>::RepetitionPrototypes,
+        SamplePrototypes = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::SamplePrototypes,
+        Outcome = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::Outcome,
+    >,
+    <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::Outcome:
+        DifferentialOutcome<Precision::Raw>,
+{
+    type Parameters = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::Parameters;
+
+    fn compare_all_parameters(
+        first_engine: &mut FirstEngine,
+        second_engine: &mut SecondEngine,
+        seed: u128,
+        repetitions: Repetitions,
+        sample_size: SampleSize,
+    ) -> Vec<Divergence<Self::Parameters>> {
+        let mut seeder = RandomGenerator::new(Some(seed));
+        let mut divergences = Vec::new();
+        for parameters in
+            <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::generate_parameters_iterator()
+        {
+            for _ in 0..repetitions.0 {
+                let repetition_seed: u128 = seeder.random_uniform();
+                if let Err(kind) = <Fix as DifferentialFixture<
+                    Precision,
+                    FirstEngine,
+                    FirstEntities,
+                    SecondEngine,
+                    SecondEntities,
+                >>::compare(
+                    first_engine,
+                    second_engine,
+                    &parameters,
+                    repetition_seed,
+                    sample_size,
+                ) {
+                    // The other repetitions would most likely diverge the same way.
+                    divergences.push(Divergence {
+                        parameters,
+                        seed: repetition_seed,
+                        kind,
+                    });
+                    break;
+                }
+            }
+        }
+        divergences
+    }
+
+    fn compare(
+        first_engine: &mut FirstEngine,
+        second_engine: &mut SecondEngine,
+        parameters: &Self::Parameters,
+        seed: u128,
+        sample_size: SampleSize,
+    ) -> Result<(), DivergenceKind> {
+        let mut maker = Maker::new_seeded(seed);
+        let repetition_proto = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::generate_random_repetition_prototypes(
+            parameters, &mut maker,
+        );
+        let mut first_outcomes = Vec::with_capacity(sample_size.0);
+        let mut second_outcomes = Vec::with_capacity(sample_size.0);
+        for _ in 0..sample_size.0 {
+            let sample_proto = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::generate_random_sample_prototypes(
+                parameters,
+                &mut maker,
+                &repetition_proto,
+            );
+            let context = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::prepare_context(
+                parameters,
+                &mut maker,
+                &repetition_proto,
+                &sample_proto,
+            );
+            let context = <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::execute_engine(
+                parameters,
+                first_engine,
+                context,
+            );
+            first_outcomes.push(
+                <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::process_context(
+                    parameters,
+                    &mut maker,
+                    &repetition_proto,
+                    &sample_proto,
+                    context,
+                ),
+            );
+            let context = <Fix as Fixture<Precision, SecondEngine, SecondEntities>>::prepare_context(
+                parameters,
+                &mut maker,
+                &repetition_proto,
+                &sample_proto,
+            );
+            let context = <Fix as Fixture<Precision, SecondEngine, SecondEntities>>::execute_engine(
+                parameters,
+                second_engine,
+                context,
+            );
+            second_outcomes.push(
+                <Fix as Fixture<Precision, SecondEngine, SecondEntities>>::process_context(
+                    parameters,
+                    &mut maker,
+                    &repetition_proto,
+                    &sample_proto,
+                    context,
+                ),
+            );
+        }
+
+        let first_criteria =
+            <Fix as Fixture<Precision, FirstEngine, FirstEntities>>::compute_criteria(
+                parameters,
+                &mut maker,
+                &repetition_proto,
+            );
+        if !<Fix as Fixture<Precision, FirstEngine, FirstEntities>>::verify(
+            &first_criteria,
+            first_outcomes.as_slice(),
+        ) {
+            return Err(DivergenceKind::FailedVerification(Side::First));
+        }
+        let second_criteria =
+            <Fix as Fixture<Precision, SecondEngine, SecondEntities>>::compute_criteria(
+                parameters,
+                &mut maker,
+                &repetition_proto,
+            );
+        if !<Fix as Fixture<Precision, SecondEngine, SecondEntities>>::verify(
+            &second_criteria,
+            second_outcomes.as_slice(),
+        ) {
+            return Err(DivergenceKind::FailedVerification(Side::Second));
+        }
+
+        compare_outcomes(first_outcomes.as_slice(), second_outcomes.as_slice())
+    }
+}
+
+// Compares the outcomes of two engines executed on the same prototypes.
+fn compare_outcomes<Raw, Outcome>(
+    first_outcomes: &[Outcome],
+    second_outcomes: &[Outcome],
+) -> Result<(), DivergenceKind>
+where
+    Raw: RawUnsignedIntegers,
+    Outcome: DifferentialOutcome<Raw>,
+{
+    let first: Vec<_> = first_outcomes
+        .iter()
+        .map(DifferentialOutcome::expected_and_actual)
+        .collect();
+    let second: Vec<_> = second_outcomes
+        .iter()
+        .map(DifferentialOutcome::expected_and_actual)
+        .collect();
+
+    // When the outputs of the first engine carry no noise, those of the second engine must be the
+    // same values.
+    if first.iter().all(|(expected, actual)| expected == actual) {
+        return match first
+            .iter()
+            .zip(second.iter())
+            .position(|((_, first_actual), (_, second_actual))| first_actual != second_actual)
+        {
+            Some(sample) => Err(DivergenceKind::OutputMismatch { sample }),
+            None => Ok(()),
+        };
+    }
+
+    let errors = |outputs: &[(Vec<Raw>, Vec<Raw>)]| -> Vec<f64> {
+        outputs
+            .iter()
+            .flat_map(|(expected, actual)| torus_errors(actual.as_slice(), expected.as_slice()))
+            .collect()
+    };
+    let first_errors = errors(first.as_slice());
+    let second_errors = errors(second.as_slice());
+    if assert_same_noise_distribution(first_errors.as_slice(), second_errors.as_slice()) {
+        Ok(())
+    } else {
+        Err(DivergenceKind::NoiseDistributionMismatch {
+            first_log2_std_dev: log2_standard_dev(first_errors.as_slice()),
+            second_log2_std_dev: log2_standard_dev(second_errors.as_slice()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare_outcomes, DivergenceKind};
+
+    #[test]
+    fn test_compare_noiseless_outcomes() {
+        let first = vec![(1u32, 1u32), (2, 2), (3, 3)];
+        assert_eq!(compare_outcomes(first.as_slice(), first.as_slice()), Ok(()));
+        let second = vec![(1u32, 1u32), (2, 3), (3, 3)];
+        assert_eq!(
+            compare_outcomes(first.as_slice(), second.as_slice()),
+            Err(DivergenceKind::OutputMismatch { sample: 1 })
+        );
+    }
+
+    #[test]
+    fn test_compare_noisy_outcomes() {
+        let noisy = |shift: u32| -> Vec<(Vec<u64>, Vec<u64>)> {
+            (0..100_u64)
+                .map(|index| {
+                    let error = (index * 0x9e37_79b9_7f4a_7c15) >> shift;
+                    let error = error.wrapping_sub(1 << (63 - shift));
+                    (vec![index << 50], vec![(index << 50).wrapping_add(error)])
+                })
+                .collect()
+        };
+        assert_eq!(
+            compare_outcomes(noisy(20).as_slice(), noisy(20).as_slice()),
+            Ok(())
+        );
+        assert!(matches!(
+            compare_outcomes(noisy(20).as_slice(), noisy(10).as_slice()),
+            Err(DivergenceKind::NoiseDistributionMismatch { .. })
+        ));
+    }
+}
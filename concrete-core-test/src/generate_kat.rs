@@ -0,0 +1,18 @@
+//! This program generates the known answers of the core backend, and writes them to the
+//! directory checked in the repository (or to the directory given as first argument).
+use concrete_core_test::kat::{generate_known_answers, kat_directory};
+use std::error::Error;
+use std::path::PathBuf;
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let directory = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(kat_directory);
+    std::fs::create_dir_all(&directory)?;
+    for known_answer in generate_known_answers()? {
+        known_answer.write(&directory)?;
+        println!("{}", known_answer.path(&directory).display());
+    }
+    Ok(())
+}
@@ -0,0 +1,349 @@
+//! A module containing the known-answer tests of the core backend.
+//!
+//! The known answers are entities generated by a [`CoreEngine`] seeded with [`KAT_SEED`]: an LWE
+//! secret key, a GLWE secret key, a keyswitch key and a bootstrap key, an LWE ciphertext encrypted
+//! under the LWE secret key extracted from the GLWE one, and the ciphertexts obtained by
+//! keyswitching and then bootstrapping it. They are generated for 32 and 64 bits integers, and
+//! written to the [`KAT_DIRECTORY`] with the [`CoreSerializationEngine`], one file per entity.
+//!
+//! The files are checked in the repository, and the tests of this module generate the entities
+//! again to compare them byte-for-byte with the files. A change breaking those tests changes the
+//! keys or ciphertexts produced for a given seed, in which case the files must be generated again
+//! on purpose, with:
+//! ```shell
+//! cargo run --release --features=backend_core --bin generate_kat
+//! ```
+//!
+//! # Note:
+//!
+//! The bootstrap is computed in the Fourier domain, with floating point arithmetic. For 32 bits
+//! integers, the rounding errors of the transforms are small enough to vanish when the result is
+//! converted back to integers, and the bootstrapped ciphertext is reproduced byte-for-byte. For 64
+//! bits integers, they affect the low bits of the intermediate results, which change with the
+//! plans chosen by the FFT library and with the processor, and can flip the digits of the
+//! subsequent decompositions. The mask of the bootstrapped ciphertext is then not reproducible,
+//! and the ciphertext is compared by decrypting it: its phase must match the one of the known
+//! answer up to [`FOURIER_LOG2_TOLERANCE`].
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::backends::core::private::math::random::RandomGenerator;
+use concrete_core::prelude::*;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The seed of the engine generating the known answers.
+pub const KAT_SEED: u128 = 0x4b41_545f_5345_4544;
+
+/// The directory containing the known answers, relative to the root of the crate.
+pub const KAT_DIRECTORY: &str = "kat";
+
+/// The base 2 logarithm of the largest difference accepted between the phase of a 64 bits
+/// ciphertext computed in the Fourier domain and the one of its known answer.
+pub const FOURIER_LOG2_TOLERANCE: u32 = 55;
+
+/// The parameters used to generate the known answers.
+///
+/// # Warning:
+///
+/// Those parameters are kept small to keep the size of the files reasonable, and are not secure.
+#[derive(Debug, Clone, Copy)]
+pub struct KatParameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub lwe_noise: Variance,
+    pub glwe_noise: Variance,
+    pub ks_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+/// The parameters used to generate the known answers of both precisions.
+pub const KAT_PARAMETERS: KatParameters = KatParameters {
+    lwe_dimension: LweDimension(10),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(512),
+    // A standard deviation of 2^-20.
+    lwe_noise: Variance(9.094_947_017_729_282e-13),
+    // A standard deviation of 2^-30.
+    glwe_noise: Variance(8.673_617_379_884_035e-19),
+    ks_level: DecompositionLevelCount(2),
+    ks_base_log: DecompositionBaseLog(8),
+    pbs_level: DecompositionLevelCount(2),
+    pbs_base_log: DecompositionBaseLog(10),
+    message_bits: 2,
+};
+
+/// A known answer: the serialization of an entity, along with the name of the file storing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    /// The name of the file, without directory.
+    pub file_name: String,
+    /// The serialized entity.
+    pub bytes: Vec<u8>,
+    /// The base 2 logarithm of the largest difference accepted between the phases of the
+    /// ciphertext and of the file, if the ciphertext is not reproduced byte-for-byte.
+    pub log2_phase_tolerance: Option<u32>,
+}
+
+impl KnownAnswer {
+    fn new(name: &str, precision: usize, bytes: Vec<u8>) -> KnownAnswer {
+        KnownAnswer {
+            file_name: format!("{}_{}.bin", name, precision),
+            bytes,
+            log2_phase_tolerance: None,
+        }
+    }
+
+    fn with_log2_phase_tolerance(mut self, log2_phase_tolerance: Option<u32>) -> KnownAnswer {
+        self.log2_phase_tolerance = log2_phase_tolerance;
+        self
+    }
+
+    /// Returns the path of the file storing the known answer, in a given directory.
+    pub fn path(&self, directory: &Path) -> PathBuf {
+        directory.join(&self.file_name)
+    }
+
+    /// Writes the known answer to its file, in a given directory.
+    pub fn write(&self, directory: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(self.path(directory), self.bytes.as_slice())?;
+        Ok(())
+    }
+
+    /// Returns whether the file stored in a given directory contains the known answer.
+    pub fn replay(&self, directory: &Path) -> Result<bool, Box<dyn Error>> {
+        let expected = fs::read(self.path(directory))?;
+        match self.log2_phase_tolerance {
+            None => Ok(expected == self.bytes),
+            Some(log2_tolerance) => phases_match(directory, &expected, &self.bytes, log2_tolerance),
+        }
+    }
+}
+
+/// Returns whether two serialized 64 bits LWE ciphertexts decrypt to phases separated by less
+/// than a tolerance, under the key extracted from the GLWE secret key stored in a directory.
+fn phases_match(
+    directory: &Path,
+    expected: &[u8],
+    actual: &[u8],
+    log2_tolerance: u32,
+) -> Result<bool, Box<dyn Error>> {
+    let mut engine = CoreEngine::new()?;
+    let mut serialization_engine = CoreSerializationEngine::new()?;
+    let glwe_secret_key: GlweSecretKey64 = serialization_engine
+        .deserialize(fs::read(directory.join("glwe_secret_key_64.bin"))?.as_slice())?;
+    let lwe_secret_key: LweSecretKey64 =
+        engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_secret_key)?;
+    let mut phase = |bytes: &[u8]| -> Result<u64, Box<dyn Error>> {
+        let ciphertext: LweCiphertext64 = serialization_engine.deserialize(bytes)?;
+        let plaintext = engine.decrypt_lwe_ciphertext(&lwe_secret_key, &ciphertext)?;
+        let phase = engine.retrieve_plaintext(&plaintext)?;
+        engine.destroy(ciphertext)?;
+        engine.destroy(plaintext)?;
+        Ok(phase)
+    };
+    let expected_phase = phase(expected)?;
+    let actual_phase = phase(actual)?;
+    let distance = expected_phase
+        .wrapping_sub(actual_phase)
+        .min(actual_phase.wrapping_sub(expected_phase));
+    engine.destroy(lwe_secret_key)?;
+    Ok(distance < 1 << log2_tolerance)
+}
+
+macro_rules! implement_generation {
+    ($generate: ident, $precision: literal, $Scalar: ty, $fourier_log2_tolerance: expr) => {
+        paste::paste! {
+            /// Generates the known answers for a given precision.
+            pub fn $generate(
+                parameters: KatParameters,
+            ) -> Result<Vec<KnownAnswer>, Box<dyn Error>> {
+                let mut engine = CoreEngine::new_seeded(KAT_SEED);
+                let mut serialization_engine = CoreSerializationEngine::new()?;
+
+                // The message is drawn from a generator seeded from the same value, and is never
+                // zero, so that the bootstrap does not output an encryption of zero.
+                let mut generator = RandomGenerator::new(Some(KAT_SEED));
+                let message: $Scalar = 1 + generator.random_uniform::<$Scalar>()
+                    % ((1 << parameters.message_bits) - 1);
+                // The message is encoded with one bit of padding.
+                let delta_log = $precision - parameters.message_bits - 1;
+
+                let lwe_secret_key: [< LweSecretKey $precision >] =
+                    engine.create_lwe_secret_key(parameters.lwe_dimension)?;
+                let glwe_secret_key: [< GlweSecretKey $precision >] = engine
+                    .create_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size)?;
+                let big_lwe_secret_key: [< LweSecretKey $precision >] = engine
+                    .transmute_glwe_secret_key_to_lwe_secret_key(glwe_secret_key.clone())?;
+                let keyswitch_key: [< LweKeyswitchKey $precision >] = engine
+                    .create_lwe_keyswitch_key(
+                        &big_lwe_secret_key,
+                        &lwe_secret_key,
+                        parameters.ks_level,
+                        parameters.ks_base_log,
+                        parameters.lwe_noise,
+                    )?;
+                let bootstrap_key: [< LweBootstrapKey $precision >] = engine
+                    .create_lwe_bootstrap_key(
+                        &lwe_secret_key,
+                        &glwe_secret_key,
+                        parameters.pbs_base_log,
+                        parameters.pbs_level,
+                        parameters.glwe_noise,
+                    )?;
+                let fourier_bootstrap_key: [< FourierLweBootstrapKey $precision >] =
+                    engine.convert_lwe_bootstrap_key(&bootstrap_key)?;
+
+                let plaintext = engine.create_plaintext(&(message << delta_log))?;
+                let ciphertext: [< LweCiphertext $precision >] = engine.encrypt_lwe_ciphertext(
+                    &big_lwe_secret_key,
+                    &plaintext,
+                    parameters.glwe_noise,
+                )?;
+
+                let mut keyswitched_ciphertext: [< LweCiphertext $precision >] =
+                    engine.zero_encrypt_lwe_ciphertext(&lwe_secret_key, parameters.lwe_noise)?;
+                engine.discard_keyswitch_lwe_ciphertext(
+                    &mut keyswitched_ciphertext,
+                    &ciphertext,
+                    &keyswitch_key,
+                )?;
+
+                // The bootstrap evaluates the identity function on the messages, with boxes
+                // centered on the encoded messages.
+                let box_size = parameters.polynomial_size.0 >> parameters.message_bits;
+                let lut: Vec<$Scalar> = (0..parameters.polynomial_size.0)
+                    .map(|index| {
+                        let message = ((index + box_size / 2) / box_size)
+                            % (1 << parameters.message_bits);
+                        (message as $Scalar) << delta_log
+                    })
+                    .collect();
+                let lut = engine.create_plaintext_vector(lut.as_slice())?;
+                let accumulator: [< GlweCiphertext $precision >] = engine
+                    .trivially_encrypt_glwe_ciphertext(
+                        parameters.glwe_dimension.to_glwe_size(),
+                        &lut,
+                    )?;
+                let mut bootstrapped_ciphertext: [< LweCiphertext $precision >] = engine
+                    .zero_encrypt_lwe_ciphertext(&big_lwe_secret_key, parameters.glwe_noise)?;
+                engine.discard_bootstrap_lwe_ciphertext(
+                    &mut bootstrapped_ciphertext,
+                    &keyswitched_ciphertext,
+                    &accumulator,
+                    &fourier_bootstrap_key,
+                )?;
+
+                let known_answers = vec![
+                    KnownAnswer::new(
+                        "lwe_secret_key",
+                        $precision,
+                        serialization_engine.serialize(&lwe_secret_key)?,
+                    ),
+                    KnownAnswer::new(
+                        "glwe_secret_key",
+                        $precision,
+                        serialization_engine.serialize(&glwe_secret_key)?,
+                    ),
+                    KnownAnswer::new(
+                        "lwe_keyswitch_key",
+                        $precision,
+                        serialization_engine.serialize(&keyswitch_key)?,
+                    ),
+                    KnownAnswer::new(
+                        "lwe_bootstrap_key",
+                        $precision,
+                        serialization_engine.serialize(&bootstrap_key)?,
+                    ),
+                    KnownAnswer::new(
+                        "lwe_ciphertext",
+                        $precision,
+                        serialization_engine.serialize(&ciphertext)?,
+                    ),
+                    KnownAnswer::new(
+                        "keyswitched_lwe_ciphertext",
+                        $precision,
+                        serialization_engine.serialize(&keyswitched_ciphertext)?,
+                    ),
+                    KnownAnswer::new(
+                        "bootstrapped_lwe_ciphertext",
+                        $precision,
+                        serialization_engine.serialize(&bootstrapped_ciphertext)?,
+                    )
+                    .with_log2_phase_tolerance($fourier_log2_tolerance),
+                ];
+
+                engine.destroy(lwe_secret_key)?;
+                engine.destroy(glwe_secret_key)?;
+                engine.destroy(big_lwe_secret_key)?;
+                engine.destroy(keyswitch_key)?;
+                engine.destroy(bootstrap_key)?;
+                engine.destroy(fourier_bootstrap_key)?;
+                engine.destroy(plaintext)?;
+                engine.destroy(ciphertext)?;
+                engine.destroy(keyswitched_ciphertext)?;
+                engine.destroy(lut)?;
+                engine.destroy(accumulator)?;
+                engine.destroy(bootstrapped_ciphertext)?;
+
+                Ok(known_answers)
+            }
+        }
+    };
+}
+
+implement_generation!(generate_known_answers_32, 32, u32, None);
+implement_generation!(
+    generate_known_answers_64,
+    64,
+    u64,
+    Some(FOURIER_LOG2_TOLERANCE)
+);
+
+/// Generates the known answers for both precisions.
+pub fn generate_known_answers() -> Result<Vec<KnownAnswer>, Box<dyn Error>> {
+    let mut known_answers = generate_known_answers_32(KAT_PARAMETERS)?;
+    known_answers.extend(generate_known_answers_64(KAT_PARAMETERS)?);
+    Ok(known_answers)
+}
+
+/// Returns the path of the directory containing the known answers checked in the repository.
+pub fn kat_directory() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(KAT_DIRECTORY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KAT_PARAMETERS;
+    use super::{generate_known_answers_32, generate_known_answers_64, kat_directory};
+
+    #[test]
+    fn test_known_answers_32() {
+        let directory = kat_directory();
+        for known_answer in generate_known_answers_32(KAT_PARAMETERS).unwrap() {
+            assert!(
+                known_answer.replay(&directory).unwrap(),
+                "{} does not match the known answer.",
+                known_answer.file_name
+            );
+        }
+    }
+
+    #[test]
+    fn test_known_answers_64() {
+        let directory = kat_directory();
+        for known_answer in generate_known_answers_64(KAT_PARAMETERS).unwrap() {
+            assert!(
+                known_answer.replay(&directory).unwrap(),
+                "{} does not match the known answer.",
+                known_answer.file_name
+            );
+        }
+    }
+}
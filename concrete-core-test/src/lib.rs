@@ -3,7 +3,11 @@
 //!
 //! Each submodule here is expected to be activated by a given feature flag (matching the
 //! `backend_*` naming), and to contain the instantiation of a generic correctness test for every
-//! implemented operator.
+//! implemented operator. The [`differential`] module contains a harness comparing the engines of
+//! two backends, which such submodules can instantiate as well. The [`kat`] module contains the
+//! known answers of the core backend, and the `robustness` module contains property-based tests
+//! checking that the safe entry points of the core engine never panic.
+use concrete_core::backends::core::private::math::random::RandomGenerator;
 use concrete_core_fixture::{Repetitions, SampleSize};
 
 /// The number of time a test is repeated for a single set of parameter.
@@ -12,5 +16,35 @@ pub const REPETITIONS: Repetitions = Repetitions(10);
 /// The size of the sample used to perform statistical tests.
 pub const SAMPLE_SIZE: SampleSize = SampleSize(100);
 
+/// The size of the sample used to estimate failure probabilities.
+pub const FAILURE_SAMPLE_SIZE: SampleSize = SampleSize(1000);
+
+/// The base 2 logarithm of the largest failure probability accepted by the tests.
+pub const FAILURE_PROBABILITY_TARGET: f64 = -40.;
+
+/// The name of the environment variable used to set the seed of the differential tests.
+pub const SEED_VARIABLE: &str = "CONCRETE_CORE_TEST_SEED";
+
+/// Returns the seed used by the differential tests.
+///
+/// The seed is read from the [`SEED_VARIABLE`] environment variable if it is set, and is drawn at
+/// random otherwise.
+pub fn seed() -> u128 {
+    match std::env::var(SEED_VARIABLE) {
+        Ok(seed) => seed
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be an unsigned integer.", SEED_VARIABLE)),
+        Err(_) => RandomGenerator::new(None).random_uniform(),
+    }
+}
+
+pub mod differential;
+
+#[cfg(feature = "backend_core")]
+pub mod kat;
+
 #[cfg(all(test, feature = "backend_core"))]
 pub mod core;
+
+#[cfg(all(test, feature = "backend_core"))]
+mod robustness;
@@ -0,0 +1,1396 @@
+//! A module containing property-based tests of the safe entry points of the core engine.
+//!
+//! Every safe engine method validates its inputs before executing the `*_unchecked` version of the
+//! operation. The properties of this module build entities of arbitrary dimensions, call the safe
+//! methods with them, and check that the result is either `Ok`, when the inputs are compatible, or
+//! one of the errors documented for the incompatibilities of the inputs. Any panic reaching the
+//! `*_unchecked` code makes the property fail.
+//!
+//! The properties of the mapping and parallel bootstrap engines are only compiled with the `mmap`
+//! and `multithread` features of this crate.
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount,
+    GlweDimension, LweCiphertextCount, LweDimension, LweSize, MonomialIndex, PolynomialSize,
+};
+use concrete_core::prelude::*;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+/// The noise used for all the encryptions.
+const NOISE: Variance = Variance(0.000_000_001);
+
+/// The polynomial sizes supported by the operations performed in the Fourier domain.
+const FOURIER_POLYNOMIAL_SIZES: [usize; 8] = [128, 256, 512, 1024, 2048, 4096, 8192, 16384];
+
+/// Asserts that the result of an operation is `Ok` if none of the conditions holds, and is an
+/// error matching the pattern of one of the conditions that hold otherwise. Evaluates to the output
+/// of the operation, if any.
+macro_rules! prop_assert_documented {
+    ($result: expr, { $($condition: expr => $error: pat),* $(,)? }) => {{
+        let result = $result;
+        let violated = false $(|| $condition)*;
+        match &result {
+            Ok(_) => prop_assert!(!violated, "The operation succeeded on invalid inputs."),
+            Err(error) => prop_assert!(
+                false $(|| ($condition && matches!(error, $error)))*,
+                "The operation returned an undocumented error: {:?}",
+                error
+            ),
+        }
+        result.ok()
+    }};
+}
+
+fn small_dimension() -> impl Strategy<Value = usize> {
+    1usize..4
+}
+
+fn polynomial_size() -> impl Strategy<Value = usize> {
+    prop_oneof![2usize..9, Just(512)]
+}
+
+fn fourier_polynomial_size() -> impl Strategy<Value = usize> {
+    prop_oneof![Just(4usize), Just(512), Just(1024)]
+}
+
+fn engine() -> CoreEngine {
+    CoreEngine::new().unwrap()
+}
+
+fn lwe_ciphertext(engine: &mut CoreEngine, lwe_dimension: usize) -> LweCiphertext64 {
+    let plaintext = engine.create_plaintext(&0u64).unwrap();
+    engine
+        .trivially_encrypt_lwe_ciphertext(LweSize(lwe_dimension + 1), &plaintext)
+        .unwrap()
+}
+
+fn lwe_ciphertext_vector(
+    engine: &mut CoreEngine,
+    lwe_dimension: usize,
+    count: usize,
+) -> LweCiphertextVector64 {
+    let plaintexts = engine.create_plaintext_vector(&vec![0u64; count]).unwrap();
+    engine
+        .trivially_encrypt_lwe_ciphertext_vector(LweSize(lwe_dimension + 1), &plaintexts)
+        .unwrap()
+}
+
+fn glwe_ciphertext(
+    engine: &mut CoreEngine,
+    glwe_dimension: usize,
+    polynomial_size: usize,
+) -> GlweCiphertext64 {
+    let plaintexts = engine
+        .create_plaintext_vector(&vec![0u64; polynomial_size])
+        .unwrap();
+    engine
+        .trivially_encrypt_glwe_ciphertext(
+            GlweDimension(glwe_dimension).to_glwe_size(),
+            &plaintexts,
+        )
+        .unwrap()
+}
+
+fn glwe_ciphertext_vector(
+    engine: &mut CoreEngine,
+    glwe_dimension: usize,
+    polynomial_size: usize,
+    count: usize,
+) -> GlweCiphertextVector64 {
+    let plaintexts = engine
+        .create_plaintext_vector(&vec![0u64; polynomial_size * count])
+        .unwrap();
+    engine
+        .trivially_encrypt_glwe_ciphertext_vector(
+            GlweDimension(glwe_dimension).to_glwe_size(),
+            GlweCiphertextCount(count),
+            &plaintexts,
+        )
+        .unwrap()
+}
+
+fn ciphertext_modulus() -> impl Strategy<Value = CiphertextModulus> {
+    prop_oneof![
+        Just(CiphertextModulus::Native),
+        (0u128..4).prop_map(CiphertextModulus::Custom),
+        ((1u128 << 31)..(1u128 << 33)).prop_map(CiphertextModulus::Custom),
+        ((1u128 << 63)..(1u128 << 65)).prop_map(CiphertextModulus::Custom),
+        any::<u128>().prop_map(CiphertextModulus::Custom),
+    ]
+}
+
+fn deserialize<Entity>(
+    bytes: &[u8],
+) -> Result<Entity, EntityDeserializationError<CoreSerializationError>>
+where
+    Entity: AbstractEntity,
+    for<'data> CoreSerializationEngine: EntityDeserializationEngine<&'data [u8], Entity>,
+{
+    CoreSerializationEngine::new().unwrap().deserialize(bytes)
+}
+
+fn decomposition_is_invalid(base_log: usize, level_count: usize, precision: usize) -> bool {
+    base_log == 0 || level_count == 0 || base_log * level_count > precision
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_plaintext_and_cleartext_vectors(length in 0usize..4, output_length in 0usize..4) {
+        let mut engine = engine();
+        let values = vec![1u64; length];
+        let mut output = vec![0u64; output_length];
+        let plaintexts = prop_assert_documented!(engine.create_plaintext_vector(&values), {
+            length == 0 => PlaintextVectorCreationError::EmptyInput,
+        });
+        let cleartexts = prop_assert_documented!(engine.create_cleartext_vector(&values), {
+            length == 0 => CleartextVectorCreationError::EmptyInput,
+        });
+        if let (Some(plaintexts), Some(cleartexts)) = (plaintexts, cleartexts) {
+            prop_assert_documented!(
+                engine.discard_retrieve_plaintext_vector(&mut output, &plaintexts),
+                {
+                    length != output_length =>
+                        PlaintextVectorDiscardingRetrievalError::PlaintextCountMismatch,
+                }
+            );
+            prop_assert_documented!(
+                engine.discard_retrieve_cleartext_vector(&mut output, &cleartexts),
+                {
+                    length != output_length =>
+                        CleartextVectorDiscardingRetrievalError::CleartextCountMismatch,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn prop_secret_key_creation(
+        lwe_dimension in 0usize..4,
+        glwe_dimension in 0usize..3,
+        polynomial_size in 0usize..5,
+    ) {
+        let mut engine = engine();
+        let _: Option<LweSecretKey64> = prop_assert_documented!(
+            engine.create_lwe_secret_key(LweDimension(lwe_dimension)),
+            {
+                lwe_dimension == 0 => LweSecretKeyCreationError::NullLweDimension,
+            }
+        );
+        let _: Option<GlweSecretKey64> = prop_assert_documented!(
+            engine.create_glwe_secret_key(
+                GlweDimension(glwe_dimension),
+                PolynomialSize(polynomial_size)
+            ),
+            {
+                glwe_dimension == 0 => GlweSecretKeyCreationError::NullGlweDimension,
+                polynomial_size == 0 => GlweSecretKeyCreationError::NullPolynomialSize,
+                polynomial_size == 1 => GlweSecretKeyCreationError::SizeOnePolynomial,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_ciphertext_vector_creation(
+        container_length in 0usize..13,
+        lwe_size in 0usize..4,
+        glwe_dimension in 0usize..3,
+        polynomial_size in 0usize..4,
+    ) {
+        let mut engine = engine();
+        let _: Option<LweCiphertextVector64> = prop_assert_documented!(
+            engine.create_lwe_ciphertext_vector(vec![0u64; container_length], LweSize(lwe_size)),
+            {
+                container_length == 0 => LweCiphertextVectorCreationError::EmptyContainer,
+                lwe_size < 2 => LweCiphertextVectorCreationError::NullLweDimension,
+                lwe_size != 0 && container_length % lwe_size != 0 =>
+                    LweCiphertextVectorCreationError::ContainerSizeNotCompatibleWithLweSize,
+            }
+        );
+        let glwe_length = (glwe_dimension + 1) * polynomial_size;
+        let _: Option<GlweCiphertextVector64> = prop_assert_documented!(
+            engine.create_glwe_ciphertext_vector(
+                vec![0u64; container_length],
+                GlweDimension(glwe_dimension),
+                PolynomialSize(polynomial_size),
+            ),
+            {
+                container_length == 0 => GlweCiphertextVectorCreationError::EmptyContainer,
+                glwe_dimension == 0 => GlweCiphertextVectorCreationError::NullGlweDimension,
+                polynomial_size == 0 => GlweCiphertextVectorCreationError::NullPolynomialSize,
+                glwe_length != 0 && container_length % glwe_length != 0 =>
+                    GlweCiphertextVectorCreationError::ContainerSizeNotCompatibleWithGlweSize,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_zero_encryption(
+        lwe_dimension in small_dimension(),
+        glwe_dimension in small_dimension(),
+        polynomial_size in polynomial_size(),
+        count in 0usize..3,
+    ) {
+        let mut engine = engine();
+        let lwe_key: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(lwe_dimension)).unwrap();
+        let glwe_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(glwe_dimension), PolynomialSize(polynomial_size))
+            .unwrap();
+        let _: Option<LweCiphertextVector64> = prop_assert_documented!(
+            engine.zero_encrypt_lwe_ciphertext_vector(&lwe_key, NOISE, LweCiphertextCount(count)),
+            {
+                count == 0 => LweCiphertextVectorZeroEncryptionError::NullCiphertextCount,
+            }
+        );
+        let _: Option<GlweCiphertextVector64> = prop_assert_documented!(
+            engine.zero_encrypt_glwe_ciphertext_vector(
+                &glwe_key,
+                NOISE,
+                GlweCiphertextCount(count),
+            ),
+            {
+                count == 0 => GlweCiphertextVectorZeroEncryptionError::NullCiphertextCount,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_keyswitch_key_creation(
+        input_lwe_dimension in small_dimension(),
+        output_lwe_dimension in small_dimension(),
+        glwe_dimension in small_dimension(),
+        polynomial_size in 2usize..5,
+        base_log in 0usize..40,
+        level_count in 0usize..4,
+    ) {
+        let mut engine = engine();
+        let input_key_32: LweSecretKey32 =
+            engine.create_lwe_secret_key(LweDimension(input_lwe_dimension)).unwrap();
+        let output_key_32: LweSecretKey32 =
+            engine.create_lwe_secret_key(LweDimension(output_lwe_dimension)).unwrap();
+        let glwe_key_32: GlweSecretKey32 = engine
+            .create_glwe_secret_key(GlweDimension(glwe_dimension), PolynomialSize(polynomial_size))
+            .unwrap();
+        let input_key_64: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(input_lwe_dimension)).unwrap();
+        let output_key_64: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(output_lwe_dimension)).unwrap();
+        let glwe_key_64: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(glwe_dimension), PolynomialSize(polynomial_size))
+            .unwrap();
+        let (level, base) = (DecompositionLevelCount(level_count), DecompositionBaseLog(base_log));
+        let _: Option<LweKeyswitchKey32> = prop_assert_documented!(
+            engine.create_lwe_keyswitch_key(&input_key_32, &output_key_32, level, base, NOISE),
+            {
+                base_log == 0 => LweKeyswitchKeyCreationError::NullDecompositionBaseLog,
+                level_count == 0 => LweKeyswitchKeyCreationError::NullDecompositionLevelCount,
+                decomposition_is_invalid(base_log, level_count, 32) =>
+                    LweKeyswitchKeyCreationError::DecompositionTooLarge,
+            }
+        );
+        let _: Option<LweKeyswitchKey64> = prop_assert_documented!(
+            engine.create_lwe_keyswitch_key(&input_key_64, &output_key_64, level, base, NOISE),
+            {
+                base_log == 0 => LweKeyswitchKeyCreationError::NullDecompositionBaseLog,
+                level_count == 0 => LweKeyswitchKeyCreationError::NullDecompositionLevelCount,
+                decomposition_is_invalid(base_log, level_count, 64) =>
+                    LweKeyswitchKeyCreationError::DecompositionTooLarge,
+            }
+        );
+        let _: Option<PackingKeyswitchKey32> = prop_assert_documented!(
+            engine.create_packing_keyswitch_key(&input_key_32, &glwe_key_32, level, base, NOISE),
+            {
+                base_log == 0 => PackingKeyswitchKeyCreationError::NullDecompositionBaseLog,
+                level_count == 0 => PackingKeyswitchKeyCreationError::NullDecompositionLevelCount,
+                decomposition_is_invalid(base_log, level_count, 32) =>
+                    PackingKeyswitchKeyCreationError::DecompositionTooLarge,
+            }
+        );
+        let _: Option<PackingKeyswitchKey64> = prop_assert_documented!(
+            engine.create_packing_keyswitch_key(&input_key_64, &glwe_key_64, level, base, NOISE),
+            {
+                base_log == 0 => PackingKeyswitchKeyCreationError::NullDecompositionBaseLog,
+                level_count == 0 => PackingKeyswitchKeyCreationError::NullDecompositionLevelCount,
+                decomposition_is_invalid(base_log, level_count, 64) =>
+                    PackingKeyswitchKeyCreationError::DecompositionTooLarge,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_bootstrap_key_creation(
+        lwe_dimension in 1usize..3,
+        glwe_dimension in 1usize..3,
+        polynomial_size in fourier_polynomial_size(),
+        base_log in 0usize..40,
+        level_count in 0usize..3,
+    ) {
+        let mut engine = engine();
+        let lwe_key: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(lwe_dimension)).unwrap();
+        let glwe_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(glwe_dimension), PolynomialSize(polynomial_size))
+            .unwrap();
+        let (level, base) = (DecompositionLevelCount(level_count), DecompositionBaseLog(base_log));
+        let bsk: Option<LweBootstrapKey64> = prop_assert_documented!(
+            engine.create_lwe_bootstrap_key(&lwe_key, &glwe_key, base, level, NOISE),
+            {
+                base_log == 0 => LweBootstrapKeyCreationError::NullDecompositionBaseLog,
+                level_count == 0 => LweBootstrapKeyCreationError::NullDecompositionLevelCount,
+                decomposition_is_invalid(base_log, level_count, 64) =>
+                    LweBootstrapKeyCreationError::DecompositionTooLarge,
+            }
+        );
+        let unsupported = !FOURIER_POLYNOMIAL_SIZES.contains(&polynomial_size);
+        let _: Option<FourierLweBootstrapKey64> = prop_assert_documented!(
+            engine.create_lwe_bootstrap_key(&lwe_key, &glwe_key, base, level, NOISE),
+            {
+                base_log == 0 => LweBootstrapKeyCreationError::NullDecompositionBaseLog,
+                level_count == 0 => LweBootstrapKeyCreationError::NullDecompositionLevelCount,
+                decomposition_is_invalid(base_log, level_count, 64) =>
+                    LweBootstrapKeyCreationError::DecompositionTooLarge,
+                unsupported =>
+                    LweBootstrapKeyCreationError::Engine(CoreError::UnsupportedPolynomialSize),
+            }
+        );
+        if let Some(bsk) = bsk {
+            let _: Option<FourierLweBootstrapKey64> = prop_assert_documented!(
+                engine.convert_lwe_bootstrap_key(&bsk),
+                {
+                    unsupported =>
+                        LweBootstrapKeyConversionError::Engine(
+                            CoreError::UnsupportedPolynomialSize
+                        ),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn prop_lwe_ciphertext_operations(
+        output_dimension in small_dimension(),
+        first_dimension in small_dimension(),
+        second_dimension in small_dimension(),
+        key_dimension in small_dimension(),
+    ) {
+        let mut engine = engine();
+        let mut output = lwe_ciphertext(&mut engine, output_dimension);
+        let first = lwe_ciphertext(&mut engine, first_dimension);
+        let second = lwe_ciphertext(&mut engine, second_dimension);
+        let plaintext = engine.create_plaintext(&1u64).unwrap();
+        let mut plaintext_output = engine.create_plaintext(&0u64).unwrap();
+        let cleartext = engine.create_cleartext(&2u64).unwrap();
+        let key: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(key_dimension)).unwrap();
+        let binary_mismatch = output_dimension != first_dimension
+            || output_dimension != second_dimension;
+        let unary_mismatch = output_dimension != first_dimension;
+        let key_mismatch = key_dimension != first_dimension;
+
+        prop_assert_documented!(engine.discard_add_lwe_ciphertext(&mut output, &first, &second), {
+            binary_mismatch => LweCiphertextDiscardingAdditionError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(engine.discard_sub_lwe_ciphertext(&mut output, &first, &second), {
+            binary_mismatch => LweCiphertextDiscardingSubtractionError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(engine.fuse_add_lwe_ciphertext(&mut output, &first), {
+            unary_mismatch => LweCiphertextFusingAdditionError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(engine.fuse_sub_lwe_ciphertext(&mut output, &first), {
+            unary_mismatch => LweCiphertextFusingSubtractionError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(engine.discard_opp_lwe_ciphertext(&mut output, &first), {
+            unary_mismatch => LweCiphertextDiscardingOppositeError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(
+            engine.discard_add_lwe_ciphertext_plaintext(&mut output, &first, &plaintext),
+            {
+                unary_mismatch =>
+                    LweCiphertextPlaintextDiscardingAdditionError::LweDimensionMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_sub_lwe_ciphertext_plaintext(&mut output, &first, &plaintext),
+            {
+                unary_mismatch =>
+                    LweCiphertextPlaintextDiscardingSubtractionError::LweDimensionMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_mul_lwe_ciphertext_cleartext(&mut output, &first, &cleartext),
+            {
+                unary_mismatch =>
+                    LweCiphertextCleartextDiscardingMultiplicationError::LweDimensionMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_encrypt_lwe_ciphertext(&key, &mut output, &plaintext, NOISE),
+            {
+                key_dimension != output_dimension =>
+                    LweCiphertextDiscardingEncryptionError::LweDimensionMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_decrypt_lwe_ciphertext(&key, &mut plaintext_output, &first),
+            {
+                key_mismatch => LweCiphertextDiscardingDecryptionError::LweDimensionMismatch,
+            }
+        );
+        prop_assert_documented!(engine.decrypt_lwe_ciphertext(&key, &first), {
+            key_mismatch => LweCiphertextDecryptionError::LweDimensionMismatch,
+        });
+    }
+
+    #[test]
+    fn prop_lwe_ciphertext_vector_operations(
+        output_dimension in small_dimension(),
+        input_dimension in small_dimension(),
+        key_dimension in small_dimension(),
+        output_count in 1usize..3,
+        input_count in 1usize..3,
+        other_count in 1usize..3,
+    ) {
+        let mut engine = engine();
+        let mut output = lwe_ciphertext_vector(&mut engine, output_dimension, output_count);
+        let input = lwe_ciphertext_vector(&mut engine, input_dimension, input_count);
+        let mut output_ciphertext = lwe_ciphertext(&mut engine, output_dimension);
+        let key: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(key_dimension)).unwrap();
+        let plaintexts = engine.create_plaintext_vector(&vec![1u64; other_count]).unwrap();
+        let mut plaintexts_output =
+            engine.create_plaintext_vector(&vec![0u64; other_count]).unwrap();
+        let weights = engine.create_cleartext_vector(&vec![1u64; other_count]).unwrap();
+        let bias = engine.create_plaintext(&0u64).unwrap();
+        let dimension_mismatch = output_dimension != input_dimension;
+        let count_mismatch = output_count != input_count;
+
+        prop_assert_documented!(
+            engine.discard_add_lwe_ciphertext_vector(&mut output, &input, &input),
+            {
+                dimension_mismatch =>
+                    LweCiphertextVectorDiscardingAdditionError::LweDimensionMismatch,
+                count_mismatch =>
+                    LweCiphertextVectorDiscardingAdditionError::CiphertextCountMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_sub_lwe_ciphertext_vector(&mut output, &input, &input),
+            {
+                dimension_mismatch =>
+                    LweCiphertextVectorDiscardingSubtractionError::LweDimensionMismatch,
+                count_mismatch =>
+                    LweCiphertextVectorDiscardingSubtractionError::CiphertextCountMismatch,
+            }
+        );
+        prop_assert_documented!(engine.fuse_add_lwe_ciphertext_vector(&mut output, &input), {
+            dimension_mismatch => LweCiphertextVectorFusingAdditionError::LweDimensionMismatch,
+            count_mismatch => LweCiphertextVectorFusingAdditionError::CiphertextCountMismatch,
+        });
+        prop_assert_documented!(engine.fuse_sub_lwe_ciphertext_vector(&mut output, &input), {
+            dimension_mismatch => LweCiphertextVectorFusingSubtractionError::LweDimensionMismatch,
+            count_mismatch => LweCiphertextVectorFusingSubtractionError::CiphertextCountMismatch,
+        });
+        prop_assert_documented!(
+            engine.discard_affine_transform_lwe_ciphertext_vector(
+                &mut output_ciphertext,
+                &input,
+                &weights,
+                &bias,
+            ),
+            {
+                dimension_mismatch =>
+                    LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+                input_count != other_count =>
+                    LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            }
+        );
+        let _: Option<LweCiphertextVector64> = prop_assert_documented!(
+            engine.encrypt_lwe_ciphertext_vector(&key, &plaintexts, NOISE),
+            {}
+        );
+        prop_assert_documented!(
+            engine.discard_encrypt_lwe_ciphertext_vector(&key, &mut output, &plaintexts, NOISE),
+            {
+                key_dimension != output_dimension =>
+                    LweCiphertextVectorDiscardingEncryptionError::LweDimensionMismatch,
+                output_count != other_count =>
+                    LweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch,
+            }
+        );
+        prop_assert_documented!(engine.decrypt_lwe_ciphertext_vector(&key, &input), {
+            key_dimension != input_dimension =>
+                LweCiphertextVectorDecryptionError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(
+            engine.discard_decrypt_lwe_ciphertext_vector(&key, &mut plaintexts_output, &input),
+            {
+                key_dimension != input_dimension =>
+                    LweCiphertextVectorDiscardingDecryptionError::LweDimensionMismatch,
+                input_count != other_count =>
+                    LweCiphertextVectorDiscardingDecryptionError::PlaintextCountMismatch,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_lwe_keyswitch(
+        input_key_dimension in small_dimension(),
+        output_key_dimension in small_dimension(),
+        input_dimension in small_dimension(),
+        output_dimension in small_dimension(),
+    ) {
+        let mut engine = engine();
+        let input_key: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(input_key_dimension)).unwrap();
+        let output_key: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(output_key_dimension)).unwrap();
+        let ksk: LweKeyswitchKey64 = engine
+            .create_lwe_keyswitch_key(
+                &input_key,
+                &output_key,
+                DecompositionLevelCount(2),
+                DecompositionBaseLog(4),
+                NOISE,
+            )
+            .unwrap();
+        let input = lwe_ciphertext(&mut engine, input_dimension);
+        let mut output = lwe_ciphertext(&mut engine, output_dimension);
+        prop_assert_documented!(
+            engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &ksk),
+            {
+                input_dimension != input_key_dimension =>
+                    LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch,
+                output_dimension != output_key_dimension =>
+                    LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_lwe_bootstrap(
+        input_dimension in 1usize..3,
+        output_dimension in prop_oneof![Just(512usize), Just(1024), Just(3)],
+        accumulator_glwe_dimension in 1usize..3,
+        accumulator_polynomial_size in fourier_polynomial_size(),
+    ) {
+        let mut engine = engine();
+        let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(1)).unwrap();
+        let glwe_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(1), PolynomialSize(512))
+            .unwrap();
+        let bsk: FourierLweBootstrapKey64 = engine
+            .create_lwe_bootstrap_key(
+                &lwe_key,
+                &glwe_key,
+                DecompositionBaseLog(8),
+                DecompositionLevelCount(1),
+                NOISE,
+            )
+            .unwrap();
+        let input = lwe_ciphertext(&mut engine, input_dimension);
+        let mut output = lwe_ciphertext(&mut engine, output_dimension);
+        let accumulator =
+            glwe_ciphertext(&mut engine, accumulator_glwe_dimension, accumulator_polynomial_size);
+        let mut workspace: ExternalProductWorkspace64 = engine
+            .create_external_product_workspace(GlweDimension(1), PolynomialSize(512))
+            .unwrap();
+        let unsupported = !FOURIER_POLYNOMIAL_SIZES.contains(&accumulator_polynomial_size);
+        prop_assert_documented!(
+            engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &accumulator, &bsk),
+            {
+                input_dimension != 1 =>
+                    LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch,
+                accumulator_polynomial_size != 512 =>
+                    LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+                accumulator_glwe_dimension != 1 =>
+                    LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+                output_dimension != 512 =>
+                    LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch,
+                unsupported =>
+                    LweCiphertextDiscardingBootstrapError::Engine(
+                        CoreError::UnsupportedPolynomialSize
+                    ),
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_bootstrap_lwe_ciphertext_with_workspace(
+                &mut output,
+                &input,
+                &accumulator,
+                &bsk,
+                &mut workspace,
+            ),
+            {
+                input_dimension != 1 =>
+                    LweCiphertextDiscardingBootstrapWithWorkspaceError::InputLweDimensionMismatch,
+                accumulator_polynomial_size != 512 =>
+                    LweCiphertextDiscardingBootstrapWithWorkspaceError
+                        ::AccumulatorPolynomialSizeMismatch,
+                accumulator_glwe_dimension != 1 =>
+                    LweCiphertextDiscardingBootstrapWithWorkspaceError
+                        ::AccumulatorGlweDimensionMismatch,
+                output_dimension != 512 =>
+                    LweCiphertextDiscardingBootstrapWithWorkspaceError::OutputLweDimensionMismatch,
+                unsupported =>
+                    LweCiphertextDiscardingBootstrapWithWorkspaceError::Engine(
+                        CoreError::UnsupportedPolynomialSize
+                    ),
+            }
+        );
+    }
+
+    #[test]
+    fn prop_lwe_extraction(
+        output_dimension in 1usize..10,
+        glwe_dimension in small_dimension(),
+        polynomial_size in 1usize..5,
+        nth in 0usize..6,
+    ) {
+        let mut engine = engine();
+        let input = glwe_ciphertext(&mut engine, glwe_dimension, polynomial_size);
+        let mut output = lwe_ciphertext(&mut engine, output_dimension);
+        prop_assert_documented!(
+            engine.discard_extract_lwe_ciphertext(&mut output, &input, MonomialIndex(nth)),
+            {
+                output_dimension != glwe_dimension * polynomial_size =>
+                    LweCiphertextDiscardingExtractionError::SizeMismatch,
+                nth >= polynomial_size =>
+                    LweCiphertextDiscardingExtractionError::MonomialIndexTooLarge,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_packing_keyswitch(
+        input_key_dimension in small_dimension(),
+        output_glwe_dimension in small_dimension(),
+        output_polynomial_size in 2usize..5,
+        input_dimension in small_dimension(),
+        input_count in 1usize..6,
+        glwe_dimension in small_dimension(),
+        polynomial_size in 2usize..5,
+    ) {
+        let mut engine = engine();
+        let input_key: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(input_key_dimension)).unwrap();
+        let output_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(
+                GlweDimension(output_glwe_dimension),
+                PolynomialSize(output_polynomial_size),
+            )
+            .unwrap();
+        let pksk: PackingKeyswitchKey64 = engine
+            .create_packing_keyswitch_key(
+                &input_key,
+                &output_key,
+                DecompositionLevelCount(2),
+                DecompositionBaseLog(4),
+                NOISE,
+            )
+            .unwrap();
+        let input = lwe_ciphertext_vector(&mut engine, input_dimension, input_count);
+        let mut output = glwe_ciphertext(&mut engine, glwe_dimension, polynomial_size);
+        prop_assert_documented!(
+            engine.discard_packing_keyswitch_lwe_ciphertext_vector(&mut output, &input, &pksk),
+            {
+                input_dimension != input_key_dimension =>
+                    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError
+                        ::InputLweDimensionMismatch,
+                glwe_dimension != output_glwe_dimension =>
+                    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError
+                        ::OutputGlweDimensionMismatch,
+                polynomial_size != output_polynomial_size =>
+                    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError
+                        ::OutputPolynomialSizeMismatch,
+                input_count > polynomial_size =>
+                    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError
+                        ::CiphertextCountMismatch,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_glwe_ciphertext_operations(
+        key_glwe_dimension in small_dimension(),
+        key_polynomial_size in 2usize..5,
+        glwe_dimension in small_dimension(),
+        polynomial_size in 2usize..5,
+        plaintext_count in 1usize..9,
+        count in 0usize..3,
+    ) {
+        let mut engine = engine();
+        let key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(
+                GlweDimension(key_glwe_dimension),
+                PolynomialSize(key_polynomial_size),
+            )
+            .unwrap();
+        let mut ciphertext = glwe_ciphertext(&mut engine, glwe_dimension, polynomial_size);
+        let mut ciphertext_vector =
+            glwe_ciphertext_vector(&mut engine, glwe_dimension, polynomial_size, 2);
+        let plaintexts = engine.create_plaintext_vector(&vec![1u64; plaintext_count]).unwrap();
+        let mut plaintexts_output =
+            engine.create_plaintext_vector(&vec![0u64; plaintext_count]).unwrap();
+        let dimension_mismatch = key_glwe_dimension != glwe_dimension;
+        let size_mismatch = key_polynomial_size != polynomial_size;
+
+        let _: Option<GlweCiphertext64> = prop_assert_documented!(
+            engine.encrypt_glwe_ciphertext(&key, &plaintexts, NOISE),
+            {
+                plaintext_count != key_polynomial_size =>
+                    GlweCiphertextEncryptionError::PlaintextCountMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_encrypt_glwe_ciphertext(&key, &mut ciphertext, &plaintexts, NOISE),
+            {
+                dimension_mismatch =>
+                    GlweCiphertextDiscardingEncryptionError::GlweDimensionMismatch,
+                size_mismatch => GlweCiphertextDiscardingEncryptionError::PolynomialSizeMismatch,
+                plaintext_count != polynomial_size =>
+                    GlweCiphertextDiscardingEncryptionError::PlaintextCountMismatch,
+            }
+        );
+        prop_assert_documented!(engine.decrypt_glwe_ciphertext(&key, &ciphertext), {
+            dimension_mismatch => GlweCiphertextDecryptionError::GlweDimensionMismatch,
+            size_mismatch => GlweCiphertextDecryptionError::PolynomialSizeMismatch,
+        });
+        prop_assert_documented!(
+            engine.discard_decrypt_glwe_ciphertext(&key, &mut plaintexts_output, &ciphertext),
+            {
+                dimension_mismatch =>
+                    GlweCiphertextDiscardingDecryptionError::GlweDimensionMismatch,
+                size_mismatch => GlweCiphertextDiscardingDecryptionError::PolynomialSizeMismatch,
+                plaintext_count != polynomial_size =>
+                    GlweCiphertextDiscardingDecryptionError::PlaintextCountMismatch,
+            }
+        );
+        let _: Option<GlweCiphertextVector64> = prop_assert_documented!(
+            engine.encrypt_glwe_ciphertext_vector(&key, &plaintexts, NOISE),
+            {
+                plaintext_count % key_polynomial_size != 0 =>
+                    GlweCiphertextVectorEncryptionError::PlaintextCountMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_encrypt_glwe_ciphertext_vector(
+                &key,
+                &mut ciphertext_vector,
+                &plaintexts,
+                NOISE,
+            ),
+            {
+                dimension_mismatch =>
+                    GlweCiphertextVectorDiscardingEncryptionError::GlweDimensionMismatch,
+                size_mismatch =>
+                    GlweCiphertextVectorDiscardingEncryptionError::PolynomialSizeMismatch,
+                plaintext_count != 2 * polynomial_size =>
+                    GlweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch,
+            }
+        );
+        prop_assert_documented!(engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector), {
+            dimension_mismatch => GlweCiphertextVectorDecryptionError::GlweDimensionMismatch,
+            size_mismatch => GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch,
+        });
+        prop_assert_documented!(
+            engine.discard_decrypt_glwe_ciphertext_vector(
+                &key,
+                &mut plaintexts_output,
+                &ciphertext_vector,
+            ),
+            {
+                dimension_mismatch =>
+                    GlweCiphertextVectorDiscardingDecryptionError::GlweDimensionMismatch,
+                size_mismatch =>
+                    GlweCiphertextVectorDiscardingDecryptionError::PolynomialSizeMismatch,
+                plaintext_count != 2 * polynomial_size =>
+                    GlweCiphertextVectorDiscardingDecryptionError::PlaintextCountMismatch,
+            }
+        );
+        let _: Option<GlweCiphertextVector64> = prop_assert_documented!(
+            engine.trivially_encrypt_glwe_ciphertext_vector(
+                GlweDimension(glwe_dimension).to_glwe_size(),
+                GlweCiphertextCount(count),
+                &plaintexts,
+            ),
+            {
+                count == 0 => GlweCiphertextVectorTrivialEncryptionError::NullCiphertextCount,
+                count != 0 && plaintext_count % count != 0 =>
+                    GlweCiphertextVectorTrivialEncryptionError::PlaintextCountMismatch,
+            }
+        );
+    }
+
+    #[test]
+    fn prop_ggsw_ciphertext_operations(
+        key_glwe_dimension in 1usize..3,
+        key_polynomial_size in fourier_polynomial_size(),
+        glwe_dimension in 1usize..3,
+        polynomial_size in fourier_polynomial_size(),
+        output_glwe_dimension in 1usize..3,
+        output_polynomial_size in fourier_polynomial_size(),
+        workspace_glwe_dimension in 1usize..3,
+    ) {
+        let mut engine = engine();
+        let key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(
+                GlweDimension(key_glwe_dimension),
+                PolynomialSize(key_polynomial_size),
+            )
+            .unwrap();
+        let other_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(glwe_dimension), PolynomialSize(polynomial_size))
+            .unwrap();
+        let plaintext = engine.create_plaintext(&1u64).unwrap();
+        let (level, base) = (DecompositionLevelCount(1), DecompositionBaseLog(4));
+        let ggsw: GgswCiphertext64 = engine
+            .encrypt_scalar_ggsw_ciphertext(&key, &plaintext, NOISE, level, base)
+            .unwrap();
+        let mut other_ggsw: GgswCiphertext64 = engine
+            .encrypt_scalar_ggsw_ciphertext(&other_key, &plaintext, NOISE, level, base)
+            .unwrap();
+        prop_assert_documented!(
+            engine.discard_encrypt_scalar_ggsw_ciphertext(&key, &mut other_ggsw, &plaintext, NOISE),
+            {
+                key_polynomial_size != polynomial_size =>
+                    GgswCiphertextScalarDiscardingEncryptionError::PolynomialSizeMismatch,
+                key_glwe_dimension != glwe_dimension =>
+                    GgswCiphertextScalarDiscardingEncryptionError::GlweDimensionMismatch,
+            }
+        );
+
+        let key_unsupported = !FOURIER_POLYNOMIAL_SIZES.contains(&key_polynomial_size);
+        let fourier_ggsw: Option<FourierGgswCiphertext64> = prop_assert_documented!(
+            engine.convert_ggsw_ciphertext(&ggsw),
+            {
+                key_unsupported =>
+                    GgswCiphertextConversionError::Engine(CoreError::UnsupportedPolynomialSize),
+            }
+        );
+        let glwe = glwe_ciphertext(&mut engine, glwe_dimension, polynomial_size);
+        let input_unsupported = !FOURIER_POLYNOMIAL_SIZES.contains(&polynomial_size);
+        let _: Option<FourierGlweCiphertext64> = prop_assert_documented!(
+            engine.convert_glwe_ciphertext(&glwe),
+            {
+                input_unsupported =>
+                    GlweCiphertextConversionError::Engine(CoreError::UnsupportedPolynomialSize),
+            }
+        );
+        let fourier_ggsw = match fourier_ggsw {
+            Some(fourier_ggsw) => fourier_ggsw,
+            None => return Ok(()),
+        };
+        let mut output =
+            glwe_ciphertext(&mut engine, output_glwe_dimension, output_polynomial_size);
+        let size_mismatch = polynomial_size != key_polynomial_size
+            || output_polynomial_size != key_polynomial_size;
+        let dimension_mismatch = glwe_dimension != key_glwe_dimension
+            || output_glwe_dimension != key_glwe_dimension;
+
+        let _: Option<GlweCiphertext64> = prop_assert_documented!(
+            engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &fourier_ggsw),
+            {
+                polynomial_size != key_polynomial_size =>
+                    GlweCiphertextGgswCiphertextExternalProductError::PolynomialSizeMismatch,
+                glwe_dimension != key_glwe_dimension =>
+                    GlweCiphertextGgswCiphertextExternalProductError::GlweDimensionMismatch,
+                input_unsupported => GlweCiphertextGgswCiphertextExternalProductError::Engine(
+                    CoreError::UnsupportedPolynomialSize
+                ),
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                &glwe,
+                &fourier_ggsw,
+                &mut output,
+            ),
+            {
+                size_mismatch =>
+                    GlweCiphertextGgswCiphertextDiscardingExternalProductError
+                        ::PolynomialSizeMismatch,
+                dimension_mismatch =>
+                    GlweCiphertextGgswCiphertextDiscardingExternalProductError
+                        ::GlweDimensionMismatch,
+                input_unsupported =>
+                    GlweCiphertextGgswCiphertextDiscardingExternalProductError::Engine(
+                        CoreError::UnsupportedPolynomialSize
+                    ),
+            }
+        );
+        let mut workspace: ExternalProductWorkspace64 = engine
+            .create_external_product_workspace(
+                GlweDimension(workspace_glwe_dimension),
+                PolynomialSize(key_polynomial_size),
+            )
+            .unwrap();
+        prop_assert_documented!(
+            engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_with_workspace(
+                &glwe,
+                &fourier_ggsw,
+                &mut output,
+                &mut workspace,
+            ),
+            {
+                size_mismatch =>
+                    GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError
+                        ::PolynomialSizeMismatch,
+                dimension_mismatch =>
+                    GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError
+                        ::GlweDimensionMismatch,
+                workspace_glwe_dimension != glwe_dimension =>
+                    GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError
+                        ::WorkspaceGlweDimensionMismatch,
+                input_unsupported =>
+                    GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError
+                        ::Engine(CoreError::UnsupportedPolynomialSize),
+            }
+        );
+    }
+
+    #[test]
+    fn prop_external_product_workspace_creation(
+        glwe_dimension in 0usize..3,
+        polynomial_size in prop_oneof![Just(0usize), Just(4), Just(512)],
+    ) {
+        let mut engine = engine();
+        let _: Option<ExternalProductWorkspace64> = prop_assert_documented!(
+            engine.create_external_product_workspace(
+                GlweDimension(glwe_dimension),
+                PolynomialSize(polynomial_size),
+            ),
+            {
+                glwe_dimension == 0 => ExternalProductWorkspaceCreationError::NullGlweDimension,
+                polynomial_size == 0 => ExternalProductWorkspaceCreationError::NullPolynomialSize,
+                !FOURIER_POLYNOMIAL_SIZES.contains(&polynomial_size) =>
+                    ExternalProductWorkspaceCreationError::Engine(
+                        CoreError::UnsupportedPolynomialSize
+                    ),
+            }
+        );
+    }
+
+    #[test]
+    fn prop_deserialization_of_arbitrary_bytes(
+        bytes in proptest::collection::vec(any::<u8>(), 0..256),
+    ) {
+        prop_assert!(deserialize::<LweSecretKey128>(&bytes).is_err());
+        prop_assert!(deserialize::<LweCiphertext32>(&bytes).is_err());
+        prop_assert!(deserialize::<LweCiphertext64>(&bytes).is_err());
+        prop_assert!(deserialize::<LweCiphertext128>(&bytes).is_err());
+        prop_assert!(deserialize::<GlweCiphertextVector64>(&bytes).is_err());
+        prop_assert!(deserialize::<GgswCiphertext64>(&bytes).is_err());
+        prop_assert!(deserialize::<LweKeyswitchKey64>(&bytes).is_err());
+        prop_assert!(deserialize::<FourierLweBootstrapKey64>(&bytes).is_err());
+    }
+
+    #[test]
+    fn prop_deserialization_of_altered_entities(
+        lwe_dimension in small_dimension(),
+        modulus in ciphertext_modulus(),
+        index in any::<Index>(),
+        mask in 1u8..=255,
+        length in any::<Index>(),
+    ) {
+        let mut engine = engine();
+        let mut serialization_engine = CoreSerializationEngine::new().unwrap();
+        let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(lwe_dimension)).unwrap();
+        let plaintext = engine.create_plaintext(&0u64).unwrap();
+        let ciphertext = match engine
+            .custom_modulus_encrypt_lwe_ciphertext(&key, &plaintext, NOISE, modulus)
+        {
+            Ok(ciphertext) => ciphertext,
+            Err(_) => return Ok(()),
+        };
+        let bytes = serialization_engine.serialize(&ciphertext).unwrap();
+        let deserialized: LweCiphertext64 = deserialize(&bytes).unwrap();
+        prop_assert_eq!(deserialized.lwe_dimension(), LweDimension(lwe_dimension));
+        prop_assert_eq!(deserialized.ciphertext_modulus(), modulus);
+
+        // The checksum covers the whole serialization, so altering any byte must be detected.
+        let mut altered = bytes.clone();
+        altered[index.index(bytes.len())] ^= mask;
+        prop_assert!(deserialize::<LweCiphertext64>(&altered).is_err());
+        prop_assert!(deserialize::<LweCiphertext64>(&bytes[..length.index(bytes.len())]).is_err());
+    }
+
+    #[test]
+    fn prop_ciphertext_vector_views(
+        container_length in 0usize..13,
+        lwe_size in 0usize..4,
+        glwe_dimension in 0usize..3,
+        polynomial_size in 0usize..4,
+        key_dimension in small_dimension(),
+    ) {
+        let mut engine = engine();
+        let lwe_container = vec![0u32; container_length];
+        let mut lwe_mut_container = vec![0u64; container_length];
+        let glwe_container = vec![0u64; container_length];
+        let mut glwe_mut_container = vec![0u32; container_length];
+        let lwe_key: LweSecretKey32 =
+            engine.create_lwe_secret_key(LweDimension(key_dimension)).unwrap();
+        let glwe_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(key_dimension), PolynomialSize(2))
+            .unwrap();
+
+        let view: Option<LweCiphertextVectorView32> = prop_assert_documented!(
+            engine.create_lwe_ciphertext_vector(lwe_container.as_slice(), LweSize(lwe_size)),
+            {
+                container_length == 0 => LweCiphertextVectorCreationError::EmptyContainer,
+                lwe_size < 2 => LweCiphertextVectorCreationError::NullLweDimension,
+                lwe_size != 0 && container_length % lwe_size != 0 =>
+                    LweCiphertextVectorCreationError::ContainerSizeNotCompatibleWithLweSize,
+            }
+        );
+        if let Some(view) = view {
+            prop_assert_documented!(engine.decrypt_lwe_ciphertext_vector(&lwe_key, &view), {
+                key_dimension != lwe_size - 1 =>
+                    LweCiphertextVectorDecryptionError::LweDimensionMismatch,
+            });
+        }
+        let mut_view: Option<LweCiphertextVectorMutView64> = prop_assert_documented!(
+            engine.create_lwe_ciphertext_vector(
+                lwe_mut_container.as_mut_slice(),
+                LweSize(lwe_size),
+            ),
+            {
+                container_length == 0 => LweCiphertextVectorCreationError::EmptyContainer,
+                lwe_size < 2 => LweCiphertextVectorCreationError::NullLweDimension,
+                lwe_size != 0 && container_length % lwe_size != 0 =>
+                    LweCiphertextVectorCreationError::ContainerSizeNotCompatibleWithLweSize,
+            }
+        );
+        if let Some(mut mut_view) = mut_view {
+            let input = lwe_ciphertext_vector(&mut engine, key_dimension, container_length / lwe_size);
+            prop_assert_documented!(engine.fuse_add_lwe_ciphertext_vector(&mut mut_view, &input), {
+                key_dimension != lwe_size - 1 =>
+                    LweCiphertextVectorFusingAdditionError::LweDimensionMismatch,
+            });
+        }
+
+        let glwe_length = (glwe_dimension + 1) * polynomial_size;
+        let view: Option<GlweCiphertextVectorView64> = prop_assert_documented!(
+            engine.create_glwe_ciphertext_vector(
+                glwe_container.as_slice(),
+                GlweDimension(glwe_dimension),
+                PolynomialSize(polynomial_size),
+            ),
+            {
+                container_length == 0 => GlweCiphertextVectorCreationError::EmptyContainer,
+                glwe_dimension == 0 => GlweCiphertextVectorCreationError::NullGlweDimension,
+                polynomial_size == 0 => GlweCiphertextVectorCreationError::NullPolynomialSize,
+                glwe_length != 0 && container_length % glwe_length != 0 =>
+                    GlweCiphertextVectorCreationError::ContainerSizeNotCompatibleWithGlweSize,
+            }
+        );
+        if let Some(view) = view {
+            prop_assert_documented!(engine.decrypt_glwe_ciphertext_vector(&glwe_key, &view), {
+                key_dimension != glwe_dimension =>
+                    GlweCiphertextVectorDecryptionError::GlweDimensionMismatch,
+                polynomial_size != 2 => GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch,
+            });
+        }
+        let _: Option<GlweCiphertextVectorMutView32> = prop_assert_documented!(
+            engine.create_glwe_ciphertext_vector(
+                glwe_mut_container.as_mut_slice(),
+                GlweDimension(glwe_dimension),
+                PolynomialSize(polynomial_size),
+            ),
+            {
+                container_length == 0 => GlweCiphertextVectorCreationError::EmptyContainer,
+                glwe_dimension == 0 => GlweCiphertextVectorCreationError::NullGlweDimension,
+                polynomial_size == 0 => GlweCiphertextVectorCreationError::NullPolynomialSize,
+                glwe_length != 0 && container_length % glwe_length != 0 =>
+                    GlweCiphertextVectorCreationError::ContainerSizeNotCompatibleWithGlweSize,
+            }
+        );
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn prop_mapping_of_altered_files(
+        index in any::<Index>(),
+        mask in 1u8..=255,
+        length in any::<Index>(),
+    ) {
+        let mut engine = engine();
+        let mut mapping_engine = CoreMappingEngine::new().unwrap();
+        let input_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(3)).unwrap();
+        let output_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(2)).unwrap();
+        let ksk: LweKeyswitchKey64 = engine
+            .create_lwe_keyswitch_key(
+                &input_key,
+                &output_key,
+                DecompositionLevelCount(2),
+                DecompositionBaseLog(4),
+                NOISE,
+            )
+            .unwrap();
+        let bytes = mapping_engine.serialize(&ksk).unwrap();
+        let path = std::env::temp_dir().join(format!(
+            "concrete_core_robustness_{}.ksk",
+            std::process::id()
+        ));
+
+        // Only the header of a mappable entity is covered by the checksum, so an altered file is
+        // either rejected, or mapped with the original dimensions.
+        let mut altered = bytes.clone();
+        altered[index.index(bytes.len())] ^= mask;
+        std::fs::write(&path, &altered).unwrap();
+        // Safety: the file is not modified until the mapped key is dropped.
+        let mapped: Result<MappedLweKeyswitchKey64, _> =
+            unsafe { mapping_engine.map_entity(path.as_path()) };
+        if let Ok(mapped) = mapped {
+            prop_assert_eq!(mapped.input_lwe_dimension(), LweDimension(3));
+            prop_assert_eq!(mapped.output_lwe_dimension(), LweDimension(2));
+            prop_assert_eq!(mapped.decomposition_level_count(), DecompositionLevelCount(2));
+        }
+
+        std::fs::write(&path, &bytes[..length.index(bytes.len())]).unwrap();
+        // Safety: the file is not modified until the mapped key is dropped.
+        let truncated: Result<MappedLweKeyswitchKey64, _> =
+            unsafe { mapping_engine.map_entity(path.as_path()) };
+        prop_assert!(truncated.is_err());
+
+        std::fs::remove_file(&path).unwrap();
+        // Safety: the file does not exist.
+        let missing: Result<MappedLweKeyswitchKey64, _> =
+            unsafe { mapping_engine.map_entity(path.as_path()) };
+        prop_assert!(missing.is_err());
+    }
+
+    #[test]
+    fn prop_custom_modulus_lwe_ciphertext_operations(
+        lwe_dimension in small_dimension(),
+        modulus in ciphertext_modulus(),
+    ) {
+        let mut engine = engine();
+        let key_32: LweSecretKey32 =
+            engine.create_lwe_secret_key(LweDimension(lwe_dimension)).unwrap();
+        let key_64: LweSecretKey64 =
+            engine.create_lwe_secret_key(LweDimension(lwe_dimension)).unwrap();
+        let plaintext_32 = engine.create_plaintext(&0u32).unwrap();
+        let plaintext_64 = engine.create_plaintext(&0u64).unwrap();
+        let invalid = matches!(modulus, CiphertextModulus::Custom(modulus) if modulus < 2);
+
+        let _: Option<LweCiphertext32> = prop_assert_documented!(
+            engine.custom_modulus_encrypt_lwe_ciphertext(&key_32, &plaintext_32, NOISE, modulus),
+            {
+                invalid => LweCiphertextCustomModulusEncryptionError::InvalidCiphertextModulus,
+                !modulus.is_compatible_with_bits(32) =>
+                    LweCiphertextCustomModulusEncryptionError::Engine(
+                        CoreError::UnsupportedCiphertextModulus
+                    ),
+            }
+        );
+        let ciphertext: Option<LweCiphertext64> = prop_assert_documented!(
+            engine.custom_modulus_encrypt_lwe_ciphertext(&key_64, &plaintext_64, NOISE, modulus),
+            {
+                invalid => LweCiphertextCustomModulusEncryptionError::InvalidCiphertextModulus,
+                !modulus.is_compatible_with_bits(64) =>
+                    LweCiphertextCustomModulusEncryptionError::Engine(
+                        CoreError::UnsupportedCiphertextModulus
+                    ),
+            }
+        );
+        let mut ciphertext = match ciphertext {
+            Some(ciphertext) => ciphertext,
+            None => return Ok(()),
+        };
+        let custom = !modulus.is_native();
+        let native = lwe_ciphertext(&mut engine, lwe_dimension);
+        let mut output = lwe_ciphertext(&mut engine, lwe_dimension);
+
+        prop_assert_documented!(
+            engine.discard_add_lwe_ciphertext(&mut output, &ciphertext, &native),
+            {
+                custom => LweCiphertextDiscardingAdditionError::CiphertextModulusMismatch,
+            }
+        );
+        prop_assert_documented!(
+            engine.discard_sub_lwe_ciphertext(&mut output, &native, &ciphertext),
+            {
+                custom => LweCiphertextDiscardingSubtractionError::CiphertextModulusMismatch,
+            }
+        );
+        prop_assert_documented!(engine.fuse_add_lwe_ciphertext(&mut output, &ciphertext), {
+            custom => LweCiphertextFusingAdditionError::CiphertextModulusMismatch,
+        });
+        prop_assert_documented!(engine.fuse_sub_lwe_ciphertext(&mut output, &ciphertext), {
+            custom => LweCiphertextFusingSubtractionError::CiphertextModulusMismatch,
+        });
+        // The discarding operations give their output the modulus of their inputs.
+        prop_assert_documented!(engine.discard_opp_lwe_ciphertext(&mut output, &ciphertext), {});
+        prop_assert_eq!(output.ciphertext_modulus(), modulus);
+        prop_assert_documented!(engine.fuse_add_lwe_ciphertext(&mut output, &ciphertext), {});
+        prop_assert_documented!(
+            engine.discard_add_lwe_ciphertext_plaintext(&mut output, &ciphertext, &plaintext_64),
+            {
+                custom => LweCiphertextPlaintextDiscardingAdditionError::Engine(
+                    CoreError::UnsupportedCiphertextModulus
+                ),
+            }
+        );
+        prop_assert_documented!(
+            engine.fuse_add_lwe_ciphertext_plaintext(&mut ciphertext, &plaintext_64),
+            {
+                custom => LweCiphertextPlaintextFusingAdditionError::Engine(
+                    CoreError::UnsupportedCiphertextModulus
+                ),
+            }
+        );
+        prop_assert_documented!(engine.decrypt_lwe_ciphertext(&key_64, &ciphertext), {});
+
+        let output_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(1)).unwrap();
+        let ksk: LweKeyswitchKey64 = engine
+            .create_lwe_keyswitch_key(
+                &key_64,
+                &output_key,
+                DecompositionLevelCount(2),
+                DecompositionBaseLog(4),
+                NOISE,
+            )
+            .unwrap();
+        let mut switched = lwe_ciphertext(&mut engine, 1);
+        prop_assert_documented!(
+            engine.discard_keyswitch_lwe_ciphertext(&mut switched, &ciphertext, &ksk),
+            {}
+        );
+        prop_assert_eq!(switched.ciphertext_modulus(), modulus);
+    }
+
+    #[cfg(feature = "multithread")]
+    #[test]
+    fn prop_lwe_parallel_bootstrap(
+        input_count in 0usize..3,
+        output_count in 0usize..3,
+        input_dimension in 1usize..3,
+        output_dimension in prop_oneof![Just(512usize), Just(3)],
+        accumulator_glwe_dimension in 1usize..3,
+        accumulator_polynomial_size in fourier_polynomial_size(),
+    ) {
+        let mut engine = engine();
+        let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(1)).unwrap();
+        let glwe_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(1), PolynomialSize(512))
+            .unwrap();
+        let bsk: FourierLweBootstrapKey64 = engine
+            .create_lwe_bootstrap_key(
+                &lwe_key,
+                &glwe_key,
+                DecompositionBaseLog(8),
+                DecompositionLevelCount(1),
+                NOISE,
+            )
+            .unwrap();
+        let input: Vec<_> = (0..input_count)
+            .map(|_| lwe_ciphertext(&mut engine, input_dimension))
+            .collect();
+        let mut output: Vec<_> = (0..output_count)
+            .map(|_| lwe_ciphertext(&mut engine, output_dimension))
+            .collect();
+        let accumulator =
+            glwe_ciphertext(&mut engine, accumulator_glwe_dimension, accumulator_polynomial_size);
+        prop_assert_documented!(
+            engine.discard_parallel_bootstrap_lwe_ciphertexts(
+                &mut output,
+                &input,
+                &accumulator,
+                &bsk,
+            ),
+            {
+                input_count != output_count =>
+                    LweCiphertextDiscardingParallelBootstrapError::CiphertextCountMismatch,
+                input_count != 0 && input_dimension != 1 =>
+                    LweCiphertextDiscardingParallelBootstrapError::InputLweDimensionMismatch,
+                accumulator_polynomial_size != 512 =>
+                    LweCiphertextDiscardingParallelBootstrapError
+                        ::AccumulatorPolynomialSizeMismatch,
+                accumulator_glwe_dimension != 1 =>
+                    LweCiphertextDiscardingParallelBootstrapError
+                        ::AccumulatorGlweDimensionMismatch,
+                output_count != 0 && output_dimension != 512 =>
+                    LweCiphertextDiscardingParallelBootstrapError::OutputLweDimensionMismatch,
+                !FOURIER_POLYNOMIAL_SIZES.contains(&accumulator_polynomial_size) =>
+                    LweCiphertextDiscardingParallelBootstrapError::Engine(
+                        CoreError::UnsupportedPolynomialSize
+                    ),
+            }
+        );
+    }
+
+    #[test]
+    fn prop_128_bits_operations(
+        key_dimension in small_dimension(),
+        other_key_dimension in small_dimension(),
+        glwe_dimension in small_dimension(),
+        polynomial_size in 2usize..5,
+        plaintext_count in 1usize..6,
+        output_dimension in 1usize..10,
+        nth in 0usize..6,
+    ) {
+        let mut engine = engine();
+        let key: LweSecretKey128 =
+            engine.create_lwe_secret_key(LweDimension(key_dimension)).unwrap();
+        let other_key: LweSecretKey128 =
+            engine.create_lwe_secret_key(LweDimension(other_key_dimension)).unwrap();
+        let plaintext = engine.create_plaintext(&1u128).unwrap();
+        let cleartext = engine.create_cleartext(&2u128).unwrap();
+        let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, NOISE).unwrap();
+        let other_ciphertext: LweCiphertext128 =
+            engine.zero_encrypt_lwe_ciphertext(&other_key, NOISE).unwrap();
+        let mismatch = key_dimension != other_key_dimension;
+
+        prop_assert_documented!(engine.fuse_add_lwe_ciphertext(&mut ciphertext, &other_ciphertext), {
+            mismatch => LweCiphertextFusingAdditionError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(engine.fuse_sub_lwe_ciphertext(&mut ciphertext, &other_ciphertext), {
+            mismatch => LweCiphertextFusingSubtractionError::LweDimensionMismatch,
+        });
+        prop_assert_documented!(engine.fuse_opp_lwe_ciphertext(&mut ciphertext), {});
+        prop_assert_documented!(engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext), {});
+        prop_assert_documented!(engine.fuse_add_lwe_ciphertext_plaintext(&mut ciphertext, &plaintext), {});
+        prop_assert_documented!(engine.decrypt_lwe_ciphertext(&other_key, &ciphertext), {
+            mismatch => LweCiphertextDecryptionError::LweDimensionMismatch,
+        });
+
+        let glwe_key: GlweSecretKey128 = engine
+            .create_glwe_secret_key(GlweDimension(glwe_dimension), PolynomialSize(polynomial_size))
+            .unwrap();
+        let plaintexts = engine.create_plaintext_vector(&vec![1u128; plaintext_count]).unwrap();
+        let glwe: Option<GlweCiphertext128> = prop_assert_documented!(
+            engine.encrypt_glwe_ciphertext(&glwe_key, &plaintexts, NOISE),
+            {
+                plaintext_count != polynomial_size =>
+                    GlweCiphertextEncryptionError::PlaintextCountMismatch,
+            }
+        );
+        if let Some(glwe) = glwe {
+            let _: Option<PlaintextVector128> =
+                prop_assert_documented!(engine.decrypt_glwe_ciphertext(&glwe_key, &glwe), {});
+            let output_key: LweSecretKey128 = engine
+                .create_lwe_secret_key(LweDimension(output_dimension))
+                .unwrap();
+            let mut output: LweCiphertext128 =
+                engine.zero_encrypt_lwe_ciphertext(&output_key, NOISE).unwrap();
+            prop_assert_documented!(
+                engine.discard_extract_lwe_ciphertext(&mut output, &glwe, MonomialIndex(nth)),
+                {
+                    output_dimension != glwe_dimension * polynomial_size =>
+                        LweCiphertextDiscardingExtractionError::SizeMismatch,
+                    nth >= polynomial_size =>
+                        LweCiphertextDiscardingExtractionError::MonomialIndexTooLarge,
+                }
+            );
+            let lwe_key: LweSecretKey128 =
+                engine.transmute_glwe_secret_key_to_lwe_secret_key(glwe_key).unwrap();
+            prop_assert_documented!(engine.decrypt_lwe_ciphertext(&lwe_key, &output), {
+                output_dimension != glwe_dimension * polynomial_size =>
+                    LweCiphertextDecryptionError::LweDimensionMismatch,
+            });
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{Cleartext32, Cleartext64};
+use crate::backends::core::implementation::entities::{Cleartext128, Cleartext32, Cleartext64};
 use crate::specification::engines::{CleartextRetrievalEngine, CleartextRetrievalError};
 
 /// # Description:
@@ -69,3 +69,37 @@ impl CleartextRetrievalEngine<Cleartext64, u64> for CoreEngine {
         cleartext.0 .0
     }
 }
+
+/// # Description:
+/// Implementation of [`CleartextRetrievalEngine`] for [`CoreEngine`] that operates on 128 bits
+/// integers.
+impl CleartextRetrievalEngine<Cleartext128, u128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u128 = 3;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext128 = engine.create_cleartext(&input)?;
+    /// let output: u128 = engine.retrieve_cleartext(&cleartext)?;
+    ///
+    /// assert_eq!(output, 3_u128);
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_cleartext(
+        &mut self,
+        cleartext: &Cleartext128,
+    ) -> Result<u128, CleartextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_cleartext_unchecked(cleartext) })
+    }
+
+    unsafe fn retrieve_cleartext_unchecked(&mut self, cleartext: &Cleartext128) -> u128 {
+        cleartext.0 .0
+    }
+}
@@ -1,13 +1,23 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, CleartextVector32, CleartextVector64, FourierGgswCiphertext32,
+    Cleartext128, Cleartext32, Cleartext64, CleartextVector32, CleartextVector64,
+    ExternalProductWorkspace32, ExternalProductWorkspace64, FourierGgswCiphertext32,
     FourierGgswCiphertext64, FourierGlweCiphertext32, FourierGlweCiphertext64,
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64,
-    GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64,
-    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
-    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
-    LweKeyswitchKey64, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey32,
-    PackingKeyswitchKey64, Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
+    GlweCiphertext128, GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32,
+    GlweCiphertextVector64, GlweCiphertextVectorMutView32, GlweCiphertextVectorMutView64,
+    GlweCiphertextVectorView32, GlweCiphertextVectorView64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext128, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey128, LweSecretKey32, LweSecretKey64,
+    PackingKeyswitchKey32, PackingKeyswitchKey64, Plaintext128, Plaintext32, Plaintext64,
+    PlaintextVector128, PlaintextVector32, PlaintextVector64,
+};
+#[cfg(feature = "mmap")]
+use crate::backends::core::implementation::entities::{
+    MappedFourierLweBootstrapKey32, MappedFourierLweBootstrapKey64, MappedLweKeyswitchKey32,
+    MappedLweKeyswitchKey64,
 };
 use crate::backends::core::private::math::tensor::AsMutTensor;
 use crate::specification::engines::{DestructionEngine, DestructionError};
@@ -36,6 +46,18 @@ impl DestructionEngine<Cleartext64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut Cleartext64) {}
 }
 
+impl DestructionEngine<Cleartext128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: Cleartext128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut Cleartext128) {}
+}
+
 impl DestructionEngine<CleartextVector32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -84,6 +106,18 @@ impl DestructionEngine<Plaintext64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut Plaintext64) {}
 }
 
+impl DestructionEngine<Plaintext128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: Plaintext128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut Plaintext128) {}
+}
+
 impl DestructionEngine<PlaintextVector32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -108,6 +142,18 @@ impl DestructionEngine<PlaintextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut PlaintextVector64) {}
 }
 
+impl DestructionEngine<PlaintextVector128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: PlaintextVector128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut PlaintextVector128) {}
+}
+
 impl DestructionEngine<LweCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -132,6 +178,18 @@ impl DestructionEngine<LweCiphertext64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut LweCiphertext64) {}
 }
 
+impl DestructionEngine<LweCiphertext128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: LweCiphertext128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut LweCiphertext128) {}
+}
+
 impl DestructionEngine<LweCiphertextVector32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -156,6 +214,54 @@ impl DestructionEngine<LweCiphertextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut LweCiphertextVector64) {}
 }
 
+impl<'data> DestructionEngine<LweCiphertextVectorView32<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: LweCiphertextVectorView32<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut LweCiphertextVectorView32<'data>) {}
+}
+
+impl<'data> DestructionEngine<LweCiphertextVectorView64<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: LweCiphertextVectorView64<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut LweCiphertextVectorView64<'data>) {}
+}
+
+impl<'data> DestructionEngine<LweCiphertextVectorMutView32<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: LweCiphertextVectorMutView32<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut LweCiphertextVectorMutView32<'data>) {}
+}
+
+impl<'data> DestructionEngine<LweCiphertextVectorMutView64<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: LweCiphertextVectorMutView64<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut LweCiphertextVectorMutView64<'data>) {}
+}
+
 impl DestructionEngine<GlweCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -180,6 +286,18 @@ impl DestructionEngine<GlweCiphertext64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertext64) {}
 }
 
+impl DestructionEngine<GlweCiphertext128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertext128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertext128) {}
+}
+
 impl DestructionEngine<FourierGlweCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -228,6 +346,54 @@ impl DestructionEngine<GlweCiphertextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVector64) {}
 }
 
+impl<'data> DestructionEngine<GlweCiphertextVectorView32<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorView32<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorView32<'data>) {}
+}
+
+impl<'data> DestructionEngine<GlweCiphertextVectorView64<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorView64<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorView64<'data>) {}
+}
+
+impl<'data> DestructionEngine<GlweCiphertextVectorMutView32<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorMutView32<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorMutView32<'data>) {}
+}
+
+impl<'data> DestructionEngine<GlweCiphertextVectorMutView64<'data>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorMutView64<'data>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorMutView64<'data>) {}
+}
+
 impl DestructionEngine<GgswCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -376,6 +542,20 @@ impl DestructionEngine<LweSecretKey64> for CoreEngine {
     }
 }
 
+impl DestructionEngine<LweSecretKey128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: LweSecretKey128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut LweSecretKey128) {
+        entity.0.as_mut_tensor().fill_with_element(0u128);
+    }
+}
+
 impl DestructionEngine<GlweSecretKey32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -404,6 +584,20 @@ impl DestructionEngine<GlweSecretKey64> for CoreEngine {
     }
 }
 
+impl DestructionEngine<GlweSecretKey128> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweSecretKey128,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut GlweSecretKey128) {
+        entity.0.as_mut_tensor().fill_with_element(0u128);
+    }
+}
+
 impl DestructionEngine<PackingKeyswitchKey32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -431,3 +625,79 @@ impl DestructionEngine<PackingKeyswitchKey64> for CoreEngine {
         entity.0.as_mut_tensor().fill_with_element(0u64);
     }
 }
+
+impl DestructionEngine<ExternalProductWorkspace32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: ExternalProductWorkspace32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut ExternalProductWorkspace32) {}
+}
+
+impl DestructionEngine<ExternalProductWorkspace64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: ExternalProductWorkspace64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut ExternalProductWorkspace64) {}
+}
+
+#[cfg(feature = "mmap")]
+impl DestructionEngine<MappedFourierLweBootstrapKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: MappedFourierLweBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut MappedFourierLweBootstrapKey32) {}
+}
+
+#[cfg(feature = "mmap")]
+impl DestructionEngine<MappedFourierLweBootstrapKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: MappedFourierLweBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut MappedFourierLweBootstrapKey64) {}
+}
+
+#[cfg(feature = "mmap")]
+impl DestructionEngine<MappedLweKeyswitchKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: MappedLweKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut MappedLweKeyswitchKey32) {}
+}
+
+#[cfg(feature = "mmap")]
+impl DestructionEngine<MappedLweKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: MappedLweKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut MappedLweKeyswitchKey64) {}
+}
@@ -0,0 +1,136 @@
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    ExternalProductWorkspace32, ExternalProductWorkspace64,
+};
+use crate::backends::core::private::crypto::bootstrap::FourierBuffers as ImplFourierBuffers;
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::prelude::CoreError;
+use crate::specification::engines::{
+    ExternalProductWorkspaceCreationEngine, ExternalProductWorkspaceCreationError,
+};
+
+impl From<CoreError> for ExternalProductWorkspaceCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`ExternalProductWorkspaceCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl ExternalProductWorkspaceCreationEngine<ExternalProductWorkspace32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let workspace: ExternalProductWorkspace32 =
+    ///     engine.create_external_product_workspace(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(workspace.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(workspace.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(workspace)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_external_product_workspace(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<ExternalProductWorkspace32, ExternalProductWorkspaceCreationError<Self::EngineError>>
+    {
+        ExternalProductWorkspaceCreationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        if !ALLOWED_POLY_SIZE.contains(&polynomial_size.0) {
+            return Err(ExternalProductWorkspaceCreationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        Ok(unsafe {
+            self.create_external_product_workspace_unchecked(glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_external_product_workspace_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> ExternalProductWorkspace32 {
+        ExternalProductWorkspace32(ImplFourierBuffers::new(
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`ExternalProductWorkspaceCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl ExternalProductWorkspaceCreationEngine<ExternalProductWorkspace64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let workspace: ExternalProductWorkspace64 =
+    ///     engine.create_external_product_workspace(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(workspace.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(workspace.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(workspace)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_external_product_workspace(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<ExternalProductWorkspace64, ExternalProductWorkspaceCreationError<Self::EngineError>>
+    {
+        ExternalProductWorkspaceCreationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        if !ALLOWED_POLY_SIZE.contains(&polynomial_size.0) {
+            return Err(ExternalProductWorkspaceCreationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        Ok(unsafe {
+            self.create_external_product_workspace_unchecked(glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_external_product_workspace_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> ExternalProductWorkspace64 {
+        ExternalProductWorkspace64(ImplFourierBuffers::new(
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+        ))
+    }
+}
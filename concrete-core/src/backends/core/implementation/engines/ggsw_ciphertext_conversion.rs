@@ -3,12 +3,19 @@ use crate::backends::core::implementation::entities::{
     FourierGgswCiphertext32, FourierGgswCiphertext64, GgswCiphertext32, GgswCiphertext64,
 };
 use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
-use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     GgswCiphertextConversionEngine, GgswCiphertextConversionError,
 };
 use crate::specification::entities::GgswCiphertextEntity;
 
+impl From<CoreError> for GgswCiphertextConversionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`GgswCiphertextConversionEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers. It converts a GGSW ciphertext from the standard to the Fourier domain.
@@ -61,6 +68,11 @@ impl GgswCiphertextConversionEngine<GgswCiphertext32, FourierGgswCiphertext32> f
         &mut self,
         input: &GgswCiphertext32,
     ) -> Result<FourierGgswCiphertext32, GgswCiphertextConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(GgswCiphertextConversionError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
     }
 
@@ -136,6 +148,11 @@ impl GgswCiphertextConversionEngine<GgswCiphertext64, FourierGgswCiphertext64> f
         &mut self,
         input: &GgswCiphertext64,
     ) -> Result<FourierGgswCiphertext64, GgswCiphertextConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(GgswCiphertextConversionError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
     }
 
@@ -2,8 +2,8 @@ use concrete_commons::parameters::PlaintextCount;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
-    PlaintextVector64,
+    GlweCiphertext128, GlweCiphertext32, GlweCiphertext64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, PlaintextVector128, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
 use crate::specification::engines::{
@@ -134,3 +134,65 @@ impl GlweCiphertextDecryptionEngine<GlweSecretKey64, GlweCiphertext64, Plaintext
         PlaintextVector64(plaintext)
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on 128 bits
+/// integers.
+impl GlweCiphertextDecryptionEngine<GlweSecretKey128, GlweCiphertext128, PlaintextVector128>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = vec![3_u128 << 110; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey128 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     plaintext_vector.plaintext_count()
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey128,
+        input: &GlweCiphertext128,
+    ) -> Result<PlaintextVector128, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey128,
+        input: &GlweCiphertext128,
+    ) -> PlaintextVector128 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u128, PlaintextCount(key.polynomial_size().0));
+        key.0.decrypt_glwe(&mut plaintext, &input.0);
+        PlaintextVector128(plaintext)
+    }
+}
@@ -2,8 +2,8 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
-    PlaintextVector64,
+    GlweCiphertext128, GlweCiphertext32, GlweCiphertext64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, PlaintextVector128, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
 use crate::specification::engines::{
@@ -148,3 +148,72 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiph
         GlweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 128 bits
+/// integers.
+impl GlweCiphertextEncryptionEngine<GlweSecretKey128, PlaintextVector128, GlweCiphertext128>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = vec![3_u128 << 110; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey128 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey128,
+        input: &PlaintextVector128,
+        noise: Variance,
+    ) -> Result<GlweCiphertext128, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey128,
+        input: &PlaintextVector128,
+        noise: Variance,
+    ) -> GlweCiphertext128 {
+        let mut ciphertext = ImplGlweCiphertext::allocate(
+            0u128,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweCiphertext128(ciphertext)
+    }
+}
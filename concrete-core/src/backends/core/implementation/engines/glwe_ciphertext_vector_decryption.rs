@@ -2,8 +2,9 @@ use concrete_commons::parameters::PlaintextCount;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey32, GlweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorMutView32,
+    GlweCiphertextVectorMutView64, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
 use crate::specification::engines::{
@@ -144,3 +145,72 @@ impl
         PlaintextVector64(plaintext_list)
     }
 }
+
+macro_rules! implement_glwe_ciphertext_vector_decryption_for_views {
+    ($(($Key: ty, $Input: ty, $PlaintextVector: ident, $Scalar: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for
+            /// [`CoreEngine`] that operates on views of GLWE ciphertext vectors.
+            impl
+                GlweCiphertextVectorDecryptionEngine<
+                    $Key,
+                    $Input,
+                    $PlaintextVector,
+                > for CoreEngine
+            {
+                fn decrypt_glwe_ciphertext_vector(
+                    &mut self,
+                    key: &$Key,
+                    input: &$Input,
+                ) -> Result<
+                    $PlaintextVector,
+                    GlweCiphertextVectorDecryptionError<Self::EngineError>,
+                > {
+                    GlweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
+                    Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+                }
+
+                unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    key: &$Key,
+                    input: &$Input,
+                ) -> $PlaintextVector {
+                    let mut plaintext_list = ImplPlaintextList::allocate(
+                        0 as $Scalar,
+                        PlaintextCount(key.polynomial_size().0 * input.glwe_ciphertext_count().0),
+                    );
+                    key.0.decrypt_glwe_list(&mut plaintext_list, &input.0);
+                    $PlaintextVector(plaintext_list)
+                }
+            }
+        )*
+    };
+}
+
+implement_glwe_ciphertext_vector_decryption_for_views!(
+    (
+        GlweSecretKey32,
+        GlweCiphertextVectorView32<'_>,
+        PlaintextVector32,
+        u32
+    ),
+    (
+        GlweSecretKey32,
+        GlweCiphertextVectorMutView32<'_>,
+        PlaintextVector32,
+        u32
+    ),
+    (
+        GlweSecretKey64,
+        GlweCiphertextVectorView64<'_>,
+        PlaintextVector64,
+        u64
+    ),
+    (
+        GlweSecretKey64,
+        GlweCiphertextVectorMutView64<'_>,
+        PlaintextVector64,
+        u64
+    )
+);
@@ -1,7 +1,8 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey32, GlweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorMutView32,
+    GlweCiphertextVectorMutView64, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::specification::engines::{
     GlweCiphertextVectorDiscardingDecryptionEngine, GlweCiphertextVectorDiscardingDecryptionError,
@@ -140,3 +141,68 @@ impl
         key.0.decrypt_glwe_list(&mut output.0, &input.0);
     }
 }
+
+macro_rules! implement_glwe_ciphertext_vector_discarding_decryption_for_views {
+    ($(($Key: ty, $Input: ty, $PlaintextVector: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`GlweCiphertextVectorDiscardingDecryptionEngine`] for
+            /// [`CoreEngine`] that operates on views of GLWE ciphertext vectors.
+            impl
+                GlweCiphertextVectorDiscardingDecryptionEngine<
+                    $Key,
+                    $Input,
+                    $PlaintextVector,
+                > for CoreEngine
+            {
+                fn discard_decrypt_glwe_ciphertext_vector(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $PlaintextVector,
+                    input: &$Input,
+                ) -> Result<(), GlweCiphertextVectorDiscardingDecryptionError<Self::EngineError>>
+                {
+                    GlweCiphertextVectorDiscardingDecryptionError::perform_generic_checks(
+                        key, output, input,
+                    )?;
+                    unsafe {
+                        self.discard_decrypt_glwe_ciphertext_vector_unchecked(key, output, input)
+                    };
+                    Ok(())
+                }
+
+                unsafe fn discard_decrypt_glwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $PlaintextVector,
+                    input: &$Input,
+                ) {
+                    key.0.decrypt_glwe_list(&mut output.0, &input.0);
+                }
+            }
+        )*
+    };
+}
+
+implement_glwe_ciphertext_vector_discarding_decryption_for_views!(
+    (
+        GlweSecretKey32,
+        GlweCiphertextVectorView32<'_>,
+        PlaintextVector32
+    ),
+    (
+        GlweSecretKey32,
+        GlweCiphertextVectorMutView32<'_>,
+        PlaintextVector32
+    ),
+    (
+        GlweSecretKey64,
+        GlweCiphertextVectorView64<'_>,
+        PlaintextVector64
+    ),
+    (
+        GlweSecretKey64,
+        GlweCiphertextVectorMutView64<'_>,
+        PlaintextVector64
+    )
+);
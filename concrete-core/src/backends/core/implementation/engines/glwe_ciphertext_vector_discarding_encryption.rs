@@ -2,8 +2,9 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey32, GlweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorMutView32,
+    GlweCiphertextVectorMutView64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
 };
 use crate::specification::engines::{
     GlweCiphertextVectorDiscardingEncryptionEngine, GlweCiphertextVectorDiscardingEncryptionError,
@@ -176,3 +177,67 @@ impl
         );
     }
 }
+
+macro_rules! implement_glwe_ciphertext_vector_discarding_encryption_for_views {
+    ($(($Key: ty, $PlaintextVector: ty, $Output: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`GlweCiphertextVectorDiscardingEncryptionEngine`] for
+            /// [`CoreEngine`] that encrypts into mutable views of GLWE ciphertext vectors.
+            impl
+                GlweCiphertextVectorDiscardingEncryptionEngine<
+                    $Key,
+                    $PlaintextVector,
+                    $Output,
+                > for CoreEngine
+            {
+                fn discard_encrypt_glwe_ciphertext_vector(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $Output,
+                    input: &$PlaintextVector,
+                    noise: Variance,
+                ) -> Result<(), GlweCiphertextVectorDiscardingEncryptionError<Self::EngineError>>
+                {
+                    GlweCiphertextVectorDiscardingEncryptionError::perform_generic_checks(
+                        key, output, input,
+                    )?;
+                    unsafe {
+                        self.discard_encrypt_glwe_ciphertext_vector_unchecked(
+                            key, output, input, noise,
+                        )
+                    };
+                    Ok(())
+                }
+
+                unsafe fn discard_encrypt_glwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $Output,
+                    input: &$PlaintextVector,
+                    noise: Variance,
+                ) {
+                    key.0.encrypt_glwe_list(
+                        &mut output.0,
+                        &input.0,
+                        noise,
+                        &mut self.encryption_generator,
+                    );
+                }
+            }
+        )*
+    };
+}
+
+implement_glwe_ciphertext_vector_discarding_encryption_for_views!(
+    (
+        GlweSecretKey32,
+        PlaintextVector32,
+        GlweCiphertextVectorMutView32<'_>
+    ),
+    (
+        GlweSecretKey64,
+        PlaintextVector64,
+        GlweCiphertextVectorMutView64<'_>
+    )
+);
@@ -2,8 +2,9 @@ use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlain
 use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::prelude::{
     CoreEngine, GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorEntity,
+    GlweCiphertextVectorMutView32, GlweCiphertextVectorMutView64,
     GlweCiphertextVectorTrivialDecryptionEngine, GlweCiphertextVectorTrivialDecryptionError,
-    PlaintextVector32, PlaintextVector64,
+    GlweCiphertextVectorView32, GlweCiphertextVectorView64, PlaintextVector32, PlaintextVector64,
 };
 use concrete_commons::parameters::PlaintextCount;
 
@@ -138,3 +139,52 @@ impl GlweCiphertextVectorTrivialDecryptionEngine<GlweCiphertextVector64, Plainte
         PlaintextVector64(output)
     }
 }
+
+macro_rules! implement_glwe_ciphertext_vector_trivial_decryption_for_views {
+    ($(($Input: ty, $PlaintextVector: ident, $Scalar: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`GlweCiphertextVectorTrivialDecryptionEngine`] for
+            /// [`CoreEngine`] that operates on views of GLWE ciphertext vectors.
+            impl GlweCiphertextVectorTrivialDecryptionEngine<$Input, $PlaintextVector>
+                for CoreEngine
+            {
+                fn trivially_decrypt_glwe_ciphertext_vector(
+                    &mut self,
+                    input: &$Input,
+                ) -> Result<
+                    $PlaintextVector,
+                    GlweCiphertextVectorTrivialDecryptionError<Self::EngineError>,
+                > {
+                    Ok(unsafe { self.trivially_decrypt_glwe_ciphertext_vector_unchecked(input) })
+                }
+
+                unsafe fn trivially_decrypt_glwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    input: &$Input,
+                ) -> $PlaintextVector {
+                    let count =
+                        PlaintextCount(input.glwe_ciphertext_count().0 * input.polynomial_size().0);
+                    let sub_count = PlaintextCount(input.polynomial_size().0);
+                    let mut output = ImplPlaintextList::allocate(0 as $Scalar, count);
+                    for (mut plaintext, ciphertext) in output
+                        .sublist_iter_mut(sub_count)
+                        .zip(input.0.ciphertext_iter())
+                    {
+                        plaintext
+                            .as_mut_tensor()
+                            .fill_with_copy(ciphertext.get_body().as_tensor());
+                    }
+                    $PlaintextVector(output)
+                }
+            }
+        )*
+    };
+}
+
+implement_glwe_ciphertext_vector_trivial_decryption_for_views!(
+    (GlweCiphertextVectorView32<'_>, PlaintextVector32, u32),
+    (GlweCiphertextVectorMutView32<'_>, PlaintextVector32, u32),
+    (GlweCiphertextVectorView64<'_>, PlaintextVector64, u64),
+    (GlweCiphertextVectorMutView64<'_>, PlaintextVector64, u64)
+);
@@ -3,12 +3,19 @@ use crate::backends::core::implementation::entities::{
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
 };
 use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey as ImplFourierBootstrapKey;
-use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
 };
 use crate::specification::entities::LweBootstrapKeyEntity;
 
+impl From<CoreError> for LweBootstrapKeyConversionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers. It converts a bootstrap key from the standard to the Fourier domain.
@@ -54,6 +61,11 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FourierLweBootstrapKey32
         &mut self,
         input: &LweBootstrapKey32,
     ) -> Result<FourierLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(LweBootstrapKeyConversionError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
     }
 
@@ -124,6 +136,11 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64
         &mut self,
         input: &LweBootstrapKey64,
     ) -> Result<FourierLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(LweBootstrapKeyConversionError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
     }
 
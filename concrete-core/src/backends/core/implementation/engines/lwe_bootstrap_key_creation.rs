@@ -10,10 +10,16 @@ use crate::backends::core::private::crypto::bootstrap::{
     FourierBootstrapKey as ImplFourierBootstrapKey,
     StandardBootstrapKey as ImplStandardBootstrapKey,
 };
-use crate::backends::core::private::math::fft::Complex64;
-use crate::prelude::LweBootstrapKeyEntity;
+use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+use crate::prelude::{CoreError, GlweSecretKeyEntity, LweBootstrapKeyEntity};
 use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
 
+impl From<CoreError> for LweBootstrapKeyCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers. It outputs a bootstrap key in the standard domain.
@@ -245,6 +251,11 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, FourierLweBo
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
     ) -> Result<FourierLweBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&output_key.polynomial_size().0) {
+            return Err(LweBootstrapKeyCreationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         LweBootstrapKeyCreationError::perform_generic_checks(
             decomposition_base_log,
             decomposition_level_count,
@@ -351,6 +362,11 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
     ) -> Result<FourierLweBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&output_key.polynomial_size().0) {
+            return Err(LweBootstrapKeyCreationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         LweBootstrapKeyCreationError::perform_generic_checks(
             decomposition_base_log,
             decomposition_level_count,
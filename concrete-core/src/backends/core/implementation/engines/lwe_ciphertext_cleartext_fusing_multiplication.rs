@@ -1,6 +1,6 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64,
+    Cleartext128, Cleartext32, Cleartext64, LweCiphertext128, LweCiphertext32, LweCiphertext64,
 };
 use crate::specification::engines::{
     LweCiphertextCleartextFusingMultiplicationEngine,
@@ -116,3 +116,60 @@ impl LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertext64, Cleartext
         output.0.update_with_scalar_mul(input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 128 bits integers.
+impl LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertext128, Cleartext128>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = 3_u128 << 110;
+    /// let cleartext_input = 12_u128;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext128 = engine.create_cleartext(&cleartext_input)?;
+    /// let key: LweSecretKey128 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertext128,
+        input: &Cleartext128,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext128,
+        input: &Cleartext128,
+    ) {
+        output.0.update_with_scalar_mul(input.0);
+    }
+}
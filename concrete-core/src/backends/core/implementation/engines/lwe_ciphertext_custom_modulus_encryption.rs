@@ -0,0 +1,198 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::CiphertextModulus;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::prelude::CoreError;
+use crate::specification::engines::{
+    LweCiphertextCustomModulusEncryptionEngine, LweCiphertextCustomModulusEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+impl From<CoreError> for LweCiphertextCustomModulusEncryptionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCustomModulusEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextCustomModulusEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulus, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here we use a prime modulus, and a hard-set encoding (multiplication by q / 16)
+    /// let modulus = (1_u32 << 31) - 1;
+    /// let ciphertext_modulus = CiphertextModulus::Custom(modulus as u128);
+    /// let input = 3 * (modulus / 16);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.custom_modulus_encrypt_lwe_ciphertext(&key, &plaintext, noise, ciphertext_modulus)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let raw = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(((raw as f64) * 16. / (modulus as f64)).round(), 3.);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn custom_modulus_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Result<LweCiphertext32, LweCiphertextCustomModulusEncryptionError<Self::EngineError>> {
+        LweCiphertextCustomModulusEncryptionError::perform_generic_checks(ciphertext_modulus)?;
+        if !ciphertext_modulus.is_compatible_with_bits(32) {
+            return Err(LweCiphertextCustomModulusEncryptionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
+        Ok(unsafe {
+            self.custom_modulus_encrypt_lwe_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                ciphertext_modulus,
+            )
+        })
+    }
+
+    unsafe fn custom_modulus_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> LweCiphertext32 {
+        let mut ciphertext = ImplLweCiphertext::allocate_with_modulus(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCustomModulusEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextCustomModulusEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulus, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here we use an NTT-friendly prime modulus, and a hard-set encoding (multiplication by
+    /// // q / 16)
+    /// let modulus = u64::MAX - (1 << 32) + 2;
+    /// let ciphertext_modulus = CiphertextModulus::Custom(modulus as u128);
+    /// let input = 3 * (modulus / 16);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.custom_modulus_encrypt_lwe_ciphertext(&key, &plaintext, noise, ciphertext_modulus)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let raw = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(((raw as f64) * 16. / (modulus as f64)).round(), 3.);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn custom_modulus_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Result<LweCiphertext64, LweCiphertextCustomModulusEncryptionError<Self::EngineError>> {
+        LweCiphertextCustomModulusEncryptionError::perform_generic_checks(ciphertext_modulus)?;
+        if !ciphertext_modulus.is_compatible_with_bits(64) {
+            return Err(LweCiphertextCustomModulusEncryptionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
+        Ok(unsafe {
+            self.custom_modulus_encrypt_lwe_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                ciphertext_modulus,
+            )
+        })
+    }
+
+    unsafe fn custom_modulus_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> LweCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate_with_modulus(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext64(ciphertext)
+    }
+}
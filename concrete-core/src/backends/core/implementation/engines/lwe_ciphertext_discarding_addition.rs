@@ -67,6 +67,9 @@ impl LweCiphertextDiscardingAdditionEngine<LweCiphertext32, LweCiphertext32> for
             .0
             .as_mut_tensor()
             .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input_1.0.ciphertext_modulus());
         output.0.update_with_add(&input_2.0);
     }
 }
@@ -133,6 +136,9 @@ impl LweCiphertextDiscardingAdditionEngine<LweCiphertext64, LweCiphertext64> for
             .0
             .as_mut_tensor()
             .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input_1.0.ciphertext_modulus());
         output.0.update_with_add(&input_2.0);
     }
 }
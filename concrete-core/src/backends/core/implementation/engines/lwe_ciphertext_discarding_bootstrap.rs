@@ -3,6 +3,10 @@ use crate::backends::core::implementation::entities::{
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
     LweCiphertext32, LweCiphertext64,
 };
+#[cfg(feature = "mmap")]
+use crate::backends::core::implementation::entities::{
+    MappedFourierLweBootstrapKey32, MappedFourierLweBootstrapKey64,
+};
 use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
 use crate::prelude::{CoreError, GlweCiphertextEntity, LweBootstrapKeyEntity};
 use crate::specification::engines::{
@@ -205,3 +209,87 @@ impl
         bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers, with a bootstrap key mapped from a file.
+#[cfg(feature = "mmap")]
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        MappedFourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &MappedFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextDiscardingBootstrapError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &MappedFourierLweBootstrapKey32,
+    ) {
+        let buffers =
+            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers, with a bootstrap key mapped from a file.
+#[cfg(feature = "mmap")]
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        MappedFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &MappedFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextDiscardingBootstrapError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &MappedFourierLweBootstrapKey64,
+    ) {
+        let buffers =
+            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+    }
+}
@@ -2,6 +2,10 @@ use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
     LweCiphertext32, LweCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64,
 };
+#[cfg(feature = "mmap")]
+use crate::backends::core::implementation::entities::{
+    MappedLweKeyswitchKey32, MappedLweKeyswitchKey64,
+};
 use crate::specification::engines::{
     LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
 };
@@ -153,3 +157,67 @@ impl LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey64, LweCiphertext64,
         ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers, with a keyswitch key mapped from a file.
+#[cfg(feature = "mmap")]
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        MappedLweKeyswitchKey32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &MappedLweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &MappedLweKeyswitchKey32,
+    ) {
+        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers, with a keyswitch key mapped from a file.
+#[cfg(feature = "mmap")]
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        MappedLweKeyswitchKey64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &MappedLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &MappedLweKeyswitchKey64,
+    ) {
+        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
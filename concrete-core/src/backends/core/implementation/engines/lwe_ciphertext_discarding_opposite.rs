@@ -57,6 +57,9 @@ impl LweCiphertextDiscardingOppositeEngine<LweCiphertext32, LweCiphertext32> for
         input: &LweCiphertext32,
     ) {
         output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input.0.ciphertext_modulus());
         output.0.update_with_neg();
     }
 }
@@ -113,6 +116,9 @@ impl LweCiphertextDiscardingOppositeEngine<LweCiphertext64, LweCiphertext64> for
         input: &LweCiphertext64,
     ) {
         output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input.0.ciphertext_modulus());
         output.0.update_with_neg();
     }
 }
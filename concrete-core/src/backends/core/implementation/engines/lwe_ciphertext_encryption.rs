@@ -2,7 +2,8 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext128, LweCiphertext32, LweCiphertext64, LweSecretKey128, LweSecretKey32,
+    LweSecretKey64, Plaintext128, Plaintext32, Plaintext64,
 };
 use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
@@ -125,3 +126,62 @@ impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
         LweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on
+/// 128 bits integers.
+impl LweCiphertextEncryptionEngine<LweSecretKey128, Plaintext128, LweCiphertext128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = 3_u128 << 110;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey128 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey128,
+        input: &Plaintext128,
+        noise: Variance,
+    ) -> Result<LweCiphertext128, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey128,
+        input: &Plaintext128,
+        noise: Variance,
+    ) -> LweCiphertext128 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u128, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext128(ciphertext)
+    }
+}
@@ -3,10 +3,17 @@ use crate::backends::core::implementation::entities::{
     LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64,
 };
 use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweCiphertextPlaintextDiscardingAdditionEngine, LweCiphertextPlaintextDiscardingAdditionError,
 };
 
+impl From<CoreError> for LweCiphertextPlaintextDiscardingAdditionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`CoreEngine`] that
 /// operates on 32 bits integers.
@@ -52,6 +59,11 @@ impl LweCiphertextPlaintextDiscardingAdditionEngine<LweCiphertext32, Plaintext32
         input_2: &Plaintext32,
     ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
         LweCiphertextPlaintextDiscardingAdditionError::perform_generic_checks(output, input_1)?;
+        if !input_1.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextDiscardingAdditionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
         Ok(())
     }
@@ -66,6 +78,9 @@ impl LweCiphertextPlaintextDiscardingAdditionEngine<LweCiphertext32, Plaintext32
             .0
             .as_mut_tensor()
             .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input_1.0.ciphertext_modulus());
         output.0.get_mut_body().0 = output.0.get_body().0.wrapping_add(input_2.0 .0);
     }
 }
@@ -115,6 +130,11 @@ impl LweCiphertextPlaintextDiscardingAdditionEngine<LweCiphertext64, Plaintext64
         input_2: &Plaintext64,
     ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
         LweCiphertextPlaintextDiscardingAdditionError::perform_generic_checks(output, input_1)?;
+        if !input_1.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextDiscardingAdditionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
         Ok(())
     }
@@ -129,6 +149,9 @@ impl LweCiphertextPlaintextDiscardingAdditionEngine<LweCiphertext64, Plaintext64
             .0
             .as_mut_tensor()
             .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input_1.0.ciphertext_modulus());
         output.0.get_mut_body().0 = output.0.get_body().0.wrapping_add(input_2.0 .0);
     }
 }
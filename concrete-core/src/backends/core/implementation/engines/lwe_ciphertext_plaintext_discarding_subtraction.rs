@@ -3,11 +3,18 @@ use crate::backends::core::implementation::entities::{
     LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64,
 };
 use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweCiphertextPlaintextDiscardingSubtractionEngine,
     LweCiphertextPlaintextDiscardingSubtractionError,
 };
 
+impl From<CoreError> for LweCiphertextPlaintextDiscardingSubtractionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextPlaintextDiscardingSubtractionEngine`] for [`CoreEngine`] that
 /// operates on 32 bits integers.
@@ -54,6 +61,11 @@ impl
         input_2: &Plaintext32,
     ) -> Result<(), LweCiphertextPlaintextDiscardingSubtractionError<Self::EngineError>> {
         LweCiphertextPlaintextDiscardingSubtractionError::perform_generic_checks(output, input_1)?;
+        if !input_1.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextDiscardingSubtractionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.discard_sub_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
         Ok(())
     }
@@ -68,6 +80,9 @@ impl
             .0
             .as_mut_tensor()
             .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input_1.0.ciphertext_modulus());
         output.0.get_mut_body().0 = output.0.get_body().0.wrapping_sub(input_2.0 .0);
     }
 }
@@ -118,6 +133,11 @@ impl
         input_2: &Plaintext64,
     ) -> Result<(), LweCiphertextPlaintextDiscardingSubtractionError<Self::EngineError>> {
         LweCiphertextPlaintextDiscardingSubtractionError::perform_generic_checks(output, input_1)?;
+        if !input_1.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextDiscardingSubtractionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.discard_sub_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
         Ok(())
     }
@@ -132,6 +152,9 @@ impl
             .0
             .as_mut_tensor()
             .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .set_ciphertext_modulus(input_1.0.ciphertext_modulus());
         output.0.get_mut_body().0 = output.0.get_body().0.wrapping_sub(input_2.0 .0);
     }
 }
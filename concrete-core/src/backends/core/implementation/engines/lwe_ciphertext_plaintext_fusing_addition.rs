@@ -1,11 +1,18 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64,
+    LweCiphertext128, LweCiphertext32, LweCiphertext64, Plaintext128, Plaintext32, Plaintext64,
 };
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweCiphertextPlaintextFusingAdditionEngine, LweCiphertextPlaintextFusingAdditionError,
 };
 
+impl From<CoreError> for LweCiphertextPlaintextFusingAdditionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`CoreEngine`] that
 /// operates on 32 bits integers.
@@ -48,6 +55,11 @@ impl LweCiphertextPlaintextFusingAdditionEngine<LweCiphertext32, Plaintext32> fo
         output: &mut LweCiphertext32,
         input: &Plaintext32,
     ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        if !output.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextFusingAdditionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
         Ok(())
     }
@@ -103,6 +115,11 @@ impl LweCiphertextPlaintextFusingAdditionEngine<LweCiphertext64, Plaintext64> fo
         output: &mut LweCiphertext64,
         input: &Plaintext64,
     ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        if !output.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextFusingAdditionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
         Ok(())
     }
@@ -115,3 +132,63 @@ impl LweCiphertextPlaintextFusingAdditionEngine<LweCiphertext64, Plaintext64> fo
         output.0.get_mut_body().0 = output.0.get_body().0.wrapping_add(input.0 .0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`CoreEngine`] that
+/// operates on 128 bits integers.
+impl LweCiphertextPlaintextFusingAdditionEngine<LweCiphertext128, Plaintext128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 40 bits)
+    /// let input_1 = 3_u128 << 40;
+    /// let input_2 = 5_u128 << 40;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey128 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_plaintext(&mut ciphertext, &plaintext_2)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut LweCiphertext128,
+        input: &Plaintext128,
+    ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        if !output.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextFusingAdditionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
+        unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext128,
+        input: &Plaintext128,
+    ) {
+        output.0.get_mut_body().0 = output.0.get_body().0.wrapping_add(input.0 .0);
+    }
+}
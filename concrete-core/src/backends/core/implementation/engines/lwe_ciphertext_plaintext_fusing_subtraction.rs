@@ -2,10 +2,17 @@ use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
     LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64,
 };
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweCiphertextPlaintextFusingSubtractionEngine, LweCiphertextPlaintextFusingSubtractionError,
 };
 
+impl From<CoreError> for LweCiphertextPlaintextFusingSubtractionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextPlaintextFusingSubtractionEngine`] for [`CoreEngine`] that
 /// operates on 32 bits integers.
@@ -48,6 +55,11 @@ impl LweCiphertextPlaintextFusingSubtractionEngine<LweCiphertext32, Plaintext32>
         output: &mut LweCiphertext32,
         input: &Plaintext32,
     ) -> Result<(), LweCiphertextPlaintextFusingSubtractionError<Self::EngineError>> {
+        if !output.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextFusingSubtractionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.fuse_sub_lwe_ciphertext_plaintext_unchecked(output, input) };
         Ok(())
     }
@@ -103,6 +115,11 @@ impl LweCiphertextPlaintextFusingSubtractionEngine<LweCiphertext64, Plaintext64>
         output: &mut LweCiphertext64,
         input: &Plaintext64,
     ) -> Result<(), LweCiphertextPlaintextFusingSubtractionError<Self::EngineError>> {
+        if !output.0.ciphertext_modulus().is_native() {
+            return Err(LweCiphertextPlaintextFusingSubtractionError::from(
+                CoreError::UnsupportedCiphertextModulus,
+            ));
+        }
         unsafe { self.fuse_sub_lwe_ciphertext_plaintext_unchecked(output, input) };
         Ok(())
     }
@@ -0,0 +1,332 @@
+use concrete_commons::parameters::LweSize;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweCiphertextVectorCreationEngine, LweCiphertextVectorCreationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an owned vector
+/// of ciphertexts, from a vector of 32 bits integers.
+impl LweCiphertextVectorCreationEngine<Vec<u32>, LweCiphertextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u32; 4 * lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u32>,
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorCreationError<Self::EngineError>> {
+        LweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            lwe_size,
+        )?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        lwe_size: LweSize,
+    ) -> LweCiphertextVector32 {
+        LweCiphertextVector32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an owned vector
+/// of ciphertexts, from a vector of 64 bits integers.
+impl LweCiphertextVectorCreationEngine<Vec<u64>, LweCiphertextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u64; 4 * lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u64>,
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorCreationError<Self::EngineError>> {
+        LweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            lwe_size,
+        )?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        lwe_size: LweSize,
+    ) -> LweCiphertextVector64 {
+        LweCiphertextVector64(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an immutable
+/// view over a slice of 32 bits integers, without copying it.
+impl<'data> LweCiphertextVectorCreationEngine<&'data [u32], LweCiphertextVectorView32<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u32; 4 * lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVectorView32<'_> =
+    ///     engine.create_lwe_ciphertext_vector(container.as_slice(), lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'data [u32],
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorView32<'data>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        LweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            lwe_size,
+        )?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data [u32],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorView32<'data> {
+        LweCiphertextVectorView32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an immutable
+/// view over a slice of 64 bits integers, without copying it.
+impl<'data> LweCiphertextVectorCreationEngine<&'data [u64], LweCiphertextVectorView64<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u64; 4 * lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVectorView64<'_> =
+    ///     engine.create_lwe_ciphertext_vector(container.as_slice(), lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'data [u64],
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorView64<'data>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        LweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            lwe_size,
+        )?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data [u64],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorView64<'data> {
+        LweCiphertextVectorView64(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns a mutable view
+/// over a slice of 32 bits integers, without copying it.
+impl<'data> LweCiphertextVectorCreationEngine<&'data mut [u32], LweCiphertextVectorMutView32<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(16);
+    /// let mut container = vec![0_u32; 4 * lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVectorMutView32<'_> =
+    ///     engine.create_lwe_ciphertext_vector(container.as_mut_slice(), lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'data mut [u32],
+        lwe_size: LweSize,
+    ) -> Result<
+        LweCiphertextVectorMutView32<'data>,
+        LweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        LweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            lwe_size,
+        )?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data mut [u32],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorMutView32<'data> {
+        LweCiphertextVectorMutView32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns a mutable view
+/// over a slice of 64 bits integers, without copying it.
+impl<'data> LweCiphertextVectorCreationEngine<&'data mut [u64], LweCiphertextVectorMutView64<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(16);
+    /// let mut container = vec![0_u64; 4 * lwe_size.0];
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 4];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_size.to_lwe_dimension())?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector: LweCiphertextVectorMutView64<'_> =
+    ///     engine.create_lwe_ciphertext_vector(container.as_mut_slice(), lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// // The ciphertexts are encrypted directly in the caller-owned container.
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// assert!(container.iter().any(|element| *element != 0));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'data mut [u64],
+        lwe_size: LweSize,
+    ) -> Result<
+        LweCiphertextVectorMutView64<'data>,
+        LweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        LweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            lwe_size,
+        )?;
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data mut [u64],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorMutView64<'data> {
+        LweCiphertextVectorMutView64(ImplLweList::from_container(container, lwe_size))
+    }
+}
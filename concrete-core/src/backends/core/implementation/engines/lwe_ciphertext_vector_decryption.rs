@@ -2,8 +2,9 @@ use concrete_commons::parameters::PlaintextCount;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+    LweSecretKey32, LweSecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
 use crate::specification::engines::{
@@ -136,3 +137,72 @@ impl LweCiphertextVectorDecryptionEngine<LweSecretKey64, LweCiphertextVector64,
         PlaintextVector64(plaintext)
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_decryption_for_views {
+    ($(($Key: ty, $Input: ty, $PlaintextVector: ident, $Scalar: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorDecryptionEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl
+                LweCiphertextVectorDecryptionEngine<
+                    $Key,
+                    $Input,
+                    $PlaintextVector,
+                > for CoreEngine
+            {
+                fn decrypt_lwe_ciphertext_vector(
+                    &mut self,
+                    key: &$Key,
+                    input: &$Input,
+                ) -> Result<
+                    $PlaintextVector,
+                    LweCiphertextVectorDecryptionError<Self::EngineError>,
+                > {
+                    LweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
+                    Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+                }
+
+                unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    key: &$Key,
+                    input: &$Input,
+                ) -> $PlaintextVector {
+                    let mut plaintext = ImplPlaintextList::allocate(
+                        0 as $Scalar,
+                        PlaintextCount(input.lwe_ciphertext_count().0),
+                    );
+                    key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+                    $PlaintextVector(plaintext)
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_decryption_for_views!(
+    (
+        LweSecretKey32,
+        LweCiphertextVectorView32<'_>,
+        PlaintextVector32,
+        u32
+    ),
+    (
+        LweSecretKey32,
+        LweCiphertextVectorMutView32<'_>,
+        PlaintextVector32,
+        u32
+    ),
+    (
+        LweSecretKey64,
+        LweCiphertextVectorView64<'_>,
+        PlaintextVector64,
+        u64
+    ),
+    (
+        LweSecretKey64,
+        LweCiphertextVectorMutView64<'_>,
+        PlaintextVector64,
+        u64
+    )
+);
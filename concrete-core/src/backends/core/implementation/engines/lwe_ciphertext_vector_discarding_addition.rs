@@ -1,6 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
 };
 use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::specification::engines::{
@@ -152,3 +153,72 @@ impl LweCiphertextVectorDiscardingAdditionEngine<LweCiphertextVector64, LweCiphe
         }
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_discarding_addition_for_views {
+    ($(($Input: ty, $Output: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorDiscardingAdditionEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl LweCiphertextVectorDiscardingAdditionEngine<$Input, $Output>
+                for CoreEngine
+            {
+                fn discard_add_lwe_ciphertext_vector(
+                    &mut self,
+                    output: &mut $Output,
+                    input_1: &$Input,
+                    input_2: &$Input,
+                ) -> Result<(), LweCiphertextVectorDiscardingAdditionError<Self::EngineError>> {
+                    LweCiphertextVectorDiscardingAdditionError::perform_generic_checks(
+                        output, input_1, input_2,
+                    )?;
+                    unsafe {
+                        self.discard_add_lwe_ciphertext_vector_unchecked(output, input_1, input_2)
+                    };
+                    Ok(())
+                }
+
+                unsafe fn discard_add_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    output: &mut $Output,
+                    input_1: &$Input,
+                    input_2: &$Input,
+                ) {
+                    for (mut out, (in_1, in_2)) in output
+                        .0
+                        .ciphertext_iter_mut()
+                        .zip(input_1.0.ciphertext_iter().zip(input_2.0.ciphertext_iter()))
+                    {
+                        out.as_mut_tensor().fill_with_copy(in_1.as_tensor());
+                        out.update_with_add(&in_2);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_discarding_addition_for_views!(
+    (LweCiphertextVectorView32<'_>, LweCiphertextVector32),
+    (LweCiphertextVectorMutView32<'_>, LweCiphertextVector32),
+    (LweCiphertextVector32, LweCiphertextVectorMutView32<'_>),
+    (
+        LweCiphertextVectorView32<'_>,
+        LweCiphertextVectorMutView32<'_>
+    ),
+    (
+        LweCiphertextVectorMutView32<'_>,
+        LweCiphertextVectorMutView32<'_>
+    ),
+    (LweCiphertextVectorView64<'_>, LweCiphertextVector64),
+    (LweCiphertextVectorMutView64<'_>, LweCiphertextVector64),
+    (LweCiphertextVector64, LweCiphertextVectorMutView64<'_>),
+    (
+        LweCiphertextVectorView64<'_>,
+        LweCiphertextVectorMutView64<'_>
+    ),
+    (
+        LweCiphertextVectorMutView64<'_>,
+        LweCiphertextVectorMutView64<'_>
+    )
+);
@@ -1,13 +1,21 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
     CleartextVector32, CleartextVector64, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
-    LweCiphertextVector64, Plaintext32, Plaintext64,
+    LweCiphertextVector64, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView32, LweCiphertextVectorView64, Plaintext32, Plaintext64,
 };
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingAffineTransformationEngine,
     LweCiphertextVectorDiscardingAffineTransformationError,
 };
 
+impl From<CoreError> for LweCiphertextVectorDiscardingAffineTransformationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for [`CoreEngine`]
 /// that operates on 32 bits integers.
@@ -72,6 +80,13 @@ impl
         LweCiphertextVectorDiscardingAffineTransformationError::perform_generic_checks(
             output, inputs, weights,
         )?;
+        if !output.0.ciphertext_modulus().is_native() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::from(
+                    CoreError::UnsupportedCiphertextModulus,
+                ),
+            );
+        }
         unsafe {
             self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
                 output, inputs, weights, bias,
@@ -157,6 +172,13 @@ impl
         LweCiphertextVectorDiscardingAffineTransformationError::perform_generic_checks(
             output, inputs, weights,
         )?;
+        if !output.0.ciphertext_modulus().is_native() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::from(
+                    CoreError::UnsupportedCiphertextModulus,
+                ),
+            );
+        }
         unsafe {
             self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
                 output, inputs, weights, bias,
@@ -177,3 +199,81 @@ impl
             .fill_with_multisum_with_bias(&inputs.0, &weights.0, &bias.0);
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_discarding_affine_transformation_for_views {
+    ($(($Input: ty, $CleartextVector: ty, $Plaintext: ty, $Output: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl
+                LweCiphertextVectorDiscardingAffineTransformationEngine<
+                    $Input,
+                    $CleartextVector,
+                    $Plaintext,
+                    $Output,
+                > for CoreEngine
+            {
+                fn discard_affine_transform_lwe_ciphertext_vector(
+                    &mut self,
+                    output: &mut $Output,
+                    inputs: &$Input,
+                    weights: &$CleartextVector,
+                    bias: &$Plaintext,
+                ) -> Result<
+                    (),
+                    LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>,
+                > {
+                    LweCiphertextVectorDiscardingAffineTransformationError::perform_generic_checks(
+                        output, inputs, weights,
+                    )?;
+                    unsafe {
+                        self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                            output, inputs, weights, bias,
+                        )
+                    };
+                    Ok(())
+                }
+
+                unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    output: &mut $Output,
+                    inputs: &$Input,
+                    weights: &$CleartextVector,
+                    bias: &$Plaintext,
+                ) {
+                    output
+                        .0
+                        .fill_with_multisum_with_bias(&inputs.0, &weights.0, &bias.0);
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_discarding_affine_transformation_for_views!(
+    (
+        LweCiphertextVectorView32<'_>,
+        CleartextVector32,
+        Plaintext32,
+        LweCiphertext32
+    ),
+    (
+        LweCiphertextVectorMutView32<'_>,
+        CleartextVector32,
+        Plaintext32,
+        LweCiphertext32
+    ),
+    (
+        LweCiphertextVectorView64<'_>,
+        CleartextVector64,
+        Plaintext64,
+        LweCiphertext64
+    ),
+    (
+        LweCiphertextVectorMutView64<'_>,
+        CleartextVector64,
+        Plaintext64,
+        LweCiphertext64
+    )
+);
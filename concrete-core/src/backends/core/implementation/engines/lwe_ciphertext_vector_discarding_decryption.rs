@@ -1,7 +1,8 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+    LweSecretKey32, LweSecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingDecryptionEngine, LweCiphertextVectorDiscardingDecryptionError,
@@ -138,3 +139,68 @@ impl
         key.0.decrypt_lwe_list(&mut output.0, &input.0);
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_discarding_decryption_for_views {
+    ($(($Key: ty, $Input: ty, $PlaintextVector: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorDiscardingDecryptionEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl
+                LweCiphertextVectorDiscardingDecryptionEngine<
+                    $Key,
+                    $Input,
+                    $PlaintextVector,
+                > for CoreEngine
+            {
+                fn discard_decrypt_lwe_ciphertext_vector(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $PlaintextVector,
+                    input: &$Input,
+                ) -> Result<(), LweCiphertextVectorDiscardingDecryptionError<Self::EngineError>>
+                {
+                    LweCiphertextVectorDiscardingDecryptionError::perform_generic_checks(
+                        key, output, input,
+                    )?;
+                    unsafe {
+                        self.discard_decrypt_lwe_ciphertext_vector_unchecked(key, output, input)
+                    };
+                    Ok(())
+                }
+
+                unsafe fn discard_decrypt_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $PlaintextVector,
+                    input: &$Input,
+                ) {
+                    key.0.decrypt_lwe_list(&mut output.0, &input.0);
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_discarding_decryption_for_views!(
+    (
+        LweSecretKey32,
+        LweCiphertextVectorView32<'_>,
+        PlaintextVector32
+    ),
+    (
+        LweSecretKey32,
+        LweCiphertextVectorMutView32<'_>,
+        PlaintextVector32
+    ),
+    (
+        LweSecretKey64,
+        LweCiphertextVectorView64<'_>,
+        PlaintextVector64
+    ),
+    (
+        LweSecretKey64,
+        LweCiphertextVectorMutView64<'_>,
+        PlaintextVector64
+    )
+);
@@ -2,8 +2,9 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweSecretKey32, LweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
 };
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingEncryptionEngine, LweCiphertextVectorDiscardingEncryptionError,
@@ -164,3 +165,67 @@ impl
         );
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_discarding_encryption_for_views {
+    ($(($Key: ty, $PlaintextVector: ty, $Output: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for
+            /// [`CoreEngine`] that encrypts into mutable views of LWE ciphertext vectors.
+            impl
+                LweCiphertextVectorDiscardingEncryptionEngine<
+                    $Key,
+                    $PlaintextVector,
+                    $Output,
+                > for CoreEngine
+            {
+                fn discard_encrypt_lwe_ciphertext_vector(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $Output,
+                    input: &$PlaintextVector,
+                    noise: Variance,
+                ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>>
+                {
+                    LweCiphertextVectorDiscardingEncryptionError::perform_generic_checks(
+                        key, output, input,
+                    )?;
+                    unsafe {
+                        self.discard_encrypt_lwe_ciphertext_vector_unchecked(
+                            key, output, input, noise,
+                        )
+                    };
+                    Ok(())
+                }
+
+                unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    key: &$Key,
+                    output: &mut $Output,
+                    input: &$PlaintextVector,
+                    noise: Variance,
+                ) {
+                    key.0.encrypt_lwe_list(
+                        &mut output.0,
+                        &input.0,
+                        noise,
+                        &mut self.encryption_generator,
+                    );
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_discarding_encryption_for_views!(
+    (
+        LweSecretKey32,
+        PlaintextVector32,
+        LweCiphertextVectorMutView32<'_>
+    ),
+    (
+        LweSecretKey64,
+        PlaintextVector64,
+        LweCiphertextVectorMutView64<'_>
+    )
+);
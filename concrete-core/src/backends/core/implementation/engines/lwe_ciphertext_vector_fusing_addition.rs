@@ -1,6 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
 };
 use crate::specification::engines::{
     LweCiphertextVectorFusingAdditionEngine, LweCiphertextVectorFusingAdditionError,
@@ -133,3 +134,65 @@ impl LweCiphertextVectorFusingAdditionEngine<LweCiphertextVector64, LweCiphertex
         }
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_fusing_addition_for_views {
+    ($(($Input: ty, $Output: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorFusingAdditionEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl LweCiphertextVectorFusingAdditionEngine<$Input, $Output>
+                for CoreEngine
+            {
+                fn fuse_add_lwe_ciphertext_vector(
+                    &mut self,
+                    output: &mut $Output,
+                    input: &$Input,
+                ) -> Result<(), LweCiphertextVectorFusingAdditionError<Self::EngineError>> {
+                    LweCiphertextVectorFusingAdditionError::perform_generic_checks(output, input)?;
+                    unsafe { self.fuse_add_lwe_ciphertext_vector_unchecked(output, input) };
+                    Ok(())
+                }
+
+                unsafe fn fuse_add_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    output: &mut $Output,
+                    input: &$Input,
+                ) {
+                    for (mut out, inp) in output
+                        .0
+                        .ciphertext_iter_mut()
+                        .zip(input.0.ciphertext_iter())
+                    {
+                        out.update_with_add(&inp);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_fusing_addition_for_views!(
+    (LweCiphertextVectorView32<'_>, LweCiphertextVector32),
+    (LweCiphertextVectorMutView32<'_>, LweCiphertextVector32),
+    (LweCiphertextVector32, LweCiphertextVectorMutView32<'_>),
+    (
+        LweCiphertextVectorView32<'_>,
+        LweCiphertextVectorMutView32<'_>
+    ),
+    (
+        LweCiphertextVectorMutView32<'_>,
+        LweCiphertextVectorMutView32<'_>
+    ),
+    (LweCiphertextVectorView64<'_>, LweCiphertextVector64),
+    (LweCiphertextVectorMutView64<'_>, LweCiphertextVector64),
+    (LweCiphertextVector64, LweCiphertextVectorMutView64<'_>),
+    (
+        LweCiphertextVectorView64<'_>,
+        LweCiphertextVectorMutView64<'_>
+    ),
+    (
+        LweCiphertextVectorMutView64<'_>,
+        LweCiphertextVectorMutView64<'_>
+    )
+);
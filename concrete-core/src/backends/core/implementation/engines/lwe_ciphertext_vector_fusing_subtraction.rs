@@ -1,6 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
 };
 use crate::specification::engines::{
     LweCiphertextVectorFusingSubtractionEngine, LweCiphertextVectorFusingSubtractionError,
@@ -133,3 +134,67 @@ impl LweCiphertextVectorFusingSubtractionEngine<LweCiphertextVector64, LweCipher
         }
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_fusing_subtraction_for_views {
+    ($(($Input: ty, $Output: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorFusingSubtractionEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl LweCiphertextVectorFusingSubtractionEngine<$Input, $Output>
+                for CoreEngine
+            {
+                fn fuse_sub_lwe_ciphertext_vector(
+                    &mut self,
+                    output: &mut $Output,
+                    input: &$Input,
+                ) -> Result<(), LweCiphertextVectorFusingSubtractionError<Self::EngineError>> {
+                    LweCiphertextVectorFusingSubtractionError::perform_generic_checks(
+                        output, input,
+                    )?;
+                    unsafe { self.fuse_sub_lwe_ciphertext_vector_unchecked(output, input) };
+                    Ok(())
+                }
+
+                unsafe fn fuse_sub_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    output: &mut $Output,
+                    input: &$Input,
+                ) {
+                    for (mut out, inp) in output
+                        .0
+                        .ciphertext_iter_mut()
+                        .zip(input.0.ciphertext_iter())
+                    {
+                        out.update_with_sub(&inp);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_fusing_subtraction_for_views!(
+    (LweCiphertextVectorView32<'_>, LweCiphertextVector32),
+    (LweCiphertextVectorMutView32<'_>, LweCiphertextVector32),
+    (LweCiphertextVector32, LweCiphertextVectorMutView32<'_>),
+    (
+        LweCiphertextVectorView32<'_>,
+        LweCiphertextVectorMutView32<'_>
+    ),
+    (
+        LweCiphertextVectorMutView32<'_>,
+        LweCiphertextVectorMutView32<'_>
+    ),
+    (LweCiphertextVectorView64<'_>, LweCiphertextVector64),
+    (LweCiphertextVectorMutView64<'_>, LweCiphertextVector64),
+    (LweCiphertextVector64, LweCiphertextVectorMutView64<'_>),
+    (
+        LweCiphertextVectorView64<'_>,
+        LweCiphertextVectorMutView64<'_>
+    ),
+    (
+        LweCiphertextVectorMutView64<'_>,
+        LweCiphertextVectorMutView64<'_>
+    )
+);
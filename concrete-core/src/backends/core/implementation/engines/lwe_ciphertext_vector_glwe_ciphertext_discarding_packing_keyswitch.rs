@@ -1,7 +1,8 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::prelude::{
     GlweCiphertext32, GlweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
-    PackingKeyswitchKey32, PackingKeyswitchKey64,
+    LweCiphertextVectorMutView32, LweCiphertextVectorMutView64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64, PackingKeyswitchKey32, PackingKeyswitchKey64,
 };
 use crate::specification::engines::{
     LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
@@ -191,3 +192,74 @@ impl
         ksk.0.packing_keyswitch(&mut output.0, &input.0);
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_discarding_packing_keyswitch_for_views {
+    ($(($KeyswitchKey: ty, $Input: ty, $Output: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl
+                LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+                    $KeyswitchKey,
+                    $Input,
+                    $Output,
+                > for CoreEngine
+            {
+                fn discard_packing_keyswitch_lwe_ciphertext_vector(
+                    &mut self,
+                    output: &mut $Output,
+                    input: &$Input,
+                    ksk: &$KeyswitchKey,
+                ) -> Result<
+                    (),
+                    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError<
+                        Self::EngineError,
+                    >,
+                > {
+                    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError::perform_generic_checks(
+                        output, input, ksk,
+                    )?;
+                    unsafe {
+                        self.discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+                            output, input, ksk,
+                        )
+                    };
+                    Ok(())
+                }
+
+                unsafe fn discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    output: &mut $Output,
+                    input: &$Input,
+                    ksk: &$KeyswitchKey,
+                ) {
+                    ksk.0.packing_keyswitch(&mut output.0, &input.0);
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_discarding_packing_keyswitch_for_views!(
+    (
+        PackingKeyswitchKey32,
+        LweCiphertextVectorView32<'_>,
+        GlweCiphertext32
+    ),
+    (
+        PackingKeyswitchKey32,
+        LweCiphertextVectorMutView32<'_>,
+        GlweCiphertext32
+    ),
+    (
+        PackingKeyswitchKey64,
+        LweCiphertextVectorView64<'_>,
+        GlweCiphertext64
+    ),
+    (
+        PackingKeyswitchKey64,
+        LweCiphertextVectorMutView64<'_>,
+        GlweCiphertext64
+    )
+);
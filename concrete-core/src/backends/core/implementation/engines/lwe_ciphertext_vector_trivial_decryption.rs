@@ -3,8 +3,10 @@ use crate::backends::core::private::crypto::encoding::{
 };
 use crate::prelude::{
     CoreEngine, LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorEntity,
+    LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
     LweCiphertextVectorTrivialDecryptionEngine, LweCiphertextVectorTrivialDecryptionError,
-    PlaintextCount, PlaintextVector32, PlaintextVector64,
+    LweCiphertextVectorView32, LweCiphertextVectorView64, PlaintextCount, PlaintextVector32,
+    PlaintextVector64,
 };
 
 impl LweCiphertextVectorTrivialDecryptionEngine<LweCiphertextVector32, PlaintextVector32>
@@ -114,3 +116,47 @@ impl LweCiphertextVectorTrivialDecryptionEngine<LweCiphertextVector64, Plaintext
         PlaintextVector64(output)
     }
 }
+
+macro_rules! implement_lwe_ciphertext_vector_trivial_decryption_for_views {
+    ($(($Input: ty, $PlaintextVector: ident, $Scalar: ty)),* $(,)?) => {
+        $(
+            /// # Description:
+            /// Implementation of [`LweCiphertextVectorTrivialDecryptionEngine`] for
+            /// [`CoreEngine`] that operates on views of LWE ciphertext vectors.
+            impl LweCiphertextVectorTrivialDecryptionEngine<$Input, $PlaintextVector>
+                for CoreEngine
+            {
+                fn trivially_decrypt_lwe_ciphertext_vector(
+                    &mut self,
+                    input: &$Input,
+                ) -> Result<
+                    $PlaintextVector,
+                    LweCiphertextVectorTrivialDecryptionError<Self::EngineError>,
+                > {
+                    unsafe { Ok(self.trivially_decrypt_lwe_ciphertext_vector_unchecked(input)) }
+                }
+
+                unsafe fn trivially_decrypt_lwe_ciphertext_vector_unchecked(
+                    &mut self,
+                    input: &$Input,
+                ) -> $PlaintextVector {
+                    let count = PlaintextCount(input.lwe_ciphertext_count().0);
+                    let mut output = ImplPlaintextList::allocate(0 as $Scalar, count);
+                    for (plaintext, ciphertext) in
+                        output.plaintext_iter_mut().zip(input.0.ciphertext_iter())
+                    {
+                        *plaintext = Plaintext(ciphertext.get_body().0);
+                    }
+                    $PlaintextVector(output)
+                }
+            }
+        )*
+    };
+}
+
+implement_lwe_ciphertext_vector_trivial_decryption_for_views!(
+    (LweCiphertextVectorView32<'_>, PlaintextVector32, u32),
+    (LweCiphertextVectorMutView32<'_>, PlaintextVector32, u32),
+    (LweCiphertextVectorView64<'_>, PlaintextVector64, u64),
+    (LweCiphertextVectorMutView64<'_>, PlaintextVector64, u64)
+);
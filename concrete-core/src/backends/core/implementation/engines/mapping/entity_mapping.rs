@@ -0,0 +1,76 @@
+use std::fs::File;
+use std::path::Path;
+
+use super::format::map_entity;
+use super::{CoreMappingEngine, CoreMappingError};
+use crate::backends::core::implementation::entities::{
+    MappedFourierLweBootstrapKey32, MappedFourierLweBootstrapKey64, MappedLweKeyswitchKey32,
+    MappedLweKeyswitchKey64,
+};
+use crate::specification::engines::{EntityMappingEngine, EntityMappingError};
+
+macro_rules! implement_entity_mapping {
+    ($($Entity: ident),*) => {
+        $(
+            /// # Description:
+            /// Implementation of [`EntityMappingEngine`] for [`CoreMappingEngine`] mapping the
+            /// key from an open file, with the layout described in the
+            /// [module documentation](super).
+            ///
+            /// # Safety:
+            /// The file must not be modified or truncated as long as the key, or one of its
+            /// clones, is alive.
+            impl<'file> EntityMappingEngine<&'file File, $Entity> for CoreMappingEngine {
+                unsafe fn map_entity(
+                    &mut self,
+                    source: &'file File,
+                ) -> Result<$Entity, EntityMappingError<Self::EngineError>> {
+                    map_entity(source, true).map_err(EntityMappingError::Engine)
+                }
+
+                /// # Safety:
+                /// The header of the file is not validated, and its checksum is not verified.
+                /// The file must have been written from a key of the same type, otherwise this
+                /// method may panic or return an invalid key.
+                unsafe fn map_entity_unchecked(&mut self, source: &'file File) -> $Entity {
+                    map_entity(source, false).unwrap()
+                }
+            }
+
+            /// # Description:
+            /// Implementation of [`EntityMappingEngine`] for [`CoreMappingEngine`] mapping the
+            /// key from the file at the given path, with the layout described in the
+            /// [module documentation](super).
+            ///
+            /// # Safety:
+            /// The file must not be modified or truncated as long as the key, or one of its
+            /// clones, is alive.
+            impl<'path> EntityMappingEngine<&'path Path, $Entity> for CoreMappingEngine {
+                unsafe fn map_entity(
+                    &mut self,
+                    source: &'path Path,
+                ) -> Result<$Entity, EntityMappingError<Self::EngineError>> {
+                    let file = File::open(source)
+                        .map_err(|error| EntityMappingError::Engine(CoreMappingError::Io(error.kind())))?;
+                    self.map_entity(&file)
+                }
+
+                /// # Safety:
+                /// The header of the file is not validated, and its checksum is not verified.
+                /// The file must have been written from a key of the same type, otherwise this
+                /// method may panic or return an invalid key.
+                unsafe fn map_entity_unchecked(&mut self, source: &'path Path) -> $Entity {
+                    let file = File::open(source).unwrap();
+                    self.map_entity_unchecked(&file)
+                }
+            }
+        )*
+    };
+}
+
+implement_entity_mapping!(
+    MappedFourierLweBootstrapKey32,
+    MappedFourierLweBootstrapKey64,
+    MappedLweKeyswitchKey32,
+    MappedLweKeyswitchKey64
+);
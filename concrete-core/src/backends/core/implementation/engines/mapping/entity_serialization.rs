@@ -0,0 +1,35 @@
+use super::format::serialize_mappable_entity;
+use super::CoreMappingEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::specification::engines::{EntitySerializationEngine, EntitySerializationError};
+
+macro_rules! implement_entity_serialization {
+    ($($Entity: ident),*) => {
+        $(
+            /// # Description:
+            /// Implementation of [`EntitySerializationEngine`] for [`CoreMappingEngine`] writing
+            /// the key to the mappable layout described in the [module documentation](super).
+            impl EntitySerializationEngine<$Entity, Vec<u8>> for CoreMappingEngine {
+                fn serialize(
+                    &mut self,
+                    entity: &$Entity,
+                ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+                    Ok(unsafe { self.serialize_unchecked(entity) })
+                }
+
+                unsafe fn serialize_unchecked(&mut self, entity: &$Entity) -> Vec<u8> {
+                    serialize_mappable_entity(entity)
+                }
+            }
+        )*
+    };
+}
+
+implement_entity_serialization!(
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64
+);
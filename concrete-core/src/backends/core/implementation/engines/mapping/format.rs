@@ -0,0 +1,174 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+};
+use memmap2::Mmap;
+
+use super::CoreMappingError;
+use crate::backends::core::implementation::engines::serialization::format::{
+    crc32, read_identification, write_identification, PayloadElement, Reader, SerializableEntity,
+};
+use crate::backends::core::implementation::engines::CoreSerializationError;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweKeyswitchKey32, LweKeyswitchKey64,
+    MappedFourierLweBootstrapKey32, MappedFourierLweBootstrapKey64, MappedLweKeyswitchKey32,
+    MappedLweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey as ImplFourierBootstrapKey;
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{Mappable, MappedSlice};
+
+/// The bytes every mappable entity starts with.
+pub(crate) const MAGIC_NUMBER: [u8; 4] = *b"CNCM";
+
+/// The version of the layout written by the mapping engine.
+pub(crate) const FORMAT_VERSION: u16 = 1;
+
+/// The alignment in bytes of the payload of a mappable entity.
+pub(crate) const PAYLOAD_ALIGNMENT: usize = 64;
+
+/// An entity whose elements can be mapped from a file.
+pub(crate) trait MappableEntity: Sized {
+    /// The type of the elements of the payload.
+    type Element: PayloadElement + Mappable;
+
+    /// The owned entity written to the mappable layout.
+    type Owned: SerializableEntity<Element = Self::Element>;
+
+    /// Builds the entity from its mapped elements and dimensions.
+    fn from_parts(container: MappedSlice<Self::Element>, dimensions: &[usize]) -> Self;
+}
+
+/// Writes an entity to the mappable layout.
+pub(crate) fn serialize_mappable_entity<Entity: SerializableEntity>(entity: &Entity) -> Vec<u8> {
+    let dimensions = entity.dimensions();
+    let payload = entity.payload();
+    let mut bytes = Vec::with_capacity(
+        payload_offset(dimensions.len()) + payload.len() * Entity::Element::SIZE,
+    );
+    write_identification::<Entity>(&mut bytes, MAGIC_NUMBER, FORMAT_VERSION, &dimensions);
+    bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    let checksum = crc32(bytes.as_slice());
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    bytes.resize(payload_offset(dimensions.len()), 0);
+    for element in payload.iter() {
+        element.write_le(&mut bytes);
+    }
+    bytes
+}
+
+/// Maps an entity stored in a file with the mappable layout.
+///
+/// When `checked` is false, the header fields identifying the entity and the header checksum are
+/// not verified. The dimensions, and the bounds and alignment of the payload, are always
+/// verified, since the entity could not be built safely otherwise.
+///
+/// # Safety
+///
+/// The file must not be modified or truncated until the entity and all its clones are dropped.
+pub(crate) unsafe fn map_entity<Entity: MappableEntity>(
+    file: &File,
+    checked: bool,
+) -> Result<Entity, CoreMappingError> {
+    if cfg!(target_endian = "big") {
+        return Err(CoreMappingError::UnsupportedPlatform);
+    }
+    // Safety: the file is not modified while the entity is alive, as required from the caller.
+    let map = Mmap::map(file).map_err(|error| CoreMappingError::Io(error.kind()))?;
+
+    let mut reader = Reader::new(&map);
+    let dimensions =
+        read_identification::<Entity::Owned>(&mut reader, MAGIC_NUMBER, FORMAT_VERSION, checked)?;
+    let payload_length = reader.read_u64()?;
+    let checksum_position = reader.position();
+    let checksum = reader.take(4)?;
+    let offset = payload_offset(dimensions.len());
+    let expected_payload_length = Entity::Owned::payload_length(dimensions.as_slice())
+        .ok_or(CoreSerializationError::InvalidDimensions)?;
+    let expected_size = expected_payload_length
+        .checked_mul(Entity::Element::SIZE)
+        .and_then(|size| size.checked_add(offset))
+        .ok_or(CoreSerializationError::InvalidDimensions)?;
+    if dimensions.contains(&0) {
+        return Err(CoreSerializationError::InvalidDimensions.into());
+    }
+
+    if checked {
+        if crc32(&map[..checksum_position]).to_le_bytes() != checksum {
+            return Err(CoreSerializationError::ChecksumMismatch.into());
+        }
+        if payload_length != expected_payload_length as u64 {
+            return Err(CoreSerializationError::PayloadLengthMismatch {
+                expected: expected_payload_length as u64,
+                found: payload_length,
+            }
+            .into());
+        }
+        if map.len() != expected_size {
+            return Err(CoreSerializationError::SizeMismatch {
+                expected: expected_size,
+                found: map.len(),
+            }
+            .into());
+        }
+    } else if map.len() < expected_size {
+        return Err(CoreSerializationError::UnexpectedEndOfData.into());
+    }
+
+    // Safety: the bounds of the payload were checked above, and the payload offset is a multiple
+    // of the page-aligned start of the map.
+    let container = MappedSlice::from_map(Arc::new(map), offset, expected_payload_length);
+    Ok(Entity::from_parts(container, dimensions.as_slice()))
+}
+
+// Returns the offset in bytes of the payload of an entity with `dimension_count` dimensions.
+fn payload_offset(dimension_count: usize) -> usize {
+    let header_size = MAGIC_NUMBER.len() + 2 + 5 + 8 * dimension_count + 8 + 4;
+    (header_size + PAYLOAD_ALIGNMENT - 1) / PAYLOAD_ALIGNMENT * PAYLOAD_ALIGNMENT
+}
+
+macro_rules! implement_fourier_lwe_bootstrap_key {
+    ($Entity: ident, $Owned: ident) => {
+        impl MappableEntity for $Entity {
+            type Element = Complex64;
+            type Owned = $Owned;
+
+            fn from_parts(container: MappedSlice<Complex64>, dimensions: &[usize]) -> Self {
+                $Entity(ImplFourierBootstrapKey::from_container(
+                    container,
+                    GlweSize(dimensions[1] + 1),
+                    PolynomialSize(dimensions[2]),
+                    DecompositionLevelCount(dimensions[3]),
+                    DecompositionBaseLog(dimensions[4]),
+                ))
+            }
+        }
+    };
+}
+
+implement_fourier_lwe_bootstrap_key!(MappedFourierLweBootstrapKey32, FourierLweBootstrapKey32);
+implement_fourier_lwe_bootstrap_key!(MappedFourierLweBootstrapKey64, FourierLweBootstrapKey64);
+
+macro_rules! implement_lwe_keyswitch_key {
+    ($Entity: ident, $Owned: ident, $Scalar: ty) => {
+        impl MappableEntity for $Entity {
+            type Element = $Scalar;
+            type Owned = $Owned;
+
+            fn from_parts(container: MappedSlice<$Scalar>, dimensions: &[usize]) -> Self {
+                $Entity(ImplLweKeyswitchKey::from_container(
+                    container,
+                    DecompositionBaseLog(dimensions[3]),
+                    DecompositionLevelCount(dimensions[2]),
+                    LweDimension(dimensions[1]),
+                ))
+            }
+        }
+    };
+}
+
+implement_lwe_keyswitch_key!(MappedLweKeyswitchKey32, LweKeyswitchKey32, u32);
+implement_lwe_keyswitch_key!(MappedLweKeyswitchKey64, LweKeyswitchKey64, u64);
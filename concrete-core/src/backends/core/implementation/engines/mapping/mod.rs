@@ -0,0 +1,172 @@
+//! A module containing the engine mapping large keys of the core backend from files.
+//!
+//! # Format
+//!
+//! Keys are written to a binary layout in which the elements of the key can be used in place,
+//! once the file is mapped to memory. All the integers are stored in little-endian order:
+//!
+//! | Field                    | Type        | Content                                             |
+//! |--------------------------|-------------|-----------------------------------------------------|
+//! | Magic number             | `[u8; 4]`   | `b"CNCM"`                                           |
+//! | Format version           | `u16`       | `1`                                                 |
+//! | Entity tag               | `u8`        | The kind of entity stored in the payload            |
+//! | Precision                | `u8`        | The number of bits of the integers of the entity    |
+//! | Input key distribution   | `u8`        | `0` for binary, `1` for ternary, `2` for gaussian   |
+//! | Output key distribution  | `u8`        | `0` for binary, `1` for ternary, `2` for gaussian   |
+//! | Dimension count          | `u8`        | The number of dimensions following                  |
+//! | Dimensions               | `[u64; _]`  | The dimensions of the entity                        |
+//! | Payload length           | `u64`       | The number of elements of the payload               |
+//! | Header checksum          | `u32`       | The CRC-32 of all the preceding bytes               |
+//! | Padding                  | `[u8; _]`   | Zeros, up to the next multiple of 64 bytes          |
+//! | Payload                  | `[_; _]`    | The elements of the entity                          |
+//!
+//! The entity tags, key distributions and dimensions are the same as the ones of the
+//! [serialization layout](super::CoreSerializationEngine). Contrary to the latter, the payload is
+//! aligned, and is not covered by the checksum: verifying it would require reading the whole key
+//! from the disk every time it is mapped.
+//!
+//! # Note
+//!
+//! As the payload is used in place, keys can only be mapped on little-endian platforms.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+
+use crate::backends::core::implementation::engines::CoreSerializationError;
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur when mapping an entity with the [`CoreMappingEngine`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoreMappingError {
+    Io(ErrorKind),
+    UnsupportedPlatform,
+    Format(CoreSerializationError),
+}
+
+impl Display for CoreMappingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoreMappingError::Io(kind) => {
+                write!(f, "The file could not be mapped: {:?}.", kind)
+            }
+            CoreMappingError::UnsupportedPlatform => {
+                write!(f, "Entities can only be mapped on little-endian platforms.")
+            }
+            CoreMappingError::Format(error) => {
+                write!(f, "The mapped file is invalid: {}", error)
+            }
+        }
+    }
+}
+
+impl Error for CoreMappingError {}
+
+impl From<CoreSerializationError> for CoreMappingError {
+    fn from(error: CoreSerializationError) -> Self {
+        CoreMappingError::Format(error)
+    }
+}
+
+/// The engine mapping the large keys of the core backend from files.
+///
+/// Keys are written to the layout described in the [module documentation](self) with the
+/// [`EntitySerializationEngine`](crate::specification::engines::EntitySerializationEngine)
+/// implementations of this engine. Once stored in a file, they can be mapped read-only with the
+/// [`EntityMappingEngine`](crate::specification::engines::EntityMappingEngine) implementations,
+/// which return entities backed by the page cache of the operating system. Every process mapping
+/// the same file hence shares a single physical copy of the key.
+///
+/// # Example:
+/// ```
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+/// let input_lwe_dimension = LweDimension(6);
+/// let output_lwe_dimension = LweDimension(3);
+/// let decomposition_level_count = DecompositionLevelCount(2);
+/// let decomposition_base_log = DecompositionBaseLog(8);
+/// let noise = Variance(2_f64.powf(-25.));
+///
+/// let mut engine = CoreEngine::new()?;
+/// let mut mapping_engine = CoreMappingEngine::new()?;
+/// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+/// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+/// let keyswitch_key: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+///     &input_key,
+///     &output_key,
+///     decomposition_level_count,
+///     decomposition_base_log,
+///     noise,
+/// )?;
+///
+/// let path = std::env::temp_dir().join("concrete_core_mapping_example.ksk");
+/// std::fs::write(&path, mapping_engine.serialize(&keyswitch_key)?)?;
+/// // Safety: the file is not modified until the mapped key is dropped.
+/// let mapped_keyswitch_key: MappedLweKeyswitchKey64 =
+///     unsafe { mapping_engine.map_entity(path.as_path())? };
+/// assert_eq!(mapped_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+/// assert_eq!(mapped_keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+///
+/// // The mapped key can be used in place of the owned one.
+/// let plaintext = engine.create_plaintext(&(3_u64 << 50))?;
+/// let ciphertext = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+/// let mut switched = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+/// let mut mapped_switched = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+/// engine.discard_keyswitch_lwe_ciphertext(&mut switched, &ciphertext, &keyswitch_key)?;
+/// engine.discard_keyswitch_lwe_ciphertext(
+///     &mut mapped_switched,
+///     &ciphertext,
+///     &mapped_keyswitch_key,
+/// )?;
+/// assert_eq!(
+///     engine.decrypt_lwe_ciphertext(&output_key, &switched)?,
+///     engine.decrypt_lwe_ciphertext(&output_key, &mapped_switched)?
+/// );
+///
+/// // A file can not be mapped to a key of another kind.
+/// let result: Result<MappedFourierLweBootstrapKey64, _> =
+///     unsafe { mapping_engine.map_entity(path.as_path()) };
+/// assert_eq!(
+///     result.unwrap_err(),
+///     EntityMappingError::Engine(CoreMappingError::Format(
+///         CoreSerializationError::EntityMismatch
+///     ))
+/// );
+///
+/// engine.destroy(input_key)?;
+/// engine.destroy(output_key)?;
+/// engine.destroy(keyswitch_key)?;
+/// engine.destroy(mapped_keyswitch_key)?;
+/// engine.destroy(plaintext)?;
+/// engine.destroy(ciphertext)?;
+/// engine.destroy(switched)?;
+/// engine.destroy(mapped_switched)?;
+/// std::fs::remove_file(path)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct CoreMappingEngine;
+
+impl AbstractEngineSeal for CoreMappingEngine {}
+
+impl AbstractEngine for CoreMappingEngine {
+    type EngineError = CoreMappingError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(CoreMappingEngine)
+    }
+}
+
+mod entity_mapping;
+mod entity_serialization;
+mod format;
+#[cfg(test)]
+mod tests;
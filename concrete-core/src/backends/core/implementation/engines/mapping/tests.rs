@@ -0,0 +1,278 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+
+use super::format::{serialize_mappable_entity, MappableEntity, PAYLOAD_ALIGNMENT};
+use super::CoreMappingError;
+use crate::backends::core::implementation::engines::serialization::format::serialize_entity;
+use crate::backends::core::implementation::engines::CoreSerializationError;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey64, LweKeyswitchKey32, LweKeyswitchKey64, MappedFourierLweBootstrapKey64,
+    MappedLweKeyswitchKey32, MappedLweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey;
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::entities::{LweBootstrapKeyEntity, LweKeyswitchKeyEntity};
+
+// A file removed when dropped.
+struct TemporaryFile(PathBuf);
+
+impl TemporaryFile {
+    fn new(name: &str, bytes: &[u8]) -> TemporaryFile {
+        let path = std::env::temp_dir().join(format!(
+            "concrete_core_mapping_{}_{}",
+            std::process::id(),
+            name
+        ));
+        std::fs::write(&path, bytes).unwrap();
+        TemporaryFile(path)
+    }
+
+    fn open(&self) -> File {
+        File::open(&self.0).unwrap()
+    }
+}
+
+impl Drop for TemporaryFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn map<Entity: MappableEntity>(file: &File, checked: bool) -> Result<Entity, CoreMappingError> {
+    // Safety: the temporary files are not modified while the mapped entities are alive.
+    unsafe { super::format::map_entity(file, checked) }
+}
+
+fn keyswitch_key_64() -> LweKeyswitchKey64 {
+    let mut generator = RandomGenerator::new(None);
+    LweKeyswitchKey64(LweKeyswitchKey::from_container(
+        (0..7 * 4 * 3)
+            .map(|_| generator.random_uniform())
+            .collect::<Vec<u64>>(),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(3),
+        LweDimension(3),
+    ))
+}
+
+fn fourier_bootstrap_key_64() -> FourierLweBootstrapKey64 {
+    let mut generator = RandomGenerator::new(None);
+    let mut container = AlignedVec::new(2 * 3 * 2 * 2 * 8);
+    for element in container.iter_mut() {
+        *element = Complex64::new(
+            generator.random_uniform::<u32>() as f64,
+            -(generator.random_uniform::<u32>() as f64),
+        );
+    }
+    FourierLweBootstrapKey64(FourierBootstrapKey::from_container(
+        container,
+        GlweSize(2),
+        PolynomialSize(8),
+        DecompositionLevelCount(3),
+        DecompositionBaseLog(7),
+    ))
+}
+
+#[test]
+fn test_map_keyswitch_key() {
+    let key = keyswitch_key_64();
+    let file = TemporaryFile::new("ksk", serialize_mappable_entity(&key).as_slice());
+    for checked in [true, false] {
+        let mapped: MappedLweKeyswitchKey64 = map(&file.open(), checked).unwrap();
+        assert_eq!(mapped.input_lwe_dimension(), key.input_lwe_dimension());
+        assert_eq!(mapped.output_lwe_dimension(), key.output_lwe_dimension());
+        assert_eq!(
+            mapped.decomposition_level_count(),
+            key.decomposition_level_count()
+        );
+        assert_eq!(
+            mapped.decomposition_base_log(),
+            key.decomposition_base_log()
+        );
+        assert_eq!(
+            mapped.0.as_tensor().as_slice(),
+            key.0.as_tensor().as_slice()
+        );
+    }
+}
+
+#[test]
+fn test_map_fourier_bootstrap_key() {
+    let key = fourier_bootstrap_key_64();
+    let bytes = serialize_mappable_entity(&key);
+    let file = TemporaryFile::new("bsk", bytes.as_slice());
+    let mapped: MappedFourierLweBootstrapKey64 = map(&file.open(), true).unwrap();
+    assert_eq!(mapped.input_lwe_dimension(), key.input_lwe_dimension());
+    assert_eq!(mapped.glwe_dimension(), key.glwe_dimension());
+    assert_eq!(mapped.polynomial_size(), key.polynomial_size());
+    assert_eq!(
+        mapped.0.as_tensor().as_slice(),
+        key.0.as_tensor().as_slice()
+    );
+
+    // The payload starts on an aligned offset, and clones share the same mapping.
+    assert_eq!(
+        (bytes.len() - 2 * 3 * 2 * 2 * 8 * 16) % PAYLOAD_ALIGNMENT,
+        0
+    );
+    let clone = mapped.clone();
+    assert_eq!(
+        clone.0.as_tensor().as_slice().as_ptr(),
+        mapped.0.as_tensor().as_slice().as_ptr()
+    );
+}
+
+#[test]
+fn test_map_mismatches() {
+    let bytes = serialize_mappable_entity(&keyswitch_key_64());
+
+    let file = TemporaryFile::new("precision", bytes.as_slice());
+    assert_eq!(
+        map::<MappedLweKeyswitchKey32>(&file.open(), true),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::PrecisionMismatch {
+                expected: 32,
+                found: 64
+            }
+        ))
+    );
+    assert_eq!(
+        map::<MappedFourierLweBootstrapKey64>(&file.open(), true),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::EntityMismatch
+        ))
+    );
+
+    let mut corrupted = bytes.clone();
+    corrupted[11] ^= 1;
+    let file = TemporaryFile::new("checksum", corrupted.as_slice());
+    assert_eq!(
+        map::<MappedLweKeyswitchKey64>(&file.open(), true),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::ChecksumMismatch
+        ))
+    );
+
+    let file = TemporaryFile::new("truncated", &bytes[..bytes.len() - 8]);
+    assert_eq!(
+        map::<MappedLweKeyswitchKey64>(&file.open(), true),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::SizeMismatch {
+                expected: bytes.len(),
+                found: bytes.len() - 8
+            }
+        ))
+    );
+    // Even unchecked, a truncated payload is never mapped.
+    assert_eq!(
+        map::<MappedLweKeyswitchKey64>(&file.open(), false),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::UnexpectedEndOfData
+        ))
+    );
+
+    // Even unchecked, an entity with a zero dimension is never mapped.
+    let mut zero_dimension = bytes.clone();
+    zero_dimension[11..19].copy_from_slice(&0u64.to_le_bytes());
+    let file = TemporaryFile::new("zero-dimension", zero_dimension.as_slice());
+    assert_eq!(
+        map::<MappedLweKeyswitchKey64>(&file.open(), false),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::InvalidDimensions
+        ))
+    );
+
+    let file = TemporaryFile::new("empty", &[]);
+    assert_eq!(
+        map::<MappedLweKeyswitchKey64>(&file.open(), true),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::UnexpectedEndOfData
+        ))
+    );
+}
+
+#[test]
+fn test_serialized_layouts_are_distinct() {
+    // A key written with the serialization engine can not be mapped.
+    let key = LweKeyswitchKey32(LweKeyswitchKey::allocate(
+        0u32,
+        DecompositionLevelCount(2),
+        DecompositionBaseLog(3),
+        LweDimension(2),
+        LweDimension(2),
+    ));
+    let bytes = serialize_entity(&key);
+    let file = TemporaryFile::new("layout", bytes.as_slice());
+    assert_eq!(
+        map::<MappedLweKeyswitchKey32>(&file.open(), true),
+        Err(CoreMappingError::Format(
+            CoreSerializationError::InvalidMagicNumber
+        ))
+    );
+}
+
+#[test]
+fn test_bootstrap_with_mapped_key() {
+    use crate::prelude::*;
+    use concrete_commons::dispersion::Variance;
+    use concrete_commons::parameters::GlweDimension;
+
+    let (lwe_dimension, glwe_dimension, polynomial_size) =
+        (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    let noise = Variance(2_f64.powf(-50.));
+    let mut engine = CoreEngine::new().unwrap();
+    let mut mapping_engine = CoreMappingEngine::new().unwrap();
+    let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .create_glwe_secret_key(glwe_dimension, polynomial_size)
+        .unwrap();
+    let bsk: FourierLweBootstrapKey64 = engine
+        .create_lwe_bootstrap_key(
+            &lwe_key,
+            &glwe_key,
+            DecompositionBaseLog(5),
+            DecompositionLevelCount(3),
+            noise,
+        )
+        .unwrap();
+    let file = TemporaryFile::new(
+        "bootstrap",
+        mapping_engine.serialize(&bsk).unwrap().as_slice(),
+    );
+    let mapped_bsk: MappedFourierLweBootstrapKey64 =
+        unsafe { mapping_engine.map_entity(file.0.as_path()) }.unwrap();
+
+    let output_key = engine
+        .transmute_glwe_secret_key_to_lwe_secret_key(glwe_key)
+        .unwrap();
+    let lut = engine
+        .create_plaintext_vector(&vec![3_u64 << 60; polynomial_size.0])
+        .unwrap();
+    let acc = engine
+        .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &lut)
+        .unwrap();
+    let plaintext = engine.create_plaintext(&(1_u64 << 60)).unwrap();
+    let input = engine
+        .encrypt_lwe_ciphertext(&lwe_key, &plaintext, noise)
+        .unwrap();
+    let mut output = engine
+        .zero_encrypt_lwe_ciphertext(&output_key, noise)
+        .unwrap();
+    let mut mapped_output = output.clone();
+
+    engine
+        .discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)
+        .unwrap();
+    engine
+        .discard_bootstrap_lwe_ciphertext(&mut mapped_output, &input, &acc, &mapped_bsk)
+        .unwrap();
+    assert_eq!(output, mapped_output);
+}
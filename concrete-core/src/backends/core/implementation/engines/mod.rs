@@ -11,6 +11,8 @@ use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
     SecretRandomGenerator as ImplSecretRandomGenerator,
 };
+#[cfg(feature = "seeded_engine")]
+use crate::backends::core::private::math::random::RandomGenerator;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
 
@@ -18,12 +20,19 @@ use crate::specification::engines::AbstractEngine;
 ///
 /// # Note:
 ///
-/// There is currently no such case, as the core implementation is not expected to undergo some
-/// major issues unrelated to FHE.
+/// These errors are returned when the inputs are valid for the operation in general, but use
+/// parameters the core implementation does not support.
 #[derive(Debug)]
 pub enum CoreError {
+    /// _Specific_ error: the borrowing rules were broken during execution.
     Borrow,
+    /// _Specific_ error: the polynomial size is not one of the sizes supported by the Fourier
+    /// transform of the core backend.
     UnsupportedPolynomialSize,
+    /// _Specific_ error: the ciphertext modulus can not be used with the operation, either
+    /// because the operation only supports the native modulus, or because the custom modulus does
+    /// not fit in the integer type of the ciphertext.
+    UnsupportedCiphertextModulus,
 }
 
 impl Display for CoreError {
@@ -39,6 +48,14 @@ impl Display for CoreError {
                 1024, 2048, 4096, 8192, 16384."
                 )
             }
+            CoreError::UnsupportedCiphertextModulus => {
+                write!(
+                    f,
+                    "The Core Backend does not support this ciphertext modulus for this \
+                operation. Custom ciphertext moduli must be strictly smaller than the integer \
+                range, and are only supported for 32 and 64 bits integers."
+                )
+            }
         }
     }
 }
@@ -49,15 +66,19 @@ impl Error for CoreError {}
 pub(crate) struct FourierBufferKey(pub PolynomialSize, pub GlweSize);
 
 /// The main engine exposed by the core backend.
-// We attach Fourier buffers to the Core Engine:
-// each time a bootstrap key is created, a check
-// is made to see whether those buffers exist for
-// the required polynomial and GLWE sizes.
-// If the buffers already exist, they are simply
-// used when it comes to computing FFTs.
-// If they don't exist already, they are allocated.
-// In this way we avoid re-allocating those buffers
-// every time an FFT or iFFT is performed.
+///
+/// # Note:
+///
+/// The operations relying on FFTs (bootstraps, external products, conversions to the Fourier
+/// domain) need temporary buffers which depend on the polynomial size and GLWE dimension. When no
+/// workspace is given by the caller, the engine allocates those buffers on first use and caches
+/// them for later operations with the same parameters. This cache can be emptied with
+/// [`CoreEngine::clear_fourier_buffers`].
+///
+/// To control when those buffers are allocated and freed, an
+/// [`ExternalProductWorkspace32`](crate::backends::core::entities::ExternalProductWorkspace32) or
+/// [`ExternalProductWorkspace64`](crate::backends::core::entities::ExternalProductWorkspace64)
+/// can be created explicitly and passed to the `*WithWorkspaceEngine` operations instead.
 pub struct CoreEngine {
     secret_generator: ImplSecretRandomGenerator,
     encryption_generator: ImplEncryptionRandomGenerator,
@@ -66,6 +87,27 @@ pub struct CoreEngine {
 }
 
 impl CoreEngine {
+    /// Frees the Fourier buffers cached by the engine.
+    ///
+    /// The buffers will be allocated again by the next operation needing them. Operations
+    /// performed with an explicit workspace do not use those buffers.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// engine.clear_fourier_buffers();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clear_fourier_buffers(&mut self) {
+        self.fourier_buffers_u32.clear();
+        self.fourier_buffers_u64.clear();
+    }
+
     pub(crate) fn get_fourier_u32_buffer(
         &mut self,
         poly_size: PolynomialSize,
@@ -89,6 +131,47 @@ impl CoreEngine {
     }
 }
 
+#[cfg(feature = "seeded_engine")]
+impl CoreEngine {
+    /// Creates an engine whose random generators are all seeded from the given value.
+    ///
+    /// Two engines created with the same seed generate the same secret keys and ciphertexts when
+    /// they execute the same sequence of operations.
+    ///
+    /// # Warning:
+    ///
+    /// The keys and noises generated by a seeded engine are fully determined by the seed, and are
+    /// hence not secure. This constructor is only meant to make tests reproducible.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut first_engine = CoreEngine::new_seeded(42);
+    /// let mut second_engine = CoreEngine::new_seeded(42);
+    /// let first_key: LweSecretKey64 = first_engine.create_lwe_secret_key(LweDimension(8))?;
+    /// let second_key: LweSecretKey64 = second_engine.create_lwe_secret_key(LweDimension(8))?;
+    /// assert_eq!(first_key, second_key);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_seeded(seed: u128) -> CoreEngine {
+        let mut seeder = RandomGenerator::new(Some(seed));
+        CoreEngine {
+            secret_generator: ImplSecretRandomGenerator::new(Some(seeder.random_uniform())),
+            encryption_generator: ImplEncryptionRandomGenerator::new_seeded(
+                seeder.random_uniform(),
+                seeder.random_uniform(),
+            ),
+            fourier_buffers_u32: Default::default(),
+            fourier_buffers_u64: Default::default(),
+        }
+    }
+}
+
 impl AbstractEngineSeal for CoreEngine {}
 
 impl AbstractEngine for CoreEngine {
@@ -111,6 +194,7 @@ mod cleartext_vector_creation;
 mod cleartext_vector_discarding_retrieval;
 mod cleartext_vector_retrieval;
 mod destruction;
+mod external_product_workspace_creation;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
@@ -122,9 +206,11 @@ mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
 mod glwe_ciphertext_encryption;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product_with_workspace;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
+mod glwe_ciphertext_vector_creation;
 mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
@@ -139,14 +225,18 @@ mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_custom_modulus_encryption;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_bootstrap_with_workspace;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_opposite;
+#[cfg(feature = "multithread")]
+mod lwe_ciphertext_discarding_parallel_bootstrap;
 mod lwe_ciphertext_discarding_subtraction;
 mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
@@ -158,6 +248,7 @@ mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_vector_creation;
 mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
@@ -174,6 +265,8 @@ mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
 mod lwe_keyswitch_key_creation;
 mod lwe_secret_key_creation;
+#[cfg(feature = "mmap")]
+mod mapping;
 mod packing_keyswitch_key_creation;
 mod plaintext_creation;
 mod plaintext_discarding_retrieval;
@@ -181,3 +274,8 @@ mod plaintext_retrieval;
 mod plaintext_vector_creation;
 mod plaintext_vector_discarding_retrieval;
 mod plaintext_vector_retrieval;
+mod serialization;
+
+#[cfg(feature = "mmap")]
+pub use mapping::*;
+pub use serialization::*;
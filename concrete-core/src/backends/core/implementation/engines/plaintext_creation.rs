@@ -1,5 +1,5 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::implementation::entities::{Plaintext128, Plaintext32, Plaintext64};
 use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
 
@@ -66,3 +66,35 @@ impl PlaintextCreationEngine<u64, Plaintext64> for CoreEngine {
         Plaintext64(ImplPlaintext(*input))
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`CoreEngine`] that operates on
+/// 128 bits integers.
+impl PlaintextCreationEngine<u128, Plaintext128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = 3_u128 << 110;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext128 = engine.create_plaintext(&input)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext(
+        &mut self,
+        input: &u128,
+    ) -> Result<Plaintext128, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_unchecked(&mut self, input: &u128) -> Plaintext128 {
+        Plaintext128(ImplPlaintext(*input))
+    }
+}
@@ -1,5 +1,5 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::implementation::entities::{Plaintext128, Plaintext32, Plaintext64};
 use crate::specification::engines::{PlaintextRetrievalEngine, PlaintextRetrievalError};
 
 /// # Description:
@@ -71,3 +71,38 @@ impl PlaintextRetrievalEngine<Plaintext64, u64> for CoreEngine {
         plaintext.0 .0
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextRetrievalEngine`] for [`CoreEngine`] that operates on 128 bits
+/// integers.
+impl PlaintextRetrievalEngine<Plaintext128, u128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u128 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext128 = engine.create_plaintext(&input)?;
+    /// let output: u128 = engine.retrieve_plaintext(&plaintext)?;
+    ///
+    /// assert_eq!(output, 3_u128 << 20);
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_plaintext(
+        &mut self,
+        plaintext: &Plaintext128,
+    ) -> Result<u128, PlaintextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_unchecked(&mut self, plaintext: &Plaintext128) -> u128 {
+        plaintext.0 .0
+    }
+}
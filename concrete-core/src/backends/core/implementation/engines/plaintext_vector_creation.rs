@@ -1,5 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::implementation::entities::{
+    PlaintextVector128, PlaintextVector32, PlaintextVector64,
+};
 use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
 use crate::specification::engines::{PlaintextVectorCreationEngine, PlaintextVectorCreationError};
 
@@ -78,3 +80,41 @@ impl PlaintextVectorCreationEngine<u64, PlaintextVector64> for CoreEngine {
         PlaintextVector64(ImplPlaintextList::from_container(input.to_vec()))
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextVectorCreationEngine`] for [`CoreEngine`] that operates on
+/// 128 bits integers.
+impl PlaintextVectorCreationEngine<u128, PlaintextVector128> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = vec![3_u128 << 110; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext_vector: PlaintextVector128 = engine.create_plaintext_vector(&input)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(3));
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_vector(
+        &mut self,
+        input: &[u128],
+    ) -> Result<PlaintextVector128, PlaintextVectorCreationError<Self::EngineError>> {
+        if input.is_empty() {
+            return Err(PlaintextVectorCreationError::EmptyInput);
+        }
+        Ok(unsafe { self.create_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_vector_unchecked(&mut self, input: &[u128]) -> PlaintextVector128 {
+        PlaintextVector128(ImplPlaintextList::from_container(input.to_vec()))
+    }
+}
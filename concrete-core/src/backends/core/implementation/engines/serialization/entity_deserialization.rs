@@ -0,0 +1,75 @@
+use super::format::deserialize_entity;
+use super::CoreSerializationEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, FourierGlweCiphertext32,
+    FourierGlweCiphertext64, FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, GlweCiphertext128, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext128, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweSecretKey128, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+};
+use crate::specification::engines::{EntityDeserializationEngine, EntityDeserializationError};
+
+macro_rules! implement_entity_deserialization {
+    ($($Entity: ident),*) => {
+        $(
+            /// # Description:
+            /// Implementation of [`EntityDeserializationEngine`] for [`CoreSerializationEngine`]
+            /// reading the entity from the layout described in the
+            /// [module documentation](super).
+            impl<'data> EntityDeserializationEngine<&'data [u8], $Entity>
+                for CoreSerializationEngine
+            {
+                fn deserialize(
+                    &mut self,
+                    serialized: &'data [u8],
+                ) -> Result<$Entity, EntityDeserializationError<Self::EngineError>> {
+                    deserialize_entity(serialized, true).map_err(EntityDeserializationError::Engine)
+                }
+
+                /// # Safety:
+                /// The header of the serialized data is not validated, and its checksum is not
+                /// verified. The data must have been produced by the serialization of an entity of
+                /// the same type, otherwise this method may panic or return an invalid entity.
+                unsafe fn deserialize_unchecked(&mut self, serialized: &'data [u8]) -> $Entity {
+                    deserialize_entity(serialized, false).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+implement_entity_deserialization!(
+    LweSecretKey32,
+    LweSecretKey64,
+    LweSecretKey128,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    GlweSecretKey128,
+    LweCiphertext32,
+    LweCiphertext64,
+    LweCiphertext128,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertext128,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    FourierGlweCiphertext32,
+    FourierGlweCiphertext64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    FourierGgswCiphertext32,
+    FourierGgswCiphertext64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64
+);
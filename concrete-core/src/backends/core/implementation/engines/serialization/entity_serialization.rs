@@ -0,0 +1,69 @@
+use super::format::serialize_entity;
+use super::CoreSerializationEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, FourierGlweCiphertext32,
+    FourierGlweCiphertext64, FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, GlweCiphertext128, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext128, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweSecretKey128, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+};
+use crate::specification::engines::{EntitySerializationEngine, EntitySerializationError};
+
+macro_rules! implement_entity_serialization {
+    ($($Entity: ident),*) => {
+        $(
+            /// # Description:
+            /// Implementation of [`EntitySerializationEngine`] for [`CoreSerializationEngine`]
+            /// writing the entity to the layout described in the
+            /// [module documentation](super).
+            impl EntitySerializationEngine<$Entity, Vec<u8>> for CoreSerializationEngine {
+                fn serialize(
+                    &mut self,
+                    entity: &$Entity,
+                ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+                    Ok(unsafe { self.serialize_unchecked(entity) })
+                }
+
+                unsafe fn serialize_unchecked(&mut self, entity: &$Entity) -> Vec<u8> {
+                    serialize_entity(entity)
+                }
+            }
+        )*
+    };
+}
+
+implement_entity_serialization!(
+    LweSecretKey32,
+    LweSecretKey64,
+    LweSecretKey128,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    GlweSecretKey128,
+    LweCiphertext32,
+    LweCiphertext64,
+    LweCiphertext128,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertext128,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    FourierGlweCiphertext32,
+    FourierGlweCiphertext64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    FourierGgswCiphertext32,
+    FourierGgswCiphertext64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64
+);
@@ -0,0 +1,1074 @@
+use std::convert::TryFrom;
+use std::ops::DerefMut;
+
+use concrete_commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    LweDimension, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+
+use super::CoreSerializationError;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, FourierGlweCiphertext32,
+    FourierGlweCiphertext64, FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, GlweCiphertext128, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext128, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweSecretKey128, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey as ImplFourierBootstrapKey,
+    StandardBootstrapKey as ImplStandardBootstrapKey,
+};
+use crate::backends::core::private::crypto::ggsw::{
+    FourierGgswCiphertext as ImplFourierGgswCiphertext,
+    StandardGgswCiphertext as ImplStandardGgswCiphertext,
+};
+use crate::backends::core::private::crypto::glwe::{
+    FourierGlweCiphertext as ImplFourierGlweCiphertext, GlweCiphertext as ImplGlweCiphertext,
+    GlweList as ImplGlweList, PackingKeyswitchKey as ImplPackingKeyswitchKey,
+};
+use crate::backends::core::private::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
+    LweList as ImplLweList,
+};
+use crate::backends::core::private::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::entities::markers::{
+    BinaryKeyDistribution, GaussianKeyDistribution, KeyDistributionMarker, TernaryKeyDistribution,
+};
+use crate::specification::entities::{
+    GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextVectorEntity, GlweSecretKeyEntity,
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+    LweSecretKeyEntity, PackingKeyswitchKeyEntity,
+};
+
+/// The bytes every serialized entity starts with.
+pub(crate) const MAGIC_NUMBER: [u8; 4] = *b"CNCR";
+
+/// The version of the layout written by the serialization engine.
+pub(crate) const FORMAT_VERSION: u16 = 1;
+
+/// The size in bytes of the checksum closing every serialized entity.
+const CHECKSUM_SIZE: usize = 4;
+
+/// The kind of entity stored in a serialized payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum EntityTag {
+    LweSecretKey = 0,
+    GlweSecretKey = 1,
+    LweCiphertext = 2,
+    LweCiphertextVector = 3,
+    GlweCiphertext = 4,
+    GlweCiphertextVector = 5,
+    FourierGlweCiphertext = 6,
+    GgswCiphertext = 7,
+    FourierGgswCiphertext = 8,
+    LweBootstrapKey = 9,
+    FourierLweBootstrapKey = 10,
+    LweKeyswitchKey = 11,
+    PackingKeyswitchKey = 12,
+}
+
+/// A key distribution which can be recorded in a serialized entity.
+pub(crate) trait KeyDistributionTag: KeyDistributionMarker {
+    const TAG: u8;
+}
+
+impl KeyDistributionTag for BinaryKeyDistribution {
+    const TAG: u8 = 0;
+}
+
+impl KeyDistributionTag for TernaryKeyDistribution {
+    const TAG: u8 = 1;
+}
+
+impl KeyDistributionTag for GaussianKeyDistribution {
+    const TAG: u8 = 2;
+}
+
+/// An element which can be stored in the payload of a serialized entity.
+pub(crate) trait PayloadElement: Copy {
+    /// The size of the element in bytes.
+    const SIZE: usize;
+
+    /// Appends the little-endian representation of the element to `bytes`.
+    fn write_le(&self, bytes: &mut Vec<u8>);
+
+    /// Reads an element from the `Self::SIZE` first bytes of `bytes`.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! implement_payload_element {
+    ($Type: ty) => {
+        impl PayloadElement for $Type {
+            const SIZE: usize = std::mem::size_of::<$Type>();
+
+            fn write_le(&self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                let mut array = [0u8; std::mem::size_of::<$Type>()];
+                array.copy_from_slice(&bytes[..Self::SIZE]);
+                <$Type>::from_le_bytes(array)
+            }
+        }
+    };
+}
+
+implement_payload_element!(u32);
+implement_payload_element!(u64);
+implement_payload_element!(u128);
+
+impl PayloadElement for Complex64 {
+    const SIZE: usize = 16;
+
+    fn write_le(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.re.to_le_bytes());
+        bytes.extend_from_slice(&self.im.to_le_bytes());
+    }
+
+    fn read_le(bytes: &[u8]) -> Self {
+        let mut re = [0u8; 8];
+        let mut im = [0u8; 8];
+        re.copy_from_slice(&bytes[..8]);
+        im.copy_from_slice(&bytes[8..16]);
+        Complex64::new(f64::from_le_bytes(re), f64::from_le_bytes(im))
+    }
+}
+
+/// An entity which can be written to, and read from, the serialized layout.
+pub(crate) trait SerializableEntity: Sized {
+    /// The type of the elements of the payload.
+    type Element: PayloadElement;
+
+    /// The type of container the entity is built from.
+    type Container: DerefMut<Target = [Self::Element]>;
+
+    /// The kind of the entity.
+    const ENTITY: EntityTag;
+
+    /// The number of bits of the integers of the entity.
+    const PRECISION: usize;
+
+    /// The tag of the key distribution of the input key.
+    const INPUT_KEY_DISTRIBUTION: u8;
+
+    /// The tag of the key distribution of the output key.
+    const OUTPUT_KEY_DISTRIBUTION: u8;
+
+    /// The number of dimensions recorded for the entity.
+    const DIMENSION_COUNT: usize;
+
+    /// Whether the entity can use a custom ciphertext modulus.
+    const HAS_CIPHERTEXT_MODULUS: bool = false;
+
+    /// Returns the dimensions of the entity.
+    fn dimensions(&self) -> Vec<usize>;
+
+    /// Returns the ciphertext modulus of the entity.
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        CiphertextModulus::Native
+    }
+
+    /// Returns the elements of the entity.
+    fn payload(&self) -> &[Self::Element];
+
+    /// Returns the number of elements of an entity with the given dimensions, or `None` if this
+    /// number overflows.
+    fn payload_length(dimensions: &[usize]) -> Option<usize>;
+
+    /// Allocates a container of `length` elements.
+    fn allocate(length: usize) -> Self::Container;
+
+    /// Builds the entity from its elements and dimensions.
+    fn from_parts(
+        container: Self::Container,
+        dimensions: &[usize],
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self;
+}
+
+// Returns the product of the dimensions, or `None` if it overflows.
+fn checked_product<I: IntoIterator<Item = usize>>(factors: I) -> Option<usize> {
+    factors
+        .into_iter()
+        .try_fold(1usize, |acc, factor| acc.checked_mul(factor))
+}
+
+/// Writes an entity to the serialized layout.
+pub(crate) fn serialize_entity<Entity: SerializableEntity>(entity: &Entity) -> Vec<u8> {
+    let dimensions = entity.dimensions();
+    let payload = entity.payload();
+    let mut bytes = Vec::with_capacity(
+        header_size(dimensions.len()) + payload.len() * Entity::Element::SIZE + CHECKSUM_SIZE,
+    );
+    write_identification::<Entity>(&mut bytes, MAGIC_NUMBER, FORMAT_VERSION, &dimensions);
+    let modulus = entity
+        .ciphertext_modulus()
+        .get_custom_modulus()
+        .unwrap_or(0);
+    bytes.extend_from_slice(&modulus.to_le_bytes());
+    bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    for element in payload.iter() {
+        element.write_le(&mut bytes);
+    }
+    let checksum = crc32(bytes.as_slice());
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    bytes
+}
+
+/// Reads an entity from the serialized layout.
+///
+/// When `checked` is false, the header fields identifying the entity and the checksum are not
+/// verified.
+pub(crate) fn deserialize_entity<Entity: SerializableEntity>(
+    bytes: &[u8],
+    checked: bool,
+) -> Result<Entity, CoreSerializationError> {
+    let mut reader = Reader::new(bytes);
+    let dimensions =
+        read_identification::<Entity>(&mut reader, MAGIC_NUMBER, FORMAT_VERSION, checked)?;
+    let ciphertext_modulus = match reader.read_u128()? {
+        0 => CiphertextModulus::Native,
+        modulus => CiphertextModulus::Custom(modulus),
+    };
+    let payload_length = reader.read_u64()?;
+    let expected_payload_length = Entity::payload_length(dimensions.as_slice())
+        .ok_or(CoreSerializationError::InvalidDimensions)?;
+    let payload_size = expected_payload_length
+        .checked_mul(Entity::Element::SIZE)
+        .ok_or(CoreSerializationError::InvalidDimensions)?;
+
+    if checked {
+        if dimensions.contains(&0) {
+            return Err(CoreSerializationError::InvalidDimensions);
+        }
+        let supported_modulus = ciphertext_modulus.is_native()
+            || (Entity::HAS_CIPHERTEXT_MODULUS
+                && ciphertext_modulus.is_compatible_with_bits(Entity::PRECISION));
+        if !supported_modulus {
+            return Err(CoreSerializationError::UnsupportedCiphertextModulus);
+        }
+        if payload_length != expected_payload_length as u64 {
+            return Err(CoreSerializationError::PayloadLengthMismatch {
+                expected: expected_payload_length as u64,
+                found: payload_length,
+            });
+        }
+        let expected_size = reader
+            .position()
+            .checked_add(payload_size)
+            .and_then(|size| size.checked_add(CHECKSUM_SIZE))
+            .ok_or(CoreSerializationError::InvalidDimensions)?;
+        if bytes.len() != expected_size {
+            return Err(CoreSerializationError::SizeMismatch {
+                expected: expected_size,
+                found: bytes.len(),
+            });
+        }
+        let (content, checksum) = bytes.split_at(expected_size - CHECKSUM_SIZE);
+        if crc32(content).to_le_bytes() != checksum {
+            return Err(CoreSerializationError::ChecksumMismatch);
+        }
+    }
+
+    let payload = reader.take(payload_size)?;
+    let mut container = Entity::allocate(expected_payload_length);
+    for (element, chunk) in container
+        .iter_mut()
+        .zip(payload.chunks_exact(Entity::Element::SIZE))
+    {
+        *element = Entity::Element::read_le(chunk);
+    }
+    Ok(Entity::from_parts(
+        container,
+        dimensions.as_slice(),
+        ciphertext_modulus,
+    ))
+}
+
+/// Writes the fields identifying an entity at the start of a header.
+pub(crate) fn write_identification<Entity: SerializableEntity>(
+    bytes: &mut Vec<u8>,
+    magic: [u8; 4],
+    format_version: u16,
+    dimensions: &[usize],
+) {
+    bytes.extend_from_slice(&magic);
+    bytes.extend_from_slice(&format_version.to_le_bytes());
+    bytes.push(Entity::ENTITY as u8);
+    bytes.push(Entity::PRECISION as u8);
+    bytes.push(Entity::INPUT_KEY_DISTRIBUTION);
+    bytes.push(Entity::OUTPUT_KEY_DISTRIBUTION);
+    bytes.push(dimensions.len() as u8);
+    for dimension in dimensions.iter() {
+        bytes.extend_from_slice(&(*dimension as u64).to_le_bytes());
+    }
+}
+
+/// Reads the fields identifying the entity at the start of a header, and returns the dimensions
+/// of the entity.
+///
+/// When `checked` is false, the fields identifying the entity are not verified.
+pub(crate) fn read_identification<Entity: SerializableEntity>(
+    reader: &mut Reader,
+    magic: [u8; 4],
+    format_version: u16,
+    checked: bool,
+) -> Result<Vec<usize>, CoreSerializationError> {
+    let magic_number = reader.take(magic.len())?;
+    let version = reader.read_u16()?;
+    let entity = reader.read_u8()?;
+    let precision = reader.read_u8()? as usize;
+    let input_key_distribution = reader.read_u8()?;
+    let output_key_distribution = reader.read_u8()?;
+    let dimension_count = reader.read_u8()? as usize;
+    if checked {
+        if magic_number != magic {
+            return Err(CoreSerializationError::InvalidMagicNumber);
+        }
+        if version != format_version {
+            return Err(CoreSerializationError::UnsupportedVersion(version));
+        }
+        if entity != Entity::ENTITY as u8 {
+            return Err(CoreSerializationError::EntityMismatch);
+        }
+        if precision != Entity::PRECISION {
+            return Err(CoreSerializationError::PrecisionMismatch {
+                expected: Entity::PRECISION,
+                found: precision,
+            });
+        }
+        if input_key_distribution != Entity::INPUT_KEY_DISTRIBUTION
+            || output_key_distribution != Entity::OUTPUT_KEY_DISTRIBUTION
+        {
+            return Err(CoreSerializationError::KeyDistributionMismatch);
+        }
+        if dimension_count != Entity::DIMENSION_COUNT {
+            return Err(CoreSerializationError::InvalidDimensions);
+        }
+    }
+
+    let mut dimensions = Vec::with_capacity(dimension_count);
+    for _ in 0..dimension_count {
+        let dimension = usize::try_from(reader.read_u64()?)
+            .map_err(|_| CoreSerializationError::InvalidDimensions)?;
+        dimensions.push(dimension);
+    }
+    Ok(dimensions)
+}
+
+// Returns the size in bytes of a header with `dimension_count` dimensions.
+fn header_size(dimension_count: usize) -> usize {
+    MAGIC_NUMBER.len() + 2 + 5 + 8 * dimension_count + 16 + 8
+}
+
+/// A cursor over serialized bytes.
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Reader<'a> {
+        Reader { bytes, position: 0 }
+    }
+
+    /// Returns the number of bytes read so far.
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) fn take(&mut self, size: usize) -> Result<&'a [u8], CoreSerializationError> {
+        let end = self
+            .position
+            .checked_add(size)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(CoreSerializationError::UnexpectedEndOfData)?;
+        let output = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(output)
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, CoreSerializationError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn read_u16(&mut self) -> Result<u16, CoreSerializationError> {
+        let mut array = [0u8; 2];
+        array.copy_from_slice(self.take(2)?);
+        Ok(u16::from_le_bytes(array))
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64, CoreSerializationError> {
+        Ok(u64::read_le(self.take(u64::SIZE)?))
+    }
+
+    pub(crate) fn read_u128(&mut self) -> Result<u128, CoreSerializationError> {
+        Ok(u128::read_le(self.take(u128::SIZE)?))
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut value = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 == 1 {
+                0xEDB8_8320 ^ (value >> 1)
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[index] = value;
+        index += 1;
+    }
+    table
+}
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of `bytes`.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+macro_rules! implement_lwe_secret_key {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::LweSecretKey;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as LweSecretKeyEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 1;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![self.lwe_dimension().0]
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                Some(dimensions[0])
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(container: Vec<$Scalar>, _: &[usize], _: CiphertextModulus) -> Self {
+                $Entity(ImplLweSecretKey::binary_from_container(container))
+            }
+        }
+    };
+}
+
+implement_lwe_secret_key!(LweSecretKey32, u32);
+implement_lwe_secret_key!(LweSecretKey64, u64);
+implement_lwe_secret_key!(LweSecretKey128, u128);
+
+macro_rules! implement_glwe_secret_key {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::GlweSecretKey;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as GlweSecretKeyEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 2;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![self.glwe_dimension().0, self.polynomial_size().0]
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                checked_product(dimensions.iter().copied())
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(
+                container: Vec<$Scalar>,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplGlweSecretKey::binary_from_container(
+                    container,
+                    PolynomialSize(dimensions[1]),
+                ))
+            }
+        }
+    };
+}
+
+implement_glwe_secret_key!(GlweSecretKey32, u32);
+implement_glwe_secret_key!(GlweSecretKey64, u64);
+implement_glwe_secret_key!(GlweSecretKey128, u128);
+
+macro_rules! implement_lwe_ciphertext {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::LweCiphertext;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as LweCiphertextEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 1;
+            const HAS_CIPHERTEXT_MODULUS: bool = true;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![self.lwe_dimension().0]
+            }
+
+            fn ciphertext_modulus(&self) -> CiphertextModulus {
+                self.0.ciphertext_modulus()
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                dimensions[0].checked_add(1)
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(
+                container: Vec<$Scalar>,
+                _: &[usize],
+                ciphertext_modulus: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplLweCiphertext::from_container_with_modulus(
+                    container,
+                    ciphertext_modulus,
+                ))
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext!(LweCiphertext32, u32);
+implement_lwe_ciphertext!(LweCiphertext64, u64);
+implement_lwe_ciphertext!(LweCiphertext128, u128);
+
+macro_rules! implement_lwe_ciphertext_vector {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::LweCiphertextVector;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as LweCiphertextVectorEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 2;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![self.lwe_dimension().0, self.lwe_ciphertext_count().0]
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                checked_product(vec![dimensions[0].checked_add(1)?, dimensions[1]])
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(
+                container: Vec<$Scalar>,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplLweList::from_container(
+                    container,
+                    LweDimension(dimensions[0]).to_lwe_size(),
+                ))
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_vector!(LweCiphertextVector32, u32);
+implement_lwe_ciphertext_vector!(LweCiphertextVector64, u64);
+
+macro_rules! implement_glwe_ciphertext {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::GlweCiphertext;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as GlweCiphertextEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 2;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![self.glwe_dimension().0, self.polynomial_size().0]
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                checked_product(vec![dimensions[0].checked_add(1)?, dimensions[1]])
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(
+                container: Vec<$Scalar>,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplGlweCiphertext::from_container(
+                    container,
+                    PolynomialSize(dimensions[1]),
+                ))
+            }
+        }
+    };
+}
+
+implement_glwe_ciphertext!(GlweCiphertext32, u32);
+implement_glwe_ciphertext!(GlweCiphertext64, u64);
+implement_glwe_ciphertext!(GlweCiphertext128, u128);
+
+macro_rules! implement_glwe_ciphertext_vector {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::GlweCiphertextVector;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as GlweCiphertextVectorEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 3;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![
+                    self.glwe_dimension().0,
+                    self.polynomial_size().0,
+                    self.glwe_ciphertext_count().0,
+                ]
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                checked_product(vec![
+                    dimensions[0].checked_add(1)?,
+                    dimensions[1],
+                    dimensions[2],
+                ])
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(
+                container: Vec<$Scalar>,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplGlweList::from_container(
+                    container,
+                    GlweDimension(dimensions[0]),
+                    PolynomialSize(dimensions[1]),
+                ))
+            }
+        }
+    };
+}
+
+implement_glwe_ciphertext_vector!(GlweCiphertextVector32, u32);
+implement_glwe_ciphertext_vector!(GlweCiphertextVector64, u64);
+
+macro_rules! implement_fourier_glwe_ciphertext {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = Complex64;
+            type Container = AlignedVec<Complex64>;
+            const ENTITY: EntityTag = EntityTag::FourierGlweCiphertext;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as GlweCiphertextEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 2;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![self.glwe_dimension().0, self.polynomial_size().0]
+            }
+
+            fn payload(&self) -> &[Complex64] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                checked_product(vec![dimensions[0].checked_add(1)?, dimensions[1]])
+            }
+
+            fn allocate(length: usize) -> AlignedVec<Complex64> {
+                AlignedVec::new(length)
+            }
+
+            fn from_parts(
+                container: AlignedVec<Complex64>,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplFourierGlweCiphertext::from_container(
+                    container,
+                    GlweDimension(dimensions[0]).to_glwe_size(),
+                    PolynomialSize(dimensions[1]),
+                ))
+            }
+        }
+    };
+}
+
+implement_fourier_glwe_ciphertext!(FourierGlweCiphertext32, u32);
+implement_fourier_glwe_ciphertext!(FourierGlweCiphertext64, u64);
+
+// The number of elements of a GGSW ciphertext with dimensions [k, N, l, b].
+fn ggsw_payload_length(dimensions: &[usize]) -> Option<usize> {
+    let glwe_size = dimensions[0].checked_add(1)?;
+    checked_product(vec![glwe_size, glwe_size, dimensions[1], dimensions[2]])
+}
+
+macro_rules! implement_ggsw_ciphertext {
+    ($Entity: ident, $Scalar: ty, $Tag: ident, $Element: ty, $Container: ty, $Impl: ident,
+    $allocate: expr) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Element;
+            type Container = $Container;
+            const ENTITY: EntityTag = EntityTag::$Tag;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as GgswCiphertextEntity>::KeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 = Self::INPUT_KEY_DISTRIBUTION;
+            const DIMENSION_COUNT: usize = 4;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![
+                    self.glwe_dimension().0,
+                    self.polynomial_size().0,
+                    self.decomposition_level_count().0,
+                    self.decomposition_base_log().0,
+                ]
+            }
+
+            fn payload(&self) -> &[$Element] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                ggsw_payload_length(dimensions)
+            }
+
+            fn allocate(length: usize) -> $Container {
+                $allocate(length)
+            }
+
+            fn from_parts(
+                container: $Container,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity($Impl::from_container(
+                    container,
+                    GlweSize(dimensions[0] + 1),
+                    PolynomialSize(dimensions[1]),
+                    DecompositionBaseLog(dimensions[3]),
+                ))
+            }
+        }
+    };
+}
+
+implement_ggsw_ciphertext!(
+    GgswCiphertext32,
+    u32,
+    GgswCiphertext,
+    u32,
+    Vec<u32>,
+    ImplStandardGgswCiphertext,
+    |length| vec![0; length]
+);
+implement_ggsw_ciphertext!(
+    GgswCiphertext64,
+    u64,
+    GgswCiphertext,
+    u64,
+    Vec<u64>,
+    ImplStandardGgswCiphertext,
+    |length| vec![0; length]
+);
+implement_ggsw_ciphertext!(
+    FourierGgswCiphertext32,
+    u32,
+    FourierGgswCiphertext,
+    Complex64,
+    AlignedVec<Complex64>,
+    ImplFourierGgswCiphertext,
+    AlignedVec::new
+);
+implement_ggsw_ciphertext!(
+    FourierGgswCiphertext64,
+    u64,
+    FourierGgswCiphertext,
+    Complex64,
+    AlignedVec<Complex64>,
+    ImplFourierGgswCiphertext,
+    AlignedVec::new
+);
+
+macro_rules! implement_lwe_bootstrap_key {
+    ($Entity: ident, $Scalar: ty, $Tag: ident, $Element: ty, $Container: ty, $Impl: ident,
+    $allocate: expr) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Element;
+            type Container = $Container;
+            const ENTITY: EntityTag = EntityTag::$Tag;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as LweBootstrapKeyEntity>::InputKeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as LweBootstrapKeyEntity>::OutputKeyDistribution as KeyDistributionTag>::TAG;
+            const DIMENSION_COUNT: usize = 5;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![
+                    self.input_lwe_dimension().0,
+                    self.glwe_dimension().0,
+                    self.polynomial_size().0,
+                    self.decomposition_level_count().0,
+                    self.decomposition_base_log().0,
+                ]
+            }
+
+            fn payload(&self) -> &[$Element] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                dimensions[0].checked_mul(ggsw_payload_length(&dimensions[1..])?)
+            }
+
+            fn allocate(length: usize) -> $Container {
+                $allocate(length)
+            }
+
+            fn from_parts(
+                container: $Container,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity($Impl::from_container(
+                    container,
+                    GlweSize(dimensions[1] + 1),
+                    PolynomialSize(dimensions[2]),
+                    DecompositionLevelCount(dimensions[3]),
+                    DecompositionBaseLog(dimensions[4]),
+                ))
+            }
+        }
+    };
+}
+
+implement_lwe_bootstrap_key!(
+    LweBootstrapKey32,
+    u32,
+    LweBootstrapKey,
+    u32,
+    Vec<u32>,
+    ImplStandardBootstrapKey,
+    |length| vec![0; length]
+);
+implement_lwe_bootstrap_key!(
+    LweBootstrapKey64,
+    u64,
+    LweBootstrapKey,
+    u64,
+    Vec<u64>,
+    ImplStandardBootstrapKey,
+    |length| vec![0; length]
+);
+implement_lwe_bootstrap_key!(
+    FourierLweBootstrapKey32,
+    u32,
+    FourierLweBootstrapKey,
+    Complex64,
+    AlignedVec<Complex64>,
+    ImplFourierBootstrapKey,
+    AlignedVec::new
+);
+implement_lwe_bootstrap_key!(
+    FourierLweBootstrapKey64,
+    u64,
+    FourierLweBootstrapKey,
+    Complex64,
+    AlignedVec<Complex64>,
+    ImplFourierBootstrapKey,
+    AlignedVec::new
+);
+
+macro_rules! implement_lwe_keyswitch_key {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::LweKeyswitchKey;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as LweKeyswitchKeyEntity>::InputKeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as LweKeyswitchKeyEntity>::OutputKeyDistribution as KeyDistributionTag>::TAG;
+            const DIMENSION_COUNT: usize = 4;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![
+                    self.input_lwe_dimension().0,
+                    self.output_lwe_dimension().0,
+                    self.decomposition_level_count().0,
+                    self.decomposition_base_log().0,
+                ]
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                checked_product(vec![
+                    dimensions[0],
+                    dimensions[1].checked_add(1)?,
+                    dimensions[2],
+                ])
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(
+                container: Vec<$Scalar>,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplLweKeyswitchKey::from_container(
+                    container,
+                    DecompositionBaseLog(dimensions[3]),
+                    DecompositionLevelCount(dimensions[2]),
+                    LweDimension(dimensions[1]),
+                ))
+            }
+        }
+    };
+}
+
+implement_lwe_keyswitch_key!(LweKeyswitchKey32, u32);
+implement_lwe_keyswitch_key!(LweKeyswitchKey64, u64);
+
+macro_rules! implement_packing_keyswitch_key {
+    ($Entity: ident, $Scalar: ty) => {
+        impl SerializableEntity for $Entity {
+            type Element = $Scalar;
+            type Container = Vec<$Scalar>;
+            const ENTITY: EntityTag = EntityTag::PackingKeyswitchKey;
+            const PRECISION: usize = <$Scalar>::BITS as usize;
+            const INPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as PackingKeyswitchKeyEntity>::InputKeyDistribution as KeyDistributionTag>::TAG;
+            const OUTPUT_KEY_DISTRIBUTION: u8 =
+                <<Self as PackingKeyswitchKeyEntity>::OutputKeyDistribution as KeyDistributionTag>::TAG;
+            const DIMENSION_COUNT: usize = 5;
+
+            fn dimensions(&self) -> Vec<usize> {
+                vec![
+                    self.input_lwe_dimension().0,
+                    self.output_glwe_dimension().0,
+                    self.output_polynomial_size().0,
+                    self.decomposition_level_count().0,
+                    self.decomposition_base_log().0,
+                ]
+            }
+
+            fn payload(&self) -> &[$Scalar] {
+                self.0.as_tensor().as_slice()
+            }
+
+            fn payload_length(dimensions: &[usize]) -> Option<usize> {
+                checked_product(vec![
+                    dimensions[0],
+                    dimensions[1].checked_add(1)?,
+                    dimensions[2],
+                    dimensions[3],
+                ])
+            }
+
+            fn allocate(length: usize) -> Vec<$Scalar> {
+                vec![0; length]
+            }
+
+            fn from_parts(
+                container: Vec<$Scalar>,
+                dimensions: &[usize],
+                _: CiphertextModulus,
+            ) -> Self {
+                $Entity(ImplPackingKeyswitchKey::from_container(
+                    container,
+                    DecompositionBaseLog(dimensions[4]),
+                    DecompositionLevelCount(dimensions[3]),
+                    GlweDimension(dimensions[1]),
+                    PolynomialSize(dimensions[2]),
+                ))
+            }
+        }
+    };
+}
+
+implement_packing_keyswitch_key!(PackingKeyswitchKey32, u32);
+implement_packing_keyswitch_key!(PackingKeyswitchKey64, u64);
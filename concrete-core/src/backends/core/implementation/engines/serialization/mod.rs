@@ -0,0 +1,204 @@
+//! A module containing the engine serializing the entities of the core backend.
+//!
+//! # Format
+//!
+//! Every entity is serialized to a self-describing binary layout, in which all the integers are
+//! stored in little-endian order:
+//!
+//! | Field                    | Type        | Content                                             |
+//! |--------------------------|-------------|-----------------------------------------------------|
+//! | Magic number             | `[u8; 4]`   | `b"CNCR"`                                           |
+//! | Format version           | `u16`       | `1`                                                 |
+//! | Entity tag               | `u8`        | The kind of entity stored in the payload            |
+//! | Precision                | `u8`        | The number of bits of the integers of the entity    |
+//! | Input key distribution   | `u8`        | `0` for binary, `1` for ternary, `2` for gaussian   |
+//! | Output key distribution  | `u8`        | Same as the input one for single-key entities       |
+//! | Dimension count          | `u8`        | The number of dimensions following                  |
+//! | Dimensions               | `[u64; _]`  | The dimensions of the entity                        |
+//! | Ciphertext modulus       | `u128`      | `0` for the native modulus                          |
+//! | Payload length           | `u64`       | The number of elements of the payload               |
+//! | Payload                  | `[_; _]`    | The elements of the entity                          |
+//! | Checksum                 | `u32`       | The CRC-32 of all the preceding bytes               |
+//!
+//! The elements of the payload are stored with the size of the integers of the entity. Entities
+//! in the Fourier domain store their complex elements as a pair of `f64`, real part first. The
+//! dimensions stored for each entity are:
+//!
+//! + LWE secret keys, LWE ciphertexts: the LWE dimension.
+//! + LWE ciphertext vectors: the LWE dimension and the ciphertext count.
+//! + GLWE secret keys, GLWE ciphertexts: the GLWE dimension and the polynomial size.
+//! + GLWE ciphertext vectors: the GLWE dimension, the polynomial size and the ciphertext count.
+//! + GGSW ciphertexts: the GLWE dimension, the polynomial size, the level count and the base log.
+//! + LWE bootstrap keys: the input LWE dimension, the GLWE dimension, the polynomial size, the
+//!   level count and the base log.
+//! + LWE keyswitch keys: the input and output LWE dimensions, the level count and the base log.
+//! + Packing keyswitch keys: the input LWE dimension, the output GLWE dimension, the polynomial
+//!   size, the level count and the base log.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur when deserializing an entity with the [`CoreSerializationEngine`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoreSerializationError {
+    UnexpectedEndOfData,
+    InvalidMagicNumber,
+    UnsupportedVersion(u16),
+    EntityMismatch,
+    PrecisionMismatch { expected: usize, found: usize },
+    KeyDistributionMismatch,
+    InvalidDimensions,
+    UnsupportedCiphertextModulus,
+    PayloadLengthMismatch { expected: u64, found: u64 },
+    SizeMismatch { expected: usize, found: usize },
+    ChecksumMismatch,
+}
+
+impl Display for CoreSerializationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoreSerializationError::UnexpectedEndOfData => {
+                write!(f, "The serialized data ended before the end of the entity.")
+            }
+            CoreSerializationError::InvalidMagicNumber => {
+                write!(
+                    f,
+                    "The serialized data does not start with the magic number."
+                )
+            }
+            CoreSerializationError::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "The serialization format version {} is not supported.",
+                    version
+                )
+            }
+            CoreSerializationError::EntityMismatch => {
+                write!(
+                    f,
+                    "The serialized data does not contain the expected kind of entity."
+                )
+            }
+            CoreSerializationError::PrecisionMismatch { expected, found } => {
+                write!(
+                    f,
+                    "The serialized entity has a precision of {} bits, while {} bits were \
+                expected.",
+                    found, expected
+                )
+            }
+            CoreSerializationError::KeyDistributionMismatch => {
+                write!(
+                    f,
+                    "The key distribution of the serialized entity does not match the expected \
+                one."
+                )
+            }
+            CoreSerializationError::InvalidDimensions => {
+                write!(f, "The dimensions of the serialized entity are invalid.")
+            }
+            CoreSerializationError::UnsupportedCiphertextModulus => {
+                write!(
+                    f,
+                    "The ciphertext modulus of the serialized entity is not supported by the \
+                expected entity."
+                )
+            }
+            CoreSerializationError::PayloadLengthMismatch { expected, found } => {
+                write!(
+                    f,
+                    "The serialized entity contains {} elements, while its dimensions imply {} \
+                elements.",
+                    found, expected
+                )
+            }
+            CoreSerializationError::SizeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "The serialized data is {} bytes long, while its header implies {} bytes.",
+                    found, expected
+                )
+            }
+            CoreSerializationError::ChecksumMismatch => {
+                write!(
+                    f,
+                    "The checksum of the serialized data does not match its content."
+                )
+            }
+        }
+    }
+}
+
+impl Error for CoreSerializationError {}
+
+/// The engine serializing the entities of the core backend.
+///
+/// Entities are serialized to a versioned binary layout described in the
+/// [module documentation](self), which can be deserialized back with the same engine.
+/// Malformed, corrupted or mismatched inputs are reported through the variants of
+/// [`CoreSerializationError`].
+///
+/// # Example:
+/// ```
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+/// let lwe_dimension = LweDimension(6);
+///
+/// let mut engine = CoreEngine::new()?;
+/// let mut serialization_engine = CoreSerializationEngine::new()?;
+/// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+///
+/// let mut serialized = serialization_engine.serialize(&key)?;
+/// let deserialized: LweSecretKey32 = serialization_engine.deserialize(serialized.as_slice())?;
+/// assert_eq!(deserialized, key);
+///
+/// // The data can not be deserialized to an entity of another precision.
+/// let result: Result<LweSecretKey64, _> = serialization_engine.deserialize(serialized.as_slice());
+/// assert_eq!(
+///     result.unwrap_err(),
+///     EntityDeserializationError::Engine(CoreSerializationError::PrecisionMismatch {
+///         expected: 64,
+///         found: 32
+///     })
+/// );
+///
+/// // Corrupted data is detected.
+/// let last = serialized.len() - 5;
+/// serialized[last] ^= 1;
+/// let result: Result<LweSecretKey32, _> = serialization_engine.deserialize(serialized.as_slice());
+/// assert_eq!(
+///     result.unwrap_err(),
+///     EntityDeserializationError::Engine(CoreSerializationError::ChecksumMismatch)
+/// );
+///
+/// engine.destroy(key)?;
+/// engine.destroy(deserialized)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct CoreSerializationEngine;
+
+impl AbstractEngineSeal for CoreSerializationEngine {}
+
+impl AbstractEngine for CoreSerializationEngine {
+    type EngineError = CoreSerializationError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(CoreSerializationEngine)
+    }
+}
+
+mod entity_deserialization;
+mod entity_serialization;
+pub(super) mod format;
+#[cfg(test)]
+mod tests;
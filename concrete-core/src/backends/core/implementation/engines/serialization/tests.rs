@@ -0,0 +1,260 @@
+use std::fmt::Debug;
+
+use concrete_commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    LweDimension, LweSize, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+
+use super::format::{crc32, deserialize_entity, serialize_entity, SerializableEntity};
+use super::CoreSerializationError;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext64, FourierLweBootstrapKey32, GgswCiphertext32, GlweCiphertextVector64,
+    GlweSecretKey32, LweBootstrapKey64, LweCiphertext128, LweCiphertext64, LweCiphertextVector32,
+    LweKeyswitchKey32, LweSecretKey128, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey, StandardBootstrapKey,
+};
+use crate::backends::core::private::crypto::ggsw::{FourierGgswCiphertext, StandardGgswCiphertext};
+use crate::backends::core::private::crypto::glwe::{GlweList, PackingKeyswitchKey};
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::random::{RandomGenerable, RandomGenerator, Uniform};
+
+fn assert_roundtrip<Entity>(entity: Entity)
+where
+    Entity: SerializableEntity + PartialEq + Debug,
+{
+    let serialized = serialize_entity(&entity);
+    let deserialized: Entity = deserialize_entity(serialized.as_slice(), true).unwrap();
+    assert_eq!(deserialized, entity);
+    let deserialized: Entity = deserialize_entity(serialized.as_slice(), false).unwrap();
+    assert_eq!(deserialized, entity);
+}
+
+fn random_complex_container(length: usize) -> AlignedVec<Complex64> {
+    let mut generator = RandomGenerator::new(None);
+    let mut container = AlignedVec::new(length);
+    for element in container.iter_mut() {
+        *element = Complex64::new(
+            generator.random_uniform::<u32>() as f64,
+            -(generator.random_uniform::<u32>() as f64),
+        );
+    }
+    container
+}
+
+fn random_container<Scalar>(length: usize) -> Vec<Scalar>
+where
+    Scalar: RandomGenerable<Uniform>,
+{
+    let mut generator = RandomGenerator::new(None);
+    (0..length).map(|_| generator.random_uniform()).collect()
+}
+
+fn lwe_secret_key_32() -> LweSecretKey32 {
+    let mut generator = RandomGenerator::new(None);
+    LweSecretKey32(LweSecretKey::binary_from_container(
+        (0..10)
+            .map(|_| generator.random_uniform_binary())
+            .collect::<Vec<u32>>(),
+    ))
+}
+
+#[test]
+fn test_crc32_check_value() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b""), 0);
+}
+
+#[test]
+fn test_roundtrip_keys() {
+    assert_roundtrip(lwe_secret_key_32());
+    assert_roundtrip(LweSecretKey64(LweSecretKey::binary_from_container(vec![
+        0u64, 1, 1, 0, 1,
+    ])));
+    assert_roundtrip(LweSecretKey128(LweSecretKey::binary_from_container(vec![
+        1u128, 0, 1,
+    ])));
+    assert_roundtrip(GlweSecretKey32(GlweSecretKey::binary_from_container(
+        vec![1u32, 0, 0, 1, 1, 1, 0, 0],
+        PolynomialSize(4),
+    )));
+    assert_roundtrip(LweBootstrapKey64(StandardBootstrapKey::from_container(
+        random_container::<u64>(3 * 2 * 9 * 4),
+        GlweSize(3),
+        PolynomialSize(4),
+        DecompositionLevelCount(2),
+        DecompositionBaseLog(5),
+    )));
+    assert_roundtrip(FourierLweBootstrapKey32(
+        FourierBootstrapKey::from_container(
+            random_complex_container(3 * 2 * 4 * 8),
+            GlweSize(2),
+            PolynomialSize(8),
+            DecompositionLevelCount(2),
+            DecompositionBaseLog(3),
+        ),
+    ));
+    assert_roundtrip(LweKeyswitchKey32(LweKeyswitchKey::from_container(
+        random_container::<u32>(7 * 3 * 5),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(3),
+        LweDimension(4),
+    )));
+    assert_roundtrip(PackingKeyswitchKey64(PackingKeyswitchKey::from_container(
+        random_container::<u64>(5 * 2 * 3 * 4),
+        DecompositionBaseLog(6),
+        DecompositionLevelCount(2),
+        GlweDimension(2),
+        PolynomialSize(4),
+    )));
+}
+
+#[test]
+fn test_roundtrip_ciphertexts() {
+    assert_roundtrip(LweCiphertext64(LweCiphertext::from_container(
+        random_container::<u64>(11),
+    )));
+    assert_roundtrip(LweCiphertext64(LweCiphertext::from_container_with_modulus(
+        vec![3u64, 17, 25, 0],
+        CiphertextModulus::Custom(29),
+    )));
+    assert_roundtrip(LweCiphertext128(LweCiphertext::from_container(
+        random_container::<u128>(5),
+    )));
+    assert_roundtrip(LweCiphertextVector32(LweList::from_container(
+        random_container::<u32>(6 * 4),
+        LweSize(6),
+    )));
+    assert_roundtrip(GlweCiphertextVector64(GlweList::from_container(
+        random_container::<u64>(3 * 8 * 2),
+        GlweDimension(2),
+        PolynomialSize(8),
+    )));
+    assert_roundtrip(GgswCiphertext32(StandardGgswCiphertext::from_container(
+        random_container::<u32>(3 * 9 * 4),
+        GlweSize(3),
+        PolynomialSize(4),
+        DecompositionBaseLog(7),
+    )));
+    assert_roundtrip(FourierGgswCiphertext64(
+        FourierGgswCiphertext::from_container(
+            random_complex_container(2 * 4 * 8),
+            GlweSize(2),
+            PolynomialSize(8),
+            DecompositionBaseLog(10),
+        ),
+    ));
+}
+
+#[test]
+fn test_header_mismatches() {
+    let serialized = serialize_entity(&lwe_secret_key_32());
+
+    let mut corrupted = serialized.clone();
+    corrupted[0] = b'X';
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(corrupted.as_slice(), true),
+        Err(CoreSerializationError::InvalidMagicNumber)
+    );
+
+    let mut corrupted = serialized.clone();
+    corrupted[4] = 2;
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(corrupted.as_slice(), true),
+        Err(CoreSerializationError::UnsupportedVersion(2))
+    );
+
+    assert_eq!(
+        deserialize_entity::<GlweSecretKey32>(serialized.as_slice(), true),
+        Err(CoreSerializationError::EntityMismatch)
+    );
+    assert_eq!(
+        deserialize_entity::<LweSecretKey64>(serialized.as_slice(), true),
+        Err(CoreSerializationError::PrecisionMismatch {
+            expected: 64,
+            found: 32
+        })
+    );
+
+    let mut corrupted = serialized.clone();
+    corrupted[8] = 1;
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(corrupted.as_slice(), true),
+        Err(CoreSerializationError::KeyDistributionMismatch)
+    );
+
+    let mut corrupted = serialized;
+    corrupted[11..19].copy_from_slice(&0u64.to_le_bytes());
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(corrupted.as_slice(), true),
+        Err(CoreSerializationError::InvalidDimensions)
+    );
+}
+
+#[test]
+fn test_size_and_checksum_mismatches() {
+    let serialized = serialize_entity(&lwe_secret_key_32());
+
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(&serialized[..6], true),
+        Err(CoreSerializationError::UnexpectedEndOfData)
+    );
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(&serialized[..serialized.len() - 1], true),
+        Err(CoreSerializationError::SizeMismatch {
+            expected: serialized.len(),
+            found: serialized.len() - 1
+        })
+    );
+
+    let mut extended = serialized.clone();
+    extended.push(0);
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(extended.as_slice(), true),
+        Err(CoreSerializationError::SizeMismatch {
+            expected: serialized.len(),
+            found: serialized.len() + 1
+        })
+    );
+
+    let mut corrupted = serialized.clone();
+    corrupted[35..43].copy_from_slice(&11u64.to_le_bytes());
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(corrupted.as_slice(), true),
+        Err(CoreSerializationError::PayloadLengthMismatch {
+            expected: 10,
+            found: 11
+        })
+    );
+
+    let mut corrupted = serialized;
+    corrupted[45] ^= 0x10;
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(corrupted.as_slice(), true),
+        Err(CoreSerializationError::ChecksumMismatch)
+    );
+}
+
+#[test]
+fn test_ciphertext_modulus_mismatches() {
+    // Only LWE ciphertexts can carry a custom modulus.
+    let mut serialized = serialize_entity(&lwe_secret_key_32());
+    serialized[19..35].copy_from_slice(&7u128.to_le_bytes());
+    assert_eq!(
+        deserialize_entity::<LweSecretKey32>(serialized.as_slice(), true),
+        Err(CoreSerializationError::UnsupportedCiphertextModulus)
+    );
+
+    // The modulus must fit in the integers of the ciphertext.
+    let ciphertext = LweCiphertext64(LweCiphertext::from_container(vec![0u64; 4]));
+    let mut serialized = serialize_entity(&ciphertext);
+    serialized[19..35].copy_from_slice(&(1u128 << 64).to_le_bytes());
+    assert_eq!(
+        deserialize_entity::<LweCiphertext64>(serialized.as_slice(), true),
+        Err(CoreSerializationError::UnsupportedCiphertextModulus)
+    );
+}
@@ -17,3 +17,11 @@ impl AbstractEntity for Cleartext64 {
     type Kind = CleartextKind;
 }
 impl CleartextEntity for Cleartext64 {}
+
+/// A structure representing a cleartext with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cleartext128(pub(crate) ImplCleartext<u128>);
+impl AbstractEntity for Cleartext128 {
+    type Kind = CleartextKind;
+}
+impl CleartextEntity for Cleartext128 {}
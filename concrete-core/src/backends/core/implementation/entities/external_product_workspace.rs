@@ -0,0 +1,52 @@
+use crate::backends::core::private::crypto::bootstrap::FourierBuffers as ImplFourierBuffers;
+use crate::specification::entities::markers::ExternalProductWorkspaceKind;
+use crate::specification::entities::{AbstractEntity, ExternalProductWorkspaceEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing an external product workspace for 32 bits integers.
+#[derive(Debug, Clone)]
+pub struct ExternalProductWorkspace32(pub(crate) ImplFourierBuffers<u32>);
+impl AbstractEntity for ExternalProductWorkspace32 {
+    type Kind = ExternalProductWorkspaceKind;
+}
+impl ExternalProductWorkspaceEntity for ExternalProductWorkspace32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.lut_buffer.mask_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.lut_buffer.polynomial_size()
+    }
+}
+
+// The content of a workspace is meaningless outside of an operation, so two workspaces are
+// considered equal as soon as they can be used for the same parameters.
+impl PartialEq for ExternalProductWorkspace32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.glwe_dimension() == other.glwe_dimension()
+            && self.polynomial_size() == other.polynomial_size()
+    }
+}
+
+/// A structure representing an external product workspace for 64 bits integers.
+#[derive(Debug, Clone)]
+pub struct ExternalProductWorkspace64(pub(crate) ImplFourierBuffers<u64>);
+impl AbstractEntity for ExternalProductWorkspace64 {
+    type Kind = ExternalProductWorkspaceKind;
+}
+impl ExternalProductWorkspaceEntity for ExternalProductWorkspace64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.lut_buffer.mask_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.lut_buffer.polynomial_size()
+    }
+}
+
+impl PartialEq for ExternalProductWorkspace64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.glwe_dimension() == other.glwe_dimension()
+            && self.polynomial_size() == other.polynomial_size()
+    }
+}
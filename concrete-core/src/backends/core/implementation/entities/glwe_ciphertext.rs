@@ -54,6 +54,27 @@ impl GlweCiphertextEntity for GlweCiphertext64 {
     }
 }
 
+/// A structure representing a GLWE ciphertext with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweCiphertext128(pub(crate) ImplGlweCiphertext<Vec<u128>>);
+
+impl AbstractEntity for GlweCiphertext128 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for GlweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
 /// A structure representing a Fourier GLWE ciphertext with 32 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
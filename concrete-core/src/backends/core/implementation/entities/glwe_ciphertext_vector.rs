@@ -50,3 +50,95 @@ impl GlweCiphertextVectorEntity for GlweCiphertextVector64 {
         GlweCiphertextCount(self.0.ciphertext_count().0)
     }
 }
+
+/// A structure representing a vector of GLWE ciphertexts with 32 bits of precision, viewing
+/// an immutable slice owned by the caller.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweCiphertextVectorView32<'data>(pub(crate) ImplGlweList<&'data [u32]>);
+impl AbstractEntity for GlweCiphertextVectorView32<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorView32<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts with 64 bits of precision, viewing
+/// an immutable slice owned by the caller.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweCiphertextVectorView64<'data>(pub(crate) ImplGlweList<&'data [u64]>);
+impl AbstractEntity for GlweCiphertextVectorView64<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorView64<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts with 32 bits of precision, viewing
+/// a mutable slice owned by the caller.
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextVectorMutView32<'data>(pub(crate) ImplGlweList<&'data mut [u32]>);
+impl AbstractEntity for GlweCiphertextVectorMutView32<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorMutView32<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts with 64 bits of precision, viewing
+/// a mutable slice owned by the caller.
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextVectorMutView64<'data>(pub(crate) ImplGlweList<&'data mut [u64]>);
+impl AbstractEntity for GlweCiphertextVectorMutView64<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorMutView64<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
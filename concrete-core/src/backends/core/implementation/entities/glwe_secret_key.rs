@@ -43,3 +43,22 @@ impl GlweSecretKeyEntity for GlweSecretKey64 {
         self.0.polynomial_size()
     }
 }
+
+/// A structure representing a GLWE secret key with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweSecretKey128(pub(crate) ImpGlweSecretKey<BinaryKeyKind, Vec<u128>>);
+impl AbstractEntity for GlweSecretKey128 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
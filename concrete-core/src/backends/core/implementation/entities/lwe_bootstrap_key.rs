@@ -3,6 +3,8 @@ use crate::backends::core::private::crypto::bootstrap::{
     StandardBootstrapKey as ImplStandardBootstrapKey,
 };
 use crate::backends::core::private::math::fft::Complex64;
+#[cfg(feature = "mmap")]
+use crate::backends::core::private::math::tensor::MappedSlice;
 use crate::specification::entities::markers::{BinaryKeyDistribution, LweBootstrapKeyKind};
 use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
 use concrete_commons::parameters::{
@@ -137,3 +139,77 @@ impl LweBootstrapKeyEntity for FourierLweBootstrapKey64 {
         self.0.level_count()
     }
 }
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision, in the fourier domain,
+/// whose elements are mapped read-only from a file.
+#[cfg(feature = "mmap")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappedFourierLweBootstrapKey32(
+    pub(crate) ImplFourierBootstrapKey<MappedSlice<Complex64>, u32>,
+);
+#[cfg(feature = "mmap")]
+impl AbstractEntity for MappedFourierLweBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+#[cfg(feature = "mmap")]
+impl LweBootstrapKeyEntity for MappedFourierLweBootstrapKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, in the fourier domain,
+/// whose elements are mapped read-only from a file.
+#[cfg(feature = "mmap")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappedFourierLweBootstrapKey64(
+    pub(crate) ImplFourierBootstrapKey<MappedSlice<Complex64>, u64>,
+);
+#[cfg(feature = "mmap")]
+impl AbstractEntity for MappedFourierLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+#[cfg(feature = "mmap")]
+impl LweBootstrapKeyEntity for MappedFourierLweBootstrapKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
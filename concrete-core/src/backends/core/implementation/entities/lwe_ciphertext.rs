@@ -1,7 +1,7 @@
 use super::super::super::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextKind};
 use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
-use concrete_commons::parameters::LweDimension;
+use concrete_commons::parameters::{CiphertextModulus, LweDimension};
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +18,10 @@ impl LweCiphertextEntity for LweCiphertext32 {
     fn lwe_dimension(&self) -> LweDimension {
         self.0.lwe_size().to_lwe_dimension()
     }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.0.ciphertext_modulus()
+    }
 }
 
 /// A structure representing an LWE ciphertext with 64 bits of precision.
@@ -33,4 +37,27 @@ impl LweCiphertextEntity for LweCiphertext64 {
     fn lwe_dimension(&self) -> LweDimension {
         self.0.lwe_size().to_lwe_dimension()
     }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.0.ciphertext_modulus()
+    }
+}
+
+/// A structure representing an LWE ciphertext with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertext128(pub(crate) ImplLweCiphertext<Vec<u128>>);
+impl AbstractEntity for LweCiphertext128 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.0.ciphertext_modulus()
+    }
 }
@@ -49,3 +49,87 @@ impl LweCiphertextVectorEntity for LweCiphertextVector64 {
         LweCiphertextCount(self.0.count().0)
     }
 }
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, viewing
+/// an immutable slice owned by the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextVectorView32<'data>(pub(crate) ImplLweList<&'data [u32]>);
+
+impl AbstractEntity for LweCiphertextVectorView32<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for LweCiphertextVectorView32<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, viewing
+/// an immutable slice owned by the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextVectorView64<'data>(pub(crate) ImplLweList<&'data [u64]>);
+
+impl AbstractEntity for LweCiphertextVectorView64<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for LweCiphertextVectorView64<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, viewing
+/// a mutable slice owned by the caller.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweCiphertextVectorMutView32<'data>(pub(crate) ImplLweList<&'data mut [u32]>);
+
+impl AbstractEntity for LweCiphertextVectorMutView32<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for LweCiphertextVectorMutView32<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, viewing
+/// a mutable slice owned by the caller.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweCiphertextVectorMutView64<'data>(pub(crate) ImplLweList<&'data mut [u64]>);
+
+impl AbstractEntity for LweCiphertextVectorMutView64<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for LweCiphertextVectorMutView64<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
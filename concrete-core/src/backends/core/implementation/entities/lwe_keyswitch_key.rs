@@ -1,4 +1,6 @@
 use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+#[cfg(feature = "mmap")]
+use crate::backends::core::private::math::tensor::MappedSlice;
 use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyKind};
 use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
@@ -60,3 +62,65 @@ impl LweKeyswitchKeyEntity for LweKeyswitchKey64 {
         self.0.decomposition_base_log()
     }
 }
+
+/// A structure representing an LWE keyswitch key with 32 bits of precision, whose elements are
+/// mapped read-only from a file.
+#[cfg(feature = "mmap")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappedLweKeyswitchKey32(pub(crate) ImplLweKeyswitchKey<MappedSlice<u32>>);
+#[cfg(feature = "mmap")]
+impl AbstractEntity for MappedLweKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+#[cfg(feature = "mmap")]
+impl LweKeyswitchKeyEntity for MappedLweKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, whose elements are
+/// mapped read-only from a file.
+#[cfg(feature = "mmap")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappedLweKeyswitchKey64(pub(crate) ImplLweKeyswitchKey<MappedSlice<u64>>);
+#[cfg(feature = "mmap")]
+impl AbstractEntity for MappedLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+#[cfg(feature = "mmap")]
+impl LweKeyswitchKeyEntity for MappedLweKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
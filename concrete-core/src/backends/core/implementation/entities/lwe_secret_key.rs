@@ -35,3 +35,18 @@ impl LweSecretKeyEntity for LweSecretKey64 {
         self.0.key_size()
     }
 }
+
+/// A structure representing an LWE secret key with 128 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweSecretKey128(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u128>>);
+impl AbstractEntity for LweSecretKey128 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
@@ -17,3 +17,11 @@ impl AbstractEntity for Plaintext64 {
     type Kind = PlaintextKind;
 }
 impl PlaintextEntity for Plaintext64 {}
+
+/// A structure representing a plaintext with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plaintext128(pub(crate) CorePlaintext<u128>);
+impl AbstractEntity for Plaintext128 {
+    type Kind = PlaintextKind;
+}
+impl PlaintextEntity for Plaintext128 {}
@@ -26,3 +26,15 @@ impl PlaintextVectorEntity for PlaintextVector64 {
         self.0.count()
     }
 }
+
+/// A structure representing a vector of plaintexts with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaintextVector128(pub(crate) CorePlaintextList<Vec<u128>>);
+impl AbstractEntity for PlaintextVector128 {
+    type Kind = PlaintextVectorKind;
+}
+impl PlaintextVectorEntity for PlaintextVector128 {
+    fn plaintext_count(&self) -> PlaintextCount {
+        self.0.count()
+    }
+}
@@ -14,11 +14,14 @@ use crate::backends::core::private::math::polynomial::Polynomial;
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
-use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::math::torus::{
+    modular_mul, modular_switch_to_power_of_two, UnsignedTorus,
+};
 use crate::backends::core::private::utils::{zip, zip_args};
+use concrete_commons::numeric::CastInto;
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
-    ModulusSwitchOffset, MonomialDegree, PolynomialSize,
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog,
+    LweDimension, ModulusSwitchOffset, MonomialDegree, PolynomialSize,
 };
 
 mod buffers;
@@ -28,6 +31,10 @@ mod tests;
 pub use buffers::{FftBuffers, FourierBuffers};
 
 /// A bootstrapping key in the fourier domain.
+///
+/// The key itself is never mutated by a bootstrap, all the temporary data living in the
+/// [`FourierBuffers`] passed to [`FourierBootstrapKey::bootstrap`]. As such, a key can be shared
+/// between several threads, each thread owning its own buffers.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct FourierBootstrapKey<Cont, Scalar>
@@ -661,6 +668,7 @@ where
     {
         // We unpack the lwe ciphertext.
         let (lwe_body, lwe_mask) = lwe.get_body_and_mask();
+        let ciphertext_modulus = lwe.ciphertext_modulus();
         let lut = &mut buffers.lut_buffer;
 
         // We perform the initial clear rotation by performing lut <- lut * X^{-body_hat}
@@ -671,6 +679,7 @@ where
                 lut_poly_size,
                 ModulusSwitchOffset(0),
                 LutCountLog(0),
+                ciphertext_modulus,
             ));
 
         // We initialize the ct_0 and ct_1 used for the successive cmuxes
@@ -695,6 +704,7 @@ where
                         lut_poly_size,
                         ModulusSwitchOffset(0),
                         LutCountLog(0),
+                        ciphertext_modulus,
                     ));
                 // We perform the cmux.
                 self.cmux(
@@ -714,15 +724,30 @@ where
 //
 // offset: the number of msb discarded
 // lut_count_log: the right padding
+// ciphertext_modulus: the modulus the input coefficient lives in
 pub fn pbs_modulus_switch<Scalar>(
     input: Scalar,
     poly_size: PolynomialSize,
     offset: ModulusSwitchOffset,
     lut_count_log: LutCountLog,
+    ciphertext_modulus: CiphertextModulus,
 ) -> MonomialDegree
 where
     Scalar: UnsignedTorus,
 {
+    if let CiphertextModulus::Custom(modulus) = ciphertext_modulus {
+        // Discarding the offset msb amounts to a multiplication by 2^offset modulo q
+        let shift = Scalar::cast_from((1u128 << offset.0) % modulus);
+        let input = modular_mul(input, shift, modulus);
+        // We switch from q to 2N / 2^lut_count_log, with rounding
+        let output = modular_switch_to_power_of_two(
+            input,
+            modulus,
+            poly_size.log2().0 + 1 - lut_count_log.0,
+        );
+        // Apply the lsb padding
+        return MonomialDegree((output << lut_count_log.0) as usize);
+    }
     // First, do the left shift (we discard the offset msb)
     let mut output = input << offset.0;
     // Start doing the right shift
@@ -733,7 +758,7 @@ where
     output >>= 1;
     // Apply the lsb padding
     output <<= lut_count_log.0;
-    MonomialDegree(output.cast_into() as usize)
+    MonomialDegree(<Scalar as CastInto<u128>>::cast_into(output) as usize)
 }
 
 impl<Cont, Scalar> FourierBootstrapKey<Cont, Scalar>
@@ -839,6 +864,8 @@ where
         LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
         GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
     {
+        // The output is written entirely, and lives modulo the native modulus.
+        lwe_out.set_ciphertext_modulus(CiphertextModulus::Native);
         // We retrieve the accumulator buffer, and fill it with the input accumulator values.
         {
             let local_accumulator = &mut buffers.lut_buffer;
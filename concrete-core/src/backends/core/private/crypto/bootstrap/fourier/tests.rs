@@ -1,22 +1,29 @@
 use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
 use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PlaintextCount,
-    PolynomialSize,
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    LweSize, PlaintextCount, PolynomialSize,
 };
+use concrete_fftw::array::AlignedVec;
 use concrete_npe as npe;
 
 use crate::backends::core::private::crypto::bootstrap::{
     FourierBootstrapKey, FourierBuffers, StandardBootstrapKey,
 };
-use crate::backends::core::private::crypto::encoding::PlaintextList;
+use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
+use crate::backends::core::private::crypto::ggsw::{FourierGgswCiphertext, StandardGgswCiphertext};
 use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::lwe::LweCiphertext;
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::backends::core::private::math::decomposition::{
+    ApproximateSignedDecomposer, Decomposer, GeneralBaseDecomposer,
+};
 use crate::backends::core::private::math::fft::Complex64;
 use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::AsMutTensor;
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::backends::core::private::test_tools::assert_noise_distribution;
 
@@ -262,3 +269,278 @@ pub fn test_cmux_1_u32() {
 pub fn test_cmux_1_u64() {
     test_cmux_1::<u64>();
 }
+
+fn test_bootstrap_custom_modulus_input<T: UnsignedTorus>(modulus: u128) {
+    // We bootstrap ciphertexts living modulo a custom modulus, with a constant accumulator. This
+    // computes the sign of the input phase, in the native representation.
+    let polynomial_size = PolynomialSize(1024);
+    let rlwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(10);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std_dev_bsk = LogStandardDev(-(T::BITS as f64) + 5.);
+    let std_dev_lwe = LogStandardDev(-25.);
+    let ciphertext_modulus = CiphertextModulus::Custom(modulus);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    let rlwe_sk =
+        GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    let lwe_sk: LweSecretKey<BinaryKeyKind, Vec<T>> =
+        LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+
+    let mut coef_bsk = StandardBootstrapKey::allocate(
+        T::ZERO,
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std_dev_bsk, &mut encryption_generator);
+    let mut fourier_bsk = FourierBootstrapKey::allocate(
+        Complex64::new(0., 0.),
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    let mut buffers = FourierBuffers::new(fourier_bsk.poly_size, fourier_bsk.glwe_size);
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk, &mut buffers);
+
+    let eighth = T::ONE << (T::BITS - 3);
+    let mut accumulator =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, rlwe_dimension.to_glwe_size());
+    accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .fill_with_element(eighth);
+    let output_sk = rlwe_sk.into_lwe_secret_key();
+
+    for (quarters, expected) in [(1, eighth), (3, eighth.wrapping_neg())] {
+        let message = Plaintext(T::cast_from(quarters * (modulus / 4)));
+        let mut lwe_in = LweCiphertext::allocate_with_modulus(
+            T::ZERO,
+            lwe_dimension.to_lwe_size(),
+            ciphertext_modulus,
+        );
+        lwe_sk.encrypt_lwe(
+            &mut lwe_in,
+            &message,
+            std_dev_lwe,
+            &mut encryption_generator,
+        );
+
+        let mut lwe_out =
+            LweCiphertext::allocate(T::ZERO, LweSize(rlwe_dimension.0 * polynomial_size.0 + 1));
+        fourier_bsk.bootstrap(&mut lwe_out, &lwe_in, &accumulator, &mut buffers);
+
+        let mut decrypted = Plaintext(T::ZERO);
+        output_sk.decrypt_lwe(&mut decrypted, &lwe_out);
+        let error: T = decrypted.0.wrapping_sub(expected);
+        let distance: u128 = error.min(error.wrapping_neg()).cast_into();
+        assert!(distance < (1u128 << (T::BITS - 5)));
+    }
+}
+
+#[test]
+pub fn test_bootstrap_custom_modulus_input_u32() {
+    test_bootstrap_custom_modulus_input::<u32>((1 << 16) + 1);
+}
+
+#[test]
+pub fn test_bootstrap_custom_modulus_input_u64() {
+    test_bootstrap_custom_modulus_input::<u64>((1 << 50) - 27);
+}
+
+#[test]
+pub fn test_bootstrap_key_is_sync() {
+    // The bootstrap key must be shareable between threads, the mutable state of a bootstrap
+    // living in the buffers.
+    fn assert_sync<T: Sync>() {}
+    fn assert_send<T: Send>() {}
+    assert_sync::<FourierBootstrapKey<AlignedVec<Complex64>, u32>>();
+    assert_sync::<FourierBootstrapKey<AlignedVec<Complex64>, u64>>();
+    assert_send::<FourierBuffers<u32>>();
+    assert_send::<FourierBuffers<u64>>();
+}
+
+#[cfg(feature = "multithread")]
+fn test_parallel_bootstrap_shared_key<T: UnsignedTorus + Send + Sync>() {
+    // Bootstrapping from several threads sharing a single key, each with its own buffers, must
+    // give the same results as bootstrapping sequentially.
+    use rayon::prelude::*;
+
+    let polynomial_size = PolynomialSize(512);
+    let rlwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(10);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std_dev = LogStandardDev(-(T::BITS as f64) + 5.);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    let mut random_generator = RandomGenerator::new(None);
+
+    let rlwe_sk =
+        GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    let lwe_sk: LweSecretKey<BinaryKeyKind, Vec<T>> =
+        LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    let mut coef_bsk = StandardBootstrapKey::allocate(
+        T::ZERO,
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std_dev, &mut encryption_generator);
+    let mut fourier_bsk = FourierBootstrapKey::allocate(
+        Complex64::new(0., 0.),
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    let mut buffers = FourierBuffers::new(polynomial_size, rlwe_dimension.to_glwe_size());
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk, &mut buffers);
+
+    let mut accumulator =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, rlwe_dimension.to_glwe_size());
+    random_generator.fill_tensor_with_random_uniform(&mut accumulator);
+    let inputs: Vec<_> = (0..16)
+        .map(|_| {
+            let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dimension.to_lwe_size());
+            random_generator.fill_tensor_with_random_uniform(&mut lwe_in);
+            lwe_in
+        })
+        .collect();
+    let output_size = LweSize(rlwe_dimension.0 * polynomial_size.0 + 1);
+
+    let sequential: Vec<_> = inputs
+        .iter()
+        .map(|lwe_in| {
+            let mut lwe_out = LweCiphertext::allocate(T::ZERO, output_size);
+            fourier_bsk.bootstrap(&mut lwe_out, lwe_in, &accumulator, &mut buffers);
+            lwe_out
+        })
+        .collect();
+    let parallel: Vec<_> = inputs
+        .par_iter()
+        .map_init(
+            || FourierBuffers::new(polynomial_size, rlwe_dimension.to_glwe_size()),
+            |buffers, lwe_in| {
+                let mut lwe_out = LweCiphertext::allocate(T::ZERO, output_size);
+                fourier_bsk.bootstrap(&mut lwe_out, lwe_in, &accumulator, buffers);
+                lwe_out
+            },
+        )
+        .collect();
+    assert_eq!(sequential, parallel);
+}
+
+#[cfg(feature = "multithread")]
+#[test]
+pub fn test_parallel_bootstrap_shared_key_u32() {
+    test_parallel_bootstrap_shared_key::<u32>();
+}
+
+#[cfg(feature = "multithread")]
+#[test]
+pub fn test_parallel_bootstrap_shared_key_u64() {
+    test_parallel_bootstrap_shared_key::<u64>();
+}
+
+fn test_external_product_with_decomposer<T, D>(decomposer: D)
+where
+    T: UnsignedTorus,
+    D: Decomposer<T>,
+{
+    // The external product between a GGSW encrypting one and a GLWE encrypting a message, both
+    // using the gadget values of the decomposer, must give back the message.
+    let polynomial_size = PolynomialSize(1024);
+    let rlwe_dimension = GlweDimension(2);
+    let std_dev = LogStandardDev(-25.);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    let mut random_generator = RandomGenerator::new(None);
+
+    let rlwe_sk =
+        GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    let mut ggsw = StandardGgswCiphertext::allocate(
+        T::ZERO,
+        polynomial_size,
+        rlwe_dimension.to_glwe_size(),
+        decomposer.level_count(),
+        DecompositionBaseLog(0),
+    );
+    rlwe_sk.encrypt_constant_ggsw_with_decomposer(
+        &mut ggsw,
+        &Plaintext(T::ONE),
+        std_dev,
+        &mut encryption_generator,
+        &decomposer,
+    );
+    let mut fourier_ggsw = FourierGgswCiphertext::allocate(
+        Complex64::new(0., 0.),
+        polynomial_size,
+        rlwe_dimension.to_glwe_size(),
+        decomposer.level_count(),
+        DecompositionBaseLog(0),
+    );
+    let mut buffers = FourierBuffers::new(polynomial_size, rlwe_dimension.to_glwe_size());
+    fourier_ggsw.fill_with_forward_fourier(&ggsw, &mut buffers);
+
+    let mut messages = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    random_generator.fill_tensor_with_random_uniform(&mut messages);
+    let mut input =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, rlwe_dimension.to_glwe_size());
+    rlwe_sk.encrypt_glwe(&mut input, &messages, std_dev, &mut encryption_generator);
+    let mut output =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, rlwe_dimension.to_glwe_size());
+    fourier_ggsw.external_product_with_decomposer(&mut output, &input, &mut buffers, &decomposer);
+
+    let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    rlwe_sk.decrypt_glwe(&mut decrypted, &output);
+    let bound = T::ONE << (T::BITS - 10);
+    for (decrypted, message) in decrypted.plaintext_iter().zip(messages.plaintext_iter()) {
+        let error = decrypted.0.wrapping_sub(message.0);
+        assert!(error.min(message.0.wrapping_sub(decrypted.0)) < bound);
+    }
+}
+
+#[test]
+pub fn test_external_product_general_base_u32() {
+    test_external_product_with_decomposer::<u32, _>(GeneralBaseDecomposer::new(
+        7,
+        DecompositionLevelCount(8),
+    ));
+}
+
+#[test]
+pub fn test_external_product_general_base_u64() {
+    test_external_product_with_decomposer::<u64, _>(GeneralBaseDecomposer::new_unbalanced(
+        7,
+        DecompositionLevelCount(8),
+        -2,
+    ));
+}
+
+#[test]
+pub fn test_external_product_approximate_u32() {
+    test_external_product_with_decomposer::<u32, _>(ApproximateSignedDecomposer::new(
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(5),
+        DecompositionLevelCount(2),
+    ));
+}
+
+#[test]
+pub fn test_external_product_approximate_u64() {
+    test_external_product_with_decomposer::<u64, _>(ApproximateSignedDecomposer::new(
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(5),
+        DecompositionLevelCount(2),
+    ));
+}
@@ -10,7 +10,9 @@ use crate::backends::core::private::utils::{zip, zip_args};
 
 use super::{GgswLevelMatrix, StandardGgswCiphertext};
 
-use crate::backends::core::private::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::backends::core::private::math::decomposition::{
+    Decomposer, DecompositionLevel, SignedDecomposer,
+};
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
 };
@@ -519,6 +521,32 @@ impl<Cont, Scalar> FourierGgswCiphertext<Cont, Scalar> {
         GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
         GlweCiphertext<C2>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
+    {
+        let decomposer =
+            SignedDecomposer::new(self.decomp_base_log, self.decomposition_level_count());
+        self.external_product_with_decomposer(output, glwe, buffers, &decomposer);
+    }
+
+    /// Computes the external product between this GGSW ciphertext and a GLWE ciphertext, using
+    /// a given decomposer, and adds the result to the output.
+    ///
+    /// The GGSW ciphertext must have been encrypted with the gadget values of the same decomposer
+    /// (see [`GlweSecretKey::encrypt_constant_ggsw_with_decomposer`]).
+    ///
+    /// [`GlweSecretKey::encrypt_constant_ggsw_with_decomposer`]:
+    /// crate::backends::core::private::crypto::secret::GlweSecretKey::encrypt_constant_ggsw_with_decomposer
+    pub fn external_product_with_decomposer<C1, C2, Decomp>(
+        &self,
+        output: &mut GlweCiphertext<C1>,
+        glwe: &GlweCiphertext<C2>,
+        buffers: &mut FourierBuffers<Scalar>,
+        decomposer: &Decomp,
+    ) where
+        Self: AsRefTensor<Element = Complex64>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Decomp: Decomposer<Scalar>,
     {
         // We check that the polynomial sizes match
         ck_dim_eq!(
@@ -533,8 +561,9 @@ impl<Cont, Scalar> FourierGgswCiphertext<Cont, Scalar> {
             output.size()
         );
 
+        ck_dim_eq!(self.decomposition_level_count().0 => decomposer.level_count().0);
+
         let fft_buffers = &mut buffers.fft_buffers;
-        let rounded_buffer = &mut buffers.rounded_buffer;
 
         // "alias" buffers to save some typing
         let fft = &mut fft_buffers.fft;
@@ -543,25 +572,20 @@ impl<Cont, Scalar> FourierGgswCiphertext<Cont, Scalar> {
         let output_fft_buffer = &mut fft_buffers.output_buffer;
         output_fft_buffer.fill_with_element(Complex64::new(0., 0.));
 
-        let rounded_input_glwe = rounded_buffer;
-
-        // We round the input mask and body
-        let decomposer =
-            SignedDecomposer::new(self.decomp_base_log, self.decomposition_level_count());
-        decomposer.fill_tensor_with_closest_representable(rounded_input_glwe, glwe);
+        // We decompose the input mask and body. The digits of each level are stored contiguously.
+        let glwe_len = glwe.as_tensor().len();
+        let mut decomposition =
+            Tensor::allocate(Scalar::ZERO, glwe_len * decomposer.level_count().0);
+        decomposer.fill_tensor_with_decomposition(&mut decomposition, glwe);
 
         // ------------------------------------------------------ EXTERNAL PRODUCT IN FOURIER DOMAIN
         // In this section, we perform the external product in the fourier domain, and accumulate
         // the result in the output_fft_buffer variable.
-        let mut decomposition = decomposer.decompose_tensor(rounded_input_glwe);
-        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
-        for ggsw_decomp_matrix in self.level_matrix_iter().rev() {
-            // We retrieve the decomposition of this level.
-            let glwe_decomp_term = decomposition.next_term().unwrap();
-            debug_assert_eq!(
-                ggsw_decomp_matrix.decomposition_level(),
-                glwe_decomp_term.level()
-            );
+        // We loop through the levels, and retrieve the decomposition of each level.
+        for (ggsw_decomp_matrix, glwe_decomp_term) in self
+            .level_matrix_iter()
+            .zip(decomposition.subtensor_iter(glwe_len))
+        {
             // For each levels we have to add the result of the vector-matrix product between the
             // decomposition of the glwe, and the ggsw level matrix to the output. To do so, we
             // iteratively add to the output, the product between every lines of the matrix, and
@@ -578,7 +602,6 @@ impl<Cont, Scalar> FourierGgswCiphertext<Cont, Scalar> {
             let mut iterator = zip!(
                 ggsw_decomp_matrix.row_iter(),
                 glwe_decomp_term
-                    .as_tensor()
                     .subtensor_iter(self.poly_size.0)
                     .map(Polynomial::from_tensor)
             );
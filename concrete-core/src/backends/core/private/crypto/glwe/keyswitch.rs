@@ -13,7 +13,7 @@ use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
 use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
 use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
 use crate::backends::core::private::math::decomposition::{
-    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+    Decomposer, DecompositionLevel, DecompositionTerm, SignedDecomposer,
 };
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
@@ -401,6 +401,113 @@ impl<Cont> PackingKeyswitchKey<Cont> {
         }
     }
 
+    /// Fills the current keyswitch key container with a packing keyswitching key constructed
+    /// from an input and an output key, using the gadget values of a given decomposer.
+    ///
+    /// The key generated this way must be used with
+    /// [`PackingKeyswitchKey::keyswitch_ciphertext_with_decomposer`], and the same decomposer.
+    /// The base log stored in the key is not used by this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PackingKeyswitchKey;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::backends::core::private::math::decomposition::ApproximateSignedDecomposer;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposer = ApproximateSignedDecomposer::<u32>::new(
+    ///     DecompositionBaseLog(3),
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionLevelCount(2),
+    /// );
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pksk = PackingKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     decomposer.level_count(),
+    ///     decomposer.base_log(),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    /// );
+    /// pksk.fill_with_packing_keyswitch_key_with_decomposer(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &decomposer,
+    /// );
+    ///
+    /// assert!(!pksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_packing_keyswitch_key_with_decomposer<InKeyCont, OutKeyCont, Scalar, Decomp>(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        decomposer: &Decomp,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Decomp: Decomposer<Scalar>,
+    {
+        ck_dim_eq!(self.decomp_level_count.0 => decomposer.level_count().0);
+
+        // We instantiate a buffer
+        let mut messages = PlaintextList::from_container(vec![
+            <Self as AsMutTensor>::Element::ZERO;
+            self.decomp_level_count.0
+                * self.output_polynomial_size.0
+        ]);
+
+        let polynomial_size = self.output_polynomial_size;
+
+        // loop over the before key blocks
+        for (input_key_bit, keyswitch_key_block) in input_lwe_key
+            .as_tensor()
+            .iter()
+            .zip(self.bit_decomp_iter_mut())
+        {
+            // We fill the buffer with the key bit times the gadget values
+            for (level, mut message) in (1..=decomposer.level_count().0)
+                .map(DecompositionLevel)
+                .zip(messages.sublist_iter_mut(PlaintextCount(polynomial_size.0)))
+            {
+                *message.as_mut_tensor().first_mut() =
+                    input_key_bit.wrapping_mul(decomposer.gadget_value(level));
+            }
+
+            // We encrypt the buffer
+            output_glwe_key.encrypt_glwe_list(
+                &mut keyswitch_key_block.into_glwe_list(),
+                &messages,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
     /// Iterates over borrowed `LweKeyBitDecomposition` elements.
     ///
     /// One `LweKeyBitDecomposition` being a set of LWE ciphertexts, encrypting under the output
@@ -551,9 +658,32 @@ impl<Cont> PackingKeyswitchKey<Cont> {
         GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
         LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
+    {
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        self.keyswitch_ciphertext_with_decomposer(after, before, &decomposer);
+    }
+
+    /// Keyswitches a single LWE ciphertext into a GLWE, using a given decomposer.
+    ///
+    /// The packing keyswitch key must have been generated with
+    /// [`PackingKeyswitchKey::fill_with_packing_keyswitch_key_with_decomposer`], using the same
+    /// decomposer.
+    pub fn keyswitch_ciphertext_with_decomposer<InCont, OutCont, Scalar, Decomp>(
+        &self,
+        after: &mut GlweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        decomposer: &Decomp,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Decomp: Decomposer<Scalar>,
     {
         ck_dim_eq!(self.input_lwe_key_dimension().0 => before.lwe_size().to_lwe_dimension().0);
         ck_dim_eq!(self.output_glwe_key_dimension().0 => after.size().to_glwe_dimension().0);
+        ck_dim_eq!(self.decomp_level_count.0 => decomposer.level_count().0);
 
         // We reset the output
         after.as_mut_tensor().fill_with(|| Scalar::ZERO);
@@ -561,32 +691,27 @@ impl<Cont> PackingKeyswitchKey<Cont> {
         // We copy the body
         *after.get_mut_body().tensor.as_mut_tensor().first_mut() = before.get_body().0;
 
-        // We instantiate a decomposer
-        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        // We instantiate a buffer for the digits of the decomposition
+        let mut digits = vec![Scalar::ZERO; self.decomp_level_count.0];
 
-        // Loop over the number of levels:
-        // We compute the multiplication of a ciphertext from the keyswitching key with a
-        // piece of the decomposition and subtract it to the buffer
         for (block, input_lwe_mask) in self
             .bit_decomp_iter()
             .zip(before.get_mask().mask_element_iter())
         {
             // We decompose
-            let mask_rounded = decomposer.closest_representable(*input_lwe_mask);
-            let decomp = decomposer.decompose(mask_rounded);
+            decomposer.decompose_into(*input_lwe_mask, digits.as_mut_slice());
 
             // Loop over the number of levels:
             // We compute the multiplication of a ciphertext from the keyswitching key with a
             // piece of the decomposition and subtract it to the buffer
-            for (level_key_cipher, decomposed) in block
+            for (level_key_cipher, digit) in block
                 .as_tensor()
                 .subtensor_iter(self.output_glwe_size.0 * self.output_polynomial_size.0)
-                .rev()
-                .zip(decomp)
+                .zip(digits.iter())
             {
                 after
                     .as_mut_tensor()
-                    .update_with_wrapping_sub_element_mul(&level_key_cipher, decomposed.value());
+                    .update_with_wrapping_sub_element_mul(&level_key_cipher, *digit);
             }
         }
     }
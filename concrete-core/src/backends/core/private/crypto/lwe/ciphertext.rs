@@ -9,16 +9,20 @@ use crate::backends::core::private::math::tensor::{
 
 use super::LweList;
 use crate::backends::core::private::crypto::glwe::GlweCiphertext;
-use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::math::torus::{
+    modular_add, modular_mul, modular_neg, modular_reduce_signed, modular_sub, UnsignedTorus,
+};
 use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::numeric::{Numeric, UnsignedInteger};
-use concrete_commons::parameters::{LweDimension, LweSize, MonomialDegree};
+use concrete_commons::parameters::{CiphertextModulus, LweDimension, LweSize, MonomialDegree};
 
 /// A ciphertext encrypted using the LWE scheme.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LweCiphertext<Cont> {
     pub(super) tensor: Tensor<Cont>,
+    #[cfg_attr(feature = "serde_serialize", serde(default))]
+    pub(super) ciphertext_modulus: CiphertextModulus,
 }
 
 tensor_traits!(LweCiphertext);
@@ -41,6 +45,30 @@ where
     pub fn allocate(value: Scalar, size: LweSize) -> Self {
         LweCiphertext {
             tensor: Tensor::from_container(vec![value; size.0]),
+            ciphertext_modulus: CiphertextModulus::Native,
+        }
+    }
+
+    /// Allocates a new ciphertext whose coefficients live modulo the given ciphertext modulus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulus, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// let modulus = CiphertextModulus::Custom((1 << 32) - 5);
+    /// let ct = LweCiphertext::allocate_with_modulus(0 as u64, LweSize(4), modulus);
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// assert_eq!(ct.ciphertext_modulus(), modulus);
+    /// ```
+    pub fn allocate_with_modulus(
+        value: Scalar,
+        size: LweSize,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self {
+        LweCiphertext {
+            tensor: Tensor::from_container(vec![value; size.0]),
+            ciphertext_modulus,
         }
     }
 }
@@ -106,8 +134,63 @@ impl<Cont> LweCiphertext<Cont> {
     /// assert_eq!(ct.get_mask().mask_size(), LweDimension(9));
     /// ```
     pub fn from_container(cont: Cont) -> LweCiphertext<Cont> {
+        Self::from_container_with_modulus(cont, CiphertextModulus::Native)
+    }
+
+    /// Creates a ciphertext from a container of values, living modulo the given ciphertext
+    /// modulus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulus, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// let vector = vec![0 as u32; 10];
+    /// let modulus = CiphertextModulus::Custom(65537);
+    /// let ct = LweCiphertext::from_container_with_modulus(vector.as_slice(), modulus);
+    /// assert_eq!(ct.lwe_size(), LweSize(10));
+    /// assert_eq!(ct.ciphertext_modulus(), modulus);
+    /// ```
+    pub fn from_container_with_modulus(
+        cont: Cont,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> LweCiphertext<Cont> {
         let tensor = Tensor::from_container(cont);
-        LweCiphertext { tensor }
+        LweCiphertext {
+            tensor,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Returns the modulus the coefficients of the ciphertext live in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulus, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// let ct = LweCiphertext::allocate(0 as u8, LweSize(4));
+    /// assert_eq!(ct.ciphertext_modulus(), CiphertextModulus::Native);
+    /// ```
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext_modulus
+    }
+
+    /// Sets the modulus the coefficients of the ciphertext live in.
+    ///
+    /// The coefficients are left untouched, and must already be reduced modulo the new modulus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulus, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// let mut ct = LweCiphertext::allocate(0 as u8, LweSize(4));
+    /// ct.set_ciphertext_modulus(CiphertextModulus::Custom(17));
+    /// assert_eq!(ct.ciphertext_modulus(), CiphertextModulus::Custom(17));
+    /// ```
+    pub fn set_ciphertext_modulus(&mut self, ciphertext_modulus: CiphertextModulus) {
+        self.ciphertext_modulus = ciphertext_modulus;
     }
 
     /// Returns the size of the cipher, e.g. the size of the mask + 1 for the body.
@@ -304,10 +387,19 @@ impl<Cont> LweCiphertext<Cont> {
     ) where
         Self: AsMutTensor<Element = Scalar>,
         LweCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
-        Scalar: UnsignedInteger,
+        Scalar: UnsignedTorus,
     {
-        self.as_mut_tensor()
-            .fill_with_one(input.as_tensor(), |o| o.wrapping_mul(scalar.0));
+        self.ciphertext_modulus = input.ciphertext_modulus;
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => self
+                .as_mut_tensor()
+                .fill_with_one(input.as_tensor(), |o| o.wrapping_mul(scalar.0)),
+            CiphertextModulus::Custom(modulus) => {
+                let scalar = modular_reduce_signed(scalar.0, modulus);
+                self.as_mut_tensor()
+                    .fill_with_one(input.as_tensor(), |o| modular_mul(*o, scalar, modulus))
+            }
+        }
     }
 
     /// Fills the ciphertext with the result of the multisum of the `input_list` with the
@@ -374,6 +466,10 @@ impl<Cont> LweCiphertext<Cont> {
         CleartextList<WeightCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedInteger,
     {
+        assert!(
+            self.ciphertext_modulus.is_native(),
+            "Multisums are only supported for ciphertexts using the native modulus."
+        );
         // loop over the ciphertexts and the weights
         for (input_cipher, weight) in input_list.ciphertext_iter().zip(weights.cleartext_iter()) {
             let cipher_tens = input_cipher.as_tensor();
@@ -436,8 +532,18 @@ impl<Cont> LweCiphertext<Cont> {
         LweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        self.as_mut_tensor()
-            .update_with_wrapping_add(other.as_tensor())
+        assert_eq!(
+            self.ciphertext_modulus, other.ciphertext_modulus,
+            "The ciphertexts must use the same modulus."
+        );
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => self
+                .as_mut_tensor()
+                .update_with_wrapping_add(other.as_tensor()),
+            CiphertextModulus::Custom(modulus) => self
+                .as_mut_tensor()
+                .update_with_one(other.as_tensor(), |o, c| *o = modular_add(*o, *c, modulus)),
+        }
     }
 
     /// Subtracts the `other` ciphertext from the current one.
@@ -489,8 +595,18 @@ impl<Cont> LweCiphertext<Cont> {
         LweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        self.as_mut_tensor()
-            .update_with_wrapping_sub(other.as_tensor())
+        assert_eq!(
+            self.ciphertext_modulus, other.ciphertext_modulus,
+            "The ciphertexts must use the same modulus."
+        );
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => self
+                .as_mut_tensor()
+                .update_with_wrapping_sub(other.as_tensor()),
+            CiphertextModulus::Custom(modulus) => self
+                .as_mut_tensor()
+                .update_with_one(other.as_tensor(), |o, c| *o = modular_sub(*o, *c, modulus)),
+        }
     }
 
     /// Computes the opposite of the ciphertext.
@@ -536,7 +652,12 @@ impl<Cont> LweCiphertext<Cont> {
         Self: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        self.as_mut_tensor().update_with_wrapping_neg()
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => self.as_mut_tensor().update_with_wrapping_neg(),
+            CiphertextModulus::Custom(modulus) => self
+                .as_mut_tensor()
+                .update_with(|o| *o = modular_neg(*o, modulus)),
+        }
     }
 
     /// Multiplies the current ciphertext with a scalar value inplace.
@@ -582,8 +703,16 @@ impl<Cont> LweCiphertext<Cont> {
         Self: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        self.as_mut_tensor()
-            .update_with_wrapping_scalar_mul(&scalar.0)
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => self
+                .as_mut_tensor()
+                .update_with_wrapping_scalar_mul(&scalar.0),
+            CiphertextModulus::Custom(modulus) => {
+                let scalar = modular_reduce_signed(scalar.0, modulus);
+                self.as_mut_tensor()
+                    .update_with(|o| *o = modular_mul(*o, scalar, modulus))
+            }
+        }
     }
 
     /// Fills an LWE ciphertext with the sample extraction of one of the coefficients of a GLWE
@@ -647,6 +776,8 @@ impl<Cont> LweCiphertext<Cont> {
         GlweCiphertext<InputCont>: AsRefTensor<Element = Element>,
         Element: UnsignedTorus,
     {
+        // The extracted sample is written entirely, and lives modulo the native modulus.
+        self.ciphertext_modulus = CiphertextModulus::Native;
         glwe.fill_lwe_with_sample_extraction(self, n_th);
     }
 
@@ -655,6 +786,7 @@ impl<Cont> LweCiphertext<Cont> {
         Scalar: Numeric,
         Self: AsMutTensor<Element = Scalar>,
     {
+        self.ciphertext_modulus = CiphertextModulus::Native;
         let (output_body, mut output_mask) = self.get_mut_body_and_mask();
 
         // generate a uniformly random mask
@@ -4,19 +4,22 @@ use serde::{Deserialize, Serialize};
 use concrete_commons::dispersion::DispersionParameter;
 use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::parameters::{
-    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    CiphertextCount, CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+    LweDimension, LweSize,
 };
 
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
 use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
 use crate::backends::core::private::crypto::secret::LweSecretKey;
 use crate::backends::core::private::math::decomposition::{
-    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+    Decomposer, DecompositionLevel, DecompositionTerm, SignedDecomposer,
 };
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
-use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::math::torus::{
+    modular_to_native, native_to_modular, UnsignedTorus,
+};
 
 use super::{LweCiphertext, LweList};
 
@@ -382,6 +385,101 @@ impl<Cont> LweKeyswitchKey<Cont> {
         }
     }
 
+    /// Fills the current keyswitch key container with a keyswitching key constructed from an
+    /// input and an output key, using the gadget values of a given decomposer.
+    ///
+    /// The key generated this way must be used with
+    /// [`LweKeyswitchKey::keyswitch_ciphertext_with_decomposer`], and the same decomposer. The
+    /// base log stored in the key is not used by this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::LweKeyswitchKey;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = LweDimension(20);
+    /// let decomposer = GeneralBaseDecomposer::<u32>::new(6, DecompositionLevelCount(5));
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(output_size, &mut secret_generator);
+    ///
+    /// let mut ksk = LweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     decomposer.level_count(),
+    ///     DecompositionBaseLog(0),
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key_with_decomposer(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &decomposer,
+    /// );
+    ///
+    /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_keyswitch_key_with_decomposer<InKeyCont, OutKeyCont, Scalar, Decomp>(
+        &mut self,
+        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        decomposer: &Decomp,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Decomp: Decomposer<Scalar>,
+    {
+        ck_dim_eq!(self.decomp_level_count.0 => decomposer.level_count().0);
+
+        // We instantiate a buffer
+        let mut messages = PlaintextList::from_container(vec![
+            <Self as AsMutTensor>::Element::ZERO;
+            self.decomp_level_count.0
+        ]);
+
+        // loop over the before key blocks
+        for (input_key_bit, keyswitch_key_block) in before_key
+            .as_tensor()
+            .iter()
+            .zip(self.bit_decomp_iter_mut())
+        {
+            // We fill the buffer with the key bit times the gadget values
+            for (level, message) in (1..=decomposer.level_count().0)
+                .map(DecompositionLevel)
+                .zip(messages.plaintext_iter_mut())
+            {
+                *message = Plaintext(input_key_bit.wrapping_mul(decomposer.gadget_value(level)));
+            }
+
+            // We encrypt the buffer
+            after_key.encrypt_lwe_list(
+                &mut keyswitch_key_block.into_lwe_list(),
+                &messages,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
     /// Iterates over borrowed `LweKeyBitDecomposition` elements.
     ///
     /// One `LweKeyBitDecomposition` being a set of lwe ciphertext, encrypting under the output
@@ -461,6 +559,10 @@ impl<Cont> LweKeyswitchKey<Cont> {
 
     /// Switches the key of a signel Lwe ciphertext.
     ///
+    /// When the input ciphertext uses a custom modulus, its mask is decomposed on the native
+    /// representation of the torus, and the output ciphertext is mapped back to the same custom
+    /// modulus.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -520,36 +622,134 @@ impl<Cont> LweKeyswitchKey<Cont> {
         LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
         LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
+    {
+        // We instantiate a decomposer, which maps the mask elements to the native torus if needed
+        let decomposer = SignedDecomposer::new_with_modulus(
+            self.decomp_base_log,
+            self.decomp_level_count,
+            before.ciphertext_modulus(),
+        );
+        self.keyswitch_ciphertext_with_decomposer(after, before, &decomposer);
+    }
+
+    /// Switches the key of a single Lwe ciphertext, using a given decomposer.
+    ///
+    /// The keyswitch key must have been generated with
+    /// [`LweKeyswitchKey::fill_with_keyswitch_key_with_decomposer`], using the same decomposer.
+    ///
+    /// When the input ciphertext uses a custom modulus, the decomposer must map the mask elements
+    /// to the native representation of the torus before decomposing them, and the output
+    /// ciphertext is mapped back to the same custom modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    ///
+    /// let input_size = LweDimension(1024);
+    /// let output_size = LweDimension(512);
+    /// let decomposer = GeneralBaseDecomposer::<u64>::new(10, DecompositionLevelCount(6));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(output_size, &mut secret_generator);
+    ///
+    /// let mut ksk = LweKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     decomposer.level_count(),
+    ///     DecompositionBaseLog(0),
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key_with_decomposer(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &decomposer,
+    /// );
+    ///
+    /// let plaintext: Plaintext<u64> = Plaintext(1 << 60);
+    /// let mut ciphertext = LweCiphertext::allocate(0 as u64, input_size.to_lwe_size());
+    /// let mut switched_ciphertext = LweCiphertext::allocate(0 as u64, output_size.to_lwe_size());
+    /// input_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// ksk.keyswitch_ciphertext_with_decomposer(&mut switched_ciphertext, &ciphertext, &decomposer);
+    ///
+    /// let mut decrypted = Plaintext(0 as u64);
+    /// output_key.decrypt_lwe(&mut decrypted, &switched_ciphertext);
+    /// let error = decrypted.0.wrapping_sub(plaintext.0) as i64;
+    /// assert!(error.abs() < 1 << 52);
+    /// ```
+    pub fn keyswitch_ciphertext_with_decomposer<InCont, OutCont, Scalar, Decomp>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        decomposer: &Decomp,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Decomp: Decomposer<Scalar>,
     {
         ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
         ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
+        ck_dim_eq!(self.decomp_level_count.0 => decomposer.level_count().0);
 
         // We reset the output
         after.as_mut_tensor().fill_with(|| Scalar::ZERO);
 
-        // We copy the body
-        *after.get_mut_body() = *before.get_body();
-        // We instantiate a decomposer
-        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        // We copy the body, in the native representation of the torus
+        let ciphertext_modulus = before.ciphertext_modulus();
+        after.get_mut_body().0 = match ciphertext_modulus {
+            CiphertextModulus::Native => before.get_body().0,
+            CiphertextModulus::Custom(modulus) => modular_to_native(before.get_body().0, modulus),
+        };
+
+        // We instantiate a buffer for the digits of the decomposition
+        let mut digits = vec![Scalar::ZERO; self.decomp_level_count.0];
 
         for (block, before_mask) in self
             .bit_decomp_iter()
             .zip(before.get_mask().mask_element_iter())
         {
-            let mask_rounded = decomposer.closest_representable(*before_mask);
-            let decomp = decomposer.decompose(mask_rounded);
+            decomposer.decompose_into(*before_mask, digits.as_mut_slice());
             // loop over the number of levels
-            for (level_key_cipher, decomposed) in block
+            for (level_key_cipher, digit) in block
                 .as_tensor()
                 .subtensor_iter(self.after_key_size().0 + 1)
-                .rev()
-                .zip(decomp)
+                .zip(digits.iter())
             {
                 after
                     .as_mut_tensor()
-                    .update_with_wrapping_sub_element_mul(&level_key_cipher, decomposed.value());
+                    .update_with_wrapping_sub_element_mul(&level_key_cipher, *digit);
             }
         }
+
+        // We map the output back to the modulus of the input
+        if let CiphertextModulus::Custom(modulus) = ciphertext_modulus {
+            after
+                .as_mut_tensor()
+                .update_with(|a| *a = native_to_modular(*a, modulus));
+        }
+        after.ciphertext_modulus = ciphertext_modulus;
     }
 
     pub fn keyswitch_list<InCont, OutCont, Scalar>(
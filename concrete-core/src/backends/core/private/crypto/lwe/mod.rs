@@ -6,3 +6,6 @@ pub use list::*;
 mod ciphertext;
 mod keyswitch;
 mod list;
+
+#[cfg(test)]
+mod tests;
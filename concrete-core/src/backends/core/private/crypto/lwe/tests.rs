@@ -0,0 +1,102 @@
+use concrete_commons::dispersion::LogStandardDev;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::CastInto;
+use concrete_commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension,
+    MonomialDegree, PolynomialSize,
+};
+
+use crate::backends::core::private::crypto::encoding::Plaintext;
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+use crate::backends::core::private::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::backends::core::private::crypto::secret::LweSecretKey;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::{modular_sub, UnsignedTorus};
+
+fn test_keyswitch_custom_modulus<T: UnsignedTorus>(modulus: u128) {
+    let input_dimension = LweDimension(600);
+    let output_dimension = LweDimension(500);
+    let level = DecompositionLevelCount(4);
+    let base_log = DecompositionBaseLog(4);
+    let std_dev_ksk = LogStandardDev(-(T::BITS as f64) + 10.);
+    let std_dev_lwe = LogStandardDev(-25.);
+    let ciphertext_modulus = CiphertextModulus::Custom(modulus);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    let input_key: LweSecretKey<BinaryKeyKind, Vec<T>> =
+        LweSecretKey::generate_binary(input_dimension, &mut secret_generator);
+    let output_key: LweSecretKey<BinaryKeyKind, Vec<T>> =
+        LweSecretKey::generate_binary(output_dimension, &mut secret_generator);
+    let mut ksk =
+        LweKeyswitchKey::allocate(T::ZERO, level, base_log, input_dimension, output_dimension);
+    ksk.fill_with_keyswitch_key(
+        &input_key,
+        &output_key,
+        std_dev_ksk,
+        &mut encryption_generator,
+    );
+
+    for quarters in 0..4 {
+        let message = Plaintext(T::cast_from(quarters * (modulus / 4)));
+        let mut input = LweCiphertext::allocate_with_modulus(
+            T::ZERO,
+            input_dimension.to_lwe_size(),
+            ciphertext_modulus,
+        );
+        input_key.encrypt_lwe(&mut input, &message, std_dev_lwe, &mut encryption_generator);
+
+        let mut output = LweCiphertext::allocate(T::ZERO, output_dimension.to_lwe_size());
+        ksk.keyswitch_ciphertext(&mut output, &input);
+        assert_eq!(output.ciphertext_modulus(), ciphertext_modulus);
+        assert!(output.as_tensor().iter().all(|a| {
+            let a: u128 = (*a).cast_into();
+            a < modulus
+        }));
+
+        let mut decrypted = Plaintext(T::ZERO);
+        output_key.decrypt_lwe(&mut decrypted, &output);
+        let error = modular_sub(decrypted.0, message.0, modulus);
+        let error: u128 = error.cast_into();
+        let distance = error.min(modulus - error);
+        assert!(distance < modulus / 16);
+    }
+}
+
+#[test]
+pub fn test_keyswitch_custom_modulus_u32() {
+    test_keyswitch_custom_modulus::<u32>((1 << 16) + 1);
+}
+
+#[test]
+pub fn test_keyswitch_custom_modulus_u64() {
+    test_keyswitch_custom_modulus::<u64>((1 << 50) - 27);
+}
+
+#[test]
+#[should_panic]
+pub fn test_add_panics_on_modulus_mismatch() {
+    let mut ct_1 = LweCiphertext::allocate(0u64, LweDimension(10).to_lwe_size());
+    let ct_2 = LweCiphertext::allocate_with_modulus(
+        0u64,
+        LweDimension(10).to_lwe_size(),
+        CiphertextModulus::Custom(1 << 40),
+    );
+    ct_1.update_with_add(&ct_2);
+}
+
+#[test]
+pub fn test_sample_extraction_outputs_native_modulus() {
+    let glwe = GlweCiphertext::allocate(0u64, PolynomialSize(16), GlweSize(2));
+    let mut lwe = LweCiphertext::allocate_with_modulus(
+        1u64,
+        LweDimension(16).to_lwe_size(),
+        CiphertextModulus::Custom(1 << 40),
+    );
+    lwe.fill_with_glwe_sample_extraction(&glwe, MonomialDegree(0));
+    assert_eq!(lwe.ciphertext_modulus(), CiphertextModulus::Native);
+    assert!(lwe.as_tensor().iter().all(|a| *a == 0));
+}
@@ -2,6 +2,7 @@ use crate::backends::core::private::math::random::{
     Gaussian, RandomGenerable, RandomGenerator, Uniform,
 };
 use crate::backends::core::private::math::tensor::AsMutTensor;
+use crate::backends::core::private::math::torus::{modular_from_torus, UnsignedTorus};
 
 use concrete_commons::dispersion::DispersionParameter;
 use concrete_commons::numeric::UnsignedInteger;
@@ -28,10 +29,19 @@ impl EncryptionRandomGenerator {
         }
     }
 
+    // Creates a generator whose mask and noise generators are both seeded. For testing purpose
+    // only.
+    #[cfg(feature = "seeded_engine")]
+    pub(crate) fn new_seeded(mask_seed: u128, noise_seed: u128) -> EncryptionRandomGenerator {
+        EncryptionRandomGenerator {
+            mask: RandomGenerator::new(Some(mask_seed)),
+            noise: RandomGenerator::new(Some(noise_seed)),
+        }
+    }
+
     // Allows to seed the noise generator. For testing purpose only.
     #[allow(dead_code)]
     pub(crate) fn seed_noise_generator(&mut self, seed: u128) {
-        println!("WARNING: The noise generator of the encryption random generator was seeded.");
         self.noise = RandomGenerator::new(Some(seed));
     }
 
@@ -229,6 +239,38 @@ impl EncryptionRandomGenerator {
         )
     }
 
+    // Fills the tensor with random uniform values in [0, modulus), using the mask generator.
+    pub(crate) fn fill_tensor_with_random_mask_custom_mod<Scalar, Tensorable>(
+        &mut self,
+        output: &mut Tensorable,
+        modulus: u128,
+    ) where
+        Scalar: UnsignedTorus,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        self.mask
+            .fill_tensor_with_random_uniform_custom_mod(output, modulus)
+    }
+
+    // Sample a noise value in [0, modulus), using the noise generator.
+    pub(crate) fn random_noise_custom_mod<Scalar>(
+        &mut self,
+        std: impl DispersionParameter,
+        modulus: u128,
+    ) -> Scalar
+    where
+        Scalar: UnsignedTorus,
+    {
+        let (sample, _) = <(f64, f64)>::generate_one(
+            &mut self.noise,
+            Gaussian {
+                std: std.get_standard_dev(),
+                mean: 0.,
+            },
+        );
+        modular_from_torus(sample, modulus)
+    }
+
     // Fills the input tensor with random noise, using the noise generator.
     pub(crate) fn fill_tensor_with_random_noise<Scalar, Tensorable>(
         &mut self,
@@ -9,6 +9,7 @@ use crate::backends::core::private::math::tensor::{
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
+use crate::backends::core::private::math::decomposition::Decomposer;
 use crate::backends::core::private::math::polynomial::PolynomialList;
 use crate::backends::core::private::math::random::{Gaussian, RandomGenerable};
 use crate::backends::core::private::math::torus::UnsignedTorus;
@@ -820,6 +821,99 @@ where
         }
     }
 
+    /// This function encrypts a message as a GGSW ciphertext, using the gadget values of a given
+    /// decomposer.
+    ///
+    /// The ciphertext generated this way must be used with the same decomposer, for instance with
+    /// [`FourierGgswCiphertext::external_product_with_decomposer`]. The base log stored in the
+    /// ciphertext is not used by this method.
+    ///
+    /// [`FourierGgswCiphertext::external_product_with_decomposer`]:
+    /// crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext::external_product_with_decomposer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+    /// use concrete_core::backends::core::private::crypto::ggsw::StandardGgswCiphertext;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// let mut generator = SecretRandomGenerator::new(None);
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(10), &mut generator);
+    /// let decomposer = GeneralBaseDecomposer::<u32>::new(12, DecompositionLevelCount(3));
+    /// let mut ciphertext = StandardGgswCiphertext::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(10),
+    ///     GlweSize(3),
+    ///     decomposer.level_count(),
+    ///     DecompositionBaseLog(0),
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let mut secret_generator = EncryptionRandomGenerator::new(None);
+    /// secret_key.encrypt_constant_ggsw_with_decomposer(
+    ///     &mut ciphertext,
+    ///     &Plaintext(10),
+    ///     noise,
+    ///     &mut secret_generator,
+    ///     &decomposer,
+    /// );
+    /// ```
+    pub fn encrypt_constant_ggsw_with_decomposer<OutputCont, Scalar, Decomp>(
+        &self,
+        encrypted: &mut StandardGgswCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        decomposer: &Decomp,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        StandardGgswCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        OutputCont: AsMutSlice<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Decomp: Decomposer<Scalar>,
+    {
+        ck_dim_eq!(self.polynomial_size() => encrypted.polynomial_size());
+        ck_dim_eq!(self.key_size() => encrypted.glwe_size().to_glwe_dimension());
+        ck_dim_eq!(encrypted.decomposition_level_count() => decomposer.level_count());
+        let gen_iter = generator
+            .fork_ggsw_to_ggsw_levels::<Scalar>(
+                encrypted.decomposition_level_count(),
+                self.key_size().to_glwe_size(),
+                self.poly_size,
+            )
+            .expect("Failed to split generator into ggsw levels");
+        for (mut matrix, mut generator) in encrypted.level_matrix_iter_mut().zip(gen_iter) {
+            let decomposition = encoded
+                .0
+                .wrapping_mul(decomposer.gadget_value(matrix.decomposition_level()));
+            let gen_iter = generator
+                .fork_ggsw_level_to_glwe::<Scalar>(self.key_size().to_glwe_size(), self.poly_size)
+                .expect("Failed to split generator into rlwe");
+            // We iterate over the rowe of the level matrix
+            for ((index, row), mut generator) in matrix.row_iter_mut().enumerate().zip(gen_iter) {
+                let mut rlwe_ct = row.into_glwe();
+                // We issue a fresh  encryption of zero
+                self.encrypt_zero_glwe(&mut rlwe_ct, noise_parameters, &mut generator);
+                // We retrieve the row as a polynomial list
+                let mut polynomial_list = rlwe_ct.into_polynomial_list();
+                // We retrieve the polynomial in the diagonal
+                let mut level_polynomial = polynomial_list.get_mut_polynomial(index);
+                // We get the first coefficient
+                let first_coef = level_polynomial.as_mut_tensor().first_mut();
+                // We update the first coefficient
+                *first_coef = first_coef.wrapping_add(decomposition);
+            }
+        }
+    }
+
     /// This function encrypts a message as a GGSW ciphertext, using as many threads as possible.
     ///
     /// # Notes
@@ -10,7 +10,7 @@ use concrete_commons::key_kinds::{
     BinaryKeyKind, GaussianKeyKind, KeyKind, TernaryKeyKind, UniformKeyKind,
 };
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::LweDimension;
+use concrete_commons::parameters::{CiphertextModulus, LweDimension};
 
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
 use crate::backends::core::private::crypto::gsw::GswCiphertext;
@@ -22,7 +22,9 @@ use crate::backends::core::private::math::random::{Gaussian, RandomGenerable};
 use crate::backends::core::private::math::tensor::{
     ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
-use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::math::torus::{
+    modular_add, modular_multisum, modular_sub, UnsignedTorus,
+};
 
 /// A LWE secret key.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -328,6 +330,11 @@ where
         LweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        if let CiphertextModulus::Custom(modulus) = output.ciphertext_modulus() {
+            self.encrypt_lwe_custom_mod(output, encoded, noise_parameters, generator, modulus);
+            return;
+        }
+
         let (output_body, mut output_masks) = output.get_mut_body_and_mask();
 
         // generate a uniformly random mask
@@ -345,6 +352,40 @@ where
         output_body.0 = output_body.0.wrapping_add(encoded.0);
     }
 
+    // Encrypts a single ciphertext whose coefficients live modulo a custom modulus. The encoded
+    // message is expected to be already reduced modulo this modulus.
+    fn encrypt_lwe_custom_mod<OutputCont, Scalar>(
+        &self,
+        output: &mut LweCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+        modulus: u128,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let (output_body, mut output_masks) = output.get_mut_body_and_mask();
+
+        // generate a mask uniformly random in [0, modulus)
+        generator.fill_tensor_with_random_mask_custom_mod(&mut output_masks, modulus);
+
+        // generate an error from the normal distribution described by std_dev
+        output_body.0 = generator.random_noise_custom_mod(noise_parameters, modulus);
+
+        // compute the multisum between the secret key and the mask
+        let multisum = modular_multisum(
+            output_masks.as_tensor().iter(),
+            self.as_tensor().iter(),
+            modulus,
+        );
+        output_body.0 = modular_add(output_body.0, multisum, modulus);
+
+        // add the encoded message
+        output_body.0 = modular_add(output_body.0, encoded.0, modulus);
+    }
+
     /// Encrypts a list of ciphertexts.
     ///
     /// # Example
@@ -427,10 +468,19 @@ where
         Scalar: UnsignedTorus,
     {
         let (body, masks) = cipher.get_body_and_mask();
-        // put body inside result
-        output.0 = body.0;
-        // subtract the multisum between the key and the mask
-        output.0 = output.0.wrapping_sub(masks.compute_multisum(self));
+        match cipher.ciphertext_modulus() {
+            CiphertextModulus::Native => {
+                // put body inside result
+                output.0 = body.0;
+                // subtract the multisum between the key and the mask
+                output.0 = output.0.wrapping_sub(masks.compute_multisum(self));
+            }
+            CiphertextModulus::Custom(modulus) => {
+                let multisum =
+                    modular_multisum(masks.as_tensor().iter(), self.as_tensor().iter(), modulus);
+                output.0 = modular_sub(body.0, multisum, modulus);
+            }
+        }
     }
 
     /// Decrypts a list of ciphertexts.
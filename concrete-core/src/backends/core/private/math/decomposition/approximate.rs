@@ -0,0 +1,181 @@
+use crate::backends::core::private::math::decomposition::{
+    Decomposer, DecompositionLevel, SignedDecomposer,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// A signed decomposer which intentionally drops the lowest levels of the decomposition.
+///
+/// With a base $B=2^b$, $l$ kept levels and $d$ dropped levels, the input $\theta$ is first
+/// decomposed over $l+d$ levels as in the [`SignedDecomposer`]:
+/// $$
+///     \theta \approx \sum_{i=1}^{l+d}\tilde{\theta}_i\frac{q}{B^i}
+/// $$
+/// The $d$ lowest terms are then discarded, and only $(\tilde{\theta}_i)_{i\in\[1,l\]}$ are
+/// returned. Contrary to a signed decomposer over $l$ levels, the value of the dropped terms is
+/// not propagated to the kept ones. The kept digits are hence the most significant digits of a
+/// finer decomposition, and the gadget vector is the same as the one of a
+/// [`SignedDecomposer`] with base $B$ and $l$ levels: keys generated for the latter can be used
+/// with this decomposer.
+///
+/// The approximation error can be bounded with
+/// [`ApproximateSignedDecomposer::approximation_error_bound`].
+#[derive(Debug)]
+pub struct ApproximateSignedDecomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    exact: SignedDecomposer<Scalar>,
+    level_count: usize,
+    dropped_level_count: usize,
+}
+
+impl<Scalar> ApproximateSignedDecomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Creates a new approximate decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::backends::core::private::math::decomposition::ApproximateSignedDecomposer;
+    /// let decomposer = ApproximateSignedDecomposer::<u32>::new(
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionLevelCount(2),
+    /// );
+    /// assert_eq!(decomposer.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(decomposer.dropped_level_count(), DecompositionLevelCount(2));
+    /// ```
+    pub fn new(
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+        dropped_level_count: DecompositionLevelCount,
+    ) -> ApproximateSignedDecomposer<Scalar> {
+        debug_assert!(
+            level_count.0 > 0,
+            "The decomposition must keep at least one level"
+        );
+        ApproximateSignedDecomposer {
+            exact: SignedDecomposer::new(
+                base_log,
+                DecompositionLevelCount(level_count.0 + dropped_level_count.0),
+            ),
+            level_count: level_count.0,
+            dropped_level_count: dropped_level_count.0,
+        }
+    }
+
+    /// Returns the logarithm in base two of the base of this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::backends::core::private::math::decomposition::ApproximateSignedDecomposer;
+    /// let decomposer = ApproximateSignedDecomposer::<u32>::new(
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionLevelCount(2),
+    /// );
+    /// assert_eq!(decomposer.base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.exact.base_log()
+    }
+
+    /// Returns the number of levels kept by this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::backends::core::private::math::decomposition::ApproximateSignedDecomposer;
+    /// let decomposer = ApproximateSignedDecomposer::<u32>::new(
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionLevelCount(2),
+    /// );
+    /// assert_eq!(decomposer.level_count(), DecompositionLevelCount(3));
+    /// ```
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.level_count)
+    }
+
+    /// Returns the number of levels dropped by this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::backends::core::private::math::decomposition::ApproximateSignedDecomposer;
+    /// let decomposer = ApproximateSignedDecomposer::<u32>::new(
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionLevelCount(2),
+    /// );
+    /// assert_eq!(decomposer.dropped_level_count(), DecompositionLevelCount(2));
+    /// ```
+    pub fn dropped_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.dropped_level_count)
+    }
+
+    /// Returns a bound on the distance between an input and its decomposition.
+    ///
+    /// The error is made of the rounding of the exact decomposition over $l+d$ levels, and of
+    /// the $d$ dropped terms, which gives:
+    /// $$
+    ///     \frac{q}{2B^{l+d}} + \sum_{i=l+1}^{l+d}\frac{B}{2}\frac{q}{B^i}
+    /// $$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::backends::core::private::math::decomposition::ApproximateSignedDecomposer;
+    /// let decomposer = ApproximateSignedDecomposer::<u32>::new(
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionLevelCount(2),
+    /// );
+    /// assert_eq!(
+    ///     decomposer.approximation_error_bound(),
+    ///     (1 << 11) + (1 << 19) + (1 << 15)
+    /// );
+    /// ```
+    pub fn approximation_error_bound(&self) -> Scalar {
+        let base_log = self.exact.base_log().0;
+        let total_level_count = self.level_count + self.dropped_level_count;
+        let rounding_error =
+            1u128 << (<Scalar as Numeric>::BITS - base_log * total_level_count - 1);
+        let dropped_error: u128 = (self.level_count + 1..=total_level_count)
+            .map(|level| 1u128 << (<Scalar as Numeric>::BITS + base_log - 1 - base_log * level))
+            .sum();
+        Scalar::cast_from(rounding_error + dropped_error)
+    }
+}
+
+impl<Scalar> Decomposer<Scalar> for ApproximateSignedDecomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    fn level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.level_count)
+    }
+
+    fn gadget_value(&self, level: DecompositionLevel) -> Scalar {
+        self.exact.gadget_value(level)
+    }
+
+    fn decompose_into(&self, input: Scalar, output: &mut [Scalar]) {
+        assert_eq!(output.len(), self.level_count);
+        for term in self.exact.decompose(input) {
+            if term.level().0 <= self.level_count {
+                output[term.level().0 - 1] = term.value();
+            }
+        }
+    }
+}
@@ -1,9 +1,14 @@
 use crate::backends::core::private::math::decomposition::{
-    SignedDecompositionIter, TensorSignedDecompositionIter,
+    Decomposer, DecompositionLevel, SignedDecompositionIter, TensorSignedDecompositionIter,
+};
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor, AsRefTensor, Tensor};
+use crate::backends::core::private::math::torus::{
+    modular_add, modular_to_native, native_to_modular, UnsignedTorus,
 };
-use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
 use concrete_commons::numeric::{Numeric, UnsignedInteger};
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+};
 use std::marker::PhantomData;
 
 /// A structure which allows to decompose unsigned integers into a set of smaller terms.
@@ -16,12 +21,13 @@ where
 {
     pub(super) base_log: usize,
     pub(super) level_count: usize,
+    pub(super) ciphertext_modulus: CiphertextModulus,
     integer_type: PhantomData<Scalar>,
 }
 
 impl<Scalar> SignedDecomposer<Scalar>
 where
-    Scalar: UnsignedInteger,
+    Scalar: UnsignedTorus,
 {
     /// Creates a new decomposer.
     ///
@@ -43,13 +49,69 @@ where
             Scalar::BITS > base_log.0 * level_count.0,
             "Decomposed bits exceeds the size of the integer to be decomposed"
         );
+        Self::new_with_modulus(base_log, level_count, CiphertextModulus::Native)
+    }
+
+    /// Creates a new decomposer, operating on integers living modulo the given ciphertext
+    /// modulus.
+    ///
+    /// With a custom modulus $q$, the inputs are first mapped to the closest value of the native
+    /// torus representation, which is then decomposed. The recomposition performs the opposite
+    /// mapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+    /// };
+    /// use concrete_core::backends::core::private::math::decomposition::SignedDecomposer;
+    /// let modulus = CiphertextModulus::Custom((1 << 31) - 1);
+    /// let decomposer = SignedDecomposer::<u32>::new_with_modulus(
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(3),
+    ///     modulus,
+    /// );
+    /// assert_eq!(decomposer.ciphertext_modulus(), modulus);
+    /// ```
+    pub fn new_with_modulus(
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> SignedDecomposer<Scalar> {
+        debug_assert!(
+            Scalar::BITS > base_log.0 * level_count.0,
+            "Decomposed bits exceeds the size of the integer to be decomposed"
+        );
+        debug_assert!(
+            ciphertext_modulus.is_compatible_with_bits(Scalar::BITS),
+            "The ciphertext modulus is not compatible with the integer type"
+        );
         SignedDecomposer {
             base_log: base_log.0,
             level_count: level_count.0,
+            ciphertext_modulus,
             integer_type: PhantomData,
         }
     }
 
+    /// Returns the ciphertext modulus of the integers decomposed by this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+    /// };
+    /// use concrete_core::backends::core::private::math::decomposition::SignedDecomposer;
+    /// let decomposer =
+    ///     SignedDecomposer::<u32>::new(DecompositionBaseLog(4), DecompositionLevelCount(3));
+    /// assert_eq!(decomposer.ciphertext_modulus(), CiphertextModulus::Native);
+    /// ```
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext_modulus
+    }
+
     /// Returns the logarithm in base two of the base of this decomposer.
     ///
     /// If the decomposer uses a base $B=2^b$, this returns $b$.
@@ -97,6 +159,33 @@ where
     /// assert_eq!(closest, 1_341_128_704_u32);
     /// ```
     pub fn closest_representable(&self, input: Scalar) -> Scalar {
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => self.native_closest_representable(input),
+            CiphertextModulus::Custom(modulus) => native_to_modular(
+                self.native_closest_representable(modular_to_native(input, modulus)),
+                modulus,
+            ),
+        }
+    }
+
+    // Maps the input to the native torus representation, if a custom modulus is used.
+    fn map_to_native(&self, input: Scalar) -> Scalar {
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => input,
+            CiphertextModulus::Custom(modulus) => modular_to_native(input, modulus),
+        }
+    }
+
+    // Maps the input from the native torus representation, if a custom modulus is used.
+    fn map_from_native(&self, input: Scalar) -> Scalar {
+        match self.ciphertext_modulus {
+            CiphertextModulus::Native => input,
+            CiphertextModulus::Custom(modulus) => native_to_modular(input, modulus),
+        }
+    }
+
+    // Computes the closest representable value, for an input in the native representation.
+    fn native_closest_representable(&self, input: Scalar) -> Scalar {
         // The closest number representable by the decomposition can be computed by performing
         // the rounding at the appropriate bit.
 
@@ -170,7 +259,7 @@ where
         // Note that there would be no sense of making the decomposition on an input which was
         // not rounded to the closest representable first. We then perform it before decomposing.
         SignedDecompositionIter::new(
-            self.closest_representable(input),
+            self.native_closest_representable(self.map_to_native(input)),
             DecompositionBaseLog(self.base_log),
             DecompositionLevelCount(self.level_count),
         )
@@ -195,9 +284,10 @@ where
     /// ```
     pub fn recompose(&self, decomp: SignedDecompositionIter<Scalar>) -> Option<Scalar> {
         if decomp.is_fresh() {
-            Some(decomp.fold(Scalar::ZERO, |acc, term| {
+            let native = decomp.fold(Scalar::ZERO, |acc, term| {
                 acc.wrapping_add(term.to_recomposition_summand())
-            }))
+            });
+            Some(self.map_from_native(native))
         } else {
             None
         }
@@ -244,7 +334,9 @@ where
         // Note that there would be no sense of making the decomposition on an input which was
         // not rounded to the closest representable first. We then perform it before decomposing.
         let mut rounded = Tensor::allocate(Scalar::ZERO, input.as_tensor().len());
-        self.fill_tensor_with_closest_representable(&mut rounded, input);
+        rounded.fill_with_one(input.as_tensor(), |elmt| {
+            self.native_closest_representable(self.map_to_native(*elmt))
+        });
         TensorSignedDecompositionIter::new(
             rounded,
             DecompositionBaseLog(self.base_log),
@@ -285,8 +377,24 @@ where
     {
         let mut decomp = decomp;
         if decomp.is_fresh() {
-            while let Some(term) = decomp.next_term() {
-                term.update_tensor_with_recomposition_summand_wrapping_addition(output);
+            match self.ciphertext_modulus {
+                CiphertextModulus::Native => {
+                    while let Some(term) = decomp.next_term() {
+                        term.update_tensor_with_recomposition_summand_wrapping_addition(output);
+                    }
+                }
+                CiphertextModulus::Custom(modulus) => {
+                    // We recompose in the native representation before mapping back the result.
+                    let mut native = Tensor::allocate(Scalar::ZERO, output.as_mut_tensor().len());
+                    while let Some(term) = decomp.next_term() {
+                        term.update_tensor_with_recomposition_summand_wrapping_addition(
+                            &mut native,
+                        );
+                    }
+                    output.as_mut_tensor().update_with_one(&native, |o, n| {
+                        *o = modular_add(*o, native_to_modular(*n, modulus), modulus)
+                    });
+                }
             }
             Some(())
         } else {
@@ -294,3 +402,56 @@ where
         }
     }
 }
+
+impl<Scalar> Decomposer<Scalar> for SignedDecomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    fn level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.level_count)
+    }
+
+    fn gadget_value(&self, level: DecompositionLevel) -> Scalar {
+        Scalar::ONE << (<Scalar as Numeric>::BITS - self.base_log * level.0)
+    }
+
+    fn decompose_into(&self, input: Scalar, output: &mut [Scalar]) {
+        assert_eq!(output.len(), self.level_count);
+        for term in self.decompose(input) {
+            output[term.level().0 - 1] = term.value();
+        }
+    }
+
+    fn recompose(&self, digits: &[Scalar]) -> Scalar {
+        debug_assert_eq!(digits.len(), self.level_count);
+        let native = digits
+            .iter()
+            .enumerate()
+            .fold(Scalar::ZERO, |acc, (index, digit)| {
+                acc.wrapping_add(
+                    *digit << (<Scalar as Numeric>::BITS - self.base_log * (index + 1)),
+                )
+            });
+        self.map_from_native(native)
+    }
+
+    fn closest_representable(&self, input: Scalar) -> Scalar {
+        SignedDecomposer::closest_representable(self, input)
+    }
+
+    fn fill_tensor_with_decomposition<I, O>(&self, output: &mut O, input: &I)
+    where
+        I: AsRefTensor<Element = Scalar>,
+        O: AsMutTensor<Element = Scalar>,
+    {
+        let len = input.as_tensor().len();
+        ck_dim_eq!(output.as_mut_tensor().len() => len * self.level_count);
+        let mut decomposition = self.decompose_tensor(input);
+        while let Some(term) = decomposition.next_term() {
+            output
+                .as_mut_tensor()
+                .get_sub_mut((term.level().0 - 1) * len..term.level().0 * len)
+                .fill_with_one(term.as_tensor(), |digit| *digit);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::backends::core::private::math::decomposition::{Decomposer, DecompositionLevel};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::DecompositionLevelCount;
+use std::marker::PhantomData;
+
+/// A decomposer using an arbitrary base, and an arbitrary range of digits.
+///
+/// Given a base $B\geq 2$ (not necessarily a power of two), a number of levels $l$ such that
+/// $B^l\leq q$, and a lowest digit $m\in[-(B-1), 0]$, this decomposer decomposes an input
+/// $\theta$ as:
+/// $$
+///     \theta \approx \sum_{i=1}^l\tilde{\theta}_ig_i
+/// $$
+/// with $g_i = \lfloor\frac{q}{B^i}\rceil$ and digits $\tilde{\theta}_i\in[m, m+B-1]$.
+///
+/// The input is first rounded to the closest multiple of $\frac{q}{B^l}$, which is then
+/// decomposed exactly in base $B$. When $B$ is not a power of two, the gadget values are not
+/// exact, and the recomposition carries an additional error of at most $\frac{l(B-1)}{2}$.
+///
+/// Choosing $m=-\lfloor\frac{B}{2}\rfloor$ gives a balanced decomposition, while $m=0$ gives the
+/// usual unsigned decomposition.
+///
+/// # Note
+///
+/// This decomposer only supports integers of at most 64 bits.
+#[derive(Debug)]
+pub struct GeneralBaseDecomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    base: u128,
+    level_count: usize,
+    lowest_digit: i128,
+    integer_type: PhantomData<Scalar>,
+}
+
+impl<Scalar> GeneralBaseDecomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Creates a new balanced decomposer.
+    ///
+    /// The digits of the decomposition lie in $[-\lfloor\frac{B}{2}\rfloor, B-1-\lfloor\frac{B}{2}
+    /// \rfloor]$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::DecompositionLevelCount;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// let decomposer = GeneralBaseDecomposer::<u64>::new(3, DecompositionLevelCount(20));
+    /// assert_eq!(decomposer.base(), 3);
+    /// assert_eq!(decomposer.lowest_digit(), -1);
+    /// assert_eq!(decomposer.highest_digit(), 1);
+    /// ```
+    pub fn new(base: usize, level_count: DecompositionLevelCount) -> GeneralBaseDecomposer<Scalar> {
+        Self::new_unbalanced(base, level_count, -((base / 2) as isize))
+    }
+
+    /// Creates a new decomposer whose digits lie in $[m, m+B-1]$, where $m$ is the given lowest
+    /// digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is smaller than two, if $B^l$ exceeds the modulus, if the integer type
+    /// has more than 64 bits, or if the lowest digit is not in $[-(B-1), 0]$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::DecompositionLevelCount;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// let decomposer =
+    ///     GeneralBaseDecomposer::<u32>::new_unbalanced(10, DecompositionLevelCount(5), -2);
+    /// assert_eq!(decomposer.lowest_digit(), -2);
+    /// assert_eq!(decomposer.highest_digit(), 7);
+    /// ```
+    pub fn new_unbalanced(
+        base: usize,
+        level_count: DecompositionLevelCount,
+        lowest_digit: isize,
+    ) -> GeneralBaseDecomposer<Scalar> {
+        assert!(
+            <Scalar as Numeric>::BITS <= 64,
+            "General base decomposition is only supported for integers of at most 64 bits"
+        );
+        assert!(base >= 2, "The decomposition base must be at least two");
+        assert!(
+            level_count.0 > 0,
+            "The decomposition must have at least one level"
+        );
+        assert!(
+            (base as u128)
+                .checked_pow(level_count.0 as u32)
+                .is_some_and(|power| power <= 1u128 << <Scalar as Numeric>::BITS),
+            "Decomposed digits exceed the size of the integer to be decomposed"
+        );
+        assert!(
+            lowest_digit <= 0 && lowest_digit > -(base as isize),
+            "The lowest digit must be in [-(base-1), 0]"
+        );
+        GeneralBaseDecomposer {
+            base: base as u128,
+            level_count: level_count.0,
+            lowest_digit: lowest_digit as i128,
+            integer_type: PhantomData,
+        }
+    }
+
+    /// Returns the base of this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::DecompositionLevelCount;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// let decomposer = GeneralBaseDecomposer::<u32>::new(5, DecompositionLevelCount(4));
+    /// assert_eq!(decomposer.base(), 5);
+    /// ```
+    pub fn base(&self) -> usize {
+        self.base as usize
+    }
+
+    /// Returns the number of levels of this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::DecompositionLevelCount;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// let decomposer = GeneralBaseDecomposer::<u32>::new(5, DecompositionLevelCount(4));
+    /// assert_eq!(decomposer.level_count(), DecompositionLevelCount(4));
+    /// ```
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.level_count)
+    }
+
+    /// Returns the smallest digit output by this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::DecompositionLevelCount;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// let decomposer =
+    ///     GeneralBaseDecomposer::<u32>::new_unbalanced(5, DecompositionLevelCount(4), 0);
+    /// assert_eq!(decomposer.lowest_digit(), 0);
+    /// ```
+    pub fn lowest_digit(&self) -> isize {
+        self.lowest_digit as isize
+    }
+
+    /// Returns the largest digit output by this decomposer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::DecompositionLevelCount;
+    /// use concrete_core::backends::core::private::math::decomposition::GeneralBaseDecomposer;
+    /// let decomposer =
+    ///     GeneralBaseDecomposer::<u32>::new_unbalanced(5, DecompositionLevelCount(4), 0);
+    /// assert_eq!(decomposer.highest_digit(), 4);
+    /// ```
+    pub fn highest_digit(&self) -> isize {
+        (self.lowest_digit + self.base as i128 - 1) as isize
+    }
+
+    // Returns B^l.
+    fn base_power(&self) -> u128 {
+        self.base.pow(self.level_count as u32)
+    }
+}
+
+impl<Scalar> Decomposer<Scalar> for GeneralBaseDecomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    fn level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.level_count)
+    }
+
+    fn gadget_value(&self, level: DecompositionLevel) -> Scalar {
+        debug_assert!(1 <= level.0 && level.0 <= self.level_count);
+        let divisor = self.base.pow(level.0 as u32);
+        let modulus = 1u128 << <Scalar as Numeric>::BITS;
+        Scalar::cast_from((modulus + divisor / 2) / divisor)
+    }
+
+    fn decompose_into(&self, input: Scalar, output: &mut [Scalar]) {
+        assert_eq!(output.len(), self.level_count);
+        let base_power = self.base_power();
+        // We round the input to the closest multiple of q / B^l. As B^l <= q <= 2^64, the product
+        // fits in 128 bits.
+        let input: u128 = input.cast_into();
+        let mut state = ((input * base_power + (1u128 << (<Scalar as Numeric>::BITS - 1)))
+            >> <Scalar as Numeric>::BITS)
+            % base_power;
+        // We extract the digits, starting from the least significant one.
+        let highest_digit = self.lowest_digit + self.base as i128 - 1;
+        for digit in output.iter_mut().rev() {
+            let mut value = (state % self.base) as i128;
+            state /= self.base;
+            if value > highest_digit {
+                value -= self.base as i128;
+                state += 1;
+            }
+            *digit = Scalar::cast_from(value as u128);
+        }
+    }
+}
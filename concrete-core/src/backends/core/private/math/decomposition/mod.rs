@@ -17,20 +17,38 @@
 //! is no longer an approximation, and becomes exact. The rationale behind using an approximate
 //! decomposition like that, is that when using this decomposition the approximation error will be
 //! located in the least significant bits, which are already erroneous.
+//!
+//! # Alternative decomposers
+//!
+//! Some algorithms benefit from other decompositions. This module also provides:
+//!
+//! + An [`ApproximateSignedDecomposer`], which intentionally drops the lowest levels of a signed
+//!   decomposition, without propagating them to the kept levels.
+//! + A [`GeneralBaseDecomposer`], which decomposes over a base which is not necessarily a power of
+//!   two, with a configurable (possibly unbalanced) range of digits.
+//!
+//! All the decomposers implement the [`Decomposer`] trait, over which the keyswitch and external
+//! product algorithms are generic.
 use std::fmt::Debug;
 
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
+pub use approximate::*;
 pub use decomposer::*;
+pub use general_base::*;
 pub use iter::*;
 pub use term::*;
+pub use traits::*;
 
+mod approximate;
 mod decomposer;
+mod general_base;
 mod iter;
 mod term;
 #[cfg(test)]
 mod tests;
+mod traits;
 
 /// The level of a given term of a decomposition.
 ///
@@ -1,14 +1,19 @@
-use crate::backends::core::private::math::decomposition::SignedDecomposer;
-use crate::backends::core::private::math::random::{RandomGenerable, Uniform};
+use crate::backends::core::private::math::decomposition::{
+    ApproximateSignedDecomposer, Decomposer, GeneralBaseDecomposer, SignedDecomposer,
+};
 use crate::backends::core::private::math::tensor::Tensor;
-use crate::backends::core::private::math::torus::UnsignedTorus;
-use crate::backends::core::private::test_tools::{any_uint, any_usize, random_usize_between};
-use concrete_commons::numeric::{Numeric, SignedInteger, UnsignedInteger};
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::backends::core::private::math::torus::{modular_sub, UnsignedTorus};
+use crate::backends::core::private::test_tools::{
+    any_uint, any_usize, random_uint_between, random_usize_between,
+};
+use concrete_commons::numeric::{CastInto, Numeric, SignedInteger, UnsignedInteger};
+use concrete_commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+};
 use std::fmt::Debug;
 
 // Returns a random decomposition valid for the size of the T type.
-fn random_decomp<T: UnsignedInteger>() -> SignedDecomposer<T> {
+fn random_decomp<T: UnsignedTorus>() -> SignedDecomposer<T> {
     let mut base_log;
     let mut level_count;
     loop {
@@ -24,7 +29,7 @@ fn random_decomp<T: UnsignedInteger>() -> SignedDecomposer<T> {
     )
 }
 
-fn test_decompose_recompose<T: UnsignedInteger + Debug + RandomGenerable<Uniform>>()
+fn test_decompose_recompose<T: UnsignedTorus + Debug>()
 where
     <T as UnsignedInteger>::Signed: Debug + SignedInteger,
 {
@@ -58,7 +63,12 @@ fn test_decompose_recompose_u64() {
     test_decompose_recompose::<u64>()
 }
 
-fn test_decompose_recompose_tensor<T: UnsignedInteger + Debug + RandomGenerable<Uniform>>()
+#[test]
+fn test_decompose_recompose_u128() {
+    test_decompose_recompose::<u128>()
+}
+
+fn test_decompose_recompose_tensor<T: UnsignedTorus + Debug>()
 where
     <T as UnsignedInteger>::Signed: Debug + SignedInteger,
 {
@@ -94,6 +104,11 @@ fn test_decompose_recompose_tensor_u64() {
     test_decompose_recompose_tensor::<u64>()
 }
 
+#[test]
+fn test_decompose_recompose_tensor_u128() {
+    test_decompose_recompose_tensor::<u128>()
+}
+
 fn test_round_to_closest_representable<T: UnsignedTorus>() {
     for _ in 0..1000 {
         let log_b = any_usize();
@@ -134,6 +149,11 @@ fn test_round_to_closest_representable_u64() {
     test_round_to_closest_representable::<u64>();
 }
 
+#[test]
+fn test_round_to_closest_representable_u128() {
+    test_round_to_closest_representable::<u128>();
+}
+
 fn test_round_to_closest_twice<T: UnsignedTorus + Debug>() {
     for _ in 0..1000 {
         let decomp = random_decomp();
@@ -155,6 +175,11 @@ fn test_round_to_closest_twice_u64() {
     test_round_to_closest_twice::<u64>();
 }
 
+#[test]
+fn test_round_to_closest_twice_u128() {
+    test_round_to_closest_twice::<u128>();
+}
+
 fn test_round_tensor_to_closest_twice<T: UnsignedTorus + Debug>() {
     for _ in 0..1000 {
         let decomp = random_decomp();
@@ -178,3 +203,199 @@ fn test_round_tensor_to_closest_twice_u32() {
 fn test_round_tensor_to_closest_twice_u64() {
     test_round_tensor_to_closest_twice::<u64>();
 }
+
+#[test]
+fn test_round_tensor_to_closest_twice_u128() {
+    test_round_tensor_to_closest_twice::<u128>();
+}
+
+fn test_decompose_recompose_custom_modulus<T: UnsignedTorus + Debug>() {
+    // Checks that the decomposition of a value modulo a custom modulus recomposes to the closest
+    // representable value, which lies close to the input.
+    for _ in 0..100_000 {
+        let base_decomposer = random_decomp::<T>();
+        let modulus = random_uint_between::<u128>(2..(1u128 << T::BITS));
+        let decomposer = SignedDecomposer::<T>::new_with_modulus(
+            base_decomposer.base_log(),
+            base_decomposer.level_count(),
+            CiphertextModulus::Custom(modulus),
+        );
+        let input = T::cast_from(CastInto::<u128>::cast_into(any_uint::<T>()) % modulus);
+        let closest = decomposer.closest_representable(input);
+        assert!(CastInto::<u128>::cast_into(closest) < modulus);
+        assert_eq!(
+            closest,
+            decomposer.recompose(decomposer.decompose(input)).unwrap()
+        );
+        let represented_bits = decomposer.base_log * decomposer.level_count;
+        let bound = (modulus >> (represented_bits + 1)) + 1;
+        let dist_1: u128 = modular_sub(closest, input, modulus).cast_into();
+        let dist_2: u128 = modular_sub(input, closest, modulus).cast_into();
+        assert!(dist_1.min(dist_2) <= bound);
+    }
+}
+
+#[test]
+fn test_decompose_recompose_custom_modulus_u32() {
+    test_decompose_recompose_custom_modulus::<u32>()
+}
+
+#[test]
+fn test_decompose_recompose_custom_modulus_u64() {
+    test_decompose_recompose_custom_modulus::<u64>()
+}
+
+fn modular_distance<T: UnsignedTorus>(first: T, second: T) -> T {
+    first.wrapping_sub(second).min(second.wrapping_sub(first))
+}
+
+fn test_decomposer_trait_signed<T: UnsignedTorus + Debug>() {
+    // Checks that the trait implementation of the signed decomposer agrees with the iterators.
+    for _ in 0..100_000 {
+        let decomposer = random_decomp::<T>();
+        let input = any_uint::<T>();
+        let mut digits = vec![T::ZERO; decomposer.level_count];
+        Decomposer::decompose_into(&decomposer, input, digits.as_mut_slice());
+        for term in decomposer.decompose(input) {
+            assert_eq!(digits[term.level().0 - 1], term.value());
+        }
+        assert_eq!(
+            Decomposer::recompose(&decomposer, digits.as_slice()),
+            decomposer.closest_representable(input)
+        );
+    }
+}
+
+#[test]
+fn test_decomposer_trait_signed_u32() {
+    test_decomposer_trait_signed::<u32>()
+}
+
+#[test]
+fn test_decomposer_trait_signed_u64() {
+    test_decomposer_trait_signed::<u64>()
+}
+
+fn test_approximate_decompose_recompose<T: UnsignedTorus + Debug>() {
+    // Checks that the approximate decomposition keeps the most significant digits of the exact
+    // decomposition, and that the approximation error is bounded.
+    for _ in 0..100_000 {
+        let exact = random_decomp::<T>();
+        let dropped_level_count = random_usize_between(0..exact.level_count);
+        let level_count = exact.level_count - dropped_level_count;
+        let decomposer = ApproximateSignedDecomposer::<T>::new(
+            exact.base_log(),
+            DecompositionLevelCount(level_count),
+            DecompositionLevelCount(dropped_level_count),
+        );
+        let input = any_uint::<T>();
+        let mut digits = vec![T::ZERO; level_count];
+        decomposer.decompose_into(input, digits.as_mut_slice());
+        for term in exact.decompose(input) {
+            if term.level().0 <= level_count {
+                assert_eq!(digits[term.level().0 - 1], term.value());
+            }
+        }
+        let recomposition = decomposer.recompose(digits.as_slice());
+        assert!(modular_distance(recomposition, input) <= decomposer.approximation_error_bound());
+    }
+}
+
+#[test]
+fn test_approximate_decompose_recompose_u32() {
+    test_approximate_decompose_recompose::<u32>()
+}
+
+#[test]
+fn test_approximate_decompose_recompose_u64() {
+    test_approximate_decompose_recompose::<u64>()
+}
+
+// Returns a random general base decomposition valid for the size of the T type.
+fn random_general_base_decomp<T: UnsignedTorus>() -> GeneralBaseDecomposer<T> {
+    let base = random_usize_between(2..1000);
+    let max_level_count = (T::BITS as f64 / (base as f64).log2()).floor() as usize;
+    let level_count = random_usize_between(1..max_level_count + 1);
+    let lowest_digit = -(random_usize_between(0..base) as isize);
+    GeneralBaseDecomposer::new_unbalanced(base, DecompositionLevelCount(level_count), lowest_digit)
+}
+
+fn test_general_base_decompose_recompose<T: UnsignedTorus + Debug>()
+where
+    <T as UnsignedInteger>::Signed: Debug + SignedInteger,
+{
+    // Checks that the digits lie in the expected range, and that the recomposition is close to the
+    // input.
+    for _ in 0..100_000 {
+        let decomposer = random_general_base_decomp::<T>();
+        let level_count = decomposer.level_count().0;
+        let input = any_uint::<T>();
+        let mut digits = vec![T::ZERO; level_count];
+        decomposer.decompose_into(input, digits.as_mut_slice());
+        for digit in digits.iter() {
+            let signed_digit: i128 = digit.into_signed().cast_into() as i128;
+            assert!(decomposer.lowest_digit() as i128 <= signed_digit);
+            assert!(signed_digit <= decomposer.highest_digit() as i128);
+        }
+        let recomposition = decomposer.recompose(digits.as_slice());
+        let base_power = (decomposer.base() as f64).powi(level_count as i32);
+        let bound = 2f64.powi(T::BITS as i32) / (2. * base_power)
+            + (level_count * decomposer.base()) as f64;
+        let distance: f64 = modular_distance(recomposition, input).cast_into();
+        assert!(distance <= bound);
+        assert_eq!(decomposer.closest_representable(input), recomposition);
+    }
+}
+
+#[test]
+fn test_general_base_decompose_recompose_u32() {
+    test_general_base_decompose_recompose::<u32>()
+}
+
+#[test]
+fn test_general_base_decompose_recompose_u64() {
+    test_general_base_decompose_recompose::<u64>()
+}
+
+fn test_decompose_tensor_with_trait<T: UnsignedTorus + Debug>() {
+    // Checks that the tensor decomposition stores the digits of each level contiguously, for the
+    // signed decomposer and the default implementation.
+    for _ in 0..1_000 {
+        let signed = random_decomp::<T>();
+        let general = random_general_base_decomp::<T>();
+        let len = random_usize_between(1..100);
+        let mut input = Tensor::allocate(T::ZERO, len);
+        input.fill_with(any_uint::<T>);
+        check_tensor_decomposition(&signed, &input);
+        check_tensor_decomposition(&general, &input);
+    }
+}
+
+fn check_tensor_decomposition<T: UnsignedTorus + Debug, D: Decomposer<T>>(
+    decomposer: &D,
+    input: &Tensor<Vec<T>>,
+) {
+    let level_count = decomposer.level_count().0;
+    let mut decomposition = Tensor::allocate(T::ZERO, input.len() * level_count);
+    decomposer.fill_tensor_with_decomposition(&mut decomposition, input);
+    let mut digits = vec![T::ZERO; level_count];
+    for (index, element) in input.iter().enumerate() {
+        decomposer.decompose_into(*element, digits.as_mut_slice());
+        for (level_index, digit) in digits.iter().enumerate() {
+            assert_eq!(
+                *decomposition.get_element(level_index * input.len() + index),
+                *digit
+            );
+        }
+    }
+}
+
+#[test]
+fn test_decompose_tensor_with_trait_u32() {
+    test_decompose_tensor_with_trait::<u32>()
+}
+
+#[test]
+fn test_decompose_tensor_with_trait_u64() {
+    test_decompose_tensor_with_trait::<u64>()
+}
@@ -0,0 +1,87 @@
+use crate::backends::core::private::math::decomposition::DecompositionLevel;
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::parameters::DecompositionLevelCount;
+
+/// A trait for objects which decompose integers over a gadget vector.
+///
+/// A decomposer is characterized by a number of levels $l$ and a gadget vector
+/// $(g_1, \dots, g_l)$. Decomposing a value $\theta$ yields a set of small digits
+/// $(\tilde{\theta}_1, \dots, \tilde{\theta}_l)$ such that:
+/// $$
+///     \theta \approx \sum_{i=1}^l\tilde{\theta}_ig_i
+/// $$
+///
+/// Algorithms consuming a decomposition (keyswitch, external product) are generic over this
+/// trait. Keys consumed by those algorithms must be generated with the gadget values of the same
+/// decomposer.
+///
+/// # Note
+///
+/// Contrary to the [`SignedDecomposer::decompose`](super::SignedDecomposer::decompose) iterator,
+/// the methods of this trait store the digits in order of increasing level: the digit of level
+/// $i$ is stored at index $i-1$.
+pub trait Decomposer<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Returns the number of levels of the decomposition.
+    fn level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the gadget value $g_i$ associated with a given level.
+    fn gadget_value(&self, level: DecompositionLevel) -> Scalar;
+
+    /// Fills the output slice with the digits of the decomposition of the input.
+    ///
+    /// The input does not have to be rounded to the closest representable value beforehand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the output slice is not equal to the number of levels.
+    fn decompose_into(&self, input: Scalar, output: &mut [Scalar]);
+
+    /// Recomposes a value from the digits of its decomposition.
+    ///
+    /// If the input slice contains $(\tilde{\theta}_i)_{i\in\[1,l\]}$, this returns
+    /// $\sum_{i=1}^l\tilde{\theta}_ig_i$.
+    fn recompose(&self, digits: &[Scalar]) -> Scalar {
+        debug_assert_eq!(digits.len(), self.level_count().0);
+        digits
+            .iter()
+            .enumerate()
+            .fold(Scalar::ZERO, |acc, (index, digit)| {
+                acc.wrapping_add(
+                    digit.wrapping_mul(self.gadget_value(DecompositionLevel(index + 1))),
+                )
+            })
+    }
+
+    /// Returns the closest value representable by the decomposition.
+    fn closest_representable(&self, input: Scalar) -> Scalar {
+        let mut digits = vec![Scalar::ZERO; self.level_count().0];
+        self.decompose_into(input, digits.as_mut_slice());
+        self.recompose(digits.as_slice())
+    }
+
+    /// Fills the output tensor with the decomposition of every element of the input tensor.
+    ///
+    /// The output tensor is made of $l$ contiguous chunks of the size of the input, the chunk at
+    /// index $i-1$ containing the digits of level $i$ of every input elements.
+    fn fill_tensor_with_decomposition<I, O>(&self, output: &mut O, input: &I)
+    where
+        I: AsRefTensor<Element = Scalar>,
+        O: AsMutTensor<Element = Scalar>,
+    {
+        let input = input.as_tensor();
+        let output = output.as_mut_tensor();
+        let level_count = self.level_count().0;
+        ck_dim_eq!(output.len() => input.len() * level_count);
+        let mut digits = vec![Scalar::ZERO; level_count];
+        for (index, element) in input.iter().enumerate() {
+            self.decompose_into(*element, digits.as_mut_slice());
+            for (level_index, digit) in digits.iter().enumerate() {
+                *output.get_element_mut(level_index * input.len() + index) = *digit;
+            }
+        }
+    }
+}
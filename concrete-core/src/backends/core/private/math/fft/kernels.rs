@@ -0,0 +1,437 @@
+//! Multiply-accumulate kernels for polynomials in the fourier domain.
+//!
+//! Every operation has a scalar implementation, which is always available, and vectorized
+//! implementations for x86 processors supporting either `avx2` and `fma`, or `avx512f`. The fastest
+//! kernel supported by the executing processor is selected at runtime, which means that a binary
+//! compiled for a generic target still benefits from the wider instructions when they exist.
+//!
+//! All the kernels operate on slices of equal lengths, and the caller is responsible for taking
+//! the relevant part of the polynomials.
+use super::Complex64;
+use crate::backends::core::private::utils::{zip, zip_args};
+
+/// The different kernels available to perform the multiply-accumulate operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MultiplyAccumulateKernel {
+    /// A portable implementation.
+    Scalar,
+    /// An implementation using the `avx2` and `fma` instruction sets.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx2Fma,
+    /// An implementation using the `avx512f` instruction set.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx512,
+}
+
+impl MultiplyAccumulateKernel {
+    /// Returns the fastest kernel supported by the executing processor.
+    ///
+    /// The feature detection result is cached by the standard library, which makes this cheap
+    /// enough to be called once per operation.
+    pub(crate) fn detect() -> Self {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if Self::Avx512.is_supported() {
+                return Self::Avx512;
+            }
+            if Self::Avx2Fma.is_supported() {
+                return Self::Avx2Fma;
+            }
+        }
+        Self::Scalar
+    }
+
+    /// Returns whether the kernel can be executed on the current processor.
+    pub(crate) fn is_supported(self) -> bool {
+        match self {
+            Self::Scalar => true,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx2Fma => is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma"),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx512 => is_x86_feature_detected!("avx512f"),
+        }
+    }
+
+    /// Computes `res[i] += a[i] * b[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths, or if the kernel is not supported by the
+    /// current processor.
+    pub(crate) fn update_with_multiply_accumulate(
+        self,
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+    ) {
+        assert!(res.len() == a.len() && res.len() == b.len());
+        assert!(self.is_supported());
+        match self {
+            Self::Scalar => scalar::update_with_multiply_accumulate(res, a, b),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx2Fma => unsafe { avx2_fma::update_with_multiply_accumulate(res, a, b) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx512 => unsafe { avx512::update_with_multiply_accumulate(res, a, b) },
+        }
+    }
+
+    /// Computes `res[i] += a[i] * b[i] + c[i] * d[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths, or if the kernel is not supported by the
+    /// current processor.
+    pub(crate) fn update_with_two_multiply_accumulate(
+        self,
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+        c: &[Complex64],
+        d: &[Complex64],
+    ) {
+        assert!([a.len(), b.len(), c.len(), d.len()]
+            .iter()
+            .all(|len| *len == res.len()));
+        assert!(self.is_supported());
+        match self {
+            Self::Scalar => scalar::update_with_two_multiply_accumulate(res, a, b, c, d),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx2Fma => unsafe {
+                avx2_fma::update_with_two_multiply_accumulate(res, a, b, c, d)
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx512 => unsafe { avx512::update_with_two_multiply_accumulate(res, a, b, c, d) },
+        }
+    }
+
+    /// Computes `res_1[i] += a_1[i] * b[i] + c_1[i] * d[i]` and
+    /// `res_2[i] += a_2[i] * b[i] + c_2[i] * d[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths, or if the kernel is not supported by the
+    /// current processor.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn update_two_with_two_multiply_accumulate(
+        self,
+        res_1: &mut [Complex64],
+        res_2: &mut [Complex64],
+        a_1: &[Complex64],
+        a_2: &[Complex64],
+        b: &[Complex64],
+        c_1: &[Complex64],
+        c_2: &[Complex64],
+        d: &[Complex64],
+    ) {
+        assert!([
+            res_2.len(),
+            a_1.len(),
+            a_2.len(),
+            b.len(),
+            c_1.len(),
+            c_2.len(),
+            d.len()
+        ]
+        .iter()
+        .all(|len| *len == res_1.len()));
+        assert!(self.is_supported());
+        match self {
+            Self::Scalar => scalar::update_two_with_two_multiply_accumulate(
+                res_1, res_2, a_1, a_2, b, c_1, c_2, d,
+            ),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx2Fma => unsafe {
+                avx2_fma::update_two_with_two_multiply_accumulate(
+                    res_1, res_2, a_1, a_2, b, c_1, c_2, d,
+                )
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx512 => unsafe {
+                avx512::update_two_with_two_multiply_accumulate(
+                    res_1, res_2, a_1, a_2, b, c_1, c_2, d,
+                )
+            },
+        }
+    }
+}
+
+mod scalar {
+    use super::*;
+
+    pub fn update_with_multiply_accumulate(
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+    ) {
+        for zip_args!(res_i, a_i, b_i) in zip!(res.iter_mut(), a.iter(), b.iter()) {
+            *res_i += a_i * b_i;
+        }
+    }
+
+    pub fn update_with_two_multiply_accumulate(
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+        c: &[Complex64],
+        d: &[Complex64],
+    ) {
+        for zip_args!(res_i, a_i, b_i, c_i, d_i) in
+            zip!(res.iter_mut(), a.iter(), b.iter(), c.iter(), d.iter())
+        {
+            *res_i += a_i * b_i + c_i * d_i;
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_two_with_two_multiply_accumulate(
+        res_1: &mut [Complex64],
+        res_2: &mut [Complex64],
+        a_1: &[Complex64],
+        a_2: &[Complex64],
+        b: &[Complex64],
+        c_1: &[Complex64],
+        c_2: &[Complex64],
+        d: &[Complex64],
+    ) {
+        for zip_args!(res_1_i, a_1_i, b_i, c_1_i, d_i, res_2_i, a_2_i, c_2_i) in zip!(
+            res_1.iter_mut(),
+            a_1.iter(),
+            b.iter(),
+            c_1.iter(),
+            d.iter(),
+            res_2.iter_mut(),
+            a_2.iter(),
+            c_2.iter()
+        ) {
+            *res_1_i += a_1_i * b_i + c_1_i * d_i;
+            *res_2_i += a_2_i * b_i + c_2_i * d_i;
+        }
+    }
+}
+
+// The vectorized kernels below process the complex numbers by packs (two per register with avx2,
+// four with avx512), interpreted as interleaved `[re, im, re, im, ...]` doubles. The product of
+// `a = a_re + i a_im` by `b = b_re + i b_im` is computed as:
+//
+//     fmaddsub(a, [b_re, b_re], swap(a) * [b_im, b_im])
+//
+// which yields `a_re * b_re - a_im * b_im` in the even lanes and `a_im * b_re + a_re * b_im` in
+// the odd lanes. The elements which do not fill a complete pack are handled by the scalar kernel.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2_fma {
+    use super::*;
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    const PACK: usize = 2;
+
+    /// Returns the real and imaginary parts of `b`, each broadcast to the lanes of the other.
+    #[inline]
+    #[target_feature(enable = "avx2,fma")]
+    unsafe fn split(b: __m256d) -> (__m256d, __m256d) {
+        (_mm256_movedup_pd(b), _mm256_permute_pd(b, 0b1111))
+    }
+
+    /// Multiplies `a` by the complex numbers whose parts were split with [`split`].
+    #[inline]
+    #[target_feature(enable = "avx2,fma")]
+    unsafe fn mul(a: __m256d, (b_re, b_im): (__m256d, __m256d)) -> __m256d {
+        _mm256_fmaddsub_pd(a, b_re, _mm256_mul_pd(_mm256_permute_pd(a, 0b0101), b_im))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2,fma")]
+    unsafe fn load(slice: &[Complex64], i: usize) -> __m256d {
+        _mm256_loadu_pd(slice.as_ptr().add(i) as *const f64)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2,fma")]
+    unsafe fn accumulate(slice: &mut [Complex64], i: usize, value: __m256d) {
+        let ptr = slice.as_mut_ptr().add(i) as *mut f64;
+        _mm256_storeu_pd(ptr, _mm256_add_pd(_mm256_loadu_pd(ptr), value));
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn update_with_multiply_accumulate(
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+    ) {
+        let body = res.len() - res.len() % PACK;
+        for i in (0..body).step_by(PACK) {
+            accumulate(res, i, mul(load(a, i), split(load(b, i))));
+        }
+        scalar::update_with_multiply_accumulate(&mut res[body..], &a[body..], &b[body..]);
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn update_with_two_multiply_accumulate(
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+        c: &[Complex64],
+        d: &[Complex64],
+    ) {
+        let body = res.len() - res.len() % PACK;
+        for i in (0..body).step_by(PACK) {
+            let ab = mul(load(a, i), split(load(b, i)));
+            let cd = mul(load(c, i), split(load(d, i)));
+            accumulate(res, i, _mm256_add_pd(ab, cd));
+        }
+        scalar::update_with_two_multiply_accumulate(
+            &mut res[body..],
+            &a[body..],
+            &b[body..],
+            &c[body..],
+            &d[body..],
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn update_two_with_two_multiply_accumulate(
+        res_1: &mut [Complex64],
+        res_2: &mut [Complex64],
+        a_1: &[Complex64],
+        a_2: &[Complex64],
+        b: &[Complex64],
+        c_1: &[Complex64],
+        c_2: &[Complex64],
+        d: &[Complex64],
+    ) {
+        let body = res_1.len() - res_1.len() % PACK;
+        for i in (0..body).step_by(PACK) {
+            let b_i = split(load(b, i));
+            let d_i = split(load(d, i));
+            let res_1_i = _mm256_add_pd(mul(load(a_1, i), b_i), mul(load(c_1, i), d_i));
+            let res_2_i = _mm256_add_pd(mul(load(a_2, i), b_i), mul(load(c_2, i), d_i));
+            accumulate(res_1, i, res_1_i);
+            accumulate(res_2, i, res_2_i);
+        }
+        scalar::update_two_with_two_multiply_accumulate(
+            &mut res_1[body..],
+            &mut res_2[body..],
+            &a_1[body..],
+            &a_2[body..],
+            &b[body..],
+            &c_1[body..],
+            &c_2[body..],
+            &d[body..],
+        );
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx512 {
+    use super::*;
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    const PACK: usize = 4;
+
+    /// Returns the real and imaginary parts of `b`, each broadcast to the lanes of the other.
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn split(b: __m512d) -> (__m512d, __m512d) {
+        (_mm512_movedup_pd(b), _mm512_permute_pd(b, 0b1111_1111))
+    }
+
+    /// Multiplies `a` by the complex numbers whose parts were split with [`split`].
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn mul(a: __m512d, (b_re, b_im): (__m512d, __m512d)) -> __m512d {
+        _mm512_fmaddsub_pd(
+            a,
+            b_re,
+            _mm512_mul_pd(_mm512_permute_pd(a, 0b0101_0101), b_im),
+        )
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn load(slice: &[Complex64], i: usize) -> __m512d {
+        _mm512_loadu_pd(slice.as_ptr().add(i) as *const f64)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn accumulate(slice: &mut [Complex64], i: usize, value: __m512d) {
+        let ptr = slice.as_mut_ptr().add(i) as *mut f64;
+        _mm512_storeu_pd(ptr, _mm512_add_pd(_mm512_loadu_pd(ptr), value));
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub unsafe fn update_with_multiply_accumulate(
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+    ) {
+        let body = res.len() - res.len() % PACK;
+        for i in (0..body).step_by(PACK) {
+            accumulate(res, i, mul(load(a, i), split(load(b, i))));
+        }
+        scalar::update_with_multiply_accumulate(&mut res[body..], &a[body..], &b[body..]);
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub unsafe fn update_with_two_multiply_accumulate(
+        res: &mut [Complex64],
+        a: &[Complex64],
+        b: &[Complex64],
+        c: &[Complex64],
+        d: &[Complex64],
+    ) {
+        let body = res.len() - res.len() % PACK;
+        for i in (0..body).step_by(PACK) {
+            let ab = mul(load(a, i), split(load(b, i)));
+            let cd = mul(load(c, i), split(load(d, i)));
+            accumulate(res, i, _mm512_add_pd(ab, cd));
+        }
+        scalar::update_with_two_multiply_accumulate(
+            &mut res[body..],
+            &a[body..],
+            &b[body..],
+            &c[body..],
+            &d[body..],
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[target_feature(enable = "avx512f")]
+    pub unsafe fn update_two_with_two_multiply_accumulate(
+        res_1: &mut [Complex64],
+        res_2: &mut [Complex64],
+        a_1: &[Complex64],
+        a_2: &[Complex64],
+        b: &[Complex64],
+        c_1: &[Complex64],
+        c_2: &[Complex64],
+        d: &[Complex64],
+    ) {
+        let body = res_1.len() - res_1.len() % PACK;
+        for i in (0..body).step_by(PACK) {
+            let b_i = split(load(b, i));
+            let d_i = split(load(d, i));
+            let res_1_i = _mm512_add_pd(mul(load(a_1, i), b_i), mul(load(c_1, i), d_i));
+            let res_2_i = _mm512_add_pd(mul(load(a_2, i), b_i), mul(load(c_2, i), d_i));
+            accumulate(res_1, i, res_1_i);
+            accumulate(res_2, i, res_2_i);
+        }
+        scalar::update_two_with_two_multiply_accumulate(
+            &mut res_1[body..],
+            &mut res_2[body..],
+            &a_1[body..],
+            &a_2[body..],
+            &b[body..],
+            &c_1[body..],
+            &c_2[body..],
+            &d[body..],
+        );
+    }
+}
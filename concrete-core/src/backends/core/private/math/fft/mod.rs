@@ -12,6 +12,8 @@ use twiddles::*;
 
 mod plan;
 
+mod kernels;
+
 mod polynomial;
 
 pub use polynomial::*;
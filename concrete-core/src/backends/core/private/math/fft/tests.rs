@@ -1,3 +1,4 @@
+use crate::backends::core::private::math::fft::kernels::MultiplyAccumulateKernel;
 use crate::backends::core::private::math::fft::twiddles::{BackwardCorrector, ForwardCorrector};
 use crate::backends::core::private::math::fft::{
     Complex64, Fft, FourierPolynomial, ALLOWED_POLY_SIZE,
@@ -131,3 +132,166 @@ fn test_two_forward_backward() {
         }
     }
 }
+
+fn random_complex_vec(generator: &mut RandomGenerator, len: usize) -> Vec<Complex64> {
+    (0..len)
+        .map(|_| {
+            let (re, im) = generator.random_gaussian(0., 1.);
+            Complex64::new(re, im)
+        })
+        .collect()
+}
+
+fn assert_close(expected: &[Complex64], actual: &[Complex64]) {
+    for (exp, act) in expected.iter().zip(actual.iter()) {
+        assert!((exp - act).norm() < 1e-12f64);
+    }
+}
+
+fn supported_kernels() -> Vec<MultiplyAccumulateKernel> {
+    #[allow(unused_mut)]
+    let mut kernels = vec![MultiplyAccumulateKernel::Scalar];
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    kernels.extend_from_slice(&[
+        MultiplyAccumulateKernel::Avx2Fma,
+        MultiplyAccumulateKernel::Avx512,
+    ]);
+    kernels
+        .into_iter()
+        .filter(|kernel| kernel.is_supported())
+        .collect()
+}
+
+#[test]
+fn test_multiply_accumulate_kernels() {
+    let mut generator = RandomGenerator::new(None);
+    for kernel in supported_kernels() {
+        // We use lengths which are not multiples of the vector width to exercise the remainders.
+        for len in (0..20).chain(ALLOWED_POLY_SIZE.iter().map(|size| size / 2 + 2)) {
+            let a = random_complex_vec(&mut generator, len);
+            let b = random_complex_vec(&mut generator, len);
+            let c = random_complex_vec(&mut generator, len);
+            let d = random_complex_vec(&mut generator, len);
+            let init = random_complex_vec(&mut generator, len);
+
+            let mut expected = init.clone();
+            let mut actual = init.clone();
+            MultiplyAccumulateKernel::Scalar.update_with_multiply_accumulate(&mut expected, &a, &b);
+            kernel.update_with_multiply_accumulate(&mut actual, &a, &b);
+            assert_close(&expected, &actual);
+
+            let mut expected = init.clone();
+            let mut actual = init.clone();
+            MultiplyAccumulateKernel::Scalar.update_with_two_multiply_accumulate(
+                &mut expected,
+                &a,
+                &b,
+                &c,
+                &d,
+            );
+            kernel.update_with_two_multiply_accumulate(&mut actual, &a, &b, &c, &d);
+            assert_close(&expected, &actual);
+        }
+    }
+}
+
+#[test]
+fn test_two_multiply_accumulate_kernels() {
+    let mut generator = RandomGenerator::new(None);
+    for kernel in supported_kernels() {
+        for len in (0..20).chain(ALLOWED_POLY_SIZE.iter().map(|size| size / 2 + 2)) {
+            let inputs: Vec<_> = (0..6)
+                .map(|_| random_complex_vec(&mut generator, len))
+                .collect();
+            let init_1 = random_complex_vec(&mut generator, len);
+            let init_2 = random_complex_vec(&mut generator, len);
+
+            let (mut expected_1, mut expected_2) = (init_1.clone(), init_2.clone());
+            MultiplyAccumulateKernel::Scalar.update_two_with_two_multiply_accumulate(
+                &mut expected_1,
+                &mut expected_2,
+                &inputs[0],
+                &inputs[1],
+                &inputs[2],
+                &inputs[3],
+                &inputs[4],
+                &inputs[5],
+            );
+            let (mut actual_1, mut actual_2) = (init_1, init_2);
+            kernel.update_two_with_two_multiply_accumulate(
+                &mut actual_1,
+                &mut actual_2,
+                &inputs[0],
+                &inputs[1],
+                &inputs[2],
+                &inputs[3],
+                &inputs[4],
+                &inputs[5],
+            );
+            assert_close(&expected_1, &actual_1);
+            assert_close(&expected_2, &actual_2);
+        }
+    }
+}
+
+#[test]
+fn test_kernels_panic_on_length_mismatch() {
+    // The vectorized kernels read and write `res.len()` elements of every slice, so the length
+    // check must hold in release builds as well.
+    let mut generator = RandomGenerator::new(None);
+    for kernel in supported_kernels() {
+        let a = random_complex_vec(&mut generator, 16);
+        let b = random_complex_vec(&mut generator, 8);
+        let mut res = random_complex_vec(&mut generator, 16);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            kernel.update_with_multiply_accumulate(&mut res, &a, &b)
+        }));
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            kernel.update_with_two_multiply_accumulate(&mut res, &a, &a, &a, &b)
+        }));
+        assert!(result.is_err());
+        let mut res_2 = random_complex_vec(&mut generator, 16);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            kernel.update_two_with_two_multiply_accumulate(
+                &mut res, &mut res_2, &a, &a, &a, &a, &a, &b,
+            )
+        }));
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn test_multiply_accumulate_small_polynomials() {
+    // Polynomials of less than four coefficients have less than half+2 coefficients, and are
+    // updated entirely.
+    for size in 1..4 {
+        let poly =
+            |re: f64| FourierPolynomial::allocate(Complex64::new(re, 0.), PolynomialSize(size));
+        let (mut res_1, mut res_2) = (poly(1.), poly(1.));
+        res_1.update_with_multiply_accumulate(&poly(2.), &poly(3.));
+        assert!(res_1
+            .coefficient_iter()
+            .all(|a| *a == Complex64::new(7., 0.)));
+        res_1.update_with_two_multiply_accumulate(&poly(1.), &poly(1.), &poly(1.), &poly(1.));
+        assert!(res_1
+            .coefficient_iter()
+            .all(|a| *a == Complex64::new(9., 0.)));
+        FourierPolynomial::update_two_with_two_multiply_accumulate(
+            &mut res_1,
+            &mut res_2,
+            &poly(1.),
+            &poly(1.),
+            &poly(1.),
+            &poly(1.),
+            &poly(1.),
+            &poly(1.),
+        );
+        assert!(res_1
+            .coefficient_iter()
+            .all(|a| *a == Complex64::new(11., 0.)));
+        assert!(res_2
+            .coefficient_iter()
+            .all(|a| *a == Complex64::new(3., 0.)));
+    }
+}
@@ -104,6 +104,11 @@ pub fn test_multiply_divide_unit_monomial_u64() {
     test_multiply_divide_unit_monomial::<u64>()
 }
 
+#[test]
+pub fn test_multiply_divide_unit_monomial_u128() {
+    test_multiply_divide_unit_monomial::<u128>()
+}
+
 #[test]
 pub fn test_multiply_karatsuba_u32() {
     test_multiply_karatsuba::<u32>()
@@ -113,3 +118,8 @@ pub fn test_multiply_karatsuba_u32() {
 pub fn test_multiply_karatsuba_u64() {
     test_multiply_karatsuba::<u64>()
 }
+
+#[test]
+pub fn test_multiply_karatsuba_u128() {
+    test_multiply_karatsuba::<u128>()
+}
@@ -15,29 +15,20 @@ pub struct Gaussian<T: FloatingPoint> {
 }
 
 macro_rules! implement_gaussian {
-    ($T:ty, $S:ty) => {
+    ($T:ty, $S:ty, $U:ty, $fill:ident) => {
         impl RandomGenerable<Gaussian<$T>> for ($T, $T) {
             fn generate_one(
                 generator: &mut RandomGenerator,
                 Gaussian { std, mean }: Gaussian<$T>,
             ) -> Self {
                 let output: ($T, $T);
-                let mut uniform_rand = vec![0 as $S; 2];
+                let mut uniform_rand = [0 as $U; 2];
                 loop {
-                    let n_bytes = (<$S as Numeric>::BITS * 2) / 8;
-                    let uniform_rand_bytes = unsafe {
-                        std::slice::from_raw_parts_mut(
-                            uniform_rand.as_mut_ptr() as *mut u8,
-                            n_bytes,
-                        )
-                    };
-                    uniform_rand_bytes
-                        .iter_mut()
-                        .for_each(|a| *a = generator.generate_next());
+                    generator.$fill(&mut uniform_rand);
                     let size = <$T>::BITS as i32;
-                    let mut u: $T = uniform_rand[0].cast_into();
+                    let mut u: $T = (uniform_rand[0] as $S).cast_into();
                     u *= <$T>::TWO.powi(-size + 1);
-                    let mut v: $T = uniform_rand[1].cast_into();
+                    let mut v: $T = (uniform_rand[1] as $S).cast_into();
                     v *= <$T>::TWO.powi(-size + 1);
                     let s = u.powi(2) + v.powi(2);
                     if (s > <$T>::ZERO && s < <$T>::ONE) {
@@ -52,8 +43,8 @@ macro_rules! implement_gaussian {
     };
 }
 
-implement_gaussian!(f32, i32);
-implement_gaussian!(f64, i64);
+implement_gaussian!(f32, i32, u32, fill_u32);
+implement_gaussian!(f64, i64, u64, fill_u64);
 
 impl<Torus> RandomGenerable<Gaussian<f64>> for (Torus, Torus)
 where
@@ -3,7 +3,9 @@ use crate::backends::core::private::math::random::{
     UniformWithZeros,
 };
 use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor, Tensor};
-use concrete_commons::numeric::{FloatingPoint, Numeric};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::numeric::{CastInto, FloatingPoint, Numeric};
+use concrete_csprng::seeders::{new_seeder, ProvidedSeeder, Seed};
 use concrete_csprng::RandomGenerator as RandomGeneratorImpl;
 #[cfg(feature = "multithread")]
 use rayon::prelude::*;
@@ -56,8 +58,26 @@ impl RandomGenerator {
         self.0.generate_next()
     }
 
+    // Fills a slice with random bytes, copied from whole batches of the csprng.
+    pub(crate) fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        self.0.fill_bytes(bytes)
+    }
+
+    // Fills a slice with random `u32` values, built from the random bytes in little endian order.
+    pub(crate) fn fill_u32(&mut self, values: &mut [u32]) {
+        self.0.fill_u32(values)
+    }
+
+    // Fills a slice with random `u64` values, built from the random bytes in little endian order.
+    pub(crate) fn fill_u64(&mut self, values: &mut [u64]) {
+        self.0.fill_u64(values)
+    }
+
     /// Generates a new generator, optionally seeding it with the given value.
     ///
+    /// If no seed is given, the generator is seeded with the `rdseed` instruction if available,
+    /// and with the entropy source of the operating system otherwise.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -66,7 +86,12 @@ impl RandomGenerator {
     /// assert!(!generator.is_bounded());
     /// ```
     pub fn new(seed: Option<u128>) -> RandomGenerator {
-        RandomGenerator(RandomGeneratorImpl::new(seed))
+        match seed {
+            Some(seed) => RandomGenerator(RandomGeneratorImpl::new(&mut ProvidedSeeder::new(
+                Seed(seed),
+            ))),
+            None => RandomGenerator(RandomGeneratorImpl::new(new_seeder().as_mut())),
+        }
     }
 
     /// Returns the number of bytes that can still be generated, if the generator is bounded.
@@ -191,6 +216,40 @@ impl RandomGenerator {
         Scalar::fill_tensor(self, Uniform, output);
     }
 
+    /// Fills an `AsMutTensor` value with random values uniformly sampled in `[0, modulus)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::core::private::math::random::RandomGenerator;
+    /// use concrete_core::backends::core::private::math::tensor::Tensor;
+    /// let mut generator = RandomGenerator::new(None);
+    /// let mut tensor = Tensor::allocate(0 as u64, 100);
+    /// let modulus = (1u128 << 64) - (1 << 32) + 1;
+    /// generator.fill_tensor_with_random_uniform_custom_mod(&mut tensor, modulus);
+    /// assert!(tensor.iter().all(|&x| (x as u128) < modulus));
+    /// ```
+    pub fn fill_tensor_with_random_uniform_custom_mod<Scalar, Tensorable>(
+        &mut self,
+        output: &mut Tensorable,
+        modulus: u128,
+    ) where
+        Scalar: UnsignedTorus,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        // We perform a rejection sampling on the smallest number of bits able to represent the
+        // values of [0, modulus).
+        let mask = u128::MAX >> (modulus - 1).leading_zeros();
+        output.as_mut_tensor().iter_mut().for_each(|s| {
+            *s = loop {
+                let candidate = <Scalar as CastInto<u128>>::cast_into(self.random_uniform()) & mask;
+                if candidate < modulus {
+                    break Scalar::cast_from(candidate);
+                }
+            }
+        });
+    }
+
     /// Generates a tensor of random uniform values of a given size.
     ///
     /// # Example
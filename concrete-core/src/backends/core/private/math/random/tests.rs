@@ -58,6 +58,11 @@ fn test_normal_random_u64() {
     test_normal_random::<u64>();
 }
 
+#[test]
+fn test_normal_random_u128() {
+    test_normal_random::<u128>();
+}
+
 fn test_distribution<T: UnsignedTorus>() {
     //! tests gaussianity against the rand crate generation
     // settings
@@ -83,3 +88,29 @@ fn test_distribution_u32() {
 fn test_distribution_u64() {
     test_distribution::<u64>();
 }
+
+#[test]
+fn test_distribution_u128() {
+    test_distribution::<u128>();
+}
+
+#[test]
+fn test_gaussian_small_std_u128() {
+    // The standard deviation used here is far below the precision of the `f64` mantissa, which
+    // checks that the conversion to the torus keeps the precision of the negative samples.
+    let log_std_dev = -100;
+    let k = 100_000;
+    let mut generator = RandomGenerator::new(None);
+    let mut samples = Tensor::allocate(0u128, k);
+    generator.fill_tensor_with_random_gaussian(&mut samples, 0., f64::powi(2., log_std_dev));
+
+    // We interpret the samples as signed integers, whose std is expected to be 2^(128-100).
+    let scaled: Vec<f64> = samples
+        .iter()
+        .map(|s| (*s as i128) as f64 * f64::powi(2., -(128 + log_std_dev)))
+        .collect();
+    let mean = scaled.iter().sum::<f64>() / k as f64;
+    let variance = scaled.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (k - 1) as f64;
+    assert!(mean.abs() < 0.05, "mean = {}", mean);
+    assert!((variance - 1.).abs() < 0.05, "variance = {}", variance);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::backends::core::private::math::tensor::AsMutSlice;
 
 /// A distribution type representing uniform sampling for unsigned integer types. The value is
 /// uniformly sampled in `[0, 2^n[` where `n` is the size of the integer type.
@@ -6,25 +7,56 @@ use super::*;
 pub struct Uniform;
 
 macro_rules! implement_uniform {
-    ($T:ty, $bytes:literal) => {
+    ($T:ty) => {
         impl RandomGenerable<Uniform> for $T {
             #[allow(unused)]
             fn generate_one(generator: &mut RandomGenerator, distribution: Uniform) -> Self {
-                let mut buf = [0; $bytes];
-                buf.iter_mut().for_each(|a| *a = generator.generate_next());
-                unsafe { *(buf.as_ptr() as *const $T) }
+                let mut buf = [0; std::mem::size_of::<$T>()];
+                generator.fill_bytes(&mut buf);
+                <$T>::from_le_bytes(buf)
+            }
+        }
+    };
+    ($T:ty, $fill:ident) => {
+        impl RandomGenerable<Uniform> for $T {
+            #[allow(unused)]
+            fn generate_one(generator: &mut RandomGenerator, distribution: Uniform) -> Self {
+                let mut buf = [0; 1];
+                generator.$fill(&mut buf);
+                buf[0]
+            }
+
+            fn generate_tensor(
+                generator: &mut RandomGenerator,
+                distribution: Uniform,
+                size: usize,
+            ) -> Tensor<Vec<Self>> {
+                let mut tensor = Tensor::allocate(0, size);
+                Self::fill_tensor(generator, distribution, &mut tensor);
+                tensor
+            }
+
+            // The whole tensor is filled at once, from successive batches of the csprng.
+            fn fill_tensor<Tens>(
+                generator: &mut RandomGenerator,
+                _distribution: Uniform,
+                tensor: &mut Tens,
+            ) where
+                Tens: AsMutTensor<Element = Self>,
+            {
+                generator.$fill(tensor.as_mut_tensor().as_mut_slice());
             }
         }
     };
 }
 
-implement_uniform!(u8, 1);
-implement_uniform!(u16, 2);
-implement_uniform!(u32, 4);
-implement_uniform!(u64, 8);
-implement_uniform!(u128, 16);
-implement_uniform!(i8, 1);
-implement_uniform!(i16, 1);
-implement_uniform!(i32, 1);
-implement_uniform!(i64, 1);
-implement_uniform!(i128, 1);
+implement_uniform!(u8, fill_bytes);
+implement_uniform!(u16);
+implement_uniform!(u32, fill_u32);
+implement_uniform!(u64, fill_u64);
+implement_uniform!(u128);
+implement_uniform!(i8);
+implement_uniform!(i16);
+implement_uniform!(i32);
+implement_uniform!(i64);
+implement_uniform!(i128);
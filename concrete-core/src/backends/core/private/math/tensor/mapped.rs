@@ -0,0 +1,98 @@
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use memmap2::Mmap;
+
+use super::AsRefSlice;
+
+/// A marker trait for the types which can be read directly from the bytes of a memory map.
+///
+/// # Safety
+///
+/// Every bit pattern of the size of the type must be a valid value of the type, and the type must
+/// not contain any padding.
+pub unsafe trait Mappable: Copy {}
+
+unsafe impl Mappable for u32 {}
+unsafe impl Mappable for u64 {}
+unsafe impl Mappable for crate::backends::core::private::math::fft::Complex64 {}
+
+/// A read-only container whose elements are stored in a memory-mapped file.
+///
+/// Cloning a mapped slice does not copy the elements: every clone refers to the same mapping,
+/// which is unmapped when the last clone is dropped.
+pub struct MappedSlice<Element> {
+    map: Arc<Mmap>,
+    offset: usize,
+    length: usize,
+    element: PhantomData<Element>,
+}
+
+impl<Element: Mappable> MappedSlice<Element> {
+    /// Creates a slice of `length` elements, starting `offset` bytes after the beginning of the
+    /// map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements do not fit in the map, or if they are not properly aligned.
+    ///
+    /// # Safety
+    ///
+    /// The mapped file must not be modified as long as the slice, or one of its clones, is alive.
+    pub unsafe fn from_map(map: Arc<Mmap>, offset: usize, length: usize) -> MappedSlice<Element> {
+        let size = length
+            .checked_mul(std::mem::size_of::<Element>())
+            .and_then(|size| size.checked_add(offset))
+            .expect("The size of the mapped slice overflows.");
+        assert!(size <= map.len(), "The mapped slice exceeds the map.");
+        assert_eq!(
+            (map.as_ptr() as usize + offset) % std::mem::align_of::<Element>(),
+            0,
+            "The mapped slice is not aligned."
+        );
+        MappedSlice {
+            map,
+            offset,
+            length,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<Element: Mappable> AsRefSlice for MappedSlice<Element> {
+    type Element = Element;
+    fn as_slice(&self) -> &[Element] {
+        // Safety: the bounds and alignment were checked at construction, and every bit pattern is
+        // a valid element.
+        unsafe {
+            std::slice::from_raw_parts(
+                self.map.as_ptr().add(self.offset) as *const Element,
+                self.length,
+            )
+        }
+    }
+}
+
+impl<Element> Clone for MappedSlice<Element> {
+    fn clone(&self) -> Self {
+        MappedSlice {
+            map: self.map.clone(),
+            offset: self.offset,
+            length: self.length,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<Element: Mappable + Debug> Debug for MappedSlice<Element> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.as_slice().iter()).finish()
+    }
+}
+
+impl<Element: Mappable + PartialEq> PartialEq for MappedSlice<Element> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
@@ -185,3 +185,8 @@ pub use as_tensor::*;
 
 mod into_tensor;
 pub use into_tensor::*;
+
+#[cfg(feature = "mmap")]
+mod mapped;
+#[cfg(feature = "mmap")]
+pub use mapped::*;
@@ -16,9 +16,18 @@ use crate::backends::core::private::math::random::{
     Gaussian, RandomGenerable, Uniform, UniformBinary, UniformTernary,
 };
 use concrete_commons::dispersion::LogStandardDev;
-use concrete_commons::numeric::{CastInto, FloatingPoint, Numeric, UnsignedInteger};
+use concrete_commons::numeric::{
+    CastFrom, CastInto, FloatingPoint, Numeric, SignedInteger, UnsignedInteger,
+};
 use std::fmt::{Debug, Display};
 
+pub use modular::*;
+
+#[cfg(test)]
+mod tests;
+
+mod modular;
+
 /// A trait that converts a torus element in unsigned integer representation to the closest
 /// torus element in floating point representation.
 pub trait IntoTorus<F>: Sized
@@ -42,7 +51,7 @@ where
 }
 
 macro_rules! implement {
-    ($Type: tt) => {
+    ($Type: tt, $SignedType: tt) => {
         impl<F> IntoTorus<F> for $Type
         where
             F: FloatingPoint + CastInto<Self>,
@@ -55,28 +64,32 @@ macro_rules! implement {
         }
         impl<F> FromTorus<F> for $Type
         where
-            F: FloatingPoint + CastInto<Self>,
+            F: FloatingPoint + CastInto<Self> + CastInto<$SignedType>,
             Self: CastInto<F>,
         {
             fn from_torus(input: F) -> Self {
-                let mut fract = input - F::floor(input);
-                fract *= F::TWO.powi(<Self as Numeric>::BITS as i32);
-                let carry = fract - F::floor(fract);
                 let zero_point_five = F::ONE / F::TWO;
-                if carry >= zero_point_five {
-                    fract += F::ONE;
-                };
-                return fract.cast_into();
+                // We first bring the input in [-1/2, 1/2). Contrarily to a reduction in [0, 1),
+                // this does not lose the precision of the small negative values (typically, the
+                // noise), which matters when the integer type is wider than the mantissa.
+                let mut fract = input - F::floor(input + zero_point_five);
+                fract *= F::TWO.powi(<Self as Numeric>::BITS as i32);
+                fract = F::floor(fract + zero_point_five);
+                if fract >= F::TWO.powi(<Self as Numeric>::BITS as i32 - 1) {
+                    fract -= F::TWO.powi(<Self as Numeric>::BITS as i32);
+                }
+                let signed: $SignedType = fract.cast_into();
+                return signed.into_unsigned();
             }
         }
     };
 }
 
-implement!(u8);
-implement!(u16);
-implement!(u32);
-implement!(u64);
-implement!(u128);
+implement!(u8, i8);
+implement!(u16, i16);
+implement!(u32, i32);
+implement!(u64, i64);
+implement!(u128, i128);
 
 /// A marker trait for unsigned integer types that can be used in ciphertexts, keys etc.
 pub trait UnsignedTorus:
@@ -87,6 +100,8 @@ pub trait UnsignedTorus:
     + RandomGenerable<UniformBinary>
     + RandomGenerable<UniformTernary>
     + RandomGenerable<Uniform>
+    + CastFrom<u128>
+    + CastInto<u128>
     + Display
     + Debug
 {
@@ -101,3 +116,7 @@ impl UnsignedTorus for u32 {
 impl UnsignedTorus for u64 {
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-62.32192809488736);
 }
+
+impl UnsignedTorus for u128 {
+    const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-126.32192809488736);
+}
@@ -0,0 +1,131 @@
+//! Arithmetic on torus elements represented modulo a custom ciphertext modulus.
+//!
+//! When a ciphertext uses a [`CiphertextModulus::Custom`] modulus $q$, its coefficients are
+//! stored in $[0, q)$ instead of using the whole range of the integer type. The functions of this
+//! module implement the operations needed on such coefficients. They all expect the inputs to be
+//! already reduced modulo $q$, and the integer type to be at most 64 bits wide, so that
+//! intermediate values fit in a `u128`.
+//!
+//! [`CiphertextModulus::Custom`]: concrete_commons::parameters::CiphertextModulus::Custom
+
+use super::UnsignedTorus;
+use concrete_commons::numeric::Numeric;
+
+#[inline]
+fn to_wide<Scalar: UnsignedTorus>(value: Scalar) -> u128 {
+    assert!(
+        <Scalar as Numeric>::BITS <= 64,
+        "Custom moduli are only supported for integers of at most 64 bits."
+    );
+    value.cast_into()
+}
+
+/// Computes $(a + b) \bmod q$.
+pub fn modular_add<Scalar: UnsignedTorus>(lhs: Scalar, rhs: Scalar, modulus: u128) -> Scalar {
+    let res = (to_wide(lhs) + to_wide(rhs)) % modulus;
+    Scalar::cast_from(res)
+}
+
+/// Computes $(a - b) \bmod q$.
+pub fn modular_sub<Scalar: UnsignedTorus>(lhs: Scalar, rhs: Scalar, modulus: u128) -> Scalar {
+    let (lhs, rhs) = (to_wide(lhs), to_wide(rhs));
+    let res = if lhs >= rhs {
+        lhs - rhs
+    } else {
+        modulus - (rhs - lhs)
+    };
+    Scalar::cast_from(res)
+}
+
+/// Computes $-a \bmod q$.
+pub fn modular_neg<Scalar: UnsignedTorus>(value: Scalar, modulus: u128) -> Scalar {
+    modular_sub(Scalar::ZERO, value, modulus)
+}
+
+/// Computes $(a \cdot b) \bmod q$.
+pub fn modular_mul<Scalar: UnsignedTorus>(lhs: Scalar, rhs: Scalar, modulus: u128) -> Scalar {
+    let res = (to_wide(lhs) * to_wide(rhs)) % modulus;
+    Scalar::cast_from(res)
+}
+
+/// Reduces an integer, interpreted as a signed value, modulo $q$.
+///
+/// This is used to bring values which are meaningful as small signed integers (keys, cleartexts)
+/// to their representative in $[0, q)$.
+pub fn modular_reduce_signed<Scalar: UnsignedTorus>(value: Scalar, modulus: u128) -> Scalar {
+    let signed = value.into_signed();
+    let magnitude: u128 = if signed < <Scalar::Signed as Numeric>::ZERO {
+        to_wide(value.wrapping_neg())
+    } else {
+        to_wide(value)
+    };
+    let reduced = Scalar::cast_from(magnitude % modulus);
+    if signed < <Scalar::Signed as Numeric>::ZERO {
+        modular_neg(reduced, modulus)
+    } else {
+        reduced
+    }
+}
+
+/// Computes the inner product of a mask with a key, modulo $q$.
+///
+/// The key elements are interpreted as signed integers.
+pub fn modular_multisum<'a, Scalar, MaskIter, KeyIter>(
+    mask: MaskIter,
+    key: KeyIter,
+    modulus: u128,
+) -> Scalar
+where
+    Scalar: UnsignedTorus + 'a,
+    MaskIter: Iterator<Item = &'a Scalar>,
+    KeyIter: Iterator<Item = &'a Scalar>,
+{
+    mask.zip(key).fold(Scalar::ZERO, |acc, (m, k)| {
+        let k = modular_reduce_signed(*k, modulus);
+        modular_add(acc, modular_mul(*m, k, modulus), modulus)
+    })
+}
+
+/// Maps a value of $\mathbb{Z}\_q$ to the closest value of the native representation of the
+/// torus, that is computes $\lfloor a \cdot 2^n / q \rceil \bmod 2^n$.
+pub fn modular_to_native<Scalar: UnsignedTorus>(value: Scalar, modulus: u128) -> Scalar {
+    let bits = <Scalar as Numeric>::BITS;
+    let shifted = to_wide(value) << bits;
+    let res = (shifted + modulus / 2) / modulus;
+    Scalar::cast_from(res & ((1u128 << bits) - 1))
+}
+
+/// Maps a value of the native representation of the torus to the closest value of
+/// $\mathbb{Z}\_q$, that is computes $\lfloor a \cdot q / 2^n \rceil \bmod q$.
+pub fn native_to_modular<Scalar: UnsignedTorus>(value: Scalar, modulus: u128) -> Scalar {
+    let bits = <Scalar as Numeric>::BITS;
+    let product = to_wide(value) * modulus;
+    let res = (product >> bits) + ((product >> (bits - 1)) & 1);
+    Scalar::cast_from(res % modulus)
+}
+
+/// Converts a signed real value on the torus to its representative in $\mathbb{Z}\_q$, that is
+/// computes $\lfloor x \cdot q \rceil \bmod q$.
+pub fn modular_from_torus<Scalar: UnsignedTorus>(value: f64, modulus: u128) -> Scalar {
+    let scaled = (value - value.round()) * (modulus as f64);
+    let rounded = scaled.round();
+    let magnitude = Scalar::cast_from(rounded.abs() as u128 % modulus);
+    if rounded < 0. {
+        modular_neg(magnitude, modulus)
+    } else {
+        magnitude
+    }
+}
+
+/// Performs a modulus switch from $\mathbb{Z}\_q$ to $\mathbb{Z}\_{2^{b}}$, that is computes
+/// $\lfloor a \cdot 2^b / q \rceil$.
+///
+/// The output is not reduced, and may be equal to $2^b$.
+pub fn modular_switch_to_power_of_two<Scalar: UnsignedTorus>(
+    value: Scalar,
+    modulus: u128,
+    output_bits: usize,
+) -> u128 {
+    let shifted = to_wide(value) << output_bits;
+    (shifted + modulus / 2) / modulus
+}
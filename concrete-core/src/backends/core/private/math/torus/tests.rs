@@ -0,0 +1,79 @@
+use super::*;
+use crate::backends::core::private::test_tools::{any_uint, random_uint_between};
+use concrete_commons::numeric::{CastInto, Numeric};
+
+fn random_modulus<T: UnsignedTorus>() -> u128 {
+    random_uint_between::<u128>(2..(1u128 << <T as Numeric>::BITS))
+}
+
+fn random_value<T: UnsignedTorus>(modulus: u128) -> T {
+    let value: T = any_uint();
+    T::cast_from(CastInto::<u128>::cast_into(value) % modulus)
+}
+
+fn test_modular_arithmetic<T: UnsignedTorus>() {
+    for _ in 0..1000 {
+        let modulus = random_modulus::<T>();
+        let a: T = random_value(modulus);
+        let b: T = random_value(modulus);
+        let (wa, wb): (u128, u128) = (a.cast_into(), b.cast_into());
+        let sum: u128 = modular_add(a, b, modulus).cast_into();
+        assert_eq!(sum, (wa + wb) % modulus);
+        let diff: u128 = modular_sub(a, b, modulus).cast_into();
+        assert_eq!((diff + wb) % modulus, wa);
+        let neg: u128 = modular_neg(a, modulus).cast_into();
+        assert_eq!((neg + wa) % modulus, 0);
+        let prod: u128 = modular_mul(a, b, modulus).cast_into();
+        assert_eq!(prod, (wa * wb) % modulus);
+    }
+}
+
+fn test_modular_reduce_signed<T: UnsignedTorus>() {
+    for _ in 0..1000 {
+        let modulus = random_modulus::<T>();
+        let minus_one = T::ZERO.wrapping_sub(T::ONE);
+        let reduced: u128 = modular_reduce_signed(minus_one, modulus).cast_into();
+        assert_eq!(reduced, modulus - 1);
+        let reduced: u128 = modular_reduce_signed(T::ONE, modulus).cast_into();
+        assert_eq!(reduced, 1);
+    }
+}
+
+fn test_modular_native_round_trip<T: UnsignedTorus>() {
+    for _ in 0..1000 {
+        let modulus = random_modulus::<T>();
+        let a: T = random_value(modulus);
+        let back = native_to_modular(modular_to_native(a, modulus), modulus);
+        assert_eq!(a, back);
+    }
+}
+
+#[test]
+fn test_modular_arithmetic_u32() {
+    test_modular_arithmetic::<u32>()
+}
+
+#[test]
+fn test_modular_arithmetic_u64() {
+    test_modular_arithmetic::<u64>()
+}
+
+#[test]
+fn test_modular_reduce_signed_u32() {
+    test_modular_reduce_signed::<u32>()
+}
+
+#[test]
+fn test_modular_reduce_signed_u64() {
+    test_modular_reduce_signed::<u64>()
+}
+
+#[test]
+fn test_modular_native_round_trip_u32() {
+    test_modular_native_round_trip::<u32>()
+}
+
+#[test]
+fn test_modular_native_round_trip_u64() {
+    test_modular_native_round_trip::<u64>()
+}
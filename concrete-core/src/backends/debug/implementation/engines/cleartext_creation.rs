@@ -0,0 +1,26 @@
+use crate::backends::core::entities::{Cleartext32, Cleartext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{CleartextCreationEngine, CleartextCreationError};
+
+macro_rules! implement_cleartext_creation {
+    ($Cleartext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`CleartextCreationEngine`] for [`DebugEngine`], which delegates to
+        /// the core backend.
+        impl CleartextCreationEngine<$Scalar, $Cleartext> for DebugEngine {
+            fn create_cleartext(
+                &mut self,
+                input: &$Scalar,
+            ) -> Result<$Cleartext, CleartextCreationError<Self::EngineError>> {
+                self.core.create_cleartext(input)
+            }
+
+            unsafe fn create_cleartext_unchecked(&mut self, input: &$Scalar) -> $Cleartext {
+                self.core.create_cleartext_unchecked(input)
+            }
+        }
+    };
+}
+
+implement_cleartext_creation!(Cleartext32, u32);
+implement_cleartext_creation!(Cleartext64, u64);
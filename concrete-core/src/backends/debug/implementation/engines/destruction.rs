@@ -0,0 +1,107 @@
+use crate::backends::core::entities::{
+    Cleartext32, Cleartext64, GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64,
+    Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugFourierLweBootstrapKey32, DebugFourierLweBootstrapKey64, DebugGlweCiphertext32,
+    DebugGlweCiphertext64, DebugLweCiphertext32, DebugLweCiphertext64, DebugLweKeyswitchKey32,
+    DebugLweKeyswitchKey64,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+macro_rules! implement_core_entity_destruction {
+    ($($Entity: ident),*) => {
+        $(
+            /// # Description:
+            /// Implementation of [`DestructionEngine`] for [`DebugEngine`], which delegates to the
+            /// core backend.
+            impl DestructionEngine<$Entity> for DebugEngine {
+                fn destroy(
+                    &mut self,
+                    entity: $Entity,
+                ) -> Result<(), DestructionError<Self::EngineError>> {
+                    self.core.destroy(entity)
+                }
+
+                unsafe fn destroy_unchecked(&mut self, entity: &mut $Entity) {
+                    self.core.destroy_unchecked(entity)
+                }
+            }
+        )*
+    };
+}
+
+implement_core_entity_destruction!(
+    Cleartext32,
+    Cleartext64,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    LweSecretKey32,
+    LweSecretKey64,
+    Plaintext32,
+    Plaintext64,
+    PlaintextVector32,
+    PlaintextVector64
+);
+
+macro_rules! implement_debug_ciphertext_destruction {
+    ($($Entity: ident),*) => {
+        $(
+            /// # Description:
+            /// Implementation of [`DestructionEngine`] for [`DebugEngine`], which also erases the
+            /// plaintexts tracked along with the ciphertext.
+            impl DestructionEngine<$Entity> for DebugEngine {
+                fn destroy(
+                    &mut self,
+                    mut entity: $Entity,
+                ) -> Result<(), DestructionError<Self::EngineError>> {
+                    unsafe { self.destroy_unchecked(&mut entity) };
+                    Ok(())
+                }
+
+                unsafe fn destroy_unchecked(&mut self, entity: &mut $Entity) {
+                    self.core.destroy_unchecked(&mut entity.ciphertext);
+                    entity.erase_plaintexts();
+                }
+            }
+        )*
+    };
+}
+
+implement_debug_ciphertext_destruction!(
+    DebugGlweCiphertext32,
+    DebugGlweCiphertext64,
+    DebugLweCiphertext32,
+    DebugLweCiphertext64
+);
+
+macro_rules! implement_debug_key_destruction {
+    ($($Entity: ident),*) => {
+        $(
+            /// # Description:
+            /// Implementation of [`DestructionEngine`] for [`DebugEngine`], which delegates to the
+            /// core backend.
+            impl DestructionEngine<$Entity> for DebugEngine {
+                fn destroy(
+                    &mut self,
+                    mut entity: $Entity,
+                ) -> Result<(), DestructionError<Self::EngineError>> {
+                    unsafe { self.destroy_unchecked(&mut entity) };
+                    Ok(())
+                }
+
+                unsafe fn destroy_unchecked(&mut self, entity: &mut $Entity) {
+                    self.core.destroy_unchecked(&mut entity.key)
+                }
+            }
+        )*
+    };
+}
+
+implement_debug_key_destruction!(
+    DebugFourierLweBootstrapKey32,
+    DebugFourierLweBootstrapKey64,
+    DebugLweKeyswitchKey32,
+    DebugLweKeyswitchKey64
+);
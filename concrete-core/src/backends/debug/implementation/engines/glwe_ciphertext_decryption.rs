@@ -0,0 +1,41 @@
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugGlweCiphertext32, DebugGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+
+macro_rules! implement_glwe_ciphertext_decryption {
+    ($SecretKey: ident, $Ciphertext: ident, $PlaintextVector: ident) => {
+        /// # Description:
+        /// Implementation of [`GlweCiphertextDecryptionEngine`] for [`DebugEngine`], which
+        /// delegates to the core backend.
+        impl GlweCiphertextDecryptionEngine<$SecretKey, $Ciphertext, $PlaintextVector>
+            for DebugEngine
+        {
+            fn decrypt_glwe_ciphertext(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Ciphertext,
+            ) -> Result<$PlaintextVector, GlweCiphertextDecryptionError<Self::EngineError>> {
+                self.core.decrypt_glwe_ciphertext(key, &input.ciphertext)
+            }
+
+            unsafe fn decrypt_glwe_ciphertext_unchecked(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Ciphertext,
+            ) -> $PlaintextVector {
+                self.core
+                    .decrypt_glwe_ciphertext_unchecked(key, &input.ciphertext)
+            }
+        }
+    };
+}
+
+implement_glwe_ciphertext_decryption!(GlweSecretKey32, DebugGlweCiphertext32, PlaintextVector32);
+implement_glwe_ciphertext_decryption!(GlweSecretKey64, DebugGlweCiphertext64, PlaintextVector64);
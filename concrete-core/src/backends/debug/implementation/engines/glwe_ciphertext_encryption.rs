@@ -0,0 +1,55 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugGlweCiphertext32, DebugGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+
+macro_rules! implement_glwe_ciphertext_encryption {
+    ($SecretKey: ident, $PlaintextVector: ident, $Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`GlweCiphertextEncryptionEngine`] for [`DebugEngine`]. The variance
+        /// of the output ciphertext is the variance of the encryption noise.
+        impl GlweCiphertextEncryptionEngine<$SecretKey, $PlaintextVector, $Ciphertext>
+            for DebugEngine
+        {
+            fn encrypt_glwe_ciphertext(
+                &mut self,
+                key: &$SecretKey,
+                input: &$PlaintextVector,
+                noise: Variance,
+            ) -> Result<$Ciphertext, GlweCiphertextEncryptionError<Self::EngineError>> {
+                Ok($Ciphertext {
+                    ciphertext: self.core.encrypt_glwe_ciphertext(key, input, noise)?,
+                    variance: noise,
+                    plaintexts: input.0.as_tensor().as_slice().to_vec(),
+                })
+            }
+
+            unsafe fn encrypt_glwe_ciphertext_unchecked(
+                &mut self,
+                key: &$SecretKey,
+                input: &$PlaintextVector,
+                noise: Variance,
+            ) -> $Ciphertext {
+                $Ciphertext {
+                    ciphertext: self
+                        .core
+                        .encrypt_glwe_ciphertext_unchecked(key, input, noise),
+                    variance: noise,
+                    plaintexts: input.0.as_tensor().as_slice().to_vec(),
+                }
+            }
+        }
+    };
+}
+
+implement_glwe_ciphertext_encryption!(GlweSecretKey32, PlaintextVector32, DebugGlweCiphertext32);
+implement_glwe_ciphertext_encryption!(GlweSecretKey64, PlaintextVector64, DebugGlweCiphertext64);
@@ -0,0 +1,52 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlweSize;
+
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugGlweCiphertext32, DebugGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextTrivialEncryptionEngine, GlweCiphertextTrivialEncryptionError,
+};
+
+macro_rules! implement_glwe_ciphertext_trivial_encryption {
+    ($PlaintextVector: ident, $Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`GlweCiphertextTrivialEncryptionEngine`] for [`DebugEngine`]. The
+        /// output ciphertext contains no noise.
+        impl GlweCiphertextTrivialEncryptionEngine<$PlaintextVector, $Ciphertext> for DebugEngine {
+            fn trivially_encrypt_glwe_ciphertext(
+                &mut self,
+                glwe_size: GlweSize,
+                input: &$PlaintextVector,
+            ) -> Result<$Ciphertext, GlweCiphertextTrivialEncryptionError<Self::EngineError>> {
+                Ok($Ciphertext {
+                    ciphertext: self
+                        .core
+                        .trivially_encrypt_glwe_ciphertext(glwe_size, input)?,
+                    variance: Variance(0.),
+                    plaintexts: input.0.as_tensor().as_slice().to_vec(),
+                })
+            }
+
+            unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+                &mut self,
+                glwe_size: GlweSize,
+                input: &$PlaintextVector,
+            ) -> $Ciphertext {
+                $Ciphertext {
+                    ciphertext: self
+                        .core
+                        .trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input),
+                    variance: Variance(0.),
+                    plaintexts: input.0.as_tensor().as_slice().to_vec(),
+                }
+            }
+        }
+    };
+}
+
+implement_glwe_ciphertext_trivial_encryption!(PlaintextVector32, DebugGlweCiphertext32);
+implement_glwe_ciphertext_trivial_encryption!(PlaintextVector64, DebugGlweCiphertext64);
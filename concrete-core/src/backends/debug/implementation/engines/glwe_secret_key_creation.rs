@@ -0,0 +1,35 @@
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use crate::backends::core::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{GlweSecretKeyCreationEngine, GlweSecretKeyCreationError};
+
+macro_rules! implement_glwe_secret_key_creation {
+    ($SecretKey: ident) => {
+        /// # Description:
+        /// Implementation of [`GlweSecretKeyCreationEngine`] for [`DebugEngine`], which delegates
+        /// to the core backend.
+        impl GlweSecretKeyCreationEngine<$SecretKey> for DebugEngine {
+            fn create_glwe_secret_key(
+                &mut self,
+                glwe_dimension: GlweDimension,
+                polynomial_size: PolynomialSize,
+            ) -> Result<$SecretKey, GlweSecretKeyCreationError<Self::EngineError>> {
+                self.core
+                    .create_glwe_secret_key(glwe_dimension, polynomial_size)
+            }
+
+            unsafe fn create_glwe_secret_key_unchecked(
+                &mut self,
+                glwe_dimension: GlweDimension,
+                polynomial_size: PolynomialSize,
+            ) -> $SecretKey {
+                self.core
+                    .create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size)
+            }
+        }
+    };
+}
+
+implement_glwe_secret_key_creation!(GlweSecretKey32);
+implement_glwe_secret_key_creation!(GlweSecretKey64);
@@ -0,0 +1,36 @@
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{
+    GlweToLweSecretKeyTransmutationEngine, GlweToLweSecretKeyTransmutationEngineError,
+};
+
+macro_rules! implement_glwe_secret_key_to_lwe_secret_key_transmutation {
+    ($InputKey: ident, $OutputKey: ident) => {
+        /// # Description:
+        /// Implementation of [`GlweToLweSecretKeyTransmutationEngine`] for [`DebugEngine`], which
+        /// delegates to the core backend.
+        impl GlweToLweSecretKeyTransmutationEngine<$InputKey, $OutputKey> for DebugEngine {
+            fn transmute_glwe_secret_key_to_lwe_secret_key(
+                &mut self,
+                glwe_secret_key: $InputKey,
+            ) -> Result<$OutputKey, GlweToLweSecretKeyTransmutationEngineError<Self::EngineError>>
+            {
+                self.core
+                    .transmute_glwe_secret_key_to_lwe_secret_key(glwe_secret_key)
+            }
+
+            unsafe fn transmute_glwe_secret_key_to_lwe_secret_key_unchecked(
+                &mut self,
+                glwe_secret_key: $InputKey,
+            ) -> $OutputKey {
+                self.core
+                    .transmute_glwe_secret_key_to_lwe_secret_key_unchecked(glwe_secret_key)
+            }
+        }
+    };
+}
+
+implement_glwe_secret_key_to_lwe_secret_key_transmutation!(GlweSecretKey32, LweSecretKey32);
+implement_glwe_secret_key_to_lwe_secret_key_transmutation!(GlweSecretKey64, LweSecretKey64);
@@ -0,0 +1,69 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugFourierLweBootstrapKey32, DebugFourierLweBootstrapKey64,
+};
+use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
+
+macro_rules! implement_lwe_bootstrap_key_creation {
+    ($LweSecretKey: ident, $GlweSecretKey: ident, $BootstrapKey: ident) => {
+        /// # Description:
+        /// Implementation of [`LweBootstrapKeyCreationEngine`] for [`DebugEngine`], which keeps
+        /// the variance of the noise the key is encrypted with.
+        impl LweBootstrapKeyCreationEngine<$LweSecretKey, $GlweSecretKey, $BootstrapKey>
+            for DebugEngine
+        {
+            fn create_lwe_bootstrap_key(
+                &mut self,
+                input_key: &$LweSecretKey,
+                output_key: &$GlweSecretKey,
+                decomposition_base_log: DecompositionBaseLog,
+                decomposition_level_count: DecompositionLevelCount,
+                noise: Variance,
+            ) -> Result<$BootstrapKey, LweBootstrapKeyCreationError<Self::EngineError>> {
+                let key = self.core.create_lwe_bootstrap_key(
+                    input_key,
+                    output_key,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                    noise,
+                )?;
+                Ok($BootstrapKey { key, noise })
+            }
+
+            unsafe fn create_lwe_bootstrap_key_unchecked(
+                &mut self,
+                input_key: &$LweSecretKey,
+                output_key: &$GlweSecretKey,
+                decomposition_base_log: DecompositionBaseLog,
+                decomposition_level_count: DecompositionLevelCount,
+                noise: Variance,
+            ) -> $BootstrapKey {
+                let key = self.core.create_lwe_bootstrap_key_unchecked(
+                    input_key,
+                    output_key,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                    noise,
+                );
+                $BootstrapKey { key, noise }
+            }
+        }
+    };
+}
+
+implement_lwe_bootstrap_key_creation!(
+    LweSecretKey32,
+    GlweSecretKey32,
+    DebugFourierLweBootstrapKey32
+);
+implement_lwe_bootstrap_key_creation!(
+    LweSecretKey64,
+    GlweSecretKey64,
+    DebugFourierLweBootstrapKey64
+);
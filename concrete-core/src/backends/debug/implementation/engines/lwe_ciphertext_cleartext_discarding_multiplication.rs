@@ -0,0 +1,76 @@
+use concrete_npe::estimate_integer_plaintext_multiplication_noise;
+
+use crate::backends::core::entities::{Cleartext32, Cleartext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextDiscardingMultiplicationEngine,
+    LweCiphertextCleartextDiscardingMultiplicationError,
+};
+
+macro_rules! implement_lwe_ciphertext_cleartext_discarding_multiplication {
+    ($Ciphertext: ident, $Cleartext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for
+        /// [`DebugEngine`]. The variance of the output ciphertext is estimated with
+        /// [`estimate_integer_plaintext_multiplication_noise`].
+        impl
+            LweCiphertextCleartextDiscardingMultiplicationEngine<
+                $Ciphertext,
+                $Cleartext,
+                $Ciphertext,
+            > for DebugEngine
+        {
+            fn discard_mul_lwe_ciphertext_cleartext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Cleartext,
+            ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>>
+            {
+                self.core.discard_mul_lwe_ciphertext_cleartext(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                )?;
+                output.variance = estimate_integer_plaintext_multiplication_noise::<$Scalar, _>(
+                    input_1.variance,
+                    input_2.0 .0,
+                );
+                output.plaintext = input_1.plaintext.wrapping_mul(input_2.0 .0);
+                Ok(())
+            }
+
+            unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Cleartext,
+            ) {
+                self.core.discard_mul_lwe_ciphertext_cleartext_unchecked(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                );
+                output.variance = estimate_integer_plaintext_multiplication_noise::<$Scalar, _>(
+                    input_1.variance,
+                    input_2.0 .0,
+                );
+                output.plaintext = input_1.plaintext.wrapping_mul(input_2.0 .0);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_cleartext_discarding_multiplication!(
+    DebugLweCiphertext32,
+    Cleartext32,
+    u32
+);
+implement_lwe_ciphertext_cleartext_discarding_multiplication!(
+    DebugLweCiphertext64,
+    Cleartext64,
+    u64
+);
@@ -0,0 +1,56 @@
+use concrete_npe::estimate_integer_plaintext_multiplication_noise;
+
+use crate::backends::core::entities::{Cleartext32, Cleartext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+
+macro_rules! implement_lwe_ciphertext_cleartext_fusing_multiplication {
+    ($Ciphertext: ident, $Cleartext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for
+        /// [`DebugEngine`]. The variance of the ciphertext is updated with
+        /// [`estimate_integer_plaintext_multiplication_noise`].
+        impl LweCiphertextCleartextFusingMultiplicationEngine<$Ciphertext, $Cleartext>
+            for DebugEngine
+        {
+            fn fuse_mul_lwe_ciphertext_cleartext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Cleartext,
+            ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>>
+            {
+                self.core
+                    .fuse_mul_lwe_ciphertext_cleartext(&mut output.ciphertext, input)?;
+                output.variance = estimate_integer_plaintext_multiplication_noise::<$Scalar, _>(
+                    output.variance,
+                    input.0 .0,
+                );
+                output.plaintext = output.plaintext.wrapping_mul(input.0 .0);
+                Ok(())
+            }
+
+            unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Cleartext,
+            ) {
+                self.core
+                    .fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+                output.variance = estimate_integer_plaintext_multiplication_noise::<$Scalar, _>(
+                    output.variance,
+                    input.0 .0,
+                );
+                output.plaintext = output.plaintext.wrapping_mul(input.0 .0);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_cleartext_fusing_multiplication!(DebugLweCiphertext32, Cleartext32, u32);
+implement_lwe_ciphertext_cleartext_fusing_multiplication!(DebugLweCiphertext64, Cleartext64, u64);
@@ -0,0 +1,35 @@
+use crate::backends::core::entities::{LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+macro_rules! implement_lwe_ciphertext_decryption {
+    ($SecretKey: ident, $Ciphertext: ident, $Plaintext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextDecryptionEngine`] for [`DebugEngine`], which
+        /// delegates to the core backend.
+        impl LweCiphertextDecryptionEngine<$SecretKey, $Ciphertext, $Plaintext> for DebugEngine {
+            fn decrypt_lwe_ciphertext(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Ciphertext,
+            ) -> Result<$Plaintext, LweCiphertextDecryptionError<Self::EngineError>> {
+                self.core.decrypt_lwe_ciphertext(key, &input.ciphertext)
+            }
+
+            unsafe fn decrypt_lwe_ciphertext_unchecked(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Ciphertext,
+            ) -> $Plaintext {
+                self.core
+                    .decrypt_lwe_ciphertext_unchecked(key, &input.ciphertext)
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_decryption!(LweSecretKey32, DebugLweCiphertext32, Plaintext32);
+implement_lwe_ciphertext_decryption!(LweSecretKey64, DebugLweCiphertext64, Plaintext64);
@@ -0,0 +1,125 @@
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{CiphertextModulus, LutCountLog, ModulusSwitchOffset};
+use concrete_npe::{estimate_addition_noise, estimate_pbs_noise};
+
+use crate::backends::core::private::crypto::bootstrap::fourier::pbs_modulus_switch;
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugFourierLweBootstrapKey32, DebugFourierLweBootstrapKey64, DebugGlweCiphertext32,
+    DebugGlweCiphertext64, DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+/// Returns the plaintext output by the bootstrap of a noiseless ciphertext of `input`.
+///
+/// As in the bootstrap, the accumulator is divided by the monomial whose degree is the input
+/// switched to the modulus `2N`, and its constant coefficient is extracted.
+pub(crate) fn bootstrap_plaintext<Scalar: UnsignedTorus>(
+    input: Scalar,
+    accumulator: &[Scalar],
+) -> Scalar {
+    let mut rotated = Polynomial::from_container(accumulator.to_vec());
+    rotated.update_with_wrapping_unit_monomial_div(pbs_modulus_switch(
+        input,
+        rotated.polynomial_size(),
+        ModulusSwitchOffset(0),
+        LutCountLog(0),
+        CiphertextModulus::Native,
+    ));
+    *rotated.as_tensor().first()
+}
+
+macro_rules! implement_lwe_ciphertext_discarding_bootstrap {
+    ($BootstrapKey: ident, $Accumulator: ident, $Ciphertext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`DebugEngine`]. The
+        /// variance of the output ciphertext is the variance of the accumulator, increased by the
+        /// estimation of [`estimate_pbs_noise`].
+        ///
+        /// The expected plaintext of the output is the coefficient of the accumulator selected by
+        /// the expected plaintext of the input. If the noise of the input is too large, the
+        /// bootstrap selects another coefficient, which shows up as a large measured error.
+        impl
+            LweCiphertextDiscardingBootstrapEngine<
+                $BootstrapKey,
+                $Accumulator,
+                $Ciphertext,
+                $Ciphertext,
+            > for DebugEngine
+        {
+            fn discard_bootstrap_lwe_ciphertext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+                acc: &$Accumulator,
+                bsk: &$BootstrapKey,
+            ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+                self.core.discard_bootstrap_lwe_ciphertext(
+                    &mut output.ciphertext,
+                    &input.ciphertext,
+                    &acc.ciphertext,
+                    &bsk.key,
+                )?;
+                output.variance = estimate_addition_noise::<$Scalar, _, _>(
+                    acc.variance,
+                    estimate_pbs_noise::<$Scalar, _, BinaryKeyKind>(
+                        bsk.input_lwe_dimension(),
+                        bsk.polynomial_size(),
+                        bsk.glwe_dimension(),
+                        bsk.decomposition_base_log(),
+                        bsk.decomposition_level_count(),
+                        bsk.noise,
+                    ),
+                );
+                output.plaintext = bootstrap_plaintext(input.plaintext, acc.plaintexts.as_slice());
+                Ok(())
+            }
+
+            unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+                acc: &$Accumulator,
+                bsk: &$BootstrapKey,
+            ) {
+                self.core.discard_bootstrap_lwe_ciphertext_unchecked(
+                    &mut output.ciphertext,
+                    &input.ciphertext,
+                    &acc.ciphertext,
+                    &bsk.key,
+                );
+                output.variance = estimate_addition_noise::<$Scalar, _, _>(
+                    acc.variance,
+                    estimate_pbs_noise::<$Scalar, _, BinaryKeyKind>(
+                        bsk.input_lwe_dimension(),
+                        bsk.polynomial_size(),
+                        bsk.glwe_dimension(),
+                        bsk.decomposition_base_log(),
+                        bsk.decomposition_level_count(),
+                        bsk.noise,
+                    ),
+                );
+                output.plaintext = bootstrap_plaintext(input.plaintext, acc.plaintexts.as_slice());
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_discarding_bootstrap!(
+    DebugFourierLweBootstrapKey32,
+    DebugGlweCiphertext32,
+    DebugLweCiphertext32,
+    u32
+);
+implement_lwe_ciphertext_discarding_bootstrap!(
+    DebugFourierLweBootstrapKey64,
+    DebugGlweCiphertext64,
+    DebugLweCiphertext64,
+    u64
+);
@@ -0,0 +1,80 @@
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms;
+
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64, DebugLweKeyswitchKey32, DebugLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+macro_rules! implement_lwe_ciphertext_discarding_keyswitch {
+    ($KeyswitchKey: ident, $Ciphertext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DebugEngine`]. The
+        /// variance of the output ciphertext is estimated with
+        /// [`estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms`], an LWE ciphertext being
+        /// the constant term of a GLWE ciphertext.
+        impl LweCiphertextDiscardingKeyswitchEngine<$KeyswitchKey, $Ciphertext, $Ciphertext>
+            for DebugEngine
+        {
+            fn discard_keyswitch_lwe_ciphertext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+                ksk: &$KeyswitchKey,
+            ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+                self.core.discard_keyswitch_lwe_ciphertext(
+                    &mut output.ciphertext,
+                    &input.ciphertext,
+                    &ksk.key,
+                )?;
+                output.variance = estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                    $Scalar,
+                    _,
+                    _,
+                    BinaryKeyKind,
+                >(
+                    ksk.input_lwe_dimension(),
+                    input.variance,
+                    ksk.noise,
+                    ksk.decomposition_base_log(),
+                    ksk.decomposition_level_count(),
+                );
+                output.plaintext = input.plaintext;
+                Ok(())
+            }
+
+            unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+                ksk: &$KeyswitchKey,
+            ) {
+                self.core.discard_keyswitch_lwe_ciphertext_unchecked(
+                    &mut output.ciphertext,
+                    &input.ciphertext,
+                    &ksk.key,
+                );
+                output.variance = estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                    $Scalar,
+                    _,
+                    _,
+                    BinaryKeyKind,
+                >(
+                    ksk.input_lwe_dimension(),
+                    input.variance,
+                    ksk.noise,
+                    ksk.decomposition_base_log(),
+                    ksk.decomposition_level_count(),
+                );
+                output.plaintext = input.plaintext;
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_discarding_keyswitch!(DebugLweKeyswitchKey32, DebugLweCiphertext32, u32);
+implement_lwe_ciphertext_discarding_keyswitch!(DebugLweKeyswitchKey64, DebugLweCiphertext64, u64);
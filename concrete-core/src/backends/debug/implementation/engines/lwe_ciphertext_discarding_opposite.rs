@@ -0,0 +1,44 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingOppositeEngine, LweCiphertextDiscardingOppositeError,
+};
+
+macro_rules! implement_lwe_ciphertext_discarding_opposite {
+    ($Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextDiscardingOppositeEngine`] for [`DebugEngine`]. The
+        /// variance of the output ciphertext is the one of the input ciphertext.
+        impl LweCiphertextDiscardingOppositeEngine<$Ciphertext, $Ciphertext> for DebugEngine {
+            fn discard_opp_lwe_ciphertext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+            ) -> Result<(), LweCiphertextDiscardingOppositeError<Self::EngineError>> {
+                self.core
+                    .discard_opp_lwe_ciphertext(&mut output.ciphertext, &input.ciphertext)?;
+                output.variance = input.variance;
+                output.plaintext = input.plaintext.wrapping_neg();
+                Ok(())
+            }
+
+            unsafe fn discard_opp_lwe_ciphertext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+            ) {
+                self.core.discard_opp_lwe_ciphertext_unchecked(
+                    &mut output.ciphertext,
+                    &input.ciphertext,
+                );
+                output.variance = input.variance;
+                output.plaintext = input.plaintext.wrapping_neg();
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_discarding_opposite!(DebugLweCiphertext32);
+implement_lwe_ciphertext_discarding_opposite!(DebugLweCiphertext64);
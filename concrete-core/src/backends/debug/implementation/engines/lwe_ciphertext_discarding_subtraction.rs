@@ -0,0 +1,54 @@
+use concrete_npe::estimate_addition_noise;
+
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingSubtractionEngine, LweCiphertextDiscardingSubtractionError,
+};
+
+macro_rules! implement_lwe_ciphertext_discarding_subtraction {
+    ($Ciphertext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextDiscardingSubtractionEngine`] for [`DebugEngine`]. The variance of the output
+        /// ciphertext is estimated with [`estimate_addition_noise`].
+        impl LweCiphertextDiscardingSubtractionEngine<$Ciphertext, $Ciphertext> for DebugEngine {
+            fn discard_sub_lwe_ciphertext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Ciphertext,
+            ) -> Result<(), LweCiphertextDiscardingSubtractionError<Self::EngineError>> {
+                self.core.discard_sub_lwe_ciphertext(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    &input_2.ciphertext,
+                )?;
+                output.variance =
+                    estimate_addition_noise::<$Scalar, _, _>(input_1.variance, input_2.variance);
+                output.plaintext = input_1.plaintext.wrapping_sub(input_2.plaintext);
+                Ok(())
+            }
+
+            unsafe fn discard_sub_lwe_ciphertext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Ciphertext,
+            ) {
+                self.core.discard_sub_lwe_ciphertext_unchecked(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    &input_2.ciphertext,
+                );
+                output.variance =
+                    estimate_addition_noise::<$Scalar, _, _>(input_1.variance, input_2.variance);
+                output.plaintext = input_1.plaintext.wrapping_sub(input_2.plaintext);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_discarding_subtraction!(DebugLweCiphertext32, u32);
+implement_lwe_ciphertext_discarding_subtraction!(DebugLweCiphertext64, u64);
@@ -0,0 +1,48 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::entities::{LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
+
+macro_rules! implement_lwe_ciphertext_encryption {
+    ($SecretKey: ident, $Plaintext: ident, $Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextEncryptionEngine`] for [`DebugEngine`]. The variance
+        /// of the output ciphertext is the variance of the encryption noise.
+        impl LweCiphertextEncryptionEngine<$SecretKey, $Plaintext, $Ciphertext> for DebugEngine {
+            fn encrypt_lwe_ciphertext(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Plaintext,
+                noise: Variance,
+            ) -> Result<$Ciphertext, LweCiphertextEncryptionError<Self::EngineError>> {
+                Ok($Ciphertext {
+                    ciphertext: self.core.encrypt_lwe_ciphertext(key, input, noise)?,
+                    variance: noise,
+                    plaintext: input.0 .0,
+                })
+            }
+
+            unsafe fn encrypt_lwe_ciphertext_unchecked(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Plaintext,
+                noise: Variance,
+            ) -> $Ciphertext {
+                $Ciphertext {
+                    ciphertext: self
+                        .core
+                        .encrypt_lwe_ciphertext_unchecked(key, input, noise),
+                    variance: noise,
+                    plaintext: input.0 .0,
+                }
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_encryption!(LweSecretKey32, Plaintext32, DebugLweCiphertext32);
+implement_lwe_ciphertext_encryption!(LweSecretKey64, Plaintext64, DebugLweCiphertext64);
@@ -0,0 +1,46 @@
+use concrete_npe::estimate_addition_noise;
+
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+
+macro_rules! implement_lwe_ciphertext_fusing_addition {
+    ($Ciphertext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`DebugEngine`]. The variance of the output
+        /// ciphertext is estimated with [`estimate_addition_noise`].
+        impl LweCiphertextFusingAdditionEngine<$Ciphertext, $Ciphertext> for DebugEngine {
+            fn fuse_add_lwe_ciphertext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+            ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+                self.core
+                    .fuse_add_lwe_ciphertext(&mut output.ciphertext, &input.ciphertext)?;
+                output.variance =
+                    estimate_addition_noise::<$Scalar, _, _>(output.variance, input.variance);
+                output.plaintext = output.plaintext.wrapping_add(input.plaintext);
+                Ok(())
+            }
+
+            unsafe fn fuse_add_lwe_ciphertext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+            ) {
+                self.core
+                    .fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+                output.variance =
+                    estimate_addition_noise::<$Scalar, _, _>(output.variance, input.variance);
+                output.plaintext = output.plaintext.wrapping_add(input.plaintext);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_fusing_addition!(DebugLweCiphertext32, u32);
+implement_lwe_ciphertext_fusing_addition!(DebugLweCiphertext64, u64);
@@ -0,0 +1,34 @@
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingOppositeEngine, LweCiphertextFusingOppositeError,
+};
+
+macro_rules! implement_lwe_ciphertext_fusing_opposite {
+    ($Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextFusingOppositeEngine`] for [`DebugEngine`]. The
+        /// variance of the ciphertext is left unchanged.
+        impl LweCiphertextFusingOppositeEngine<$Ciphertext> for DebugEngine {
+            fn fuse_opp_lwe_ciphertext(
+                &mut self,
+                input: &mut $Ciphertext,
+            ) -> Result<(), LweCiphertextFusingOppositeError<Self::EngineError>> {
+                self.core.fuse_opp_lwe_ciphertext(&mut input.ciphertext)?;
+                input.plaintext = input.plaintext.wrapping_neg();
+                Ok(())
+            }
+
+            unsafe fn fuse_opp_lwe_ciphertext_unchecked(&mut self, input: &mut $Ciphertext) {
+                self.core
+                    .fuse_opp_lwe_ciphertext_unchecked(&mut input.ciphertext);
+                input.plaintext = input.plaintext.wrapping_neg();
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_fusing_opposite!(DebugLweCiphertext32);
+implement_lwe_ciphertext_fusing_opposite!(DebugLweCiphertext64);
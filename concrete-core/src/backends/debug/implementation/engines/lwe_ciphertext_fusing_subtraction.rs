@@ -0,0 +1,46 @@
+use concrete_npe::estimate_addition_noise;
+
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingSubtractionEngine, LweCiphertextFusingSubtractionError,
+};
+
+macro_rules! implement_lwe_ciphertext_fusing_subtraction {
+    ($Ciphertext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextFusingSubtractionEngine`] for [`DebugEngine`]. The variance of the output
+        /// ciphertext is estimated with [`estimate_addition_noise`].
+        impl LweCiphertextFusingSubtractionEngine<$Ciphertext, $Ciphertext> for DebugEngine {
+            fn fuse_sub_lwe_ciphertext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+            ) -> Result<(), LweCiphertextFusingSubtractionError<Self::EngineError>> {
+                self.core
+                    .fuse_sub_lwe_ciphertext(&mut output.ciphertext, &input.ciphertext)?;
+                output.variance =
+                    estimate_addition_noise::<$Scalar, _, _>(output.variance, input.variance);
+                output.plaintext = output.plaintext.wrapping_sub(input.plaintext);
+                Ok(())
+            }
+
+            unsafe fn fuse_sub_lwe_ciphertext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Ciphertext,
+            ) {
+                self.core
+                    .fuse_sub_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+                output.variance =
+                    estimate_addition_noise::<$Scalar, _, _>(output.variance, input.variance);
+                output.plaintext = output.plaintext.wrapping_sub(input.plaintext);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_fusing_subtraction!(DebugLweCiphertext32, u32);
+implement_lwe_ciphertext_fusing_subtraction!(DebugLweCiphertext64, u64);
@@ -0,0 +1,135 @@
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+use crate::backends::core::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{engine_error, AbstractEngine, LweCiphertextDecryptionEngine};
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity};
+
+engine_error! {
+    LweCiphertextNoiseMeasurementError for LweCiphertextNoiseMeasurementEngine @
+    LweDimensionMismatch => "The ciphertext and secret key LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextNoiseMeasurementError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext>(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// The noise of an LWE ciphertext, as measured by a [`LweCiphertextNoiseMeasurementEngine`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LweNoiseMeasurement {
+    /// The variance of the noise, as predicted by the estimators.
+    pub predicted_variance: Variance,
+    /// The error contained in the ciphertext, as a torus value in `[-0.5, 0.5)`.
+    pub measured_error: f64,
+}
+
+impl LweNoiseMeasurement {
+    /// Returns the absolute measured error, in number of predicted standard deviations.
+    ///
+    /// Since the noise is gaussian, the score of a ciphertext whose noise was properly estimated
+    /// is smaller than 3 in more than 99.7% of the cases. Larger scores hint at an operation whose
+    /// noise was underestimated, or at a bootstrap which selected the wrong coefficient of the
+    /// accumulator.
+    pub fn standard_score(&self) -> f64 {
+        if self.measured_error == 0. {
+            0.
+        } else {
+            self.measured_error.abs() / self.predicted_variance.get_standard_dev()
+        }
+    }
+}
+
+/// A trait for engines measuring the noise contained in LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](crate::specification::engines#operation-semantics) operation decrypts the `input`
+/// LWE ciphertext under the `key` secret key, and compares the result with the plaintext the
+/// ciphertext would decrypt to without noise. The difference is returned along with the variance
+/// predicted for the noise of the ciphertext.
+pub trait LweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Measures the noise of an LWE ciphertext.
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<LweNoiseMeasurement, LweCiphertextNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextNoiseMeasurementError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> LweNoiseMeasurement;
+}
+
+// Returns the difference between two torus elements, mapped to [-0.5, 0.5).
+fn torus_error<Scalar: UnsignedTorus>(decrypted: Scalar, expected: Scalar) -> f64 {
+    let error: f64 = decrypted.wrapping_sub(expected).into_torus();
+    if error < 0.5 {
+        error
+    } else {
+        error - 1.
+    }
+}
+
+macro_rules! implement_lwe_ciphertext_noise_measurement {
+    ($SecretKey: ident, $Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`DebugEngine`].
+        impl LweCiphertextNoiseMeasurementEngine<$SecretKey, $Ciphertext> for DebugEngine {
+            fn measure_lwe_ciphertext_noise(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Ciphertext,
+            ) -> Result<LweNoiseMeasurement, LweCiphertextNoiseMeasurementError<Self::EngineError>>
+            {
+                LweCiphertextNoiseMeasurementError::perform_generic_checks(key, input)?;
+                Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input) })
+            }
+
+            unsafe fn measure_lwe_ciphertext_noise_unchecked(
+                &mut self,
+                key: &$SecretKey,
+                input: &$Ciphertext,
+            ) -> LweNoiseMeasurement {
+                let decrypted = self
+                    .core
+                    .decrypt_lwe_ciphertext_unchecked(key, &input.ciphertext);
+                LweNoiseMeasurement {
+                    predicted_variance: input.variance,
+                    measured_error: torus_error(decrypted.0 .0, input.plaintext),
+                }
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_noise_measurement!(LweSecretKey32, DebugLweCiphertext32);
+implement_lwe_ciphertext_noise_measurement!(LweSecretKey64, DebugLweCiphertext64);
@@ -0,0 +1,53 @@
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextPlaintextDiscardingAdditionEngine, LweCiphertextPlaintextDiscardingAdditionError,
+};
+
+macro_rules! implement_lwe_ciphertext_plaintext_discarding_addition {
+    ($Ciphertext: ident, $Plaintext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`DebugEngine`].
+        /// The variance of the output ciphertext is the one of the input ciphertext.
+        impl LweCiphertextPlaintextDiscardingAdditionEngine<$Ciphertext, $Plaintext, $Ciphertext>
+            for DebugEngine
+        {
+            fn discard_add_lwe_ciphertext_plaintext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Plaintext,
+            ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
+                self.core.discard_add_lwe_ciphertext_plaintext(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                )?;
+                output.variance = input_1.variance;
+                output.plaintext = input_1.plaintext.wrapping_add(input_2.0 .0);
+                Ok(())
+            }
+
+            unsafe fn discard_add_lwe_ciphertext_plaintext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Plaintext,
+            ) {
+                self.core.discard_add_lwe_ciphertext_plaintext_unchecked(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                );
+                output.variance = input_1.variance;
+                output.plaintext = input_1.plaintext.wrapping_add(input_2.0 .0);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_plaintext_discarding_addition!(DebugLweCiphertext32, Plaintext32);
+implement_lwe_ciphertext_plaintext_discarding_addition!(DebugLweCiphertext64, Plaintext64);
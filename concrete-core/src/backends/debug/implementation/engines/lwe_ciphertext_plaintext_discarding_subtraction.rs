@@ -0,0 +1,55 @@
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextPlaintextDiscardingSubtractionEngine,
+    LweCiphertextPlaintextDiscardingSubtractionError,
+};
+
+macro_rules! implement_lwe_ciphertext_plaintext_discarding_subtraction {
+    ($Ciphertext: ident, $Plaintext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextPlaintextDiscardingSubtractionEngine`] for [`DebugEngine`].
+        /// The variance of the output ciphertext is the one of the input ciphertext.
+        impl LweCiphertextPlaintextDiscardingSubtractionEngine<$Ciphertext, $Plaintext, $Ciphertext>
+            for DebugEngine
+        {
+            fn discard_sub_lwe_ciphertext_plaintext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Plaintext,
+            ) -> Result<(), LweCiphertextPlaintextDiscardingSubtractionError<Self::EngineError>>
+            {
+                self.core.discard_sub_lwe_ciphertext_plaintext(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                )?;
+                output.variance = input_1.variance;
+                output.plaintext = input_1.plaintext.wrapping_sub(input_2.0 .0);
+                Ok(())
+            }
+
+            unsafe fn discard_sub_lwe_ciphertext_plaintext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input_1: &$Ciphertext,
+                input_2: &$Plaintext,
+            ) {
+                self.core.discard_sub_lwe_ciphertext_plaintext_unchecked(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                );
+                output.variance = input_1.variance;
+                output.plaintext = input_1.plaintext.wrapping_sub(input_2.0 .0);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_plaintext_discarding_subtraction!(DebugLweCiphertext32, Plaintext32);
+implement_lwe_ciphertext_plaintext_discarding_subtraction!(DebugLweCiphertext64, Plaintext64);
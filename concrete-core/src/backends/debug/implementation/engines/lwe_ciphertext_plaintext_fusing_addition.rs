@@ -0,0 +1,41 @@
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextPlaintextFusingAdditionEngine, LweCiphertextPlaintextFusingAdditionError,
+};
+
+macro_rules! implement_lwe_ciphertext_plaintext_fusing_addition {
+    ($Ciphertext: ident, $Plaintext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`DebugEngine`].
+        /// The variance of the ciphertext is left unchanged.
+        impl LweCiphertextPlaintextFusingAdditionEngine<$Ciphertext, $Plaintext> for DebugEngine {
+            fn fuse_add_lwe_ciphertext_plaintext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Plaintext,
+            ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+                self.core
+                    .fuse_add_lwe_ciphertext_plaintext(&mut output.ciphertext, input)?;
+                output.plaintext = output.plaintext.wrapping_add(input.0 .0);
+                Ok(())
+            }
+
+            unsafe fn fuse_add_lwe_ciphertext_plaintext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Plaintext,
+            ) {
+                self.core
+                    .fuse_add_lwe_ciphertext_plaintext_unchecked(&mut output.ciphertext, input);
+                output.plaintext = output.plaintext.wrapping_add(input.0 .0);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_plaintext_fusing_addition!(DebugLweCiphertext32, Plaintext32);
+implement_lwe_ciphertext_plaintext_fusing_addition!(DebugLweCiphertext64, Plaintext64);
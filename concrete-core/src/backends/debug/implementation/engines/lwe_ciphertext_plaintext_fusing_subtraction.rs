@@ -0,0 +1,43 @@
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextPlaintextFusingSubtractionEngine, LweCiphertextPlaintextFusingSubtractionError,
+};
+
+macro_rules! implement_lwe_ciphertext_plaintext_fusing_subtraction {
+    ($Ciphertext: ident, $Plaintext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextPlaintextFusingSubtractionEngine`] for [`DebugEngine`].
+        /// The variance of the ciphertext is left unchanged.
+        impl LweCiphertextPlaintextFusingSubtractionEngine<$Ciphertext, $Plaintext>
+            for DebugEngine
+        {
+            fn fuse_sub_lwe_ciphertext_plaintext(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Plaintext,
+            ) -> Result<(), LweCiphertextPlaintextFusingSubtractionError<Self::EngineError>> {
+                self.core
+                    .fuse_sub_lwe_ciphertext_plaintext(&mut output.ciphertext, input)?;
+                output.plaintext = output.plaintext.wrapping_sub(input.0 .0);
+                Ok(())
+            }
+
+            unsafe fn fuse_sub_lwe_ciphertext_plaintext_unchecked(
+                &mut self,
+                output: &mut $Ciphertext,
+                input: &$Plaintext,
+            ) {
+                self.core
+                    .fuse_sub_lwe_ciphertext_plaintext_unchecked(&mut output.ciphertext, input);
+                output.plaintext = output.plaintext.wrapping_sub(input.0 .0);
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_plaintext_fusing_subtraction!(DebugLweCiphertext32, Plaintext32);
+implement_lwe_ciphertext_plaintext_fusing_subtraction!(DebugLweCiphertext64, Plaintext64);
@@ -0,0 +1,51 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweSize;
+
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+
+macro_rules! implement_lwe_ciphertext_trivial_encryption {
+    ($Plaintext: ident, $Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextTrivialEncryptionEngine`] for [`DebugEngine`]. The
+        /// output ciphertext contains no noise.
+        impl LweCiphertextTrivialEncryptionEngine<$Plaintext, $Ciphertext> for DebugEngine {
+            fn trivially_encrypt_lwe_ciphertext(
+                &mut self,
+                lwe_size: LweSize,
+                input: &$Plaintext,
+            ) -> Result<$Ciphertext, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+                Ok($Ciphertext {
+                    ciphertext: self
+                        .core
+                        .trivially_encrypt_lwe_ciphertext(lwe_size, input)?,
+                    variance: Variance(0.),
+                    plaintext: input.0 .0,
+                })
+            }
+
+            unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+                &mut self,
+                lwe_size: LweSize,
+                input: &$Plaintext,
+            ) -> $Ciphertext {
+                $Ciphertext {
+                    ciphertext: self
+                        .core
+                        .trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input),
+                    variance: Variance(0.),
+                    plaintext: input.0 .0,
+                }
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_trivial_encryption!(Plaintext32, DebugLweCiphertext32);
+implement_lwe_ciphertext_trivial_encryption!(Plaintext64, DebugLweCiphertext64);
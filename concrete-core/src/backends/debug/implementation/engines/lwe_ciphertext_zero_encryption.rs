@@ -0,0 +1,46 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweCiphertext32, DebugLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextZeroEncryptionEngine, LweCiphertextZeroEncryptionError,
+};
+
+macro_rules! implement_lwe_ciphertext_zero_encryption {
+    ($SecretKey: ident, $Ciphertext: ident) => {
+        /// # Description:
+        /// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`DebugEngine`]. The
+        /// variance of the output ciphertext is the variance of the encryption noise.
+        impl LweCiphertextZeroEncryptionEngine<$SecretKey, $Ciphertext> for DebugEngine {
+            fn zero_encrypt_lwe_ciphertext(
+                &mut self,
+                key: &$SecretKey,
+                noise: Variance,
+            ) -> Result<$Ciphertext, LweCiphertextZeroEncryptionError<Self::EngineError>> {
+                Ok($Ciphertext {
+                    ciphertext: self.core.zero_encrypt_lwe_ciphertext(key, noise)?,
+                    variance: noise,
+                    plaintext: 0,
+                })
+            }
+
+            unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
+                &mut self,
+                key: &$SecretKey,
+                noise: Variance,
+            ) -> $Ciphertext {
+                $Ciphertext {
+                    ciphertext: self.core.zero_encrypt_lwe_ciphertext_unchecked(key, noise),
+                    variance: noise,
+                    plaintext: 0,
+                }
+            }
+        }
+    };
+}
+
+implement_lwe_ciphertext_zero_encryption!(LweSecretKey32, DebugLweCiphertext32);
+implement_lwe_ciphertext_zero_encryption!(LweSecretKey64, DebugLweCiphertext64);
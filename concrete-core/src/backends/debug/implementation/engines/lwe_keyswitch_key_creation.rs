@@ -0,0 +1,57 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::core::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::backends::debug::implementation::entities::{
+    DebugLweKeyswitchKey32, DebugLweKeyswitchKey64,
+};
+use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
+
+macro_rules! implement_lwe_keyswitch_key_creation {
+    ($SecretKey: ident, $KeyswitchKey: ident) => {
+        /// # Description:
+        /// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`DebugEngine`], which keeps
+        /// the variance of the noise the key is encrypted with.
+        impl LweKeyswitchKeyCreationEngine<$SecretKey, $SecretKey, $KeyswitchKey> for DebugEngine {
+            fn create_lwe_keyswitch_key(
+                &mut self,
+                input_key: &$SecretKey,
+                output_key: &$SecretKey,
+                decomposition_level_count: DecompositionLevelCount,
+                decomposition_base_log: DecompositionBaseLog,
+                noise: Variance,
+            ) -> Result<$KeyswitchKey, LweKeyswitchKeyCreationError<Self::EngineError>> {
+                let key = self.core.create_lwe_keyswitch_key(
+                    input_key,
+                    output_key,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                    noise,
+                )?;
+                Ok($KeyswitchKey { key, noise })
+            }
+
+            unsafe fn create_lwe_keyswitch_key_unchecked(
+                &mut self,
+                input_key: &$SecretKey,
+                output_key: &$SecretKey,
+                decomposition_level_count: DecompositionLevelCount,
+                decomposition_base_log: DecompositionBaseLog,
+                noise: Variance,
+            ) -> $KeyswitchKey {
+                let key = self.core.create_lwe_keyswitch_key_unchecked(
+                    input_key,
+                    output_key,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                    noise,
+                );
+                $KeyswitchKey { key, noise }
+            }
+        }
+    };
+}
+
+implement_lwe_keyswitch_key_creation!(LweSecretKey32, DebugLweKeyswitchKey32);
+implement_lwe_keyswitch_key_creation!(LweSecretKey64, DebugLweKeyswitchKey64);
@@ -0,0 +1,31 @@
+use concrete_commons::parameters::LweDimension;
+
+use crate::backends::core::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{LweSecretKeyCreationEngine, LweSecretKeyCreationError};
+
+macro_rules! implement_lwe_secret_key_creation {
+    ($SecretKey: ident) => {
+        /// # Description:
+        /// Implementation of [`LweSecretKeyCreationEngine`] for [`DebugEngine`], which delegates to
+        /// the core backend.
+        impl LweSecretKeyCreationEngine<$SecretKey> for DebugEngine {
+            fn create_lwe_secret_key(
+                &mut self,
+                lwe_dimension: LweDimension,
+            ) -> Result<$SecretKey, LweSecretKeyCreationError<Self::EngineError>> {
+                self.core.create_lwe_secret_key(lwe_dimension)
+            }
+
+            unsafe fn create_lwe_secret_key_unchecked(
+                &mut self,
+                lwe_dimension: LweDimension,
+            ) -> $SecretKey {
+                self.core.create_lwe_secret_key_unchecked(lwe_dimension)
+            }
+        }
+    };
+}
+
+implement_lwe_secret_key_creation!(LweSecretKey32);
+implement_lwe_secret_key_creation!(LweSecretKey64);
@@ -0,0 +1,123 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the debug backend.
+
+use crate::backends::core::engines::{CoreEngine, CoreError};
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The main engine exposed by the debug backend.
+///
+/// Every operation is executed by an inner [`CoreEngine`], and returns the same errors. The engine
+/// then updates the variance of the output ciphertext with the estimator of the `concrete-npe`
+/// crate matching the operation, and the plaintext the output would decrypt to without noise.
+///
+/// The secret keys, plaintexts and cleartexts used with this engine are the ones of the core
+/// backend. The keyswitch and bootstrap keys keep the variance they were encrypted with, which is
+/// needed to estimate the noise of the keyswitches and bootstraps.
+///
+/// Given the secret key, the noise actually contained in a ciphertext can be measured with the
+/// [`LweCiphertextNoiseMeasurementEngine`] implementations of this engine.
+///
+/// # Example:
+/// ```
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+/// let noise = Variance(2_f64.powf(-40.));
+///
+/// let mut engine = DebugEngine::new()?;
+/// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(1024))?;
+/// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(600))?;
+/// let keyswitch_key: DebugLweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+///     &input_key,
+///     &output_key,
+///     DecompositionLevelCount(3),
+///     DecompositionBaseLog(8),
+///     noise,
+/// )?;
+///
+/// let plaintext = engine.create_plaintext(&(3_u64 << 59))?;
+/// let ciphertext: DebugLweCiphertext64 =
+///     engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+/// let mut sum = ciphertext.clone();
+/// engine.fuse_add_lwe_ciphertext(&mut sum, &ciphertext)?;
+/// assert!((sum.variance().0 - 2. * noise.0).abs() < 1e-6 * noise.0);
+/// assert_eq!(sum.expected_plaintext(), 6_u64 << 59);
+///
+/// let mut switched: DebugLweCiphertext64 =
+///     engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+/// engine.discard_keyswitch_lwe_ciphertext(&mut switched, &sum, &keyswitch_key)?;
+/// assert!(switched.variance().0 > sum.variance().0);
+///
+/// // The error of the ciphertext is compared with the predicted variance.
+/// let measurement = engine.measure_lwe_ciphertext_noise(&output_key, &switched)?;
+/// assert_eq!(measurement.predicted_variance, switched.variance());
+/// assert!(measurement.standard_score() < 10.);
+///
+/// engine.destroy(input_key)?;
+/// engine.destroy(output_key)?;
+/// engine.destroy(keyswitch_key)?;
+/// engine.destroy(plaintext)?;
+/// engine.destroy(ciphertext)?;
+/// engine.destroy(sum)?;
+/// engine.destroy(switched)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct DebugEngine {
+    core: CoreEngine,
+}
+
+impl AbstractEngineSeal for DebugEngine {}
+
+impl AbstractEngine for DebugEngine {
+    type EngineError = CoreError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(DebugEngine {
+            core: CoreEngine::new()?,
+        })
+    }
+}
+
+mod cleartext_creation;
+mod destruction;
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_trivial_encryption;
+mod glwe_secret_key_creation;
+mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_cleartext_discarding_multiplication;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_discarding_subtraction;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_fusing_opposite;
+mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_noise_measurement;
+mod lwe_ciphertext_plaintext_discarding_addition;
+mod lwe_ciphertext_plaintext_discarding_subtraction;
+mod lwe_ciphertext_plaintext_fusing_addition;
+mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_creation;
+mod lwe_secret_key_creation;
+mod plaintext_creation;
+mod plaintext_retrieval;
+mod plaintext_vector_creation;
+mod plaintext_vector_retrieval;
+#[cfg(test)]
+mod tests;
+
+pub use lwe_ciphertext_noise_measurement::*;
@@ -0,0 +1,26 @@
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
+
+macro_rules! implement_plaintext_creation {
+    ($Plaintext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`PlaintextCreationEngine`] for [`DebugEngine`], which delegates to
+        /// the core backend.
+        impl PlaintextCreationEngine<$Scalar, $Plaintext> for DebugEngine {
+            fn create_plaintext(
+                &mut self,
+                input: &$Scalar,
+            ) -> Result<$Plaintext, PlaintextCreationError<Self::EngineError>> {
+                self.core.create_plaintext(input)
+            }
+
+            unsafe fn create_plaintext_unchecked(&mut self, input: &$Scalar) -> $Plaintext {
+                self.core.create_plaintext_unchecked(input)
+            }
+        }
+    };
+}
+
+implement_plaintext_creation!(Plaintext32, u32);
+implement_plaintext_creation!(Plaintext64, u64);
@@ -0,0 +1,26 @@
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{PlaintextRetrievalEngine, PlaintextRetrievalError};
+
+macro_rules! implement_plaintext_retrieval {
+    ($Plaintext: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`PlaintextRetrievalEngine`] for [`DebugEngine`], which delegates to
+        /// the core backend.
+        impl PlaintextRetrievalEngine<$Plaintext, $Scalar> for DebugEngine {
+            fn retrieve_plaintext(
+                &mut self,
+                plaintext: &$Plaintext,
+            ) -> Result<$Scalar, PlaintextRetrievalError<Self::EngineError>> {
+                self.core.retrieve_plaintext(plaintext)
+            }
+
+            unsafe fn retrieve_plaintext_unchecked(&mut self, plaintext: &$Plaintext) -> $Scalar {
+                self.core.retrieve_plaintext_unchecked(plaintext)
+            }
+        }
+    };
+}
+
+implement_plaintext_retrieval!(Plaintext32, u32);
+implement_plaintext_retrieval!(Plaintext64, u64);
@@ -0,0 +1,29 @@
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{PlaintextVectorCreationEngine, PlaintextVectorCreationError};
+
+macro_rules! implement_plaintext_vector_creation {
+    ($PlaintextVector: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`PlaintextVectorCreationEngine`] for [`DebugEngine`], which
+        /// delegates to the core backend.
+        impl PlaintextVectorCreationEngine<$Scalar, $PlaintextVector> for DebugEngine {
+            fn create_plaintext_vector(
+                &mut self,
+                input: &[$Scalar],
+            ) -> Result<$PlaintextVector, PlaintextVectorCreationError<Self::EngineError>> {
+                self.core.create_plaintext_vector(input)
+            }
+
+            unsafe fn create_plaintext_vector_unchecked(
+                &mut self,
+                input: &[$Scalar],
+            ) -> $PlaintextVector {
+                self.core.create_plaintext_vector_unchecked(input)
+            }
+        }
+    };
+}
+
+implement_plaintext_vector_creation!(PlaintextVector32, u32);
+implement_plaintext_vector_creation!(PlaintextVector64, u64);
@@ -0,0 +1,31 @@
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::debug::implementation::engines::DebugEngine;
+use crate::specification::engines::{
+    PlaintextVectorRetrievalEngine, PlaintextVectorRetrievalError,
+};
+
+macro_rules! implement_plaintext_vector_retrieval {
+    ($PlaintextVector: ident, $Scalar: ty) => {
+        /// # Description:
+        /// Implementation of [`PlaintextVectorRetrievalEngine`] for [`DebugEngine`], which
+        /// delegates to the core backend.
+        impl PlaintextVectorRetrievalEngine<$PlaintextVector, $Scalar> for DebugEngine {
+            fn retrieve_plaintext_vector(
+                &mut self,
+                plaintext: &$PlaintextVector,
+            ) -> Result<Vec<$Scalar>, PlaintextVectorRetrievalError<Self::EngineError>> {
+                self.core.retrieve_plaintext_vector(plaintext)
+            }
+
+            unsafe fn retrieve_plaintext_vector_unchecked(
+                &mut self,
+                plaintext: &$PlaintextVector,
+            ) -> Vec<$Scalar> {
+                self.core.retrieve_plaintext_vector_unchecked(plaintext)
+            }
+        }
+    };
+}
+
+implement_plaintext_vector_retrieval!(PlaintextVector32, u32);
+implement_plaintext_vector_retrieval!(PlaintextVector64, u64);
@@ -0,0 +1,199 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+use super::lwe_ciphertext_discarding_bootstrap::bootstrap_plaintext;
+use crate::prelude::*;
+
+// Returns the ratio between the mean squared error of the ciphertexts and their predicted
+// variance, which should be close to one.
+fn variance_ratio(measurements: &[LweNoiseMeasurement]) -> f64 {
+    measurements
+        .iter()
+        .map(|measurement| {
+            measurement.measured_error * measurement.measured_error
+                / measurement.predicted_variance.0
+        })
+        .sum::<f64>()
+        / measurements.len() as f64
+}
+
+#[test]
+fn test_bootstrap_plaintext() {
+    // With 4 coefficients, the torus is split in 8 slots, the last 4 being negated.
+    let accumulator = [1_u32, 2, 3, 4];
+    assert_eq!(bootstrap_plaintext(0, &accumulator), 1);
+    assert_eq!(bootstrap_plaintext(1 << 29, &accumulator), 2);
+    assert_eq!(bootstrap_plaintext((3 << 29) + (1 << 27), &accumulator), 4);
+    assert_eq!(
+        bootstrap_plaintext(5 << 29, &accumulator),
+        2_u32.wrapping_neg()
+    );
+    assert_eq!(bootstrap_plaintext(u32::MAX, &accumulator), 1);
+}
+
+#[test]
+fn test_linear_operations_32() {
+    let noise = Variance(2_f64.powf(-25.));
+    let mut engine = DebugEngine::new().unwrap();
+    let key: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(630)).unwrap();
+    let plaintext_1 = engine.create_plaintext(&(1_u32 << 28)).unwrap();
+    let plaintext_2 = engine.create_plaintext(&(5_u32 << 28)).unwrap();
+    let ciphertext_1: DebugLweCiphertext32 = engine
+        .encrypt_lwe_ciphertext(&key, &plaintext_1, noise)
+        .unwrap();
+    let ciphertext_2: DebugLweCiphertext32 = engine
+        .encrypt_lwe_ciphertext(&key, &plaintext_2, noise)
+        .unwrap();
+    assert_eq!(ciphertext_1.variance(), noise);
+    assert_eq!(ciphertext_1.expected_plaintext(), 1 << 28);
+
+    let mut output: DebugLweCiphertext32 = engine.zero_encrypt_lwe_ciphertext(&key, noise).unwrap();
+    engine
+        .discard_sub_lwe_ciphertext(&mut output, &ciphertext_2, &ciphertext_1)
+        .unwrap();
+    assert_eq!(output.expected_plaintext(), 4 << 28);
+    assert!((output.variance().0 - 2. * noise.0).abs() < 1e-9 * noise.0);
+
+    let cleartext = engine.create_cleartext(&3_u32.wrapping_neg()).unwrap();
+    engine
+        .fuse_mul_lwe_ciphertext_cleartext(&mut output, &cleartext)
+        .unwrap();
+    assert_eq!(output.expected_plaintext(), (12_u32 << 28).wrapping_neg());
+    assert!((output.variance().0 - 18. * noise.0).abs() < 1e-9 * noise.0);
+
+    engine.fuse_opp_lwe_ciphertext(&mut output).unwrap();
+    engine
+        .fuse_add_lwe_ciphertext_plaintext(&mut output, &plaintext_1)
+        .unwrap();
+    assert_eq!(output.expected_plaintext(), 13 << 28);
+    assert!((output.variance().0 - 18. * noise.0).abs() < 1e-9 * noise.0);
+
+    let measurement = engine.measure_lwe_ciphertext_noise(&key, &output).unwrap();
+    assert_eq!(measurement.predicted_variance, output.variance());
+    assert!(measurement.standard_score() < 6.);
+
+    let trivial: DebugLweCiphertext32 = engine
+        .trivially_encrypt_lwe_ciphertext(LweDimension(630).to_lwe_size(), &plaintext_2)
+        .unwrap();
+    let measurement = engine.measure_lwe_ciphertext_noise(&key, &trivial).unwrap();
+    assert_eq!(measurement.measured_error, 0.);
+    assert_eq!(measurement.standard_score(), 0.);
+}
+
+#[test]
+fn test_keyswitch_noise_64() {
+    let noise = Variance(2_f64.powf(-50.));
+    let mut engine = DebugEngine::new().unwrap();
+    let input_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(600)).unwrap();
+    let output_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(500)).unwrap();
+    let keyswitch_key: DebugLweKeyswitchKey64 = engine
+        .create_lwe_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(3),
+            DecompositionBaseLog(4),
+            noise,
+        )
+        .unwrap();
+    let plaintext = engine.create_plaintext(&(3_u64 << 59)).unwrap();
+    let mut output: DebugLweCiphertext64 = engine
+        .zero_encrypt_lwe_ciphertext(&output_key, noise)
+        .unwrap();
+
+    let measurements: Vec<_> = (0..200)
+        .map(|_| {
+            let input: DebugLweCiphertext64 = engine
+                .encrypt_lwe_ciphertext(&input_key, &plaintext, noise)
+                .unwrap();
+            engine
+                .discard_keyswitch_lwe_ciphertext(&mut output, &input, &keyswitch_key)
+                .unwrap();
+            assert_eq!(output.expected_plaintext(), 3 << 59);
+            engine
+                .measure_lwe_ciphertext_noise(&output_key, &output)
+                .unwrap()
+        })
+        .collect();
+    let ratio = variance_ratio(measurements.as_slice());
+    assert!((0.6..1.5).contains(&ratio), "ratio: {}", ratio);
+
+    // Measuring with the wrong key is reported.
+    assert!(matches!(
+        engine.measure_lwe_ciphertext_noise(&input_key, &output),
+        Err(LweCiphertextNoiseMeasurementError::LweDimensionMismatch)
+    ));
+}
+
+#[test]
+fn test_bootstrap_noise_64() {
+    let (lwe_dimension, glwe_dimension, polynomial_size) =
+        (LweDimension(100), GlweDimension(1), PolynomialSize(1024));
+    let noise = Variance(2_f64.powf(-50.));
+    let mut engine = DebugEngine::new().unwrap();
+    let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .create_glwe_secret_key(glwe_dimension, polynomial_size)
+        .unwrap();
+    let bootstrap_key: DebugFourierLweBootstrapKey64 = engine
+        .create_lwe_bootstrap_key(
+            &lwe_key,
+            &glwe_key,
+            DecompositionBaseLog(7),
+            DecompositionLevelCount(3),
+            Variance(2_f64.powf(-60.)),
+        )
+        .unwrap();
+    let output_key: LweSecretKey64 = engine
+        .transmute_glwe_secret_key_to_lwe_secret_key(glwe_key)
+        .unwrap();
+
+    // The accumulator maps each of the 8 first sixteenths of the torus to its index.
+    let lut: Vec<u64> = (0..polynomial_size.0 as u64)
+        .map(|index| (index / 128) << 58)
+        .collect();
+    let lut = engine.create_plaintext_vector(lut.as_slice()).unwrap();
+    let accumulator: DebugGlweCiphertext64 = engine
+        .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &lut)
+        .unwrap();
+    let mut output: DebugLweCiphertext64 = engine
+        .zero_encrypt_lwe_ciphertext(&output_key, noise)
+        .unwrap();
+
+    let measurements: Vec<_> = (0..100_u64)
+        .map(|index| {
+            let message = index % 8;
+            let plaintext = engine.create_plaintext(&((2 * message + 1) << 59)).unwrap();
+            let input: DebugLweCiphertext64 = engine
+                .encrypt_lwe_ciphertext(&lwe_key, &plaintext, noise)
+                .unwrap();
+            engine
+                .discard_bootstrap_lwe_ciphertext(&mut output, &input, &accumulator, &bootstrap_key)
+                .unwrap();
+            assert_eq!(output.expected_plaintext(), message << 58);
+            engine
+                .measure_lwe_ciphertext_noise(&output_key, &output)
+                .unwrap()
+        })
+        .collect();
+    let ratio = variance_ratio(measurements.as_slice());
+    assert!((0.5..2.).contains(&ratio), "ratio: {}", ratio);
+
+    // An input whose noise exceeds the width of the slots of the accumulator is bootstrapped to
+    // wrong messages, whose error is far larger than predicted.
+    let plaintext = engine.create_plaintext(&(1 << 59)).unwrap();
+    let overflowing = (0..20).any(|_| {
+        let input: DebugLweCiphertext64 = engine
+            .encrypt_lwe_ciphertext(&lwe_key, &plaintext, Variance(2_f64.powf(-6.)))
+            .unwrap();
+        engine
+            .discard_bootstrap_lwe_ciphertext(&mut output, &input, &accumulator, &bootstrap_key)
+            .unwrap();
+        let measurement = engine
+            .measure_lwe_ciphertext_noise(&output_key, &output)
+            .unwrap();
+        measurement.standard_score() > 1000.
+    });
+    assert!(overflowing);
+}
@@ -0,0 +1,100 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use crate::backends::core::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, GlweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, along with its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugGlweCiphertext32 {
+    pub(crate) ciphertext: GlweCiphertext32,
+    pub(crate) variance: Variance,
+    pub(crate) plaintexts: Vec<u32>,
+}
+
+impl DebugGlweCiphertext32 {
+    /// Returns the ciphertext of the core backend.
+    pub fn ciphertext(&self) -> &GlweCiphertext32 {
+        &self.ciphertext
+    }
+
+    /// Returns the predicted variance of the noise of the coefficients of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+
+    /// Returns the plaintexts the ciphertext would decrypt to, without noise.
+    pub fn expected_plaintexts(&self) -> &[u32] {
+        self.plaintexts.as_slice()
+    }
+
+    pub(crate) fn erase_plaintexts(&mut self) {
+        self.plaintexts
+            .iter_mut()
+            .for_each(|plaintext| *plaintext = 0);
+    }
+}
+
+impl AbstractEntity for DebugGlweCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for DebugGlweCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertext.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertext.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, along with its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugGlweCiphertext64 {
+    pub(crate) ciphertext: GlweCiphertext64,
+    pub(crate) variance: Variance,
+    pub(crate) plaintexts: Vec<u64>,
+}
+
+impl DebugGlweCiphertext64 {
+    /// Returns the ciphertext of the core backend.
+    pub fn ciphertext(&self) -> &GlweCiphertext64 {
+        &self.ciphertext
+    }
+
+    /// Returns the predicted variance of the noise of the coefficients of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+
+    /// Returns the plaintexts the ciphertext would decrypt to, without noise.
+    pub fn expected_plaintexts(&self) -> &[u64] {
+        self.plaintexts.as_slice()
+    }
+
+    pub(crate) fn erase_plaintexts(&mut self) {
+        self.plaintexts
+            .iter_mut()
+            .for_each(|plaintext| *plaintext = 0);
+    }
+}
+
+impl AbstractEntity for DebugGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for DebugGlweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertext.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertext.polynomial_size()
+    }
+}
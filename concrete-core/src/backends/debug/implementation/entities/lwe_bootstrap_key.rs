@@ -0,0 +1,106 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+use crate::backends::core::entities::{FourierLweBootstrapKey32, FourierLweBootstrapKey64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweBootstrapKeyKind};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision, in the fourier domain,
+/// along with the variance of the noise it was encrypted with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugFourierLweBootstrapKey32 {
+    pub(crate) key: FourierLweBootstrapKey32,
+    pub(crate) noise: Variance,
+}
+
+impl DebugFourierLweBootstrapKey32 {
+    /// Returns the bootstrap key of the core backend.
+    pub fn key(&self) -> &FourierLweBootstrapKey32 {
+        &self.key
+    }
+
+    /// Returns the variance of the noise the key was encrypted with.
+    pub fn noise(&self) -> Variance {
+        self.noise
+    }
+}
+
+impl AbstractEntity for DebugFourierLweBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+
+impl LweBootstrapKeyEntity for DebugFourierLweBootstrapKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.key.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.key.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, in the fourier domain,
+/// along with the variance of the noise it was encrypted with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugFourierLweBootstrapKey64 {
+    pub(crate) key: FourierLweBootstrapKey64,
+    pub(crate) noise: Variance,
+}
+
+impl DebugFourierLweBootstrapKey64 {
+    /// Returns the bootstrap key of the core backend.
+    pub fn key(&self) -> &FourierLweBootstrapKey64 {
+        &self.key
+    }
+
+    /// Returns the variance of the noise the key was encrypted with.
+    pub fn noise(&self) -> Variance {
+        self.noise
+    }
+}
+
+impl AbstractEntity for DebugFourierLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+
+impl LweBootstrapKeyEntity for DebugFourierLweBootstrapKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.key.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.key.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+}
@@ -0,0 +1,96 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{CiphertextModulus, LweDimension};
+
+use crate::backends::core::entities::{LweCiphertext32, LweCiphertext64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+
+/// A structure representing an LWE ciphertext with 32 bits of precision, along with its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugLweCiphertext32 {
+    pub(crate) ciphertext: LweCiphertext32,
+    pub(crate) variance: Variance,
+    pub(crate) plaintext: u32,
+}
+
+impl DebugLweCiphertext32 {
+    /// Returns the ciphertext of the core backend.
+    pub fn ciphertext(&self) -> &LweCiphertext32 {
+        &self.ciphertext
+    }
+
+    /// Returns the predicted variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+
+    /// Returns the plaintext the ciphertext would decrypt to, without noise.
+    pub fn expected_plaintext(&self) -> u32 {
+        self.plaintext
+    }
+
+    pub(crate) fn erase_plaintexts(&mut self) {
+        self.plaintext = 0;
+    }
+}
+
+impl AbstractEntity for DebugLweCiphertext32 {
+    type Kind = LweCiphertextKind;
+}
+
+impl LweCiphertextEntity for DebugLweCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertext.lwe_dimension()
+    }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext.ciphertext_modulus()
+    }
+}
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, along with its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugLweCiphertext64 {
+    pub(crate) ciphertext: LweCiphertext64,
+    pub(crate) variance: Variance,
+    pub(crate) plaintext: u64,
+}
+
+impl DebugLweCiphertext64 {
+    /// Returns the ciphertext of the core backend.
+    pub fn ciphertext(&self) -> &LweCiphertext64 {
+        &self.ciphertext
+    }
+
+    /// Returns the predicted variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+
+    /// Returns the plaintext the ciphertext would decrypt to, without noise.
+    pub fn expected_plaintext(&self) -> u64 {
+        self.plaintext
+    }
+
+    pub(crate) fn erase_plaintexts(&mut self) {
+        self.plaintext = 0;
+    }
+}
+
+impl AbstractEntity for DebugLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+
+impl LweCiphertextEntity for DebugLweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertext.lwe_dimension()
+    }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext.ciphertext_modulus()
+    }
+}
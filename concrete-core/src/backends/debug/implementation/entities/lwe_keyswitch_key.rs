@@ -0,0 +1,96 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+use crate::backends::core::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+
+/// A structure representing an LWE keyswitch key with 32 bits of precision, along with the
+/// variance of the noise it was encrypted with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugLweKeyswitchKey32 {
+    pub(crate) key: LweKeyswitchKey32,
+    pub(crate) noise: Variance,
+}
+
+impl DebugLweKeyswitchKey32 {
+    /// Returns the keyswitch key of the core backend.
+    pub fn key(&self) -> &LweKeyswitchKey32 {
+        &self.key
+    }
+
+    /// Returns the variance of the noise the key was encrypted with.
+    pub fn noise(&self) -> Variance {
+        self.noise
+    }
+}
+
+impl AbstractEntity for DebugLweKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+
+impl LweKeyswitchKeyEntity for DebugLweKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.key.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, along with the
+/// variance of the noise it was encrypted with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugLweKeyswitchKey64 {
+    pub(crate) key: LweKeyswitchKey64,
+    pub(crate) noise: Variance,
+}
+
+impl DebugLweKeyswitchKey64 {
+    /// Returns the keyswitch key of the core backend.
+    pub fn key(&self) -> &LweKeyswitchKey64 {
+        &self.key
+    }
+
+    /// Returns the variance of the noise the key was encrypted with.
+    pub fn noise(&self) -> Variance {
+        self.noise
+    }
+}
+
+impl AbstractEntity for DebugLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+
+impl LweKeyswitchKeyEntity for DebugLweKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.key.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+}
@@ -0,0 +1,12 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the debug
+//! backend.
+
+mod glwe_ciphertext;
+mod lwe_bootstrap_key;
+mod lwe_ciphertext;
+mod lwe_keyswitch_key;
+
+pub use glwe_ciphertext::*;
+pub use lwe_bootstrap_key::*;
+pub use lwe_ciphertext::*;
+pub use lwe_keyswitch_key::*;
@@ -0,0 +1,23 @@
+//! A module containing the debug backend implementation.
+//!
+//! This module contains a backend which executes the operations of the [`core`](super::core)
+//! backend, while keeping track of the noise contained in the ciphertexts. Along with the
+//! ciphertext of the core backend, each ciphertext of this backend carries:
+//!
+//! + The variance of its noise, as predicted by the estimators of the `concrete-npe` crate for
+//!   every operation the ciphertext went through.
+//! + The plaintext it would decrypt to, if no noise had been added.
+//!
+//! Given the secret key, the error actually contained in a ciphertext can then be measured, and
+//! compared with the predicted variance. When a computation decrypts to a wrong value, this makes
+//! it possible to find the first operation whose output noise exceeds what was expected.
+//!
+//! # Note:
+//!
+//! This backend is meant to be used while designing and debugging FHE programs. The plaintexts it
+//! tracks are stored in clear next to the ciphertexts, which makes its entities unsuitable for
+//! any other use.
+
+mod implementation;
+
+pub use implementation::{engines, entities};
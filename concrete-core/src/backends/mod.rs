@@ -1,9 +1,13 @@
 //! A module containing various backends implementing the `concrete` FHE scheme.
 //!
 //! This module contains all the backends implementing the concrete specification. As of now we
-//! support the following backend:
+//! support the following backends:
 //!
 //! + `core` : A single threaded CPU backend geared towards x86_64 architectures.
+//! + `debug` : A backend delegating to the `core` one, which tracks the noise of the ciphertexts.
 
 #[cfg(feature = "backend_core")]
 pub mod core;
+
+#[cfg(feature = "backend_debug")]
+pub mod debug;
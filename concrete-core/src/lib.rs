@@ -32,7 +32,8 @@
 //!
 //! The different backends can be activated using the feature flags `backend_*`. The `backend_core`
 //! contains an engine executing operations on a single thread of the cpu. It is activated by
-//! default.
+//! default. The `backend_debug` contains an engine delegating to the `core` one, which tracks the
+//! noise of the ciphertexts to help debugging FHE programs.
 //!
 //! # Navigating the code
 //!
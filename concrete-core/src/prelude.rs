@@ -12,5 +12,9 @@ pub use concrete_commons::*;
 pub use super::backends::core::engines::*;
 #[cfg(feature = "backend_core")]
 pub use super::backends::core::entities::*;
+#[cfg(feature = "backend_debug")]
+pub use super::backends::debug::engines::*;
+#[cfg(feature = "backend_debug")]
+pub use super::backends::debug::entities::*;
 pub use super::specification::engines::*;
 pub use super::specification::entities::*;
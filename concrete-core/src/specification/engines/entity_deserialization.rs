@@ -0,0 +1,39 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::AbstractEntity;
+
+engine_error! {
+    EntityDeserializationError for EntityDeserializationEngine @
+}
+
+/// A trait for engines deserializing entities.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity from its `serialized`
+/// representation, as produced by the matching
+/// [`EntitySerializationEngine`](super::EntitySerializationEngine).
+///
+/// # Note
+///
+/// The validity of the serialized representation depends on the layout chosen by the backend.
+/// Backends are expected to report malformed, corrupted or mismatched inputs through their engine
+/// error.
+pub trait EntityDeserializationEngine<Serialized, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity.
+    fn deserialize(
+        &mut self,
+        serialized: Serialized,
+    ) -> Result<Entity, EntityDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityDeserializationError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn deserialize_unchecked(&mut self, serialized: Serialized) -> Entity;
+}
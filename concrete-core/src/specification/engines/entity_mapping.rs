@@ -0,0 +1,48 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::AbstractEntity;
+
+engine_error! {
+    EntityMappingError for EntityMappingEngine @
+}
+
+/// A trait for engines mapping entities from a persistent storage.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a read-only entity whose data are
+/// backed by the `source`, instead of being copied to memory owned by the entity. Since the
+/// source can be modified outside of the control of the program, both methods are `unsafe`.
+///
+/// # Note
+///
+/// Contrary to the [`EntityDeserializationEngine`](super::EntityDeserializationEngine), the
+/// elements of the entity are read lazily from the source. This allows several processes mapping
+/// the same source to share a single physical copy of the entity. The layout expected in the
+/// source depends on the backend, which reports malformed or mismatched sources through its
+/// engine error.
+pub trait EntityMappingEngine<Source, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Maps an entity.
+    ///
+    /// # Safety
+    /// The entity reads its elements from the source for as long as it is alive. The source must
+    /// hence not be modified or truncated, by this process or by any other one, until the entity
+    /// and all its clones are dropped. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn map_entity(
+        &mut self,
+        source: Source,
+    ) -> Result<Entity, EntityMappingError<Self::EngineError>>;
+
+    /// Unsafely maps an entity.
+    ///
+    /// # Safety
+    /// The safety concerns of [`EntityMappingEngine::map_entity`] apply. For the _general_ safety
+    /// concerns regarding the checks skipped by this operation, refer to the different variants of
+    /// [`EntityMappingError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn map_entity_unchecked(&mut self, source: Source) -> Entity;
+}
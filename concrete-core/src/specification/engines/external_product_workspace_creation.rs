@@ -0,0 +1,61 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::ExternalProductWorkspaceEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+engine_error! {
+    ExternalProductWorkspaceCreationError for ExternalProductWorkspaceCreationEngine @
+    NullGlweDimension => "The workspace GLWE dimension must be greater than zero.",
+    NullPolynomialSize => "The workspace polynomial size must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> ExternalProductWorkspaceCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if glwe_dimension.0 == 0 {
+            return Err(Self::NullGlweDimension);
+        }
+
+        if polynomial_size.0 == 0 {
+            return Err(Self::NullPolynomialSize);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines creating external product workspaces.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation allocates a fresh workspace, which can be used
+/// to compute external products and bootstraps on ciphertexts of the given GLWE dimension and
+/// polynomial size.
+///
+/// # Formal Definition
+pub trait ExternalProductWorkspaceCreationEngine<Workspace>: AbstractEngine
+where
+    Workspace: ExternalProductWorkspaceEntity,
+{
+    /// Creates a new external product workspace.
+    fn create_external_product_workspace(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<Workspace, ExternalProductWorkspaceCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a new external product workspace.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`ExternalProductWorkspaceCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_external_product_workspace_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Workspace;
+}
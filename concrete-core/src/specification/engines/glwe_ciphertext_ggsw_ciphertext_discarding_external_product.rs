@@ -31,7 +31,7 @@ impl<EngineError: std::error::Error>
         if glwe_input.glwe_dimension().0 != ggsw_input.glwe_dimension().0
             || glwe_input.glwe_dimension().0 != output.glwe_dimension().0
         {
-            return Err(Self::PolynomialSizeMismatch);
+            return Err(Self::GlweDimensionMismatch);
         }
         Ok(())
     }
@@ -0,0 +1,103 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    ExternalProductWorkspaceEntity, GgswCiphertextEntity, GlweCiphertextEntity,
+};
+
+use super::engine_error;
+
+engine_error! {
+    GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError for
+    GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceEngine @
+    PolynomialSizeMismatch => "All the GGSW and GLWE ciphertexts polynomial sizes must be the same.",
+    GlweDimensionMismatch => "All the GGSW and GLWE ciphertexts GLWE dimension must be the same.",
+    WorkspacePolynomialSizeMismatch => "The workspace and ciphertexts polynomial sizes must be the \
+                                        same.",
+    WorkspaceGlweDimensionMismatch => "The workspace and ciphertexts GLWE dimensions must be the \
+                                       same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweCiphertext, GgswCiphertext, Workspace>(
+        glwe_input: &GlweCiphertext,
+        ggsw_input: &GgswCiphertext,
+        output: &GlweCiphertext,
+        workspace: &Workspace,
+    ) -> Result<(), Self>
+    where
+        GlweCiphertext: GlweCiphertextEntity,
+        GgswCiphertext: GgswCiphertextEntity,
+        Workspace: ExternalProductWorkspaceEntity,
+    {
+        if glwe_input.polynomial_size().0 != ggsw_input.polynomial_size().0
+            || glwe_input.polynomial_size().0 != output.polynomial_size().0
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if glwe_input.glwe_dimension().0 != ggsw_input.glwe_dimension().0
+            || glwe_input.glwe_dimension().0 != output.glwe_dimension().0
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if workspace.polynomial_size() != glwe_input.polynomial_size() {
+            return Err(Self::WorkspacePolynomialSizeMismatch);
+        }
+        if workspace.glwe_dimension() != glwe_input.glwe_dimension() {
+            return Err(Self::WorkspaceGlweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the external product between a GLWE ciphertext
+/// and a GSW ciphertext, using a caller-provided workspace.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the result of the external product between a `glwe_input` GLWE ciphertext and
+/// a `ggsw_input` GSW ciphertext. The temporary buffers needed by the computation are taken from
+/// the `workspace`, and no allocation is performed by the engine.
+///
+/// # Formal Definition
+pub trait GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceEngine<
+    GlweInput,
+    GgswInput,
+    Output,
+    Workspace,
+>: AbstractEngine where
+    GlweInput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+    Output: GlweCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+    Workspace: ExternalProductWorkspaceEntity,
+{
+    /// Computes the discarding external product between a GLWE and a GSW ciphertext, using the
+    /// given workspace.
+    fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_with_workspace(
+        &mut self,
+        glwe_input: &GlweInput,
+        ggsw_input: &GgswInput,
+        output: &mut Output,
+        workspace: &mut Workspace,
+    ) -> Result<
+        (),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError<Self::EngineError>,
+    >;
+
+    /// Unsafely computes the discarding external product between a GLWE and a GSW ciphertext,
+    /// using the given workspace.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextGgswCiphertextDiscardingExternalProductWithWorkspaceError`]. For safety
+    /// concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_with_workspace_unchecked(
+        &mut self,
+        glwe_input: &GlweInput,
+        ggsw_input: &GgswInput,
+        output: &mut Output,
+        workspace: &mut Workspace,
+    );
+}
@@ -0,0 +1,71 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextVectorEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+engine_error! {
+    GlweCiphertextVectorCreationError for GlweCiphertextVectorCreationEngine @
+    EmptyContainer => "The container used to create the GLWE ciphertext vector must not be empty.",
+    NullGlweDimension => "The GLWE dimension must be greater than zero.",
+    NullPolynomialSize => "The polynomial size must be greater than zero.",
+    ContainerSizeNotCompatibleWithGlweSize => "The container size must be a multiple of the \
+                                               number of coefficients of a GLWE ciphertext."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextVectorCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        container_length: usize,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if glwe_dimension.0 == 0 {
+            return Err(Self::NullGlweDimension);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(Self::NullPolynomialSize);
+        }
+        if container_length % (glwe_dimension.to_glwe_size().0 * polynomial_size.0) != 0 {
+            return Err(Self::ContainerSizeNotCompatibleWithGlweSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating GLWE ciphertext vectors from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a GLWE ciphertext vector from the
+/// `container` container, holding the ciphertexts of dimension `glwe_dimension` and polynomial size
+/// `polynomial_size` one after the other. Depending on the type of `container`, the ciphertext
+/// vector either owns its data, or is a view over data owned by the caller, in which case no copy
+/// is performed.
+pub trait GlweCiphertextVectorCreationEngine<Container, CiphertextVector>: AbstractEngine
+where
+    CiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Creates a GLWE ciphertext vector from an existing container.
+    fn create_glwe_ciphertext_vector(
+        &mut self,
+        container: Container,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CiphertextVector, GlweCiphertextVectorCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GLWE ciphertext vector from an existing container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Container,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> CiphertextVector;
+}
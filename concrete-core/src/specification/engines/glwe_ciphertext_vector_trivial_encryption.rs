@@ -7,6 +7,7 @@ use crate::specification::entities::GlweCiphertextVectorEntity;
 
 engine_error! {
     GlweCiphertextVectorTrivialEncryptionError for GlweCiphertextVectorTrivialEncryptionEngine @
+    NullCiphertextCount => "The ciphertext count must be greater than zero.",
     PlaintextCountMismatch => "The number of ciphertexts must divide the \
     plaintext count of the input vector (the result of this division is the polynomial size)."
 }
@@ -20,6 +21,9 @@ impl<EngineError: std::error::Error> GlweCiphertextVectorTrivialEncryptionError<
     where
         PlaintextVector: PlaintextVectorEntity,
     {
+        if glwe_ciphertext_count.0 == 0 {
+            return Err(Self::NullCiphertextCount);
+        }
         if input.plaintext_count().0 % glwe_ciphertext_count.0 != 0 {
             return Err(Self::PlaintextCountMismatch);
         }
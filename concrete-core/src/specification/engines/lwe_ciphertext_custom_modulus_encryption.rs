@@ -0,0 +1,64 @@
+use super::engine_error;
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity, PlaintextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::CiphertextModulus;
+
+engine_error! {
+    LweCiphertextCustomModulusEncryptionError for LweCiphertextCustomModulusEncryptionEngine @
+    InvalidCiphertextModulus => "A custom ciphertext modulus must be greater than one."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextCustomModulusEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(ciphertext_modulus: CiphertextModulus) -> Result<(), Self> {
+        match ciphertext_modulus {
+            CiphertextModulus::Custom(modulus) if modulus < 2 => {
+                Err(Self::InvalidCiphertextModulus)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A trait for engines encrypting LWE ciphertexts under a given ciphertext modulus.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// encryption of the `input` plaintext under the `key` secret key, whose coefficients live modulo
+/// `ciphertext_modulus`. With a custom modulus $q$, the `input` plaintext is expected to be
+/// encoded in $[0, q)$.
+///
+/// # Formal Definition
+pub trait LweCiphertextCustomModulusEncryptionEngine<SecretKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Encrypts an LWE ciphertext under a given ciphertext modulus.
+    fn custom_modulus_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Result<Ciphertext, LweCiphertextCustomModulusEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext under a given ciphertext modulus.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextCustomModulusEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn custom_modulus_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Ciphertext;
+}
@@ -4,7 +4,8 @@ use crate::specification::entities::LweCiphertextEntity;
 
 engine_error! {
     LweCiphertextDiscardingAdditionError for LweCiphertextDiscardingAdditionEngine @
-    LweDimensionMismatch => "All the ciphertext LWE dimensions must be the same."
+    LweDimensionMismatch => "All the ciphertext LWE dimensions must be the same.",
+    CiphertextModulusMismatch => "The input ciphertext moduli must be the same."
 }
 
 impl<EngineError: std::error::Error> LweCiphertextDiscardingAdditionError<EngineError> {
@@ -23,6 +24,9 @@ impl<EngineError: std::error::Error> LweCiphertextDiscardingAdditionError<Engine
         {
             return Err(Self::LweDimensionMismatch);
         }
+        if input_1.ciphertext_modulus() != input_2.ciphertext_modulus() {
+            return Err(Self::CiphertextModulusMismatch);
+        }
         Ok(())
     }
 }
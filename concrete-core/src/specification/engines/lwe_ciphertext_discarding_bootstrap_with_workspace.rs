@@ -0,0 +1,117 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    ExternalProductWorkspaceEntity, GlweCiphertextEntity, LweBootstrapKeyEntity,
+    LweCiphertextEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingBootstrapWithWorkspaceError for
+    LweCiphertextDiscardingBootstrapWithWorkspaceEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext dimension and key size (dimension * \
+                                   polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    WorkspacePolynomialSizeMismatch => "The workspace and key polynomial sizes must be the same.",
+    WorkspaceGlweDimensionMismatch => "The workspace and key GLWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextDiscardingBootstrapWithWorkspaceError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+        Workspace,
+    >(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        workspace: &Workspace,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+        InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+        OutputCiphertext:
+            LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+        Workspace: ExternalProductWorkspaceEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if workspace.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::WorkspacePolynomialSizeMismatch);
+        }
+        if workspace.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::WorkspaceGlweDimensionMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping (discarding) LWE ciphertexts, using a caller-provided
+/// workspace.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the bootstrap of the `input` LWE ciphertext, using the `acc` accumulator as lookup-table, and
+/// the `bsk` bootstrap key. The temporary buffers needed by the computation are taken from the
+/// `workspace`, and no allocation is performed by the engine.
+///
+/// # Formal Definition
+pub trait LweCiphertextDiscardingBootstrapWithWorkspaceEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertext,
+    Workspace,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    Workspace: ExternalProductWorkspaceEntity,
+{
+    /// Bootstrap an LWE ciphertext, using the given workspace.
+    fn discard_bootstrap_lwe_ciphertext_with_workspace(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        workspace: &mut Workspace,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapWithWorkspaceError<Self::EngineError>>;
+
+    /// Unsafely bootstrap an LWE ciphertext, using the given workspace.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingBootstrapWithWorkspaceError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_bootstrap_lwe_ciphertext_with_workspace_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        workspace: &mut Workspace,
+    );
+}
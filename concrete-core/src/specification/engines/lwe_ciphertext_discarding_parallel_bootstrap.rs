@@ -0,0 +1,103 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingParallelBootstrapError for LweCiphertextDiscardingParallelBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertexts and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertexts dimension and key size (dimension * \
+                                   polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingParallelBootstrapError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>(
+        output: &[OutputCiphertext],
+        input: &[InputCiphertext],
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+        InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+        OutputCiphertext:
+            LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    {
+        if input.len() != output.len() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        if input
+            .iter()
+            .any(|ct| ct.lwe_dimension() != bsk.input_lwe_dimension())
+        {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output
+            .iter()
+            .any(|ct| ct.lwe_dimension() != bsk.output_lwe_dimension())
+        {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping (discarding) slices of LWE ciphertexts in parallel.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills each ciphertext of the `output`
+/// slice with the bootstrap of the ciphertext of the `input` slice at the same index, using the
+/// `acc` accumulator as lookup-table, and the `bsk` bootstrap key. The bootstraps are independent,
+/// and may be distributed across all the available cores, every one of them sharing the same
+/// bootstrap key.
+///
+/// # Formal Definition
+pub trait LweCiphertextDiscardingParallelBootstrapEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+    InputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+{
+    /// Bootstraps a slice of LWE ciphertexts in parallel.
+    fn discard_parallel_bootstrap_lwe_ciphertexts(
+        &mut self,
+        output: &mut [OutputCiphertext],
+        input: &[InputCiphertext],
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingParallelBootstrapError<Self::EngineError>>;
+
+    /// Unsafely bootstraps a slice of LWE ciphertexts in parallel.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingParallelBootstrapError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_parallel_bootstrap_lwe_ciphertexts_unchecked(
+        &mut self,
+        output: &mut [OutputCiphertext],
+        input: &[InputCiphertext],
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    );
+}
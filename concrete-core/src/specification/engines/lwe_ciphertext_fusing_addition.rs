@@ -4,7 +4,8 @@ use crate::specification::entities::LweCiphertextEntity;
 
 engine_error! {
     LweCiphertextFusingAdditionError for LweCiphertextFusingAdditionEngine @
-    LweDimensionMismatch => "The input and output LWE dimensions must be the same."
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    CiphertextModulusMismatch => "The input and output ciphertext moduli must be the same."
 }
 
 impl<EngineError: std::error::Error> LweCiphertextFusingAdditionError<EngineError> {
@@ -20,6 +21,9 @@ impl<EngineError: std::error::Error> LweCiphertextFusingAdditionError<EngineErro
         if output.lwe_dimension() != input.lwe_dimension() {
             return Err(Self::LweDimensionMismatch);
         }
+        if output.ciphertext_modulus() != input.ciphertext_modulus() {
+            return Err(Self::CiphertextModulusMismatch);
+        }
         Ok(())
     }
 }
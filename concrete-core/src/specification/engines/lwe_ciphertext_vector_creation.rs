@@ -0,0 +1,60 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweSize;
+
+engine_error! {
+    LweCiphertextVectorCreationError for LweCiphertextVectorCreationEngine @
+    EmptyContainer => "The container used to create the LWE ciphertext vector must not be empty.",
+    NullLweDimension => "The LWE dimension must be greater than zero.",
+    ContainerSizeNotCompatibleWithLweSize => "The container size must be a multiple of the LWE \
+                                              size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(container_length: usize, lwe_size: LweSize) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if lwe_size.0 < 2 {
+            return Err(Self::NullLweDimension);
+        }
+        if container_length % lwe_size.0 != 0 {
+            return Err(Self::ContainerSizeNotCompatibleWithLweSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating LWE ciphertext vectors from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE ciphertext vector from the
+/// `container` container, holding the ciphertexts of size `lwe_size` one after the other. Depending
+/// on the type of `container`, the ciphertext vector either owns its data, or is a view over data
+/// owned by the caller, in which case no copy is performed.
+pub trait LweCiphertextVectorCreationEngine<Container, CiphertextVector>: AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Creates an LWE ciphertext vector from an existing container.
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+    ) -> Result<CiphertextVector, LweCiphertextVectorCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE ciphertext vector from an existing container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+    ) -> CiphertextVector;
+}
@@ -149,6 +149,10 @@ mod cleartext_vector_discarding_retrieval;
 mod cleartext_vector_encoding;
 mod cleartext_vector_retrieval;
 mod destruction;
+mod entity_deserialization;
+mod entity_mapping;
+mod entity_serialization;
+mod external_product_workspace_creation;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
@@ -161,10 +165,12 @@ mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
 mod glwe_ciphertext_encryption;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product_with_workspace;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_conversion;
+mod glwe_ciphertext_vector_creation;
 mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_conversion;
 mod glwe_ciphertext_vector_discarding_decryption;
@@ -184,9 +190,11 @@ mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
 mod lwe_ciphertext_conversion;
+mod lwe_ciphertext_custom_modulus_encryption;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_bootstrap_with_workspace;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
@@ -194,6 +202,7 @@ mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_loading;
 mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_discarding_parallel_bootstrap;
 mod lwe_ciphertext_discarding_storing;
 mod lwe_ciphertext_discarding_subtraction;
 mod lwe_ciphertext_encryption;
@@ -208,6 +217,7 @@ mod lwe_ciphertext_plaintext_fusing_subtraction;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
 mod lwe_ciphertext_vector_conversion;
+mod lwe_ciphertext_vector_creation;
 mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
@@ -262,6 +272,10 @@ pub use cleartext_vector_discarding_retrieval::*;
 pub use cleartext_vector_encoding::*;
 pub use cleartext_vector_retrieval::*;
 pub use destruction::*;
+pub use entity_deserialization::*;
+pub use entity_mapping::*;
+pub use entity_serialization::*;
+pub use external_product_workspace_creation::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
@@ -274,10 +288,12 @@ pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
 pub use glwe_ciphertext_encryption::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
+pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product_with_workspace::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_conversion::*;
+pub use glwe_ciphertext_vector_creation::*;
 pub use glwe_ciphertext_vector_decryption::*;
 pub use glwe_ciphertext_vector_discarding_conversion::*;
 pub use glwe_ciphertext_vector_discarding_decryption::*;
@@ -297,9 +313,11 @@ pub use lwe_bootstrap_key_discarding_conversion::*;
 pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
 pub use lwe_ciphertext_conversion::*;
+pub use lwe_ciphertext_custom_modulus_encryption::*;
 pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
+pub use lwe_ciphertext_discarding_bootstrap_with_workspace::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_decryption::*;
 pub use lwe_ciphertext_discarding_encryption::*;
@@ -307,6 +325,7 @@ pub use lwe_ciphertext_discarding_extraction::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
 pub use lwe_ciphertext_discarding_loading::*;
 pub use lwe_ciphertext_discarding_opposite::*;
+pub use lwe_ciphertext_discarding_parallel_bootstrap::*;
 pub use lwe_ciphertext_discarding_storing::*;
 pub use lwe_ciphertext_discarding_subtraction::*;
 pub use lwe_ciphertext_encryption::*;
@@ -321,6 +340,7 @@ pub use lwe_ciphertext_plaintext_fusing_subtraction::*;
 pub use lwe_ciphertext_trivial_decryption::*;
 pub use lwe_ciphertext_trivial_encryption::*;
 pub use lwe_ciphertext_vector_conversion::*;
+pub use lwe_ciphertext_vector_creation::*;
 pub use lwe_ciphertext_vector_decryption::*;
 pub use lwe_ciphertext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
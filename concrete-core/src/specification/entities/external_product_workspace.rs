@@ -0,0 +1,22 @@
+use crate::specification::entities::markers::ExternalProductWorkspaceKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A trait implemented by types embodying a workspace for external products.
+///
+/// A workspace holds the temporary buffers (fft plan, fourier polynomials, accumulators, ...)
+/// needed to compute external products between GLWE and GGSW ciphertexts of a given polynomial
+/// size and GLWE dimension. Since bootstraps are sequences of external products, a workspace can
+/// also be used to compute bootstraps with keys of the same polynomial size and GLWE dimension.
+///
+/// Explicitly creating a workspace allows to control when those buffers are allocated and freed,
+/// and to reuse them across operations.
+pub trait ExternalProductWorkspaceEntity:
+    AbstractEntity<Kind = ExternalProductWorkspaceKind>
+{
+    /// Returns the GLWE dimension supported by the workspace.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size supported by the workspace.
+    fn polynomial_size(&self) -> PolynomialSize;
+}
@@ -1,6 +1,6 @@
 use crate::specification::entities::markers::{KeyDistributionMarker, LweCiphertextKind};
 use crate::specification::entities::AbstractEntity;
-use concrete_commons::parameters::LweDimension;
+use concrete_commons::parameters::{CiphertextModulus, LweDimension};
 
 /// A trait implemented by types embodying an LWE ciphertext.
 ///
@@ -15,4 +15,11 @@ pub trait LweCiphertextEntity: AbstractEntity<Kind = LweCiphertextKind> {
 
     /// Returns the LWE dimension of the ciphertext.
     fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the modulus the coefficients of the ciphertext live in.
+    ///
+    /// Defaults to the native modulus, for backends which do not support custom moduli.
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        CiphertextModulus::Native
+    }
 }
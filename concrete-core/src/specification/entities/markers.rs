@@ -63,7 +63,9 @@ entity_kind_marker! {
         EncoderKind
             => "An empty type representing the encoder kind in the type system.",
         EncoderVectorKind
-            => "An empty type representing the encoder vector kind in the type system"
+            => "An empty type representing the encoder vector kind in the type system",
+        ExternalProductWorkspaceKind
+            => "An empty type representing the external product workspace kind in the type system."
 }
 
 /// A trait implemented by marker types encoding a _distribution_ of secret key in the type system.
@@ -38,6 +38,7 @@ mod cleartext;
 mod cleartext_vector;
 mod encoder;
 mod encoder_vector;
+mod external_product_workspace;
 mod ggsw_ciphertext;
 mod ggsw_ciphertext_vector;
 mod glwe_ciphertext;
@@ -58,6 +59,7 @@ pub use cleartext::*;
 pub use cleartext_vector::*;
 pub use encoder::*;
 pub use encoder_vector::*;
+pub use external_product_workspace::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_vector::*;
 pub use glwe_ciphertext::*;
@@ -1,10 +1,11 @@
+use concrete_csprng::seeders::RdseedSeeder;
 use concrete_csprng::RandomGenerator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 const N_GEN: usize = 1_000_000;
 
 fn unbounded_benchmark(c: &mut Criterion) {
-    let mut generator = RandomGenerator::new_hardware(None).unwrap();
+    let mut generator = RandomGenerator::new_hardware(&mut RdseedSeeder).unwrap();
     c.bench_function("unbounded", |b| {
         b.iter(|| {
             (0..N_GEN).for_each(|_| {
@@ -15,7 +16,7 @@ fn unbounded_benchmark(c: &mut Criterion) {
 }
 
 fn bounded_benchmark(c: &mut Criterion) {
-    let mut generator = RandomGenerator::new_hardware(None).unwrap();
+    let mut generator = RandomGenerator::new_hardware(&mut RdseedSeeder).unwrap();
     let mut generator = generator
         .try_fork(1, N_GEN * 10_000)
         .unwrap()
@@ -30,5 +31,21 @@ fn bounded_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, unbounded_benchmark, bounded_benchmark);
+fn chacha_benchmark(c: &mut Criterion) {
+    let mut generator = RandomGenerator::new_chacha(&mut RdseedSeeder);
+    c.bench_function("chacha", |b| {
+        b.iter(|| {
+            (0..N_GEN).for_each(|_| {
+                generator.generate_next();
+            })
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    unbounded_benchmark,
+    bounded_benchmark,
+    chacha_benchmark
+);
 criterion_main!(benches);
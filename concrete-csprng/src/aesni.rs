@@ -17,18 +17,14 @@ pub struct Generator {
 }
 
 impl AesBatchedGenerator for Generator {
-    fn new(key: Option<AesKey>) -> Generator {
-        if is_x86_feature_detected!("aes")
-            && is_x86_feature_detected!("rdseed")
-            && is_x86_feature_detected!("sse2")
-        {
-            let round_keys =
-                generate_round_keys(key.unwrap_or_else(generate_initialization_vector));
+    fn new(key: AesKey) -> Generator {
+        if is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2") {
+            let round_keys = generate_round_keys(key);
             Generator { round_keys }
         } else {
             panic!(
-                "One of the `aes`, `rdseed`, or `sse2` instructions set was not fount. It is \
-                 currently mandatory to use `concrete-csprng`."
+                "One of the `aes` or `sse2` instructions set was not found. It is mandatory to \
+                 use the hardware generator."
             )
         }
     }
@@ -48,40 +44,13 @@ impl AesBatchedGenerator for Generator {
     }
 }
 
-fn generate_initialization_vector() -> AesKey {
-    // The initialization vector is a random value from rdseed
-    AesKey(si128_to_u128(rdseed_random_m128()))
-}
-
 fn generate_round_keys(key: AesKey) -> [__m128i; 11] {
-    // The secret key is a random value from rdseed.
     let key = u128_to_si128(key.0);
     let mut keys: [__m128i; 11] = [u128_to_si128(0); 11];
     aes_128_key_expansion(key, &mut keys);
     keys
 }
 
-// Generates a random 128 bits value from rdseed
-fn rdseed_random_m128() -> __m128i {
-    let mut rand1: u64 = 0;
-    let mut rand2: u64 = 0;
-    unsafe {
-        loop {
-            if core::arch::x86_64::_rdseed64_step(&mut rand1) == 1 {
-                break;
-            }
-        }
-        loop {
-            if core::arch::x86_64::_rdseed64_step(&mut rand2) == 1 {
-                break;
-            }
-        }
-        #[repr(C)]
-        struct _tuple(u64, u64);
-        std::mem::transmute::<_tuple, __m128i>(_tuple(rand1, rand2))
-    }
-}
-
 // Uses aes to encrypt many values at once. This allows a substantial speedup (around 30%)
 // compared to the naive approach.
 #[allow(clippy::too_many_arguments)]
@@ -198,6 +167,7 @@ fn u128_to_si128(input: u128) -> __m128i {
     unsafe { transmute(input) }
 }
 
+#[allow(dead_code)] // Only used in tests.
 fn si128_to_u128(input: __m128i) -> u128 {
     unsafe { transmute(input) }
 }
@@ -265,7 +235,7 @@ mod test {
         // Checks that the PRNG generates uniform numbers
         let precision = 10f64.powi(-4);
         let n_samples = 10_000_000_usize;
-        let mut generator = Generator::new(None);
+        let mut generator = Generator::new(AesKey(rand::random()));
         let mut counts = [0usize; 256];
         let expected_prob: f64 = 1. / 256.;
         for counter in 0..n_samples {
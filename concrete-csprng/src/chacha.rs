@@ -0,0 +1,177 @@
+//! A module implementing a `chacha20-counter` random number generator, in portable software.
+//!
+//! This generator is an alternative to the `aes128-counter` ones, which is much faster than the
+//! software implementation of aes on machines lacking the `aesni` instructions. It uses the
+//! original variant of the ChaCha20 streamcipher with a 128 bits key, as described in
+//! [the ChaCha paper](https://cr.yp.to/chacha/chacha-20080128.pdf), where the last four words of
+//! the state hold a 128 bits block counter instead of a 64 bits counter and a nonce.
+//!
+//! To share the counter logic of the aes generators, a ChaCha20 block of 64 bytes is seen as
+//! four successive blocks of 16 bytes. The counter `AesCtr(i)` hence designates the bytes `16 *
+//! (i % 4)` to `16 * (i % 4) + 15` of the ChaCha20 block `i / 4`.
+use crate::counter::{AesBatchedGenerator, AesCtr, AesKey};
+
+// The constant words used with a 128 bits key.
+const TAU: [u32; 4] = [0x61707865, 0x3120646e, 0x79622d36, 0x6b206574];
+
+// The number of 16 bytes blocks in a ChaCha20 block.
+const BLOCKS_PER_CHACHA_BLOCK: u128 = 4;
+
+#[derive(Clone)]
+pub struct Generator {
+    // The initial state, whose counter words are set for each ChaCha20 block.
+    state: [u32; 16],
+}
+
+impl AesBatchedGenerator for Generator {
+    fn new(AesKey(key): AesKey) -> Generator {
+        let key_bytes = key.to_le_bytes();
+        let mut key_words = [0u32; 4];
+        for (word, bytes) in key_words.iter_mut().zip(key_bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        let mut state = [0u32; 16];
+        state[0..4].copy_from_slice(&TAU);
+        state[4..8].copy_from_slice(&key_words);
+        state[8..12].copy_from_slice(&key_words);
+        Generator { state }
+    }
+
+    fn generate_batch(&mut self, AesCtr(aes_ctr): AesCtr) -> [u8; 128] {
+        // The 8 blocks of 16 bytes of the batch span two or three ChaCha20 blocks.
+        let first_chacha_block = aes_ctr / BLOCKS_PER_CHACHA_BLOCK;
+        let offset = ((aes_ctr % BLOCKS_PER_CHACHA_BLOCK) * 16) as usize;
+        let mut keystream = [0u8; 192];
+        for (i, chunk) in keystream.chunks_exact_mut(64).enumerate() {
+            if i * 64 >= offset + 128 {
+                break;
+            }
+            chunk.copy_from_slice(&self.chacha_block(first_chacha_block.wrapping_add(i as u128)));
+        }
+        let mut output = [0u8; 128];
+        output.copy_from_slice(&keystream[offset..offset + 128]);
+        output
+    }
+}
+
+impl Generator {
+    // Computes the ChaCha20 block of the given counter.
+    fn chacha_block(&self, counter: u128) -> [u8; 64] {
+        let mut input = self.state;
+        let counter_bytes = counter.to_le_bytes();
+        for (word, bytes) in input[12..16].iter_mut().zip(counter_bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        chacha20_block(&input)
+    }
+}
+
+// Applies the ChaCha20 block function to a state.
+fn chacha20_block(input: &[u32; 16]) -> [u8; 64] {
+    let mut state = *input;
+    for _ in 0..10 {
+        // Column rounds
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+    let mut output = [0u8; 64];
+    for ((chunk, word), input_word) in output
+        .chunks_exact_mut(4)
+        .zip(state.iter())
+        .zip(input.iter())
+    {
+        chunk.copy_from_slice(&word.wrapping_add(*input_word).to_le_bytes());
+    }
+    output
+}
+
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quarter_round() {
+        // Test vector from section 2.1.1 of RFC 7539.
+        let mut state = [0u32; 16];
+        state[0] = 0x11111111;
+        state[1] = 0x01020304;
+        state[2] = 0x9b8d6f43;
+        state[3] = 0x01234567;
+        quarter_round(&mut state, 0, 1, 2, 3);
+        assert_eq!(
+            state[0..4],
+            [0xea2a92f4, 0xcb1cf8ce, 0x4581472e, 0x5881c4bb]
+        );
+    }
+
+    #[test]
+    fn test_chacha20_block() {
+        // Test vector from section 2.3.2 of RFC 7539, which uses a 256 bits key and a 96 bits
+        // nonce. The block function is the same as the one of the 128 bits key variant.
+        let input: [u32; 16] = [
+            0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, 0x03020100, 0x07060504, 0x0b0a0908,
+            0x0f0e0d0c, 0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c, 0x00000001, 0x09000000,
+            0x4a000000, 0x00000000,
+        ];
+        let expected: [u8; 64] = [
+            0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20,
+            0x71, 0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a,
+            0xc3, 0xd4, 0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2,
+            0xd7, 0x05, 0xd9, 0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9,
+            0xcb, 0xd0, 0x83, 0xe8, 0xa2, 0x50, 0x3c, 0x4e,
+        ];
+        assert_eq!(chacha20_block(&input)[..], expected[..]);
+    }
+
+    #[test]
+    fn test_unaligned_batches() {
+        // Checks that batches starting in the middle of a ChaCha20 block are consistent with the
+        // aligned ones.
+        let mut generator = Generator::new(AesKey(rand::random()));
+        let first = generator.generate_batch(AesCtr(0));
+        let second = generator.generate_batch(AesCtr(8));
+        let keystream: Vec<u8> = first.iter().chain(second.iter()).copied().collect();
+        for ctr in 0..8 {
+            let batch = generator.generate_batch(AesCtr(ctr as u128));
+            assert_eq!(batch[..], keystream[16 * ctr..16 * ctr + 128]);
+        }
+    }
+
+    #[test]
+    fn test_uniformity() {
+        // Checks that the PRNG generates uniform numbers
+        let precision = 10f64.powi(-4);
+        let n_samples = 1_000_000_usize;
+        let mut generator = Generator::new(AesKey(rand::random()));
+        let mut counts = [0usize; 256];
+        let expected_prob: f64 = 1. / 256.;
+        for counter in 0..n_samples {
+            let batch = generator.generate_batch(AesCtr(8 * counter as u128));
+            for byte in batch.iter() {
+                counts[*byte as usize] += 1;
+            }
+        }
+        counts
+            .iter()
+            .map(|a| (*a as f64) / ((n_samples * 128) as f64))
+            .for_each(|a| assert!((a - expected_prob).abs() < precision))
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{aesni, software};
+use crate::derivation::derive_key;
+use crate::{aesni, chacha, software};
 #[cfg(feature = "multithread")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
 use std::cmp::Ordering;
@@ -19,7 +20,7 @@ mod test_aes;
 /// time.
 pub trait AesBatchedGenerator: Clone {
     /// Instantiate a new generator from a secret key.
-    fn new(key: Option<AesKey>) -> Self;
+    fn new(key: AesKey) -> Self;
     /// Generates the batch corresponding to the given counter.
     fn generate_batch(&mut self, ctr: AesCtr) -> [u8; 128];
 }
@@ -56,12 +57,27 @@ pub struct State {
 
 impl State {
     /// Generate a new state from an aes counter and a byte counter.
-    #[allow(dead_code)]
     pub fn new(aes_ctr: AesCtr, byte_ctr: ByteCtr) -> Self {
         debug_assert!(byte_ctr.0 <= 127);
         State { aes_ctr, byte_ctr }
     }
 
+    /// Generates the state of the byte at a given offset, counted from the zero state.
+    pub fn from_byte_offset(offset: u128) -> Self {
+        State {
+            aes_ctr: AesCtr((offset / 128) * 8),
+            byte_ctr: ByteCtr((offset % 128) as u8),
+        }
+    }
+
+    /// Returns the offset of the byte of the state, counted from the zero state.
+    pub fn to_byte_offset(&self) -> u128 {
+        self.aes_ctr
+            .0
+            .wrapping_mul(16)
+            .wrapping_add(self.byte_ctr.0 as u128)
+    }
+
     /// Generates a new state from an AES counter value.
     pub fn from_aes_counter(aes_ctr: AesCtr) -> Self {
         State {
@@ -219,10 +235,17 @@ pub type SoftAesCtrGenerator = AesCtrGenerator<software::Generator>;
 /// A generator that uses the hardware implementation.
 pub type HardAesCtrGenerator = AesCtrGenerator<aesni::Generator>;
 
+/// A generator that uses the ChaCha20 implementation.
+pub type ChaChaCtrGenerator = AesCtrGenerator<chacha::Generator>;
+
 /// A csprng which operates in batch mode.
 #[derive(Clone)]
 pub struct AesCtrGenerator<G: AesBatchedGenerator> {
+    key: AesKey,
     generator: G,
+    // The state the generator can not move before, which prevents a child from yielding the bytes
+    // of its parent or its siblings.
+    start: State,
     state: State,
     bound: Option<State>,
     batch: [u8; 128],
@@ -231,12 +254,8 @@ pub struct AesCtrGenerator<G: AesBatchedGenerator> {
 impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
     /// Generates a new csprng.
     ///
-    /// If not given, the key is automatically selected, and the state is set to zero.
-    pub fn new(
-        key: Option<AesKey>,
-        state: Option<State>,
-        bound: Option<State>,
-    ) -> AesCtrGenerator<G> {
+    /// If not given, the state is set to zero.
+    pub fn new(key: AesKey, state: Option<State>, bound: Option<State>) -> AesCtrGenerator<G> {
         let mut generator = G::new(key);
         let state = state.unwrap_or_default();
         if let Some(ref actual_bound) = bound {
@@ -244,13 +263,43 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
         }
         let batch = generator.generate_batch(state.aes_ctr);
         AesCtrGenerator {
+            key,
             generator,
+            start: state.clone(),
             state,
             bound,
             batch,
         }
     }
 
+    /// Returns the key of the current generator.
+    pub fn get_key(&self) -> AesKey {
+        self.key
+    }
+
+    /// Derives an unbounded child generator from a label and an index.
+    ///
+    /// The key of the child is derived from the key of the current generator, and its state is
+    /// set to zero. The state of the current generator is left untouched.
+    pub fn derive_child(&self, label: &str, index: u64) -> AesCtrGenerator<G> {
+        AesCtrGenerator::new(derive_key(self.key, label, index), None, None)
+    }
+
+    /// Returns a bounded child generator yielding the `n_bytes` bytes found at a given byte
+    /// offset of the stream of the current generator.
+    ///
+    /// Contrary to `try_fork`, the state of the current generator is left untouched, and the
+    /// offset is not checked against its bound.
+    pub fn child_at(&self, offset: u128, n_bytes: usize) -> AesCtrGenerator<G> {
+        AesCtrGenerator::new(
+            self.key,
+            Some(State::from_byte_offset(offset)),
+            Some(State::from_byte_offset(
+                offset.wrapping_add(n_bytes as u128),
+            )),
+        )
+    }
+
     /// Returns the state of the current generator.
     pub fn get_state(&self) -> &State {
         &self.state
@@ -269,9 +318,13 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
     /// Returns the number of remaining bytes if bounded
     pub fn remaining_bytes(&self) -> Option<usize> {
         self.bound.as_ref().map(|bound| {
-            let res = ((bound.aes_ctr.0 - self.state.aes_ctr.0) as i128) * 16
-                + (bound.byte_ctr.0 as i128 - self.state.byte_ctr.0 as i128);
-            res as usize
+            // The difference is computed modulo 2^128, which gives the right result even when
+            // the aes counter of the bound is smaller than the one of the state.
+            let bound_bytes =
+                (bound.aes_ctr.0.wrapping_mul(16)).wrapping_add(bound.byte_ctr.0 as u128);
+            let state_bytes =
+                (self.state.aes_ctr.0.wrapping_mul(16)).wrapping_add(self.state.byte_ctr.0 as u128);
+            bound_bytes.wrapping_sub(state_bytes) as usize
         })
     }
 
@@ -279,10 +332,8 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
     pub fn generate_next(&mut self) -> u8 {
         let output = self.batch[self.state.get_batch_index()];
         if let Some(bound) = self.bound.as_ref() {
-            let reached_bound =
-                self.state.aes_ctr == bound.aes_ctr && self.state.byte_ctr == bound.byte_ctr;
             assert!(
-                !reached_bound,
+                self.state != *bound,
                 "Tried to generate a byte outside the generator bound."
             );
         }
@@ -295,11 +346,105 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
         output
     }
 
+    /// Moves the generator to the given state.
+    ///
+    /// The next byte yielded by the generator is the one of the given state, regardless of the
+    /// bytes generated before.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the state is before the state the generator was created at, or before its
+    /// last fork. If the generator is bounded, panics when the state is past the bound.
+    pub fn seek(&mut self, state: State) {
+        assert!(
+            state >= self.start,
+            "Tried to move the generator before its start."
+        );
+        if let Some(ref bound) = self.bound {
+            assert!(
+                state <= *bound,
+                "Tried to move the generator outside of its bound."
+            );
+        }
+        self.batch = self.generator.generate_batch(state.aes_ctr);
+        self.state = state;
+    }
+
+    /// Moves the generator forward to the given state, which becomes its new start.
+    ///
+    /// The bytes before the state can not be yielded anymore, as after a fork.
+    pub fn advance(&mut self, state: State) {
+        self.seek(state.clone());
+        self.start = state;
+    }
+
+    /// Fills a slice with random bytes.
+    ///
+    /// The bytes are copied from whole batches, and are the same as the ones which would be
+    /// yielded by successive calls to `generate_next`.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        if let Some(ref bound) = self.bound {
+            let mut end = self.state.clone();
+            end.shift(bytes.len());
+            assert!(
+                end <= *bound,
+                "Tried to generate a byte outside the generator bound."
+            );
+        }
+        let mut filled = 0;
+        while filled < bytes.len() {
+            let index = self.state.get_batch_index();
+            let n_bytes = (128 - index).min(bytes.len() - filled);
+            bytes[filled..filled + n_bytes].copy_from_slice(&self.batch[index..index + n_bytes]);
+            filled += n_bytes;
+            if let ShouldGenerateBatch::GenerateBatch = self.state.shift(n_bytes) {
+                self.batch = self.generator.generate_batch(self.state.get_aes_counter());
+            }
+        }
+    }
+
+    /// Fills a slice with random `u32` values.
+    ///
+    /// Each value is built from four successive random bytes, in little endian order.
+    pub fn fill_u32(&mut self, values: &mut [u32]) {
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                values.as_mut_ptr() as *mut u8,
+                std::mem::size_of_val(values),
+            )
+        };
+        self.fill_bytes(bytes);
+        // The bytes were written in memory order, which only matches little endian integers on
+        // little endian targets. This loop is a no-op on those.
+        values
+            .iter_mut()
+            .for_each(|value| *value = u32::from_le(*value));
+    }
+
+    /// Fills a slice with random `u64` values.
+    ///
+    /// Each value is built from eight successive random bytes, in little endian order.
+    pub fn fill_u64(&mut self, values: &mut [u64]) {
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                values.as_mut_ptr() as *mut u8,
+                std::mem::size_of_val(values),
+            )
+        };
+        self.fill_bytes(bytes);
+        // The bytes were written in memory order, which only matches little endian integers on
+        // little endian targets. This loop is a no-op on those.
+        values
+            .iter_mut()
+            .for_each(|value| *value = u64::from_le(*value));
+    }
+
     /// Tries to fork the current generator into `n_child` generators each able to yield
     /// `child_bytes` random bytes.
     ///
-    /// If the total number of bytes to be generated exceeds the bound of the current generator,
-    /// `None` is returned. Otherwise, we return an iterator over the children generators.
+    /// If the total number of bytes to be generated exceeds the bound of the current generator, or
+    /// does not fit in a `usize`, `None` is returned. Otherwise, we return an iterator over the
+    /// children generators.
     pub fn try_fork(
         &mut self,
         n_child: ChildCount,
@@ -316,8 +461,9 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
     /// Tries to fork the current generator into `n_child` generators each able to yield
     /// `child_bytes` random bytes as a parallel iterator.
     ///
-    /// If the total number of bytes to be generated exceeds the bound of the current generator,
-    /// `None` is returned. Otherwise, we return a parallel iterator over the children generators.
+    /// If the total number of bytes to be generated exceeds the bound of the current generator, or
+    /// does not fit in a `usize`, `None` is returned. Otherwise, we return a parallel iterator
+    /// over the children generators.
     ///
     /// # Notes
     ///
@@ -346,12 +492,18 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
         if let ShouldGenerateBatch::GenerateBatch = generate {
             self.batch = self.generator.generate_batch(self.state.get_aes_counter());
         }
+        // The bytes given to the children can not be yielded by the parent anymore.
+        self.start = self.state.clone();
     }
 
     fn is_fork_in_bound(&self, n_child: ChildCount, child_bytes: BytesPerChild) -> bool {
+        let n_bytes = match n_child.0.checked_mul(child_bytes.0) {
+            Some(n_bytes) => n_bytes,
+            None => return false,
+        };
         if let Some(ref actual_bound) = self.bound {
             let mut end = self.state.clone();
-            end.shift(n_child.0 * child_bytes.0);
+            end.shift(n_bytes);
             if end > *actual_bound {
                 return false;
             }
@@ -363,6 +515,7 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
         &self,
         child_bytes: BytesPerChild,
     ) -> impl Fn(usize) -> AesCtrGenerator<G> {
+        let key = self.key;
         let state = self.state.clone();
         let generator = self.generator.clone();
         move |i| {
@@ -374,7 +527,9 @@ impl<G: AesBatchedGenerator> AesCtrGenerator<G> {
             let mut new_generator = generator.clone();
             let batch = new_generator.generate_batch(new_state.aes_ctr);
             AesCtrGenerator {
+                key,
                 generator: new_generator,
+                start: new_state.clone(),
                 state: new_state,
                 bound: Some(new_bound),
                 batch,
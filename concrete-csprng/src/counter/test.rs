@@ -2,13 +2,12 @@ use super::*;
 use crate::AesKey;
 use rand::Rng;
 
-#[test]
-fn test_gen_byte_incr() {
+fn test_gen_byte_incr<G: AesBatchedGenerator>() {
     // Checks that the byte counter is correctly incremented.
     for _ in 0..1000 {
         let state = rand::thread_rng().gen::<u128>();
-        let mut a = SoftAesCtrGenerator::new(
-            Some(AesKey(0)),
+        let mut a = AesCtrGenerator::<G>::new(
+            AesKey(0),
             Some(State::from_aes_counter(AesCtr(state))),
             None,
         );
@@ -30,13 +29,12 @@ fn test_gen_byte_incr() {
     }
 }
 
-#[test]
-fn test_gen_aes_incr() {
+fn test_gen_aes_incr<G: AesBatchedGenerator>() {
     // Checks that the aes counter is correctly incremented.
     for _ in 0..1000 {
         let state = rand::thread_rng().gen::<u128>();
-        let mut a = SoftAesCtrGenerator::new(
-            Some(AesKey(0)),
+        let mut a = AesCtrGenerator::<G>::new(
+            AesKey(0),
             Some(State::from_aes_counter(AesCtr(state))),
             None,
         );
@@ -68,12 +66,11 @@ fn test_gen_aes_incr() {
     }
 }
 
-#[test]
-fn test_state_fork_initial_batch() {
+fn test_state_fork_initial_batch<G: AesBatchedGenerator>() {
     // Checks that forking the prng into children that spawns the initial batch gives the
     // correct states.
     let state = State::from_aes_counter(AesCtr(0));
-    let mut generator = SoftAesCtrGenerator::new(None, Some(state), None);
+    let mut generator = AesCtrGenerator::<G>::new(AesKey(0), Some(state), None);
     assert_eq!(
         *generator.get_state(),
         State {
@@ -187,12 +184,11 @@ fn test_state_fork_initial_batch() {
     );
 }
 
-#[test]
-fn test_state_fork_next_batch() {
+fn test_state_fork_next_batch<G: AesBatchedGenerator>() {
     // Checks that forking the prng into children that spawns the next batch gives the
     // correct states.
     let state = State::from_aes_counter(AesCtr(0));
-    let mut generator = SoftAesCtrGenerator::new(None, Some(state), None);
+    let mut generator = AesCtrGenerator::<G>::new(AesKey(0), Some(state), None);
     assert_eq!(
         *generator.get_state(),
         State {
@@ -392,8 +388,7 @@ fn test_state_ordering() {
     assert!(second > first);
 }
 
-#[test]
-fn test_randomized_fork_generation() {
+fn test_randomized_fork_generation<G: AesBatchedGenerator>() {
     // Checks that whatever the fork, whatever the state, children generate the same outputs
     // sequence as parent, and that parent recover at the proper position.
     for _ in 0..100 {
@@ -401,10 +396,10 @@ fn test_randomized_fork_generation() {
         let n_child = ChildCount(rand::thread_rng().gen::<usize>() % 200);
         let bytes_child = BytesPerChild(rand::thread_rng().gen::<usize>() % 200);
         let key = AesKey(rand::thread_rng().gen());
-        let mut generator = SoftAesCtrGenerator::new(Some(key), Some(state.clone()), None);
+        let mut generator = AesCtrGenerator::<G>::new(key, Some(state.clone()), None);
         let n_to_gen = n_child.0 * bytes_child.0;
         let initial_output: Vec<u8> = (0..n_to_gen).map(|_| generator.generate_next()).collect();
-        let mut forking_generator = SoftAesCtrGenerator::new(Some(key), Some(state), None);
+        let mut forking_generator = AesCtrGenerator::<G>::new(key, Some(state), None);
         let children_output: Vec<u8> = forking_generator
             .try_fork(n_child, bytes_child)
             .unwrap()
@@ -415,14 +410,13 @@ fn test_randomized_fork_generation() {
     }
 }
 
-#[test]
-fn test_randomized_remaining_bytes() {
+fn test_randomized_remaining_bytes<G: AesBatchedGenerator>() {
     for _ in 0..1000 {
         let state = State::from_aes_counter(AesCtr(rand::thread_rng().gen()));
         let n_child = ChildCount(rand::thread_rng().gen::<usize>() % 200);
         let bytes_child = BytesPerChild(rand::thread_rng().gen::<usize>() % 200);
         let key = AesKey(rand::thread_rng().gen());
-        let mut forking_generator = SoftAesCtrGenerator::new(Some(key), Some(state), None);
+        let mut forking_generator = AesCtrGenerator::<G>::new(key, Some(state), None);
         forking_generator
             .try_fork(n_child, bytes_child)
             .unwrap()
@@ -430,3 +424,163 @@ fn test_randomized_remaining_bytes() {
         assert_eq!(forking_generator.remaining_bytes(), None);
     }
 }
+
+fn test_randomized_fill_bytes<G: AesBatchedGenerator>() {
+    // Checks that filling slices of random lengths outputs the same sequence as generating the
+    // bytes one at a time, and that the generators recover at the same position.
+    for _ in 0..100 {
+        let state = State::from_aes_counter(AesCtr(rand::thread_rng().gen::<u64>() as u128));
+        let key = AesKey(rand::thread_rng().gen());
+        let mut generator = AesCtrGenerator::<G>::new(key, Some(state.clone()), None);
+        let mut filling_generator = AesCtrGenerator::<G>::new(key, Some(state), None);
+        for _ in 0..10 {
+            let n_bytes = rand::thread_rng().gen::<usize>() % 400;
+            let expected: Vec<u8> = (0..n_bytes).map(|_| generator.generate_next()).collect();
+            let mut output = vec![0u8; n_bytes];
+            filling_generator.fill_bytes(&mut output);
+            assert_eq!(expected, output);
+            assert_eq!(generator.get_state(), filling_generator.get_state());
+        }
+        assert_eq!(filling_generator.generate_next(), generator.generate_next());
+    }
+}
+
+fn test_randomized_fill_bytes_children<G: AesBatchedGenerator>() {
+    // Checks that the children of a fork can fill their whole bound at once.
+    for _ in 0..100 {
+        let state = State::from_aes_counter(AesCtr(rand::thread_rng().gen::<u64>() as u128));
+        let n_child = ChildCount(rand::thread_rng().gen::<usize>() % 20 + 1);
+        let bytes_child = BytesPerChild(rand::thread_rng().gen::<usize>() % 400 + 1);
+        let key = AesKey(rand::thread_rng().gen());
+        let mut generator = AesCtrGenerator::<G>::new(key, Some(state.clone()), None);
+        let expected: Vec<u8> = (0..n_child.0 * bytes_child.0)
+            .map(|_| generator.generate_next())
+            .collect();
+        let mut forking_generator = AesCtrGenerator::<G>::new(key, Some(state), None);
+        let output: Vec<u8> = forking_generator
+            .try_fork(n_child, bytes_child)
+            .unwrap()
+            .flat_map(|mut child| {
+                let mut bytes = vec![0u8; bytes_child.0];
+                child.fill_bytes(&mut bytes);
+                bytes
+            })
+            .collect();
+        assert_eq!(expected, output);
+    }
+}
+
+fn test_randomized_fill_integers<G: AesBatchedGenerator>() {
+    // Checks that the integers filled from the bytes of the generator are built in little endian
+    // order, and that the generators recover at the same position.
+    for _ in 0..100 {
+        let state = State::from_aes_counter(AesCtr(rand::thread_rng().gen::<u64>() as u128));
+        let key = AesKey(rand::thread_rng().gen());
+        let mut generator = AesCtrGenerator::<G>::new(key, Some(state.clone()), None);
+        let mut filling_generator = AesCtrGenerator::<G>::new(key, Some(state), None);
+        let n_values = rand::thread_rng().gen::<usize>() % 100;
+        let mut values_u32 = vec![0u32; n_values];
+        filling_generator.fill_u32(&mut values_u32);
+        for value in values_u32.iter() {
+            let bytes = [0u8; 4].map(|_| generator.generate_next());
+            assert_eq!(*value, u32::from_le_bytes(bytes));
+        }
+        let mut values_u64 = vec![0u64; n_values];
+        filling_generator.fill_u64(&mut values_u64);
+        for value in values_u64.iter() {
+            let bytes = [0u8; 8].map(|_| generator.generate_next());
+            assert_eq!(*value, u64::from_le_bytes(bytes));
+        }
+        assert_eq!(generator.get_state(), filling_generator.get_state());
+    }
+}
+
+fn test_fill_integers_out_of_bound<G: AesBatchedGenerator>() {
+    // Checks that a bounded generator panics when the integers need more bytes than allowed.
+    let mut generator = AesCtrGenerator::<G>::new(AesKey(0), None, None);
+    let mut child = generator
+        .try_fork(ChildCount(1), BytesPerChild(200))
+        .unwrap()
+        .next()
+        .unwrap();
+    let mut values = vec![0u64; 26];
+    child.fill_u64(&mut values);
+}
+
+fn test_fill_bytes_out_of_bound<G: AesBatchedGenerator>() {
+    // Checks that a bounded generator panics when filling more bytes than allowed.
+    let mut generator = AesCtrGenerator::<G>::new(AesKey(0), None, None);
+    let mut child = generator
+        .try_fork(ChildCount(1), BytesPerChild(200))
+        .unwrap()
+        .next()
+        .unwrap();
+    let mut bytes = vec![0u8; 201];
+    child.fill_bytes(&mut bytes);
+}
+
+macro_rules! instantiate_generator_tests {
+    ($module:ident, $generator:ty) => {
+        mod $module {
+            #[test]
+            fn test_gen_byte_incr() {
+                super::test_gen_byte_incr::<$generator>();
+            }
+
+            #[test]
+            fn test_gen_aes_incr() {
+                super::test_gen_aes_incr::<$generator>();
+            }
+
+            #[test]
+            fn test_state_fork_initial_batch() {
+                super::test_state_fork_initial_batch::<$generator>();
+            }
+
+            #[test]
+            fn test_state_fork_next_batch() {
+                super::test_state_fork_next_batch::<$generator>();
+            }
+
+            #[test]
+            fn test_randomized_fork_generation() {
+                super::test_randomized_fork_generation::<$generator>();
+            }
+
+            #[test]
+            fn test_randomized_remaining_bytes() {
+                super::test_randomized_remaining_bytes::<$generator>();
+            }
+
+            #[test]
+            fn test_randomized_fill_bytes() {
+                super::test_randomized_fill_bytes::<$generator>();
+            }
+
+            #[test]
+            fn test_randomized_fill_bytes_children() {
+                super::test_randomized_fill_bytes_children::<$generator>();
+            }
+
+            #[test]
+            fn test_randomized_fill_integers() {
+                super::test_randomized_fill_integers::<$generator>();
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_fill_integers_out_of_bound() {
+                super::test_fill_integers_out_of_bound::<$generator>();
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_fill_bytes_out_of_bound() {
+                super::test_fill_bytes_out_of_bound::<$generator>();
+            }
+        }
+    };
+}
+
+instantiate_generator_tests!(software, crate::software::Generator);
+instantiate_generator_tests!(chacha, crate::chacha::Generator);
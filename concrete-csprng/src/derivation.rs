@@ -0,0 +1,51 @@
+//! A module containing the key derivation used to build named child generators.
+//!
+//! The key of a child generator is derived from the key of its parent with HKDF-SHA256. The
+//! parent key is the input keying material, and the label and index of the child are encoded in
+//! the info string. Under the usual assumptions on HKDF, the keys of the children are independent
+//! from each other and from the bytes yielded by the parent.
+use crate::counter::AesKey;
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+// The salt of the extraction step, which separates this derivation from other uses of HKDF.
+const DERIVATION_SALT: &[u8] = b"concrete-csprng child derivation";
+
+/// Derives the key of the child generator with the given label and index.
+pub fn derive_key(AesKey(parent_key): AesKey, label: &str, index: u64) -> AesKey {
+    let hkdf = Hkdf::<Sha256>::new(Some(DERIVATION_SALT), &parent_key.to_le_bytes());
+    // The length of the label is encoded first, so that two different pairs of label and index
+    // can not give the same info string.
+    let mut info = Vec::with_capacity(label.len() + 16);
+    info.extend_from_slice(&(label.len() as u64).to_le_bytes());
+    info.extend_from_slice(label.as_bytes());
+    info.extend_from_slice(&index.to_le_bytes());
+    let mut child_key = [0u8; 16];
+    hkdf.expand(&info, &mut child_key)
+        .expect("16 bytes is a valid output length for HKDF-SHA256.");
+    AesKey(u128::from_le_bytes(child_key))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_derive_key_determinism() {
+        // Checks that the same parent key, label and index always give the same key.
+        let first = derive_key(AesKey(42), "mask", 3);
+        let second = derive_key(AesKey(42), "mask", 3);
+        assert_eq!(first.0, second.0);
+    }
+
+    #[test]
+    fn test_derive_key_separation() {
+        // Checks that changing any of the inputs gives a different key.
+        let reference = derive_key(AesKey(42), "mask", 3).0;
+        assert_ne!(derive_key(AesKey(43), "mask", 3).0, reference);
+        assert_ne!(derive_key(AesKey(42), "noise", 3).0, reference);
+        assert_ne!(derive_key(AesKey(42), "mask", 4).0, reference);
+        assert_ne!(derive_key(AesKey(42), "", 3).0, reference);
+        assert_ne!(derive_key(AesKey(42), "mask", 3).0, 42);
+    }
+}
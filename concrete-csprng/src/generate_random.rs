@@ -1,18 +1,261 @@
-//! This program uses the concrete csprng to generate an infinite stream of random bytes on
-//! the program stdout. For testing purpose.
+//! This program uses the concrete csprng to generate a stream of random bytes on the program
+//! stdout. For testing purpose.
+//!
+//! By default, the program outputs an infinite stream of raw bytes from a generator seeded by the
+//! best seeder available. The options allow to output reproducible test vectors, and to compare
+//! the outputs of the different implementations:
+//!
+//! + `--seed` makes the output reproducible, and allows to check that the `software`, `hardware`
+//!   and `chacha` backends output the expected bytes.
+//! + `--fork` and `--child` output the stream of a single child of a fork.
+//! + `--bytes` and `--format` bound the output, and print it in hex if needed.
+//!
+//! The full list of options is printed by `generate_random --help`.
+//!
+//! When called with `--self-test`, the program runs the health tests instead of generating bytes,
+//! and exits with a non-zero code if one of them fails.
 use std::io::prelude::*;
-use std::io::stdout;
+use std::io::{stdout, ErrorKind};
+use std::process::exit;
 
+use concrete_csprng::health::{test_seeder, HealthTestFailure};
+use concrete_csprng::seeders::{new_seeder, ProvidedSeeder, Seed, Seeder};
 use concrete_csprng::RandomGenerator;
 
+const USAGE: &str = "\
+Usage: generate_random [OPTIONS]
+
+Options:
+    --seed <SEED>              Seeds the generator with the given 128 bits value, in decimal
+                               or in hexadecimal with a `0x` prefix.
+    --bytes <N>                Stops after N bytes.
+    --backend <BACKEND>        Uses the `software`, `hardware` or `chacha` generator.
+    --fork <N_CHILD>:<BYTES>   Forks the generator into N_CHILD children of BYTES bytes ...
+    --child <INDEX>            ... and outputs the bytes of the child INDEX. Defaults to 0.
+    --format <FORMAT>          Outputs `binary` bytes, or `hex` lines of 32 bytes.
+    --self-test                Runs the health tests on the seeder and the generator.
+    --help                     Prints this message.";
+
+// The number of bytes generated at once.
+const CHUNK_BYTES: usize = 4096;
+
+// The number of bytes per line of the hex output.
+const HEX_LINE_BYTES: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    Default,
+    Software,
+    Hardware,
+    ChaCha,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Binary,
+    Hex,
+}
+
+#[derive(Debug)]
+struct Options {
+    seed: Option<u128>,
+    bytes: Option<usize>,
+    backend: Backend,
+    fork: Option<(usize, usize)>,
+    child: usize,
+    format: Format,
+    self_test: bool,
+}
+
+fn parse_value<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value `{}` for `{}`.", value, name))
+}
+
+fn parse_seed(value: &str) -> Result<u128, String> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16)
+            .map_err(|_| format!("Invalid value `{}` for `--seed`.", value)),
+        None => parse_value("--seed", value),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        seed: None,
+        bytes: None,
+        backend: Backend::Default,
+        fork: None,
+        child: 0,
+        format: Format::Binary,
+        self_test: false,
+    };
+    let mut child = None;
+    while let Some(arg) = args.next() {
+        if arg == "--help" {
+            println!("{}", USAGE);
+            exit(0);
+        }
+        if arg == "--self-test" {
+            options.self_test = true;
+            continue;
+        }
+        let value = match arg.as_str() {
+            "--seed" | "--bytes" | "--backend" | "--fork" | "--child" | "--format" => {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for `{}`.", arg))?
+            }
+            _ => return Err(format!("Unknown argument `{}`.", arg)),
+        };
+        match arg.as_str() {
+            "--seed" => options.seed = Some(parse_seed(&value)?),
+            "--bytes" => options.bytes = Some(parse_value(&arg, &value)?),
+            "--backend" => {
+                options.backend = match value.as_str() {
+                    "software" => Backend::Software,
+                    "hardware" => Backend::Hardware,
+                    "chacha" => Backend::ChaCha,
+                    _ => return Err(format!("Invalid value `{}` for `--backend`.", value)),
+                }
+            }
+            "--fork" => {
+                let (n_child, child_bytes) = value
+                    .split_once(':')
+                    .ok_or_else(|| format!("Invalid value `{}` for `--fork`.", value))?;
+                let (n_child, child_bytes): (usize, usize) =
+                    (parse_value(&arg, n_child)?, parse_value(&arg, child_bytes)?);
+                if n_child.checked_mul(child_bytes).is_none() {
+                    return Err(format!(
+                        "The fork `{}` needs more bytes than can be addressed.",
+                        value
+                    ));
+                }
+                options.fork = Some((n_child, child_bytes));
+            }
+            "--child" => child = Some(parse_value(&arg, &value)?),
+            "--format" => {
+                options.format = match value.as_str() {
+                    "binary" => Format::Binary,
+                    "hex" => Format::Hex,
+                    _ => return Err(format!("Invalid value `{}` for `--format`.", value)),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    match (options.fork, child) {
+        (None, Some(_)) => return Err("`--child` can only be used with `--fork`.".to_string()),
+        (Some((n_child, _)), Some(index)) if index >= n_child => {
+            return Err(format!(
+                "The child {} does not exist in a fork of {} children.",
+                index, n_child
+            ))
+        }
+        (_, Some(index)) => options.child = index,
+        _ => {}
+    }
+    Ok(options)
+}
+
+fn new_generator(options: &Options) -> Result<RandomGenerator, String> {
+    let mut seeder: Box<dyn Seeder> = match options.seed {
+        Some(seed) => Box::new(ProvidedSeeder::new(Seed(seed))),
+        None => new_seeder(),
+    };
+    let mut generator = match options.backend {
+        Backend::Default => RandomGenerator::new(seeder.as_mut()),
+        Backend::Software => RandomGenerator::new_software(seeder.as_mut()),
+        Backend::Hardware => RandomGenerator::new_hardware(seeder.as_mut()).ok_or_else(|| {
+            "The hardware generator is not available on this machine.".to_string()
+        })?,
+        Backend::ChaCha => RandomGenerator::new_chacha(seeder.as_mut()),
+    };
+    match options.fork {
+        Some((n_child, child_bytes)) => generator
+            .try_fork(n_child, child_bytes)
+            .and_then(|mut children| children.nth(options.child))
+            .ok_or_else(|| "The generator can not be forked with this layout.".to_string()),
+        None => Ok(generator),
+    }
+}
+
+fn report(name: &str, result: Result<(), HealthTestFailure>) -> bool {
+    match result {
+        Ok(()) => {
+            println!("{}: passed", name);
+            true
+        }
+        Err(failure) => {
+            println!("{}: {}", name, failure);
+            false
+        }
+    }
+}
+
+fn self_test(options: &Options) -> Result<bool, String> {
+    let mut seeder = new_seeder();
+    let seeder_passed = report("Seeder", test_seeder(seeder.as_mut()));
+    let generator = new_generator(options)?;
+    let generator_passed = report(
+        &format!("Generator ({:?})", generator.primitive()),
+        generator.self_test(),
+    );
+    Ok(seeder_passed && generator_passed)
+}
+
+fn write_hex(output: &mut impl Write, bytes: &[u8]) -> std::io::Result<()> {
+    for line in bytes.chunks(HEX_LINE_BYTES) {
+        let hex: String = line.iter().map(|byte| format!("{:02x}", byte)).collect();
+        writeln!(output, "{}", hex)?;
+    }
+    Ok(())
+}
+
+fn generate(mut generator: RandomGenerator, options: &Options) -> std::io::Result<()> {
+    let mut remaining = match (options.bytes, generator.remaining_bytes()) {
+        (Some(bytes), Some(bound)) => Some(bytes.min(bound)),
+        (bytes, bound) => bytes.or(bound),
+    };
+    let stdout = stdout();
+    let mut output = stdout.lock();
+    let mut buffer = [0u8; CHUNK_BYTES];
+    while remaining != Some(0) {
+        let n_bytes = remaining.map_or(CHUNK_BYTES, |bytes| bytes.min(CHUNK_BYTES));
+        let chunk = &mut buffer[..n_bytes];
+        generator.fill_bytes(chunk);
+        match options.format {
+            Format::Binary => output.write_all(chunk)?,
+            Format::Hex => write_hex(&mut output, chunk)?,
+        }
+        remaining = remaining.map(|bytes| bytes - n_bytes);
+    }
+    output.flush()
+}
+
 pub fn main() {
-    let mut generator = RandomGenerator::new(None);
-    let mut stdout = stdout();
-    let mut buffer = [0u8; 16];
-    loop {
-        buffer
-            .iter_mut()
-            .for_each(|a| *a = generator.generate_next());
-        stdout.write_all(&buffer).unwrap();
+    let options = parse_args(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        exit(2);
+    });
+    let result = if options.self_test {
+        self_test(&options).map(|passed| if passed { 0 } else { 1 })
+    } else {
+        new_generator(&options).map(|generator| match generate(generator, &options) {
+            Ok(()) => 0,
+            // The reader of the stream was closed, which is the normal way to stop it.
+            Err(error) if error.kind() == ErrorKind::BrokenPipe => 0,
+            Err(error) => {
+                eprintln!("Failed to write the random bytes: {}", error);
+                1
+            }
+        })
+    };
+    match result {
+        Ok(code) => exit(code),
+        Err(error) => {
+            eprintln!("{}", error);
+            exit(2);
+        }
     }
 }
@@ -0,0 +1,338 @@
+//! A module containing statistical health tests for the generators and the seeders.
+//!
+//! The tests are run on a sample of bytes, and are meant to detect a broken source of randomness,
+//! not to certify its quality. They follow the spirit of the NIST SP 800-90B health tests and of
+//! the NIST SP 800-22 statistical tests:
+//!
+//! + The repetition count test fails if a byte is repeated too many times in a row.
+//! + The adaptive proportion test fails if a byte occurs too often in a window of 512 bytes.
+//! + The monobit test fails if the proportion of ones in the sample is too far from one half.
+//! + The runs test fails if the number of runs of identical bits is too far from the expected one.
+//! + The chi-square test fails if the distribution of the bytes is too far from the uniform one.
+//!
+//! The cutoffs of the first two tests are set for a false positive probability of `2^-40` per
+//! byte, assuming a full entropy source. The last three tests fail when their p-value is smaller
+//! than `10^-6`.
+//!
+//! # Note
+//!
+//! Those tests are only meaningful for sources which are supposed to output full entropy bytes.
+//! In particular, the [`ProvidedSeeder`](crate::seeders::ProvidedSeeder) and the
+//! [`DeterministicSeeder`](crate::seeders::DeterministicSeeder) are expected to fail them.
+use crate::seeders::Seeder;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The number of bytes sampled from a generator by [`RandomGenerator::self_test`].
+///
+/// [`RandomGenerator::self_test`]: crate::RandomGenerator::self_test
+pub const GENERATOR_SAMPLE_BYTES: usize = 1 << 20;
+
+/// The number of seeds sampled from a seeder by [`test_seeder`].
+pub const SEEDER_SAMPLE_SEEDS: usize = 1 << 12;
+
+// The negated base 2 logarithm of the false positive probability of the counting tests.
+const COUNTING_TESTS_LOG_ALPHA: f64 = 40.;
+
+// The size of the windows of the adaptive proportion test.
+const ADAPTIVE_PROPORTION_WINDOW: usize = 512;
+
+// The p-value under which the statistical tests fail.
+const SIGNIFICANCE_LEVEL: f64 = 1e-6;
+
+/// An error returned when a health test fails.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthTestFailure {
+    /// The repetition count test found a run of identical bytes longer than the cutoff.
+    RepetitionCount { run_length: usize, cutoff: usize },
+    /// The adaptive proportion test found a byte occurring more than the cutoff in a window.
+    AdaptiveProportion { occurrences: usize, cutoff: usize },
+    /// The p-value of the monobit test is below the significance level.
+    Monobit { p_value: f64 },
+    /// The p-value of the runs test is below the significance level.
+    Runs { p_value: f64 },
+    /// The p-value of the chi-square test is below the significance level.
+    ChiSquare { p_value: f64 },
+}
+
+impl Display for HealthTestFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthTestFailure::RepetitionCount { run_length, cutoff } => write!(
+                f,
+                "Repetition count test failed: found a run of {} identical bytes, while the \
+                cutoff is {}.",
+                run_length, cutoff
+            ),
+            HealthTestFailure::AdaptiveProportion {
+                occurrences,
+                cutoff,
+            } => write!(
+                f,
+                "Adaptive proportion test failed: a byte occurs {} times in a window of {} \
+                bytes, while the cutoff is {}.",
+                occurrences, ADAPTIVE_PROPORTION_WINDOW, cutoff
+            ),
+            HealthTestFailure::Monobit { p_value } => {
+                write!(f, "Monobit test failed with p-value {:e}.", p_value)
+            }
+            HealthTestFailure::Runs { p_value } => {
+                write!(f, "Runs test failed with p-value {:e}.", p_value)
+            }
+            HealthTestFailure::ChiSquare { p_value } => {
+                write!(f, "Chi-square test failed with p-value {:e}.", p_value)
+            }
+        }
+    }
+}
+
+impl Error for HealthTestFailure {}
+
+/// Runs all the health tests on a sample of bytes.
+///
+/// The tests are run in the order of the module documentation, and the first failure is
+/// returned.
+pub fn test_bytes(bytes: &[u8]) -> Result<(), HealthTestFailure> {
+    repetition_count_test(bytes)?;
+    adaptive_proportion_test(bytes)?;
+    monobit_test(bytes)?;
+    runs_test(bytes)?;
+    chi_square_test(bytes)
+}
+
+/// Runs all the health tests on the seeds output by a seeder.
+///
+/// [`SEEDER_SAMPLE_SEEDS`] seeds are drawn from the seeder.
+pub fn test_seeder(seeder: &mut dyn Seeder) -> Result<(), HealthTestFailure> {
+    let bytes: Vec<u8> = (0..SEEDER_SAMPLE_SEEDS)
+        .flat_map(|_| seeder.seed().0.to_le_bytes().to_vec())
+        .collect();
+    test_bytes(&bytes)
+}
+
+fn repetition_count_test(bytes: &[u8]) -> Result<(), HealthTestFailure> {
+    // With a full entropy source, a byte is repeated `cutoff - 1` times with probability
+    // `2^(-8 * (cutoff - 1))`.
+    let cutoff = 1 + (COUNTING_TESTS_LOG_ALPHA / 8.).ceil() as usize;
+    let mut run_length = 0;
+    let mut previous = None;
+    for byte in bytes.iter() {
+        if previous == Some(*byte) {
+            run_length += 1;
+        } else {
+            run_length = 1;
+            previous = Some(*byte);
+        }
+        if run_length >= cutoff {
+            return Err(HealthTestFailure::RepetitionCount { run_length, cutoff });
+        }
+    }
+    Ok(())
+}
+
+fn adaptive_proportion_test(bytes: &[u8]) -> Result<(), HealthTestFailure> {
+    let cutoff = adaptive_proportion_cutoff();
+    for window in bytes.chunks_exact(ADAPTIVE_PROPORTION_WINDOW) {
+        let occurrences = window.iter().filter(|byte| **byte == window[0]).count();
+        if occurrences >= cutoff {
+            return Err(HealthTestFailure::AdaptiveProportion {
+                occurrences,
+                cutoff,
+            });
+        }
+    }
+    Ok(())
+}
+
+// Returns the smallest cutoff such that the first byte of a window occurs at least `cutoff` times
+// with probability smaller than `2^-40`. The first byte always occurs once, and the number of
+// occurrences in the rest of the window follows a binomial distribution.
+fn adaptive_proportion_cutoff() -> usize {
+    let alpha = 2f64.powf(-COUNTING_TESTS_LOG_ALPHA);
+    let n = ADAPTIVE_PROPORTION_WINDOW - 1;
+    let p: f64 = 1. / 256.;
+    let mut probabilities = Vec::with_capacity(n + 1);
+    let mut probability = (1. - p).powi(n as i32);
+    for k in 0..=n {
+        probabilities.push(probability);
+        probability *= (n - k) as f64 / (k + 1) as f64 * p / (1. - p);
+    }
+    let mut tail = 0.;
+    for k in (0..=n).rev() {
+        tail += probabilities[k];
+        if tail > alpha {
+            return k + 2;
+        }
+    }
+    1
+}
+
+fn monobit_test(bytes: &[u8]) -> Result<(), HealthTestFailure> {
+    let n_bits = (bytes.len() * 8) as f64;
+    let ones: u64 = bytes.iter().map(|byte| byte.count_ones() as u64).sum();
+    let sum = 2. * ones as f64 - n_bits;
+    let p_value = erfc(sum.abs() / (2. * n_bits).sqrt());
+    if p_value < SIGNIFICANCE_LEVEL {
+        return Err(HealthTestFailure::Monobit { p_value });
+    }
+    Ok(())
+}
+
+fn runs_test(bytes: &[u8]) -> Result<(), HealthTestFailure> {
+    let n_bits = (bytes.len() * 8) as f64;
+    let ones: u64 = bytes.iter().map(|byte| byte.count_ones() as u64).sum();
+    let proportion = ones as f64 / n_bits;
+    // The runs test is only meaningful if the monobit test passes.
+    if (proportion - 0.5).abs() >= 2. / n_bits.sqrt() {
+        return Err(HealthTestFailure::Runs { p_value: 0. });
+    }
+    let mut runs: u64 = 1;
+    let mut previous = bytes.first().map(|byte| byte & 1);
+    for byte in bytes.iter() {
+        for i in 0..8 {
+            let bit = Some((byte >> i) & 1);
+            if bit != previous {
+                runs += 1;
+                previous = bit;
+            }
+        }
+    }
+    let product = proportion * (1. - proportion);
+    let p_value =
+        erfc((runs as f64 - 2. * n_bits * product).abs() / (2. * (2. * n_bits).sqrt() * product));
+    if p_value < SIGNIFICANCE_LEVEL {
+        return Err(HealthTestFailure::Runs { p_value });
+    }
+    Ok(())
+}
+
+fn chi_square_test(bytes: &[u8]) -> Result<(), HealthTestFailure> {
+    let mut counts = [0usize; 256];
+    bytes.iter().for_each(|byte| counts[*byte as usize] += 1);
+    let expected = bytes.len() as f64 / 256.;
+    let statistic: f64 = counts
+        .iter()
+        .map(|count| (*count as f64 - expected).powi(2) / expected)
+        .sum();
+    // The p-value is approximated with the Wilson-Hilferty transformation, which is accurate for
+    // 255 degrees of freedom.
+    let dof = 255.;
+    let z = ((statistic / dof).cbrt() - (1. - 2. / (9. * dof))) / (2. / (9. * dof)).sqrt();
+    let p_value = 0.5 * erfc(z / 2f64.sqrt());
+    if p_value < SIGNIFICANCE_LEVEL {
+        return Err(HealthTestFailure::ChiSquare { p_value });
+    }
+    Ok(())
+}
+
+// The complementary error function, with a relative error smaller than 1.2e-7.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let output = t
+        * (-z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+            .exp();
+    if x >= 0. {
+        output
+    } else {
+        2. - output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::{OsRandSeeder, ProvidedSeeder, Seed};
+
+    fn random_bytes(n_bytes: usize) -> Vec<u8> {
+        (0..n_bytes).map(|_| rand::random()).collect()
+    }
+
+    #[test]
+    fn test_erfc() {
+        assert!((erfc(0.) - 1.).abs() < 1e-7);
+        assert!((erfc(1.) - 0.157_299_207).abs() < 1e-7);
+        assert!((erfc(-1.) - 1.842_700_793).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_random_bytes_pass() {
+        assert_eq!(test_bytes(&random_bytes(1 << 20)), Ok(()));
+    }
+
+    #[test]
+    fn test_repetition_count() {
+        let mut bytes = random_bytes(1 << 16);
+        bytes[1000..1006].iter_mut().for_each(|byte| *byte = 42);
+        assert!(matches!(
+            repetition_count_test(&bytes),
+            Err(HealthTestFailure::RepetitionCount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_adaptive_proportion() {
+        let mut bytes = random_bytes(1 << 16);
+        bytes[1024..1536]
+            .iter_mut()
+            .step_by(16)
+            .for_each(|byte| *byte = 42);
+        assert_eq!(repetition_count_test(&bytes), Ok(()));
+        assert!(matches!(
+            adaptive_proportion_test(&bytes),
+            Err(HealthTestFailure::AdaptiveProportion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_monobit() {
+        let mut bytes = random_bytes(1 << 16);
+        bytes.iter_mut().step_by(8).for_each(|byte| *byte |= 0x0f);
+        assert!(matches!(
+            monobit_test(&bytes),
+            Err(HealthTestFailure::Monobit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_runs() {
+        // The bits of the sample alternate too often, while their proportion is balanced.
+        let mut bytes = random_bytes(1 << 16);
+        bytes
+            .iter_mut()
+            .step_by(4)
+            .enumerate()
+            .for_each(|(i, byte)| *byte = if i % 2 == 0 { 0x55 } else { 0xaa });
+        assert_eq!(monobit_test(&bytes), Ok(()));
+        assert!(matches!(
+            runs_test(&bytes),
+            Err(HealthTestFailure::Runs { .. })
+        ));
+    }
+
+    #[test]
+    fn test_chi_square() {
+        // The bits of the sample are balanced, but the bytes only take 16 values.
+        let bytes: Vec<u8> = random_bytes(1 << 16)
+            .into_iter()
+            .map(|byte| (byte & 0xf0) | (byte >> 4))
+            .collect();
+        assert!(matches!(
+            chi_square_test(&bytes),
+            Err(HealthTestFailure::ChiSquare { .. })
+        ));
+    }
+
+    #[test]
+    fn test_seeders() {
+        assert_eq!(test_seeder(&mut OsRandSeeder), Ok(()));
+        assert!(test_seeder(&mut ProvidedSeeder::new(Seed(rand::random()))).is_err());
+    }
+}
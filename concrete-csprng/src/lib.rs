@@ -6,21 +6,50 @@
 //! This crate contains a reasonably fast cryptographically secure pseudo-random number generator.
 //! The implementation is based on the AES blockcipher used in counter (CTR) mode, as presented
 //! in the ISO/IEC 18033-4 document.
+//!
+//! Every generator is built from a [`Seeder`](seeders::Seeder), which provides the key of the
+//! AES blockcipher. The [`seeders`] module contains seeders using the `rdseed` instruction, the
+//! entropy source of the operating system, or a seed given by the caller:
+//!
+//! ```rust
+//! use concrete_csprng::seeders::{new_seeder, ProvidedSeeder, Seed};
+//! use concrete_csprng::RandomGenerator;
+//! // A generator seeded by the best source of randomness available.
+//! let mut generator = RandomGenerator::new(new_seeder().as_mut());
+//! // Two generators seeded with the same value output the same bytes.
+//! let mut first = RandomGenerator::new(&mut ProvidedSeeder::new(Seed(42)));
+//! let mut second = RandomGenerator::new(&mut ProvidedSeeder::new(Seed(42)));
+//! assert_eq!(first.generate_next(), second.generate_next());
+//! # generator.generate_next();
+//! ```
 
 #[cfg(feature = "multithread")]
 use rayon::prelude::*;
 use std::fmt::{Debug, Display, Formatter, Result};
 
 mod aesni;
+mod chacha;
 mod counter;
+mod derivation;
+pub mod health;
+pub mod seeders;
+mod shared;
+mod snapshot;
 mod software;
-use crate::counter::{AesKey, BytesPerChild, ChildCount, HardAesCtrGenerator, SoftAesCtrGenerator};
-pub use software::set_soft_rdseed_secret;
+use crate::counter::{
+    AesCtr, AesKey, ByteCtr, BytesPerChild, ChaChaCtrGenerator, ChildCount, HardAesCtrGenerator,
+    SoftAesCtrGenerator, State,
+};
+use crate::health::HealthTestFailure;
+use crate::seeders::{Seed, Seeder};
+pub use shared::SharedRandomGenerator;
+pub use snapshot::{GeneratorSnapshot, Primitive, SnapshotError};
 
 /// The pseudorandom number generator.
 ///
 /// If the correct instructions sets are available on the machine, an hardware accelerated version
-/// of the generator can be used.  
+/// of the generator can be used. On machines lacking them, the ChaCha20 variant, built with
+/// [`RandomGenerator::new_chacha`], is faster than the software aes one.
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone)]
 pub enum RandomGenerator {
@@ -28,48 +57,271 @@ pub enum RandomGenerator {
     Software(SoftAesCtrGenerator),
     #[doc(hidden)]
     Hardware(HardAesCtrGenerator),
+    #[doc(hidden)]
+    ChaCha(ChaChaCtrGenerator),
 }
 
 impl RandomGenerator {
-    /// Builds a new random generator, selecting the hardware implementation if available.
-    /// Optionally, a seed can be provided.
+    /// Builds a new random generator seeded by the given seeder, selecting the hardware
+    /// implementation if available.
     ///
     /// # Note
     ///
     /// If using the `slow` feature, this function will return the non-accelerated variant, even
     /// though the right instructions are available.
-    pub fn new(seed: Option<u128>) -> RandomGenerator {
+    pub fn new(seeder: &mut dyn Seeder) -> RandomGenerator {
         if cfg!(feature = "slow") {
-            return RandomGenerator::new_software(seed);
+            return RandomGenerator::new_software(seeder);
+        }
+        if RandomGenerator::is_hardware_available() {
+            RandomGenerator::new_hardware(seeder).unwrap()
+        } else {
+            RandomGenerator::new_software(seeder)
         }
-        RandomGenerator::new_hardware(seed).unwrap_or_else(|| RandomGenerator::new_software(seed))
     }
 
-    /// Builds a new software random generator, optionally seeding it with a given value.
-    pub fn new_software(seed: Option<u128>) -> RandomGenerator {
-        RandomGenerator::Software(SoftAesCtrGenerator::new(seed.map(AesKey), None, None))
+    /// Builds a new software random generator seeded by the given seeder.
+    pub fn new_software(seeder: &mut dyn Seeder) -> RandomGenerator {
+        let Seed(seed) = seeder.seed();
+        RandomGenerator::Software(SoftAesCtrGenerator::new(AesKey(seed), None, None))
     }
 
-    /// Tries to build a new hardware random generator, optionally seeding it with a given value.
-    pub fn new_hardware(seed: Option<u128>) -> Option<RandomGenerator> {
-        if !is_x86_feature_detected!("aes")
-            || !is_x86_feature_detected!("rdseed")
-            || !is_x86_feature_detected!("sse2")
-        {
+    /// Tries to build a new hardware random generator seeded by the given seeder.
+    ///
+    /// If the hardware implementation is not available, `None` is returned, and the seeder is
+    /// not used.
+    pub fn new_hardware(seeder: &mut dyn Seeder) -> Option<RandomGenerator> {
+        if !RandomGenerator::is_hardware_available() {
             return None;
         }
+        let Seed(seed) = seeder.seed();
         Some(RandomGenerator::Hardware(HardAesCtrGenerator::new(
-            seed.map(AesKey),
+            AesKey(seed),
             None,
             None,
         )))
     }
 
+    /// Builds a new random generator using the ChaCha20 streamcipher, seeded by the given
+    /// seeder.
+    ///
+    /// The ChaCha20 variant does not need any particular instructions set, and is a faster
+    /// alternative to [`RandomGenerator::new_software`]. It yields different bytes than the aes
+    /// variants for the same seed.
+    pub fn new_chacha(seeder: &mut dyn Seeder) -> RandomGenerator {
+        let Seed(seed) = seeder.seed();
+        RandomGenerator::ChaCha(ChaChaCtrGenerator::new(AesKey(seed), None, None))
+    }
+
+    /// Builds a new random generator from a snapshot. For the aes primitive, the hardware
+    /// implementation is selected if available.
+    ///
+    /// The generator yields the same bytes as the one the snapshot was taken from, and has the
+    /// same bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a byte counter of the snapshot is larger than 127, or if the state of
+    /// the snapshot is past its bound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::seeders::new_seeder;
+    /// use concrete_csprng::{RandomGenerator, SnapshotError};
+    /// let mut generator = RandomGenerator::new(new_seeder().as_mut());
+    /// let mut snapshot = generator.snapshot();
+    /// let first = generator.generate_next();
+    /// let mut restored = RandomGenerator::from_snapshot(&snapshot).unwrap();
+    /// assert_eq!(restored.generate_next(), first);
+    /// snapshot.byte_counter = 128;
+    /// assert_eq!(
+    ///     RandomGenerator::from_snapshot(&snapshot).unwrap_err(),
+    ///     SnapshotError::InvalidByteCounter
+    /// );
+    /// ```
+    pub fn from_snapshot(
+        snapshot: &GeneratorSnapshot,
+    ) -> std::result::Result<RandomGenerator, SnapshotError> {
+        let byte_counters_valid = snapshot.byte_counter <= 127
+            && snapshot
+                .bound
+                .map_or(true, |(_, byte_counter)| byte_counter <= 127);
+        if !byte_counters_valid {
+            return Err(SnapshotError::InvalidByteCounter);
+        }
+        let key = AesKey(snapshot.key);
+        let state = State::new(AesCtr(snapshot.aes_counter), ByteCtr(snapshot.byte_counter));
+        let bound = snapshot
+            .bound
+            .map(|(aes_ctr, byte_ctr)| State::new(AesCtr(aes_ctr), ByteCtr(byte_ctr)));
+        if matches!(bound, Some(ref bound) if state > *bound) {
+            return Err(SnapshotError::StatePastBound);
+        }
+        let generator = match snapshot.primitive {
+            Primitive::ChaCha20 => {
+                RandomGenerator::ChaCha(ChaChaCtrGenerator::new(key, Some(state), bound))
+            }
+            Primitive::Aes128
+                if !cfg!(feature = "slow") && RandomGenerator::is_hardware_available() =>
+            {
+                RandomGenerator::Hardware(HardAesCtrGenerator::new(key, Some(state), bound))
+            }
+            Primitive::Aes128 => {
+                RandomGenerator::Software(SoftAesCtrGenerator::new(key, Some(state), bound))
+            }
+        };
+        Ok(generator)
+    }
+
+    fn is_hardware_available() -> bool {
+        is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2")
+    }
+
+    /// Returns the primitive used by the generator.
+    pub fn primitive(&self) -> Primitive {
+        match self {
+            Self::Hardware(_) | Self::Software(_) => Primitive::Aes128,
+            Self::ChaCha(_) => Primitive::ChaCha20,
+        }
+    }
+
+    /// Returns a snapshot of the key and the state of the generator.
+    pub fn snapshot(&self) -> GeneratorSnapshot {
+        let (key, state, bound) = match self {
+            Self::Hardware(rand) => (rand.get_key(), rand.get_state(), rand.get_bound()),
+            Self::Software(rand) => (rand.get_key(), rand.get_state(), rand.get_bound()),
+            Self::ChaCha(rand) => (rand.get_key(), rand.get_state(), rand.get_bound()),
+        };
+        GeneratorSnapshot {
+            primitive: self.primitive(),
+            key: key.0,
+            aes_counter: state.get_aes_counter().0,
+            byte_counter: state.get_byte_counter().0,
+            bound: bound
+                .map(State::to_normalized)
+                .map(|bound| (bound.get_aes_counter().0, bound.get_byte_counter().0)),
+        }
+    }
+
+    /// Moves the generator to the given byte offset.
+    ///
+    /// The offset is counted from the first byte yielded by an unbounded generator built with the
+    /// same seed. The next byte yielded by the generator is the same as the one it would have
+    /// yielded after `offset` bytes, regardless of the bytes generated before.
+    ///
+    /// A generator can not move before the offset it was created at, or before its last fork. This
+    /// prevents a child of [`RandomGenerator::try_fork`] from yielding the bytes of its parent or
+    /// its siblings, and a parent from yielding the bytes of its children. A generator restored
+    /// with [`RandomGenerator::from_snapshot`] starts at the offset of the snapshot.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the offset is before the start of the generator. If the generator is bounded,
+    /// panics when the offset is past the bound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::seeders::{ProvidedSeeder, Seed};
+    /// use concrete_csprng::RandomGenerator;
+    /// let mut generator = RandomGenerator::new(&mut ProvidedSeeder::new(Seed(42)));
+    /// let bytes: Vec<u8> = (0..1000).map(|_| generator.generate_next()).collect();
+    /// let mut seeking = RandomGenerator::new(&mut ProvidedSeeder::new(Seed(42)));
+    /// seeking.seek(500);
+    /// assert_eq!(seeking.generate_next(), bytes[500]);
+    /// seeking.seek(10);
+    /// assert_eq!(seeking.generate_next(), bytes[10]);
+    /// ```
+    pub fn seek(&mut self, offset: u128) {
+        let state = State::from_byte_offset(offset);
+        match self {
+            Self::Hardware(ref mut rand) => rand.seek(state),
+            Self::Software(ref mut rand) => rand.seek(state),
+            Self::ChaCha(ref mut rand) => rand.seek(state),
+        }
+    }
+
+    // Moves the generator forward to the given byte offset, which becomes its new start.
+    pub(crate) fn advance(&mut self, offset: u128) {
+        let state = State::from_byte_offset(offset);
+        match self {
+            Self::Hardware(ref mut rand) => rand.advance(state),
+            Self::Software(ref mut rand) => rand.advance(state),
+            Self::ChaCha(ref mut rand) => rand.advance(state),
+        }
+    }
+
+    // Returns the byte offset of the next byte yielded by the generator.
+    pub(crate) fn byte_offset(&self) -> u128 {
+        match self {
+            Self::Hardware(rand) => rand.get_state().to_byte_offset(),
+            Self::Software(rand) => rand.get_state().to_byte_offset(),
+            Self::ChaCha(rand) => rand.get_state().to_byte_offset(),
+        }
+    }
+
+    // Returns a child yielding the `n_bytes` bytes found at a given byte offset of the stream.
+    pub(crate) fn child_at(&self, offset: u128, n_bytes: usize) -> RandomGenerator {
+        match self {
+            Self::Hardware(rand) => RandomGenerator::Hardware(rand.child_at(offset, n_bytes)),
+            Self::Software(rand) => RandomGenerator::Software(rand.child_at(offset, n_bytes)),
+            Self::ChaCha(rand) => RandomGenerator::ChaCha(rand.child_at(offset, n_bytes)),
+        }
+    }
+
     /// Yields the next byte from the generator.
     pub fn generate_next(&mut self) -> u8 {
         match self {
             Self::Hardware(ref mut rand) => rand.generate_next(),
             Self::Software(ref mut rand) => rand.generate_next(),
+            Self::ChaCha(ref mut rand) => rand.generate_next(),
+        }
+    }
+
+    /// Fills a slice with random bytes.
+    ///
+    /// The bytes are copied from whole batches, and are the same as the ones which would be
+    /// yielded by successive calls to [`RandomGenerator::generate_next`].
+    ///
+    /// # Panics
+    ///
+    /// If the generator is bounded, panics when the slice is longer than the remaining bytes.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        match self {
+            Self::Hardware(ref mut rand) => rand.fill_bytes(bytes),
+            Self::Software(ref mut rand) => rand.fill_bytes(bytes),
+            Self::ChaCha(ref mut rand) => rand.fill_bytes(bytes),
+        }
+    }
+
+    /// Fills a slice with random `u32` values.
+    ///
+    /// Each value is built from four successive random bytes, in little endian order.
+    ///
+    /// # Panics
+    ///
+    /// If the generator is bounded, panics when the values need more than the remaining bytes.
+    pub fn fill_u32(&mut self, values: &mut [u32]) {
+        match self {
+            Self::Hardware(ref mut rand) => rand.fill_u32(values),
+            Self::Software(ref mut rand) => rand.fill_u32(values),
+            Self::ChaCha(ref mut rand) => rand.fill_u32(values),
+        }
+    }
+
+    /// Fills a slice with random `u64` values.
+    ///
+    /// Each value is built from eight successive random bytes, in little endian order.
+    ///
+    /// # Panics
+    ///
+    /// If the generator is bounded, panics when the values need more than the remaining bytes.
+    pub fn fill_u64(&mut self, values: &mut [u64]) {
+        match self {
+            Self::Hardware(ref mut rand) => rand.fill_u64(values),
+            Self::Software(ref mut rand) => rand.fill_u64(values),
+            Self::ChaCha(ref mut rand) => rand.fill_u64(values),
         }
     }
 
@@ -78,6 +330,7 @@ impl RandomGenerator {
         match self {
             Self::Hardware(rand) => rand.is_bounded(),
             Self::Software(rand) => rand.is_bounded(),
+            Self::ChaCha(rand) => rand.is_bounded(),
         }
     }
 
@@ -86,32 +339,102 @@ impl RandomGenerator {
         match self {
             Self::Hardware(rand) => rand.remaining_bytes(),
             Self::Software(rand) => rand.remaining_bytes(),
+            Self::ChaCha(rand) => rand.remaining_bytes(),
+        }
+    }
+
+    /// Derives an unbounded child generator from a label and an index.
+    ///
+    /// Contrary to [`RandomGenerator::try_fork`], the child does not draw its bytes from the
+    /// stream of the current generator. Its key is derived from the key of the current generator,
+    /// the label and the index, so that independent parts of an application can each get their
+    /// own generator without agreeing on a number of bytes beforehand. The state of the current
+    /// generator is left untouched.
+    ///
+    /// The child only depends on the key of the current generator, which is shared by all the
+    /// children of a fork. Two siblings deriving a child with the same label and index would hence
+    /// get the same bytes. To prevent this, `None` is returned if the current generator is
+    /// bounded, and children must be derived from the unbounded generator they were forked from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::seeders::new_seeder;
+    /// use concrete_csprng::RandomGenerator;
+    /// let mut generator = RandomGenerator::new(new_seeder().as_mut());
+    /// let mut first = generator.derive("mask", 0).unwrap();
+    /// let mut second = generator.derive("mask", 0).unwrap();
+    /// assert!(!first.is_bounded());
+    /// assert_eq!(first.generate_next(), second.generate_next());
+    /// let forked = generator.try_fork(1, 10).unwrap().next().unwrap();
+    /// assert!(forked.derive("mask", 0).is_none());
+    /// ```
+    pub fn derive(&self, label: &str, index: u64) -> Option<RandomGenerator> {
+        if self.is_bounded() {
+            return None;
         }
+        Some(self.derive_unchecked(label, index))
+    }
+
+    // Derives a child without checking that the current generator is unbounded.
+    fn derive_unchecked(&self, label: &str, index: u64) -> RandomGenerator {
+        match self {
+            Self::Hardware(rand) => RandomGenerator::Hardware(rand.derive_child(label, index)),
+            Self::Software(rand) => RandomGenerator::Software(rand.derive_child(label, index)),
+            Self::ChaCha(rand) => RandomGenerator::ChaCha(rand.derive_child(label, index)),
+        }
+    }
+
+    /// Runs the statistical health tests of the [`health`] module on the output of the generator.
+    ///
+    /// The tests are run on [`GENERATOR_SAMPLE_BYTES`](health::GENERATOR_SAMPLE_BYTES) bytes of a
+    /// child derived from the current generator, which uses the same primitive and the same
+    /// implementation. The state of the current generator is left untouched, and none of the
+    /// bytes it will output are examined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::seeders::new_seeder;
+    /// use concrete_csprng::RandomGenerator;
+    /// let generator = RandomGenerator::new(new_seeder().as_mut());
+    /// assert!(generator.self_test().is_ok());
+    /// ```
+    pub fn self_test(&self) -> std::result::Result<(), HealthTestFailure> {
+        let mut sample = vec![0u8; health::GENERATOR_SAMPLE_BYTES];
+        // The child is never exposed, so it can be derived from a bounded generator.
+        self.derive_unchecked("concrete-csprng self-test", 0)
+            .fill_bytes(&mut sample);
+        health::test_bytes(&sample)
     }
 
     /// Tries to fork the current generator into `n_child` generators each able to yield
     /// `child_bytes` random bytes.
     ///
-    /// If the total number of bytes to be generated exceeds the bound of the current generator,
-    /// `None` is returned. Otherwise, we return an iterator over the children generators.
+    /// If the total number of bytes to be generated exceeds the bound of the current generator, or
+    /// does not fit in a `usize`, `None` is returned. Otherwise, we return an iterator over the
+    /// children generators.
     pub fn try_fork(
         &mut self,
         n_child: usize,
         child_bytes: usize,
     ) -> Option<impl Iterator<Item = RandomGenerator>> {
-        enum GeneratorChildIter<HardIter, SoftIter>
+        enum GeneratorChildIter<HardIter, SoftIter, ChaChaIter>
         where
             HardIter: Iterator<Item = HardAesCtrGenerator>,
             SoftIter: Iterator<Item = SoftAesCtrGenerator>,
+            ChaChaIter: Iterator<Item = ChaChaCtrGenerator>,
         {
             Hardware(HardIter),
             Software(SoftIter),
+            ChaCha(ChaChaIter),
         }
 
-        impl<HardIter, SoftIter> Iterator for GeneratorChildIter<HardIter, SoftIter>
+        impl<HardIter, SoftIter, ChaChaIter> Iterator for GeneratorChildIter<HardIter, SoftIter, ChaChaIter>
         where
             HardIter: Iterator<Item = HardAesCtrGenerator>,
             SoftIter: Iterator<Item = SoftAesCtrGenerator>,
+            ChaChaIter: Iterator<Item = ChaChaCtrGenerator>,
         {
             type Item = RandomGenerator;
 
@@ -123,6 +446,9 @@ impl RandomGenerator {
                     GeneratorChildIter::Software(ref mut iter) => {
                         iter.next().map(RandomGenerator::Software)
                     }
+                    GeneratorChildIter::ChaCha(ref mut iter) => {
+                        iter.next().map(RandomGenerator::ChaCha)
+                    }
                 }
             }
         }
@@ -133,14 +459,18 @@ impl RandomGenerator {
             Self::Software(ref mut rand) => rand
                 .try_fork(ChildCount(n_child), BytesPerChild(child_bytes))
                 .map(GeneratorChildIter::Software),
+            Self::ChaCha(ref mut rand) => rand
+                .try_fork(ChildCount(n_child), BytesPerChild(child_bytes))
+                .map(GeneratorChildIter::ChaCha),
         }
     }
 
     /// Tries to fork the current generator into `n_child` generators each able to yield
     /// `child_bytes` random bytes as a parallel iterator.
     ///
-    /// If the total number of bytes to be generated exceeds the bound of the current generator,
-    /// `None` is returned. Otherwise, we return a parallel iterator over the children generators.
+    /// If the total number of bytes to be generated exceeds the bound of the current generator, or
+    /// does not fit in a `usize`, `None` is returned. Otherwise, we return a parallel iterator
+    /// over the children generators.
     ///
     /// # Notes
     ///
@@ -152,18 +482,22 @@ impl RandomGenerator {
         child_bytes: usize,
     ) -> Option<impl IndexedParallelIterator<Item = RandomGenerator>> {
         use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
-        enum GeneratorChildIter<HardIter, SoftIter>
+        enum GeneratorChildIter<HardIter, SoftIter, ChaChaIter>
         where
             HardIter: IndexedParallelIterator<Item = HardAesCtrGenerator> + Send + Sync,
             SoftIter: IndexedParallelIterator<Item = SoftAesCtrGenerator> + Send + Sync,
+            ChaChaIter: IndexedParallelIterator<Item = ChaChaCtrGenerator> + Send + Sync,
         {
             Hardware(HardIter),
             Software(SoftIter),
+            ChaCha(ChaChaIter),
         }
-        impl<HardIter, SoftIter> ParallelIterator for GeneratorChildIter<HardIter, SoftIter>
+        impl<HardIter, SoftIter, ChaChaIter> ParallelIterator
+            for GeneratorChildIter<HardIter, SoftIter, ChaChaIter>
         where
             HardIter: IndexedParallelIterator<Item = HardAesCtrGenerator> + Send + Sync,
             SoftIter: IndexedParallelIterator<Item = SoftAesCtrGenerator> + Send + Sync,
+            ChaChaIter: IndexedParallelIterator<Item = ChaChaCtrGenerator> + Send + Sync,
         {
             type Item = RandomGenerator;
             fn drive_unindexed<C>(self, consumer: C) -> <C as Consumer<Self::Item>>::Result
@@ -177,18 +511,24 @@ impl RandomGenerator {
                     Self::Software(iter) => iter
                         .map(RandomGenerator::Software)
                         .drive_unindexed(consumer),
+                    Self::ChaCha(iter) => {
+                        iter.map(RandomGenerator::ChaCha).drive_unindexed(consumer)
+                    }
                 }
             }
         }
-        impl<HardIter, SoftIter> IndexedParallelIterator for GeneratorChildIter<HardIter, SoftIter>
+        impl<HardIter, SoftIter, ChaChaIter> IndexedParallelIterator
+            for GeneratorChildIter<HardIter, SoftIter, ChaChaIter>
         where
             HardIter: IndexedParallelIterator<Item = HardAesCtrGenerator> + Send + Sync,
             SoftIter: IndexedParallelIterator<Item = SoftAesCtrGenerator> + Send + Sync,
+            ChaChaIter: IndexedParallelIterator<Item = ChaChaCtrGenerator> + Send + Sync,
         {
             fn len(&self) -> usize {
                 match self {
                     Self::Software(iter) => iter.len(),
                     Self::Hardware(iter) => iter.len(),
+                    Self::ChaCha(iter) => iter.len(),
                 }
             }
             fn drive<C: Consumer<Self::Item>>(
@@ -198,6 +538,7 @@ impl RandomGenerator {
                 match self {
                     Self::Software(iter) => iter.map(RandomGenerator::Software).drive(consumer),
                     Self::Hardware(iter) => iter.map(RandomGenerator::Hardware).drive(consumer),
+                    Self::ChaCha(iter) => iter.map(RandomGenerator::ChaCha).drive(consumer),
                 }
             }
             fn with_producer<CB: ProducerCallback<Self::Item>>(
@@ -211,6 +552,7 @@ impl RandomGenerator {
                     Self::Hardware(iter) => {
                         iter.map(RandomGenerator::Hardware).with_producer(callback)
                     }
+                    Self::ChaCha(iter) => iter.map(RandomGenerator::ChaCha).with_producer(callback),
                 }
             }
         }
@@ -222,6 +564,9 @@ impl RandomGenerator {
             Self::Software(ref mut rand) => rand
                 .par_try_fork(ChildCount(n_child), BytesPerChild(child_bytes))
                 .map(GeneratorChildIter::Software),
+            Self::ChaCha(ref mut rand) => rand
+                .par_try_fork(ChildCount(n_child), BytesPerChild(child_bytes))
+                .map(GeneratorChildIter::ChaCha),
         }
     }
 }
@@ -238,16 +583,67 @@ impl Display for RandomGenerator {
     }
 }
 
+/// Allows to use the generator with the `rand` ecosystem.
+///
+/// # Notes
+///
+/// This implementation necessitates the "rand_core" feature. Generating values with a bounded
+/// generator panics if the bound is exceeded, including in `try_fill_bytes`.
+#[cfg(feature = "rand_core")]
+impl rand_core::RngCore for RandomGenerator {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        RandomGenerator::fill_bytes(self, &mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        RandomGenerator::fill_bytes(self, &mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        RandomGenerator::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> std::result::Result<(), rand_core::Error> {
+        rand_core::RngCore::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::CryptoRng for RandomGenerator {}
+
+/// Allows to build a generator from a seed of 16 bytes, as a
+/// [`ProvidedSeeder`](seeders::ProvidedSeeder) would.
+///
+/// # Notes
+///
+/// This implementation necessitates the "rand_core" feature.
+#[cfg(feature = "rand_core")]
+impl rand_core::SeedableRng for RandomGenerator {
+    type Seed = [u8; 16];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        RandomGenerator::new(&mut seeders::ProvidedSeeder::new(Seed(
+            u128::from_le_bytes(seed),
+        )))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::seeders::{new_seeder, DeterministicSeeder, OsRandSeeder, ProvidedSeeder};
 
     #[test]
     fn test_uniformity() {
         // Checks that the PRNG generates uniform numbers
         let precision = 10f64.powi(-4);
         let n_samples = 10_000_000_usize;
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = RandomGenerator::new(new_seeder().as_mut());
         let mut counts = [0usize; 256];
         let expected_prob: f64 = 1. / 256.;
         for _ in 0..n_samples {
@@ -263,9 +659,9 @@ mod test {
     fn test_generator_determinism() {
         // Checks that given a state and a key, the PRNG is determinist.
         for _ in 0..100 {
-            let key = software::dev_random();
-            let mut first_generator = RandomGenerator::new(Some(key));
-            let mut second_generator = RandomGenerator::new(Some(key));
+            let seed = OsRandSeeder.seed();
+            let mut first_generator = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+            let mut second_generator = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
             for _ in 0..128 {
                 assert_eq!(
                     first_generator.generate_next(),
@@ -275,11 +671,247 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_deterministic_seeder() {
+        // Checks that generators built from a deterministic seeder are reproducible, and that
+        // successive generators built from the same seeder output different bytes.
+        let mut first_seeder = DeterministicSeeder::new(Seed(0));
+        let mut second_seeder = DeterministicSeeder::new(Seed(0));
+        let mut outputs = Vec::new();
+        for _ in 0..2 {
+            let mut first_generator = RandomGenerator::new(&mut first_seeder);
+            let mut second_generator = RandomGenerator::new(&mut second_seeder);
+            let first_output: Vec<u8> = (0..128).map(|_| first_generator.generate_next()).collect();
+            let second_output: Vec<u8> =
+                (0..128).map(|_| second_generator.generate_next()).collect();
+            assert_eq!(first_output, second_output);
+            outputs.push(first_output);
+        }
+        assert_ne!(outputs[0], outputs[1]);
+    }
+
+    #[test]
+    fn test_fill() {
+        // Checks that the typed fill methods output the same bytes as `generate_next`.
+        let seed = OsRandSeeder.seed();
+        let mut generator = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        let mut reference = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        let mut values_u32 = [0u32; 50];
+        generator.fill_u32(&mut values_u32);
+        for value in values_u32.iter() {
+            let bytes = [0u8; 4].map(|_| reference.generate_next());
+            assert_eq!(*value, u32::from_le_bytes(bytes));
+        }
+        let mut values_u64 = [0u64; 50];
+        generator.fill_u64(&mut values_u64);
+        for value in values_u64.iter() {
+            let bytes = [0u8; 8].map(|_| reference.generate_next());
+            assert_eq!(*value, u64::from_le_bytes(bytes));
+        }
+        assert_eq!(generator.generate_next(), reference.generate_next());
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_rand_core() {
+        // Checks that the `rand_core` traits output the same bytes as `generate_next`, and that
+        // the generator can be used with the `rand` distributions.
+        use rand::seq::SliceRandom;
+        use rand::Rng;
+        use rand_core::{RngCore, SeedableRng};
+        let mut generator = RandomGenerator::from_seed([1u8; 16]);
+        let mut reference = RandomGenerator::new(&mut ProvidedSeeder::new(Seed(
+            u128::from_le_bytes([1u8; 16]),
+        )));
+        let mut bytes = [0u8; 300];
+        generator.fill_bytes(&mut bytes);
+        assert!(bytes.iter().all(|byte| *byte == reference.generate_next()));
+        let expected = u64::from_le_bytes([0u8; 8].map(|_| reference.generate_next()));
+        assert_eq!(generator.next_u64(), expected);
+        let value = generator.gen_range(0..10u32);
+        assert!(value < 10);
+        let mut values: Vec<u32> = (0..100).collect();
+        values.shuffle(&mut generator);
+        values.sort_unstable();
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_invalid_snapshot() {
+        let mut generator = RandomGenerator::new(new_seeder().as_mut());
+        let child = generator.try_fork(1, 100).unwrap().next().unwrap();
+        let snapshot = child.snapshot();
+        let (bound_aes_counter, bound_byte_counter) = snapshot.bound.unwrap();
+        let invalid_snapshots = [
+            (
+                GeneratorSnapshot {
+                    byte_counter: 128,
+                    ..snapshot
+                },
+                SnapshotError::InvalidByteCounter,
+            ),
+            (
+                GeneratorSnapshot {
+                    bound: Some((bound_aes_counter, 200)),
+                    ..snapshot
+                },
+                SnapshotError::InvalidByteCounter,
+            ),
+            (
+                GeneratorSnapshot {
+                    aes_counter: bound_aes_counter + 1,
+                    byte_counter: bound_byte_counter,
+                    ..snapshot
+                },
+                SnapshotError::StatePastBound,
+            ),
+        ];
+        for (snapshot, error) in invalid_snapshots.iter() {
+            assert_eq!(
+                RandomGenerator::from_snapshot(snapshot).unwrap_err(),
+                *error
+            );
+        }
+    }
+
+    #[test]
+    fn test_snapshot() {
+        // Checks that a generator restored from a snapshot yields the same bytes and has the
+        // same bound as the original one, including for the children of a fork.
+        let mut generator = RandomGenerator::new(new_seeder().as_mut());
+        (0..1000).for_each(|_| {
+            generator.generate_next();
+        });
+        let mut children = generator.try_fork(3, 300).unwrap().collect::<Vec<_>>();
+        for generator in children.iter_mut().chain(std::iter::once(&mut generator)) {
+            generator.generate_next();
+            let mut restored = RandomGenerator::from_snapshot(&generator.snapshot()).unwrap();
+            assert_eq!(restored.remaining_bytes(), generator.remaining_bytes());
+            let expected: Vec<u8> = (0..299).map(|_| generator.generate_next()).collect();
+            let output: Vec<u8> = (0..299).map(|_| restored.generate_next()).collect();
+            assert_eq!(expected, output);
+        }
+    }
+
+    #[test]
+    fn test_seek() {
+        // Checks that seeking to random offsets yields the same bytes as generating the bytes in
+        // sequence.
+        let seed = OsRandSeeder.seed();
+        let mut generator = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        let bytes: Vec<u8> = (0..10_000).map(|_| generator.generate_next()).collect();
+        let mut seeking = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        for offset in [9_999, 0, 127, 128, 4_000, 129, 1_000].iter() {
+            seeking.seek(*offset as u128);
+            let output: Vec<u8> = (0..bytes.len() - offset)
+                .map(|_| seeking.generate_next())
+                .collect();
+            assert_eq!(output[..], bytes[*offset..]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_child_seek_before_start_panic() {
+        // Checks that a child can not move back to the bytes of its parent or its siblings.
+        let mut gen = RandomGenerator::new(new_seeder().as_mut());
+        gen.seek(1000);
+        let mut child = gen.try_fork(2, 100).unwrap().nth(1).unwrap();
+        child.seek(1099);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parent_seek_before_fork_panic() {
+        // Checks that a parent can not move back to the bytes given to its children.
+        let mut gen = RandomGenerator::new(new_seeder().as_mut());
+        let _children = gen.try_fork(2, 100).unwrap();
+        gen.seek(150);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bounded_panic_across_batches() {
+        // Checks that a bounded prng whose bound is in another batch panics when exceeding the
+        // allowed number of bytes.
+        let mut gen = RandomGenerator::new(new_seeder().as_mut());
+        gen.generate_next();
+        let mut bounded = gen.try_fork(1, 200).unwrap().next().unwrap();
+        for _ in 0..201 {
+            bounded.generate_next();
+        }
+    }
+
+    #[test]
+    fn test_derive() {
+        // Checks that derived children are reproducible, independent from the state of the
+        // parent, different for different labels and indices, and refused to bounded generators.
+        let seed = OsRandSeeder.seed();
+        let mut generator = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        let collect = |mut child: RandomGenerator| -> Vec<u8> {
+            (0..64).map(|_| child.generate_next()).collect()
+        };
+        let reference = collect(generator.derive("mask", 1).unwrap());
+        (0..1000).for_each(|_| {
+            generator.generate_next();
+        });
+        let forked = generator.try_fork(2, 10).unwrap().next().unwrap();
+        assert_eq!(collect(generator.derive("mask", 1).unwrap()), reference);
+        assert!(forked.derive("mask", 1).is_none());
+        assert_ne!(collect(generator.derive("mask", 2).unwrap()), reference);
+        assert_ne!(collect(generator.derive("noise", 1).unwrap()), reference);
+        let parent = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        assert_ne!(collect(parent), reference);
+    }
+
+    #[test]
+    fn test_chacha() {
+        // Checks that the ChaCha20 generator is deterministic, forks properly, and is restored
+        // from a snapshot with the same primitive.
+        let seed = OsRandSeeder.seed();
+        let mut generator = RandomGenerator::new_chacha(&mut ProvidedSeeder::new(seed));
+        let mut reference = RandomGenerator::new_chacha(&mut ProvidedSeeder::new(seed));
+        assert_eq!(generator.primitive(), Primitive::ChaCha20);
+        let expected: Vec<u8> = (0..1000).map(|_| reference.generate_next()).collect();
+        let output: Vec<u8> = generator
+            .try_fork(10, 100)
+            .unwrap()
+            .flat_map(|mut child| (0..100).map(move |_| child.generate_next()))
+            .collect();
+        assert_eq!(output, expected);
+        let mut restored = RandomGenerator::from_snapshot(&generator.snapshot()).unwrap();
+        assert_eq!(restored.primitive(), Primitive::ChaCha20);
+        assert_eq!(restored.generate_next(), reference.generate_next());
+        let mut aes = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        assert_ne!(
+            (0..16).map(|_| aes.generate_next()).collect::<Vec<_>>(),
+            expected[..16]
+        );
+    }
+
+    #[test]
+    fn test_self_test() {
+        // Checks that the self test passes for every primitive, and leaves the state untouched.
+        let seed = OsRandSeeder.seed();
+        let mut generator = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        let mut reference = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        assert_eq!(generator.self_test(), Ok(()));
+        assert_eq!(generator.generate_next(), reference.generate_next());
+        assert_eq!(
+            RandomGenerator::new_software(&mut OsRandSeeder).self_test(),
+            Ok(())
+        );
+        assert_eq!(
+            RandomGenerator::new_chacha(&mut OsRandSeeder).self_test(),
+            Ok(())
+        );
+    }
+
     #[test]
     fn test_fork() {
         // Checks that forks returns a bounded child, and that the proper number of bytes can be
         // generated.
-        let mut gen = RandomGenerator::new(None);
+        let mut gen = RandomGenerator::new(new_seeder().as_mut());
         let mut bounded = gen.try_fork(1, 10).unwrap().next().unwrap();
         assert!(bounded.is_bounded());
         assert!(!gen.is_bounded());
@@ -288,11 +920,20 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_fork_overflow() {
+        // Checks that a fork whose total number of bytes overflows is refused.
+        let mut gen = RandomGenerator::new(new_seeder().as_mut());
+        assert!(gen.try_fork(usize::MAX / 2, 3).is_none());
+        let mut bounded = gen.try_fork(1, 10).unwrap().next().unwrap();
+        assert!(bounded.try_fork(usize::MAX / 2, 3).is_none());
+    }
+
     #[test]
     #[should_panic]
     fn test_bounded_panic() {
         // Checks that a bounded prng panics when exceeding the allowed number of bytes.
-        let mut gen = RandomGenerator::new(None);
+        let mut gen = RandomGenerator::new(new_seeder().as_mut());
         let mut bounded = gen.try_fork(1, 10).unwrap().next().unwrap();
         assert!(bounded.is_bounded());
         assert!(!gen.is_bounded());
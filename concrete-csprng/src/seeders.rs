@@ -0,0 +1,199 @@
+//! A module containing the seeders used to initialize the random generators.
+//!
+//! A [`Seeder`] is a source of [`Seed`]s. Every random generator is built from a seeder, which
+//! makes the origin of the randomness explicit at the construction site. The following seeders
+//! are available:
+//!
+//! + [`RdseedSeeder`] uses the `rdseed` instruction of x86_64 cpus.
+//! + [`OsRandSeeder`] uses the entropy source of the operating system.
+//! + [`ProvidedSeeder`] always returns a seed given by the caller.
+//! + [`DeterministicSeeder`] returns a reproducible sequence of seeds, for testing purpose.
+//!
+//! The [`new_seeder`] function returns the best seeder available on the current machine.
+
+/// A seed value, used to initialize a random generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Seed(pub u128);
+
+/// A trait representing a source of seeds.
+pub trait Seeder {
+    /// Generates a new seed.
+    fn seed(&mut self) -> Seed;
+
+    /// Returns whether the seeder can be used on the current machine.
+    fn is_available() -> bool
+    where
+        Self: Sized;
+}
+
+/// Returns the best seeder available on the current machine.
+///
+/// The `rdseed` instruction is used if available. Otherwise, the seeds are taken from the
+/// operating system.
+pub fn new_seeder() -> Box<dyn Seeder> {
+    if RdseedSeeder::is_available() {
+        Box::new(RdseedSeeder)
+    } else {
+        Box::new(OsRandSeeder)
+    }
+}
+
+/// A seeder which uses the `rdseed` hardware instruction.
+///
+/// # Panics
+///
+/// Generating a seed panics if the `rdseed` instruction is not available on the machine. This
+/// can be checked beforehand with [`RdseedSeeder::is_available`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RdseedSeeder;
+
+impl Seeder for RdseedSeeder {
+    fn seed(&mut self) -> Seed {
+        assert!(
+            Self::is_available(),
+            "The `rdseed` instruction is not available on this machine."
+        );
+        Seed(rdseed_random_u128())
+    }
+
+    fn is_available() -> bool {
+        is_x86_feature_detected!("rdseed")
+    }
+}
+
+// Generates a random 128 bits value from rdseed.
+fn rdseed_random_u128() -> u128 {
+    let mut rand1: u64 = 0;
+    let mut rand2: u64 = 0;
+    unsafe {
+        loop {
+            if core::arch::x86_64::_rdseed64_step(&mut rand1) == 1 {
+                break;
+            }
+        }
+        loop {
+            if core::arch::x86_64::_rdseed64_step(&mut rand2) == 1 {
+                break;
+            }
+        }
+    }
+    ((rand2 as u128) << 64) | (rand1 as u128)
+}
+
+/// A seeder which uses the entropy source of the operating system.
+///
+/// # Panics
+///
+/// Generating a seed panics if the operating system fails to provide random bytes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsRandSeeder;
+
+impl Seeder for OsRandSeeder {
+    fn seed(&mut self) -> Seed {
+        let mut buffer = [0u8; 16];
+        getrandom::getrandom(&mut buffer[..])
+            .expect("Failed to get random bytes from the operating system.");
+        Seed(u128::from_ne_bytes(buffer))
+    }
+
+    fn is_available() -> bool {
+        true
+    }
+}
+
+/// A seeder which always returns a seed provided by the caller.
+///
+/// Every call to [`Seeder::seed`] returns the same value. Two generators built from the same
+/// provided seeder will hence output the same bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct ProvidedSeeder(Seed);
+
+impl ProvidedSeeder {
+    /// Creates a seeder returning the given seed.
+    pub fn new(seed: Seed) -> ProvidedSeeder {
+        ProvidedSeeder(seed)
+    }
+}
+
+impl Seeder for ProvidedSeeder {
+    fn seed(&mut self) -> Seed {
+        self.0
+    }
+
+    fn is_available() -> bool {
+        true
+    }
+}
+
+/// A seeder which returns a reproducible sequence of seeds, for testing purpose.
+///
+/// The seeder starts from an initial seed, and increments it at each call to [`Seeder::seed`].
+/// Contrary to the [`ProvidedSeeder`], two generators built from the same deterministic seeder
+/// will output different bytes, while the whole sequence stays reproducible.
+///
+/// # Note
+///
+/// This seeder must not be used to generate secret values.
+#[derive(Debug, Clone, Copy)]
+pub struct DeterministicSeeder {
+    next: u128,
+}
+
+impl DeterministicSeeder {
+    /// Creates a seeder whose sequence starts with the given seed.
+    pub fn new(Seed(initial): Seed) -> DeterministicSeeder {
+        DeterministicSeeder { next: initial }
+    }
+}
+
+impl Seeder for DeterministicSeeder {
+    fn seed(&mut self) -> Seed {
+        let output = Seed(self.next);
+        self.next = self.next.wrapping_add(1);
+        output
+    }
+
+    fn is_available() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_seeds_differ<S: Seeder>(mut seeder: S) {
+        let first = seeder.seed();
+        let second = seeder.seed();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_rdseed_seeder() {
+        if RdseedSeeder::is_available() {
+            check_seeds_differ(RdseedSeeder);
+        }
+    }
+
+    #[test]
+    fn test_os_rand_seeder() {
+        check_seeds_differ(OsRandSeeder);
+    }
+
+    #[test]
+    fn test_provided_seeder() {
+        let mut seeder = ProvidedSeeder::new(Seed(42));
+        assert_eq!(seeder.seed(), Seed(42));
+        assert_eq!(seeder.seed(), Seed(42));
+    }
+
+    #[test]
+    fn test_deterministic_seeder() {
+        let mut first = DeterministicSeeder::new(Seed(u128::MAX));
+        let mut second = DeterministicSeeder::new(Seed(u128::MAX));
+        for _ in 0..10 {
+            assert_eq!(first.seed(), second.seed());
+        }
+        check_seeds_differ(first);
+    }
+}
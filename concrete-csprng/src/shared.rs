@@ -0,0 +1,210 @@
+//! A module containing a random generator which can be shared between threads.
+use crate::RandomGenerator;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A random generator handing out child generators to several threads.
+///
+/// Contrary to [`RandomGenerator::try_fork`], the number and the size of the children do not need
+/// to be known beforehand. Each call to [`SharedRandomGenerator::try_reserve`] atomically reserves
+/// the next bytes of the stream of the wrapped generator, and returns a child bounded to them.
+/// The reservation does not take any lock, which allows every thread of a parallel computation
+/// to draw the randomness it needs, when it needs it.
+///
+/// The children yield disjoint parts of the stream of the wrapped generator. The part given to a
+/// child depends on the order of the reservations, which is only deterministic if the
+/// reservations are made from a single thread.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_csprng::seeders::new_seeder;
+/// use concrete_csprng::{RandomGenerator, SharedRandomGenerator};
+/// let shared = SharedRandomGenerator::new(RandomGenerator::new(new_seeder().as_mut()));
+/// std::thread::scope(|scope| {
+///     for n_bytes in [10, 100, 1000] {
+///         let shared = &shared;
+///         scope.spawn(move || {
+///             let mut child = shared.try_reserve(n_bytes).unwrap();
+///             let mut bytes = vec![0u8; n_bytes];
+///             child.fill_bytes(&mut bytes);
+///         });
+///     }
+/// });
+/// let generator = shared.into_generator();
+/// ```
+pub struct SharedRandomGenerator {
+    // The wrapped generator, whose state is the one at the creation of the shared generator.
+    generator: RandomGenerator,
+    // The byte offset of the state of the wrapped generator.
+    start: u128,
+    // The maximum number of bytes which can be reserved.
+    limit: u64,
+    // The number of bytes reserved so far.
+    reserved: AtomicU64,
+}
+
+impl SharedRandomGenerator {
+    /// Wraps a generator to share it between threads.
+    ///
+    /// If the generator is bounded, the reservations are limited to its remaining bytes.
+    pub fn new(generator: RandomGenerator) -> SharedRandomGenerator {
+        let start = generator.byte_offset();
+        let limit = generator
+            .remaining_bytes()
+            .map_or(u64::MAX, |bytes| bytes as u64);
+        SharedRandomGenerator {
+            generator,
+            start,
+            limit,
+            reserved: AtomicU64::new(0),
+        }
+    }
+
+    /// Tries to reserve the next `n_bytes` bytes of the stream.
+    ///
+    /// If the bytes exceed the bound of the wrapped generator, `None` is returned. Otherwise, we
+    /// return a child generator able to yield exactly those bytes.
+    pub fn try_reserve(&self, n_bytes: usize) -> Option<RandomGenerator> {
+        let limit = self.limit;
+        self.reserved
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |reserved| {
+                reserved
+                    .checked_add(n_bytes as u64)
+                    .filter(|end| *end <= limit)
+            })
+            .ok()
+            .map(|offset| {
+                self.generator
+                    .child_at(self.start.wrapping_add(offset as u128), n_bytes)
+            })
+    }
+
+    /// Returns the number of bytes which can still be reserved, if the wrapped generator is
+    /// bounded.
+    pub fn remaining_bytes(&self) -> Option<usize> {
+        self.generator
+            .is_bounded()
+            .then(|| (self.limit - self.reserved.load(Ordering::Relaxed)) as usize)
+    }
+
+    /// Returns the wrapped generator, moved past all the reserved bytes.
+    ///
+    /// As after a fork, the returned generator can not seek back to the reserved bytes.
+    pub fn into_generator(self) -> RandomGenerator {
+        let SharedRandomGenerator {
+            mut generator,
+            start,
+            reserved,
+            ..
+        } = self;
+        generator.advance(start.wrapping_add(reserved.into_inner() as u128));
+        generator
+    }
+}
+
+impl Debug for SharedRandomGenerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "SharedRandomGenerator")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::{OsRandSeeder, ProvidedSeeder, Seeder};
+
+    #[test]
+    fn test_sequential_reservations() {
+        // Checks that the children yield the successive bytes of the wrapped generator, and that
+        // the wrapped generator resumes after them.
+        let seed = OsRandSeeder.seed();
+        let mut reference = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        let mut generator = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        (0..37).for_each(|_| {
+            reference.generate_next();
+            generator.generate_next();
+        });
+        let expected: Vec<u8> = (0..1000).map(|_| reference.generate_next()).collect();
+        let shared = SharedRandomGenerator::new(generator);
+        let output: Vec<u8> = [1, 127, 128, 255, 489]
+            .iter()
+            .flat_map(|n_bytes| {
+                let mut child = shared.try_reserve(*n_bytes).unwrap();
+                assert_eq!(child.remaining_bytes(), Some(*n_bytes));
+                (0..*n_bytes).map(move |_| child.generate_next())
+            })
+            .collect();
+        assert_eq!(output, expected);
+        assert_eq!(
+            shared.into_generator().generate_next(),
+            reference.generate_next()
+        );
+    }
+
+    #[test]
+    fn test_bounded_reservations() {
+        let mut generator = RandomGenerator::new(&mut OsRandSeeder);
+        let bounded = generator.try_fork(1, 100).unwrap().next().unwrap();
+        let shared = SharedRandomGenerator::new(bounded);
+        assert_eq!(shared.remaining_bytes(), Some(100));
+        assert!(shared.try_reserve(60).is_some());
+        assert!(shared.try_reserve(60).is_none());
+        assert!(shared.try_reserve(40).is_some());
+        assert_eq!(shared.remaining_bytes(), Some(0));
+        let generator = shared.into_generator();
+        assert_eq!(generator.remaining_bytes(), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_seek_reserved_bytes_panic() {
+        // Checks that the wrapped generator can not move back to the bytes of the children.
+        let shared = SharedRandomGenerator::new(RandomGenerator::new(&mut OsRandSeeder));
+        let _child = shared.try_reserve(100);
+        shared.into_generator().seek(50);
+    }
+
+    #[test]
+    fn test_concurrent_reservations() {
+        // Checks that the children reserved concurrently yield disjoint parts of the stream of the
+        // wrapped generator.
+        let n_threads = 8;
+        let n_children = 100;
+        let child_bytes = 100;
+        let seed = OsRandSeeder.seed();
+        let mut reference = RandomGenerator::new(&mut ProvidedSeeder::new(seed));
+        let mut expected: Vec<Vec<u8>> = (0..n_threads * n_children)
+            .map(|_| {
+                (0..child_bytes)
+                    .map(|_| reference.generate_next())
+                    .collect()
+            })
+            .collect();
+        let shared =
+            SharedRandomGenerator::new(RandomGenerator::new(&mut ProvidedSeeder::new(seed)));
+        let mut output: Vec<Vec<u8>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..n_threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..n_children)
+                            .map(|_| {
+                                let mut child = shared.try_reserve(child_bytes).unwrap();
+                                let mut bytes = vec![0u8; child_bytes];
+                                child.fill_bytes(&mut bytes);
+                                bytes
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        expected.sort();
+        output.sort();
+        assert_eq!(output, expected);
+    }
+}
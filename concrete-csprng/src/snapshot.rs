@@ -0,0 +1,72 @@
+//! A module containing the snapshot of a random generator, and the primitives it can use.
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The primitives a [`RandomGenerator`](crate::RandomGenerator) can be built upon.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Primitive {
+    /// The AES-128 blockcipher in counter mode.
+    Aes128,
+    /// The ChaCha20 streamcipher.
+    ChaCha20,
+}
+
+/// A snapshot of the key and state of a [`RandomGenerator`](crate::RandomGenerator).
+///
+/// A snapshot is taken with [`RandomGenerator::snapshot`](crate::RandomGenerator::snapshot), and
+/// allows to build a generator yielding the same bytes later on, with
+/// [`RandomGenerator::from_snapshot`](crate::RandomGenerator::from_snapshot). With the
+/// `serde_serialize` feature, the snapshot can be serialized, for instance to resume a long
+/// computation in another process.
+///
+/// # Note
+///
+/// The snapshot contains the key of the generator, and must be stored as securely as the values
+/// generated with it.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneratorSnapshot {
+    /// The primitive used by the generator.
+    pub primitive: Primitive,
+    /// The key of the primitive.
+    pub key: u128,
+    /// The counter of the batch containing the next byte, in blocks of 16 bytes.
+    pub aes_counter: u128,
+    /// The index of the next byte in the batch.
+    pub byte_counter: u8,
+    /// The block and byte counters of the bound, if the generator is bounded.
+    pub bound: Option<(u128, u8)>,
+}
+
+/// The reasons why a generator can not be restored from a snapshot.
+///
+/// Since a snapshot can be deserialized from an untrusted source, its fields are checked before
+/// the generator is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The byte counter of the state or of the bound is larger than 127.
+    InvalidByteCounter,
+    /// The state of the generator is past its bound.
+    StatePastBound,
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotError::InvalidByteCounter => {
+                write!(
+                    f,
+                    "The byte counters of the snapshot must be smaller than 128."
+                )
+            }
+            SnapshotError::StatePastBound => {
+                write!(f, "The state of the snapshot is past its bound.")
+            }
+        }
+    }
+}
+
+impl Error for SnapshotError {}
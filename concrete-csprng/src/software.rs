@@ -3,61 +3,6 @@ use crate::counter::{AesBatchedGenerator, AesCtr, AesKey};
 use aes_soft::cipher::generic_array::GenericArray;
 use aes_soft::cipher::{BlockCipher, NewBlockCipher};
 use aes_soft::Aes128;
-use std::cell::UnsafeCell;
-use std::io::Read;
-
-thread_local! {
-    static RDSEED_COUNTER: UnsafeCell<u128> = UnsafeCell::new(
-        std::time::UNIX_EPOCH
-            .elapsed()
-            .expect("Failed to initialized software rdseed counter.")
-            .as_nanos()
-    );
-    static RDSEED_SECRET: UnsafeCell<u128> = UnsafeCell::new(0);
-    static RDSEED_SEEDED: UnsafeCell<bool> = UnsafeCell::new(false);
-}
-
-/// Sets the secret used to seed the software version of the prng.
-///
-/// When using the software variant of the CSPRNG, we do not have access to the (trusted)
-/// hardware source of randomness to seed the generator. Instead, we use a value from
-/// `/dev/random`, which can be easy to temper with. To mitigate this risk, the user can provide
-/// a secret value that is included in the seed of the prng. Note that to ensure maximal
-/// security, this value should be different each time a new application using concrete is started.
-pub fn set_soft_rdseed_secret(secret: u128) {
-    RDSEED_SECRET.with(|f| {
-        let _secret = unsafe { &mut *{ f.get() } };
-        *_secret = secret;
-    });
-    RDSEED_SEEDED.with(|f| {
-        let _seeded = unsafe { &mut *{ f.get() } };
-        *_seeded = true;
-    })
-}
-
-fn rdseed() -> u128 {
-    RDSEED_SEEDED.with(|f| {
-        let is_seeded = unsafe { &*{ f.get() } };
-        if !*is_seeded {
-            println!(
-                "WARNING: You are currently using the software variant of concrete-csprng \
-                which does not have access to a hardware source of randomness. To ensure the \
-                security of your application, please arrange to provide a secret by using the \
-                `concrete_csprng::set_soft_rdseed_secret` function."
-            );
-        }
-    });
-    let mut output: u128 = 0;
-    RDSEED_SECRET.with(|f| {
-        let secret = unsafe { &*{ f.get() } };
-        RDSEED_COUNTER.with(|f| {
-            let counter = unsafe { &mut *{ f.get() } };
-            output = *secret ^ *counter ^ dev_random();
-            *counter = counter.wrapping_add(1);
-        })
-    });
-    output
-}
 
 #[derive(Clone)]
 pub struct Generator {
@@ -66,8 +11,8 @@ pub struct Generator {
 }
 
 impl AesBatchedGenerator for Generator {
-    fn new(key: Option<AesKey>) -> Generator {
-        let key: [u8; 16] = key.map(|AesKey(k)| k).unwrap_or_else(rdseed).to_ne_bytes();
+    fn new(AesKey(key): AesKey) -> Generator {
+        let key: [u8; 16] = key.to_ne_bytes();
         let key = GenericArray::clone_from_slice(&key[..]);
         let aes = Aes128::new(&key);
         Generator { aes }
@@ -88,15 +33,6 @@ impl AesBatchedGenerator for Generator {
     }
 }
 
-pub fn dev_random() -> u128 {
-    let mut random = std::fs::File::open("/dev/random").expect("Failed to open /dev/random .");
-    let mut buf = [0u8; 16];
-    random
-        .read_exact(&mut buf[..])
-        .expect("Failed to read from /dev/random .");
-    u128::from_ne_bytes(buf)
-}
-
 // Uses aes to encrypt many values at once. This allows a substantial speedup (around 30%)
 // compared to the naive approach.
 #[allow(clippy::too_many_arguments)]
@@ -176,7 +112,7 @@ mod test {
         // Checks that the PRNG generates uniform numbers
         let precision = 10f64.powi(-4);
         let n_samples = 1_000_000_usize;
-        let mut generator = Generator::new(None);
+        let mut generator = Generator::new(AesKey(rand::random()));
         let mut counts = [0usize; 256];
         let expected_prob: f64 = 1. / 256.;
         for counter in 0..n_samples {